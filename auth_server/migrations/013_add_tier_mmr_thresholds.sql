@@ -0,0 +1,11 @@
+-- MMR thresholds for tiers (a player belongs to the highest order_key whose min_mmr <= mmr)
+ALTER TABLE tiers ADD COLUMN IF NOT EXISTS min_mmr DOUBLE PRECISION NOT NULL DEFAULT 0;
+COMMENT ON COLUMN tiers.min_mmr IS 'Minimum Glicko-2 mmr required for this tier';
+UPDATE tiers SET min_mmr = CASE order_key
+    WHEN 1 THEN 0
+    WHEN 2 THEN 1400
+    WHEN 3 THEN 1600
+    WHEN 4 THEN 1800
+    WHEN 5 THEN 2000
+    ELSE min_mmr
+END;
\ No newline at end of file
@@ -0,0 +1,15 @@
+-- Rotating refresh tokens (only the SHA-256 hash of each token is stored)
+CREATE TABLE IF NOT EXISTS refresh_tokens (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    player_id BIGINT NOT NULL REFERENCES players(id) ON DELETE CASCADE,
+    family_id UUID NOT NULL,
+    token_hash TEXT UNIQUE NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    expires_at TIMESTAMPTZ NOT NULL,
+    used_at TIMESTAMPTZ,
+    revoked_at TIMESTAMPTZ
+);
+COMMENT ON TABLE refresh_tokens IS 'Refresh tokens; a token is single-use and rotation keeps the family_id of the login it came from';
+COMMENT ON COLUMN refresh_tokens.family_id IS 'All tokens rotated from the same login share a family; reuse of a used token revokes the whole family';
+CREATE INDEX IF NOT EXISTS idx_refresh_tokens_player_id ON refresh_tokens(player_id);
+CREATE INDEX IF NOT EXISTS idx_refresh_tokens_family_id ON refresh_tokens(family_id);
\ No newline at end of file
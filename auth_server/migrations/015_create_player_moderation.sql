@@ -0,0 +1,24 @@
+-- Player moderation: suspension expiry and an audit log of admin actions
+DO $$ BEGIN
+    CREATE TYPE moderation_action AS ENUM ('suspend', 'ban', 'unban', 'suspension_expired');
+EXCEPTION
+    WHEN duplicate_object THEN null;
+END $$;
+
+ALTER TABLE players ADD COLUMN IF NOT EXISTS suspended_until TIMESTAMPTZ;
+ALTER TABLE players ADD COLUMN IF NOT EXISTS status_reason TEXT;
+COMMENT ON COLUMN players.suspended_until IS 'When a suspension lifts automatically (NULL unless status is suspended)';
+COMMENT ON COLUMN players.status_reason IS 'Reason shown to the player for the current suspension or ban';
+
+CREATE TABLE IF NOT EXISTS player_moderation_audit (
+    id BIGSERIAL PRIMARY KEY,
+    player_id BIGINT NOT NULL REFERENCES players(id) ON DELETE CASCADE,
+    actor VARCHAR(64) NOT NULL,
+    action moderation_action NOT NULL,
+    reason TEXT NOT NULL,
+    suspended_until TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+COMMENT ON TABLE player_moderation_audit IS 'Audit log of suspensions, bans and unbans (actor is the admin name or system)';
+CREATE INDEX IF NOT EXISTS idx_player_moderation_audit_player_id ON player_moderation_audit(player_id, created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_players_suspended_until ON players(suspended_until) WHERE suspended_until IS NOT NULL;
\ No newline at end of file
@@ -0,0 +1,12 @@
+-- Cards are synced from the game data (game_resources/data abnormalities) at auth_server startup
+ALTER TABLE cards ADD COLUMN IF NOT EXISTS item_uuid UUID UNIQUE;
+COMMENT ON COLUMN cards.item_uuid IS 'UUID of the in-run item (AbnormalityMetadata.uuid) this card unlocks';
+
+-- Drop the placeholder sample cards unless a player already owns them
+DELETE FROM cards c
+WHERE c.internal_name IN ('fireball', 'frost_golem')
+  AND NOT EXISTS (SELECT 1 FROM player_card_collection pc WHERE pc.card_id = c.id)
+  AND NOT EXISTS (SELECT 1 FROM deck_cards dc WHERE dc.card_id = c.id);
+
+-- The sample rows were inserted with explicit ids, so move the sequence past them
+SELECT setval(pg_get_serial_sequence('cards', 'id'), COALESCE((SELECT MAX(id) FROM cards), 0) + 1, false);
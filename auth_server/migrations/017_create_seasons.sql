@@ -0,0 +1,28 @@
+-- Ranked seasons and the per-season snapshot of each player's final rating
+CREATE TABLE IF NOT EXISTS seasons (
+    id SERIAL PRIMARY KEY,
+    starts_at TIMESTAMPTZ NOT NULL,
+    ends_at TIMESTAMPTZ NOT NULL,
+    closed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (ends_at > starts_at)
+);
+COMMENT ON TABLE seasons IS 'Ranked seasons; id is the season number shown to players';
+COMMENT ON COLUMN seasons.closed_at IS 'When the rollover job snapshotted and soft-reset this season (NULL while in progress)';
+-- At most one season is in progress
+CREATE UNIQUE INDEX IF NOT EXISTS idx_seasons_single_open ON seasons((closed_at IS NULL)) WHERE closed_at IS NULL;
+
+CREATE TABLE IF NOT EXISTS season_standings (
+    season_id INT NOT NULL REFERENCES seasons(id) ON DELETE CASCADE,
+    player_id BIGINT NOT NULL REFERENCES players(id) ON DELETE CASCADE,
+    rank INT NOT NULL,
+    final_mmr DOUBLE PRECISION NOT NULL,
+    final_rd DOUBLE PRECISION NOT NULL,
+    tier_id INT NOT NULL REFERENCES tiers(id),
+    ranked_matches INT NOT NULL DEFAULT 0,
+    PRIMARY KEY (season_id, player_id)
+);
+COMMENT ON TABLE season_standings IS 'Final mmr and tier of every player when a season closed (before the soft reset)';
+COMMENT ON COLUMN season_standings.ranked_matches IS 'Ranked matches the player finished during the season';
+CREATE INDEX IF NOT EXISTS idx_season_standings_rank ON season_standings(season_id, rank);
+CREATE INDEX IF NOT EXISTS idx_season_standings_player_id ON season_standings(player_id);
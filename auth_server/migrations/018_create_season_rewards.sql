@@ -0,0 +1,12 @@
+-- End-of-season rewards (one row per player per closed season, so a rollover can never grant twice)
+CREATE TABLE IF NOT EXISTS season_rewards (
+    season_id INT NOT NULL REFERENCES seasons(id) ON DELETE CASCADE,
+    player_id BIGINT NOT NULL REFERENCES players(id) ON DELETE CASCADE,
+    tier_id INT NOT NULL REFERENCES tiers(id),
+    experience_points BIGINT NOT NULL CHECK (experience_points > 0),
+    granted_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (season_id, player_id)
+);
+COMMENT ON TABLE season_rewards IS 'Rewards granted at season close by final tier; the primary key keeps the grant idempotent';
+COMMENT ON COLUMN season_rewards.experience_points IS 'Experience points added to player_profiles.experience_points';
+CREATE INDEX IF NOT EXISTS idx_season_rewards_player_id ON season_rewards(player_id);
\ No newline at end of file
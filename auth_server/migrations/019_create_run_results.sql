@@ -0,0 +1,19 @@
+-- Finished runs submitted by game_server (source of the deepest-ordeal and fastest-clear leaderboards)
+CREATE TABLE IF NOT EXISTS run_results (
+    id BIGSERIAL PRIMARY KEY,
+    player_id BIGINT NOT NULL REFERENCES players(id) ON DELETE CASCADE,
+    run_seed BIGINT NOT NULL,
+    end_reason VARCHAR(32) NOT NULL,
+    reached_ordeal VARCHAR(16) NOT NULL,
+    reached_phase VARCHAR(8) NOT NULL,
+    duration_seconds INT NOT NULL CHECK (duration_seconds >= 0),
+    started_at TIMESTAMPTZ NOT NULL,
+    ended_at TIMESTAMPTZ NOT NULL,
+    summary JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (player_id, run_seed)
+);
+COMMENT ON TABLE run_results IS 'Run summaries reported by game_server when a run ends; (player_id, run_seed) makes resubmission idempotent';
+COMMENT ON COLUMN run_results.run_seed IS 'GameCore run seed (u64 stored bit-for-bit as BIGINT)';
+COMMENT ON COLUMN run_results.duration_seconds IS 'Run length measured by game_server from run start to run end';
+CREATE INDEX IF NOT EXISTS idx_run_results_player_id ON run_results(player_id);
\ No newline at end of file
@@ -25,6 +25,12 @@ EXCEPTION
     WHEN duplicate_object THEN null;
 END $$;
 
+DO $$ BEGIN
+    CREATE TYPE moderation_action AS ENUM ('suspend', 'ban', 'unban', 'suspension_expired');
+EXCEPTION
+    WHEN duplicate_object THEN null;
+END $$;
+
 
 -- =================================================================
 -- 1. 플레이어 계정 (Steam Player Account Mapping)
@@ -34,6 +40,8 @@ CREATE TABLE IF NOT EXISTS players (
     id BIGINT PRIMARY KEY, -- SteamID64를 기본 키로 사용
     last_known_username VARCHAR(64), -- 스팀 닉네임 스냅샷 (선택 사항)
     status player_status NOT NULL DEFAULT 'active',
+    suspended_until TIMESTAMPTZ, -- 정지 자동 해제 시각 (suspended 가 아니면 NULL)
+    status_reason TEXT, -- 현재 정지/차단 사유
     created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
     updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
     last_login_at TIMESTAMPTZ
@@ -42,6 +50,9 @@ COMMENT ON TABLE players IS '스팀 플레이어 계정과 게임 데이터를 
 COMMENT ON COLUMN players.id IS '고유 플레이어 ID (SteamID64)';
 COMMENT ON COLUMN players.last_known_username IS '마지막으로 알려진 플레이어의 스팀 닉네임';
 COMMENT ON COLUMN players.status IS '계정 상태 (ENUM: active, suspended, banned)';
+COMMENT ON COLUMN players.suspended_until IS 'When a suspension lifts automatically (NULL unless status is suspended)';
+COMMENT ON COLUMN players.status_reason IS 'Reason shown to the player for the current suspension or ban';
+CREATE INDEX IF NOT EXISTS idx_players_suspended_until ON players(suspended_until) WHERE suspended_until IS NOT NULL;
 CREATE TRIGGER trigger_players_updated_at
 BEFORE UPDATE ON players
 FOR EACH ROW
@@ -55,16 +66,17 @@ CREATE TABLE IF NOT EXISTS tiers (
     id SERIAL PRIMARY KEY,
     name VARCHAR(50) UNIQUE NOT NULL,
     order_key INT UNIQUE NOT NULL,
-    icon_url TEXT
+    icon_url TEXT,
+    min_mmr DOUBLE PRECISION NOT NULL DEFAULT 0 -- 이 티어에 필요한 최소 mmr
 );
 COMMENT ON TABLE tiers IS '게임의 티어(등급) 정의 마스터 테이블';
 -- 예시 티어 데이터 삽입
-INSERT INTO tiers (id, name, order_key, icon_url) VALUES
-(1, 'Bronze', 1, '/icons/tiers/bronze.png'),
-(2, 'Silver', 2, '/icons/tiers/silver.png'),
-(3, 'Gold', 3, '/icons/tiers/gold.png'),
-(4, 'Platinum', 4, '/icons/tiers/platinum.png'),
-(5, 'Diamond', 5, '/icons/tiers/diamond.png')
+INSERT INTO tiers (id, name, order_key, icon_url, min_mmr) VALUES
+(1, 'Bronze', 1, '/icons/tiers/bronze.png', 0),
+(2, 'Silver', 2, '/icons/tiers/silver.png', 1400),
+(3, 'Gold', 3, '/icons/tiers/gold.png', 1600),
+(4, 'Platinum', 4, '/icons/tiers/platinum.png', 1800),
+(5, 'Diamond', 5, '/icons/tiers/diamond.png', 2000)
 ON CONFLICT (id) DO NOTHING;
 
 
@@ -174,14 +186,11 @@ CREATE TABLE IF NOT EXISTS cards (
     card_type VARCHAR(50),
     image_url TEXT,
     attributes JSONB,
-    is_collectible BOOLEAN NOT NULL DEFAULT TRUE
+    is_collectible BOOLEAN NOT NULL DEFAULT TRUE,
+    item_uuid UUID UNIQUE -- 런 안의 환상체 UUID (AbnormalityMetadata.uuid)
 );
 COMMENT ON TABLE cards IS '게임 내 모든 카드 정보 마스터 테이블';
--- 예시 카드 데이터 삽입
-INSERT INTO cards (id, internal_name, display_name, rarity, mana_cost, attack, health, card_type, is_collectible) VALUES
-(1, 'fireball', 'Fireball', 'common', 4, 6, 0, 'spell', TRUE),
-(2, 'frost_golem', 'Frost Golem', 'rare', 5, 4, 5, 'minion', TRUE)
-ON CONFLICT (id) DO NOTHING;
+-- 카드 데이터는 auth_server 시작 시 game_resources/data 의 환상체 데이터로 동기화합니다.
 
 
 -- =================================================================
@@ -230,6 +239,122 @@ CREATE TABLE IF NOT EXISTS deck_cards (
 COMMENT ON TABLE deck_cards IS '각 덱에 포함된 카드 정보';
 
 
+-- =================================================================
+-- 12. 리프레시 토큰 (Refresh Tokens)
+-- 토큰 원문은 저장하지 않고 SHA-256 해시만 저장
+-- =================================================================
+CREATE TABLE IF NOT EXISTS refresh_tokens (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    player_id BIGINT NOT NULL REFERENCES players(id) ON DELETE CASCADE,
+    family_id UUID NOT NULL,
+    token_hash TEXT UNIQUE NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    expires_at TIMESTAMPTZ NOT NULL,
+    used_at TIMESTAMPTZ,
+    revoked_at TIMESTAMPTZ
+);
+COMMENT ON TABLE refresh_tokens IS 'Refresh tokens; a token is single-use and rotation keeps the family_id of the login it came from';
+COMMENT ON COLUMN refresh_tokens.family_id IS 'All tokens rotated from the same login share a family; reuse of a used token revokes the whole family';
+CREATE INDEX IF NOT EXISTS idx_refresh_tokens_player_id ON refresh_tokens(player_id);
+CREATE INDEX IF NOT EXISTS idx_refresh_tokens_family_id ON refresh_tokens(family_id);
+
+
+-- =================================================================
+-- 13. 제재 기록 (Player Moderation Audit)
+-- actor 는 관리자 이름 또는 system (정지 만료)
+-- =================================================================
+CREATE TABLE IF NOT EXISTS player_moderation_audit (
+    id BIGSERIAL PRIMARY KEY,
+    player_id BIGINT NOT NULL REFERENCES players(id) ON DELETE CASCADE,
+    actor VARCHAR(64) NOT NULL,
+    action moderation_action NOT NULL,
+    reason TEXT NOT NULL,
+    suspended_until TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+COMMENT ON TABLE player_moderation_audit IS 'Audit log of suspensions, bans and unbans (actor is the admin name or system)';
+CREATE INDEX IF NOT EXISTS idx_player_moderation_audit_player_id ON player_moderation_audit(player_id, created_at DESC);
+
+
+-- =================================================================
+-- 14. 랭크 시즌 (Seasons)
+-- id 가 플레이어에게 보이는 시즌 번호
+-- =================================================================
+CREATE TABLE IF NOT EXISTS seasons (
+    id SERIAL PRIMARY KEY,
+    starts_at TIMESTAMPTZ NOT NULL,
+    ends_at TIMESTAMPTZ NOT NULL,
+    closed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (ends_at > starts_at)
+);
+COMMENT ON TABLE seasons IS 'Ranked seasons; id is the season number shown to players';
+COMMENT ON COLUMN seasons.closed_at IS 'When the rollover job snapshotted and soft-reset this season (NULL while in progress)';
+-- 진행 중인 시즌은 최대 1개
+CREATE UNIQUE INDEX IF NOT EXISTS idx_seasons_single_open ON seasons((closed_at IS NULL)) WHERE closed_at IS NULL;
+
+
+-- =================================================================
+-- 15. 시즌 최종 순위 (Season Standings)
+-- 소프트 리셋 전의 최종 mmr / 티어
+-- =================================================================
+CREATE TABLE IF NOT EXISTS season_standings (
+    season_id INT NOT NULL REFERENCES seasons(id) ON DELETE CASCADE,
+    player_id BIGINT NOT NULL REFERENCES players(id) ON DELETE CASCADE,
+    rank INT NOT NULL,
+    final_mmr DOUBLE PRECISION NOT NULL,
+    final_rd DOUBLE PRECISION NOT NULL,
+    tier_id INT NOT NULL REFERENCES tiers(id),
+    ranked_matches INT NOT NULL DEFAULT 0,
+    PRIMARY KEY (season_id, player_id)
+);
+COMMENT ON TABLE season_standings IS 'Final mmr and tier of every player when a season closed (before the soft reset)';
+COMMENT ON COLUMN season_standings.ranked_matches IS 'Ranked matches the player finished during the season';
+CREATE INDEX IF NOT EXISTS idx_season_standings_rank ON season_standings(season_id, rank);
+CREATE INDEX IF NOT EXISTS idx_season_standings_player_id ON season_standings(player_id);
+
+
+-- =================================================================
+-- 16. 시즌 보상 (Season Rewards)
+-- (season_id, player_id) 기본 키로 한 시즌에 한 번만 지급
+-- =================================================================
+CREATE TABLE IF NOT EXISTS season_rewards (
+    season_id INT NOT NULL REFERENCES seasons(id) ON DELETE CASCADE,
+    player_id BIGINT NOT NULL REFERENCES players(id) ON DELETE CASCADE,
+    tier_id INT NOT NULL REFERENCES tiers(id),
+    experience_points BIGINT NOT NULL CHECK (experience_points > 0),
+    granted_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (season_id, player_id)
+);
+COMMENT ON TABLE season_rewards IS 'Rewards granted at season close by final tier; the primary key keeps the grant idempotent';
+COMMENT ON COLUMN season_rewards.experience_points IS 'Experience points added to player_profiles.experience_points';
+CREATE INDEX IF NOT EXISTS idx_season_rewards_player_id ON season_rewards(player_id);
+
+
+-- =================================================================
+-- 17. 런 결과 (Run Results)
+-- game_server 가 런 종료 시 제출한 요약 (시련 깊이 / 최단 클리어 리더보드의 원천)
+-- =================================================================
+CREATE TABLE IF NOT EXISTS run_results (
+    id BIGSERIAL PRIMARY KEY,
+    player_id BIGINT NOT NULL REFERENCES players(id) ON DELETE CASCADE,
+    run_seed BIGINT NOT NULL,
+    end_reason VARCHAR(32) NOT NULL,
+    reached_ordeal VARCHAR(16) NOT NULL,
+    reached_phase VARCHAR(8) NOT NULL,
+    duration_seconds INT NOT NULL CHECK (duration_seconds >= 0),
+    started_at TIMESTAMPTZ NOT NULL,
+    ended_at TIMESTAMPTZ NOT NULL,
+    summary JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (player_id, run_seed)
+);
+COMMENT ON TABLE run_results IS 'Run summaries reported by game_server when a run ends; (player_id, run_seed) makes resubmission idempotent';
+COMMENT ON COLUMN run_results.run_seed IS 'GameCore run seed (u64 stored bit-for-bit as BIGINT)';
+COMMENT ON COLUMN run_results.duration_seconds IS 'Run length measured by game_server from run start to run end';
+CREATE INDEX IF NOT EXISTS idx_run_results_player_id ON run_results(player_id);
+
+
 -- =================================================================
 -- 스크립트 실행 완료
 -- =================================================================
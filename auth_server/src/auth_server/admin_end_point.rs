@@ -0,0 +1,249 @@
+use actix_web::{web, HttpResponse};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use crate::auth_server::{
+    db_operation,
+    errors::AuthError,
+    extractor::AdminActor,
+    leaderboard::{Board, LeaderboardKind},
+    model::{ModerationAction, ModerationAudit, PlayerStatus},
+    season::refresh_caches_after_rollover,
+    types::AppState,
+};
+
+const AUDIT_LIMIT: i64 = 100;
+const MAX_REASON_LEN: usize = 500;
+
+// --- HTTP 요청/응답 구조체 ---
+#[derive(Deserialize)]
+struct SuspendRequest {
+    /// 정지 기간 (초)
+    duration_seconds: i64,
+    reason: String,
+}
+
+#[derive(Deserialize)]
+struct ReasonRequest {
+    reason: String,
+}
+
+#[derive(Serialize)]
+struct ModerationResponse {
+    steam_id: String,
+    status: PlayerStatus,
+    suspended_until: Option<DateTime<Utc>>,
+    reason: Option<String>,
+}
+
+#[derive(Serialize)]
+struct RebuiltBoard {
+    board: LeaderboardKind,
+    tier_id: Option<i32>,
+    entries: usize,
+}
+
+#[derive(Serialize)]
+struct AuditResponse {
+    steam_id: String,
+    entries: Vec<ModerationAudit>,
+}
+
+fn validate_reason(reason: &str) -> Result<&str, AuthError> {
+    let reason = reason.trim();
+    if reason.is_empty() {
+        return Err(AuthError::BadRequest(
+            "reason must not be empty".to_string(),
+        ));
+    }
+    if reason.chars().count() > MAX_REASON_LEN {
+        return Err(AuthError::BadRequest(format!(
+            "reason must be at most {} characters",
+            MAX_REASON_LEN
+        )));
+    }
+    Ok(reason)
+}
+
+/// 제재 적용 후, 정지/밴이면 플레이어의 리프레시 토큰과 액세스 토큰을 모두 폐기합니다.
+async fn moderate(
+    state: &AppState,
+    admin: &AdminActor,
+    steam_id: i64,
+    action: ModerationAction,
+    reason: &str,
+    suspended_until: Option<DateTime<Utc>>,
+) -> Result<HttpResponse, AuthError> {
+    let player = db_operation::apply_moderation(
+        &state.db_pool,
+        steam_id,
+        &admin.name,
+        action,
+        reason,
+        suspended_until,
+    )
+    .await?
+    .ok_or_else(|| AuthError::NotFound(format!("Player {} not found", steam_id)))?;
+
+    if player.status != PlayerStatus::Active {
+        db_operation::revoke_player_refresh_tokens(&state.db_pool, steam_id).await?;
+        if let Err(e) = state
+            .revocation
+            .revoke_player(steam_id, state.tokens.access_ttl)
+            .await
+        {
+            // 리프레시는 이미 막혔으므로 남은 액세스 토큰 수명 동안만 유효
+            warn!(
+                "Failed to revoke access tokens of player {}: {}",
+                steam_id, e
+            );
+        }
+    }
+
+    info!(
+        "Admin {} applied {:?} to player {} ({})",
+        admin.name, action, steam_id, reason
+    );
+    Ok(HttpResponse::Ok().json(ModerationResponse {
+        steam_id: player.id.to_string(),
+        status: player.status,
+        suspended_until: player.suspended_until,
+        reason: player.status_reason,
+    }))
+}
+
+// --- 엔드포인트 핸들러 ---
+/// POST /admin/seasons/rollover
+/// 시즌 롤오버 작업을 즉시 실행합니다. 진행 중인 시즌이 아직 끝나지 않았으면 아무것도 바꾸지 않습니다.
+#[actix_web::post("/seasons/rollover")]
+pub async fn rollover_season_handler(
+    state: web::Data<AppState>,
+    admin: AdminActor,
+) -> Result<HttpResponse, AuthError> {
+    let outcome =
+        db_operation::rollover_season(&state.db_pool, Utc::now(), &state.seasons, &state.rating)
+            .await?;
+    info!("Admin {} ran season rollover: {:?}", admin.name, outcome);
+
+    refresh_caches_after_rollover(
+        &state.db_pool,
+        &state.leaderboards,
+        &state.rating_cache,
+        &outcome,
+    )
+    .await;
+    Ok(HttpResponse::Ok().json(outcome))
+}
+
+/// POST /admin/leaderboards/rebuild
+/// 모든 리더보드를 Postgres 에서 다시 계산해 Redis 캐시를 교체합니다.
+#[actix_web::post("/leaderboards/rebuild")]
+pub async fn rebuild_leaderboards_handler(
+    state: web::Data<AppState>,
+    admin: AdminActor,
+) -> Result<HttpResponse, AuthError> {
+    let mut rebuilt = Vec::new();
+    for board in Board::all(&state.db_pool).await? {
+        let entries = state.leaderboards.rebuild(&state.db_pool, board).await?;
+        rebuilt.push(RebuiltBoard {
+            board: board.kind,
+            tier_id: board.tier_id,
+            entries: entries.len(),
+        });
+    }
+    info!(
+        "Admin {} rebuilt {} leaderboards",
+        admin.name,
+        rebuilt.len()
+    );
+    Ok(HttpResponse::Ok().json(rebuilt))
+}
+
+/// POST /admin/players/{steam_id}/suspend
+/// 플레이어를 기간 정지합니다. 기간이 지나면 로그인 / 토큰 갱신 시 또는 주기 작업에서 자동 해제됩니다.
+#[actix_web::post("/players/{steam_id}/suspend")]
+pub async fn suspend_player_handler(
+    state: web::Data<AppState>,
+    admin: AdminActor,
+    path: web::Path<i64>,
+    req_body: web::Json<SuspendRequest>,
+) -> Result<HttpResponse, AuthError> {
+    if req_body.duration_seconds <= 0 {
+        return Err(AuthError::BadRequest(
+            "duration_seconds must be positive".to_string(),
+        ));
+    }
+    let until = Duration::try_seconds(req_body.duration_seconds)
+        .and_then(|duration| Utc::now().checked_add_signed(duration))
+        .ok_or_else(|| AuthError::BadRequest("duration_seconds is too large".to_string()))?;
+    let reason = validate_reason(&req_body.reason)?;
+
+    moderate(
+        &state,
+        &admin,
+        path.into_inner(),
+        ModerationAction::Suspend,
+        reason,
+        Some(until),
+    )
+    .await
+}
+
+/// POST /admin/players/{steam_id}/ban
+/// 플레이어를 영구 정지합니다.
+#[actix_web::post("/players/{steam_id}/ban")]
+pub async fn ban_player_handler(
+    state: web::Data<AppState>,
+    admin: AdminActor,
+    path: web::Path<i64>,
+    req_body: web::Json<ReasonRequest>,
+) -> Result<HttpResponse, AuthError> {
+    let reason = validate_reason(&req_body.reason)?;
+    moderate(
+        &state,
+        &admin,
+        path.into_inner(),
+        ModerationAction::Ban,
+        reason,
+        None,
+    )
+    .await
+}
+
+/// POST /admin/players/{steam_id}/unban
+/// 정지 / 밴을 해제합니다.
+#[actix_web::post("/players/{steam_id}/unban")]
+pub async fn unban_player_handler(
+    state: web::Data<AppState>,
+    admin: AdminActor,
+    path: web::Path<i64>,
+    req_body: web::Json<ReasonRequest>,
+) -> Result<HttpResponse, AuthError> {
+    let reason = validate_reason(&req_body.reason)?;
+    moderate(
+        &state,
+        &admin,
+        path.into_inner(),
+        ModerationAction::Unban,
+        reason,
+        None,
+    )
+    .await
+}
+
+/// GET /admin/players/{steam_id}/audit
+/// 플레이어의 제재 기록을 최신순으로 반환합니다.
+#[actix_web::get("/players/{steam_id}/audit")]
+pub async fn player_audit_handler(
+    state: web::Data<AppState>,
+    _admin: AdminActor,
+    path: web::Path<i64>,
+) -> Result<HttpResponse, AuthError> {
+    let steam_id = path.into_inner();
+    let entries = db_operation::get_moderation_audit(&state.db_pool, steam_id, AUDIT_LIMIT).await?;
+    Ok(HttpResponse::Ok().json(AuditResponse {
+        steam_id: steam_id.to_string(),
+        entries,
+    }))
+}
@@ -0,0 +1,203 @@
+// src/auth/collection.rs
+
+//! 카드 컬렉션 / 덱 규칙
+//!
+//! 카드 마스터(`cards`)는 game_server 와 같은 게임 데이터(`GameDataBase`)의 환상체로 채웁니다.
+//! `internal_name` 은 환상체 ID, `item_uuid` 는 런 안의 환상체 UUID 이므로
+//! 메타 진행으로 얻은 카드와 런에서 등장하는 환상체가 1:1 로 대응합니다.
+
+use std::collections::{HashMap, HashSet};
+
+use game_core::game::{data::GameDataBase, enums::RiskLevel};
+use serde::Deserialize;
+use serde_json::json;
+use sqlx::PgPool;
+
+use crate::auth_server::{
+    db_operation::{self, CardSeed},
+    model::CardRarity,
+};
+
+/// 게임 데이터에서 동기화한 카드의 card_type
+pub const ABNORMALITY_CARD_TYPE: &str = "abnormality";
+
+/// 설정 파일 `[decks]`
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct DeckSettings {
+    /// 플레이어 1명이 만들 수 있는 최대 덱 수
+    pub max_decks: i64,
+    /// 덱 1개의 최대 카드 장수
+    pub max_deck_size: i32,
+}
+
+impl Default for DeckSettings {
+    fn default() -> Self {
+        Self {
+            max_decks: 10,
+            max_deck_size: 30,
+        }
+    }
+}
+
+/// 환상체 위험 등급 → 카드 등급
+pub fn rarity_for_risk_level(risk_level: RiskLevel) -> CardRarity {
+    match risk_level {
+        RiskLevel::ZAYIN | RiskLevel::TETH => CardRarity::Common,
+        RiskLevel::HE => CardRarity::Rare,
+        RiskLevel::WAW => CardRarity::Epic,
+        RiskLevel::ALEPH => CardRarity::Legendary,
+    }
+}
+
+/// 게임 데이터의 환상체 목록으로 카드 마스터 행을 만듭니다.
+pub fn card_seeds(game_data: &GameDataBase) -> Vec<CardSeed> {
+    game_data
+        .abnormality_data
+        .items
+        .iter()
+        .map(|meta| CardSeed {
+            internal_name: meta.id.clone(),
+            display_name: meta.name.clone(),
+            rarity: rarity_for_risk_level(meta.risk_level),
+            attack: meta.attack as i32,
+            health: meta.max_health as i32,
+            item_uuid: meta.uuid,
+            attributes: json!({
+                "risk_level": meta.risk_level,
+                "price": meta.price,
+                "defense": meta.defense,
+                "skill_id": meta.skill_id,
+            }),
+        })
+        .collect()
+}
+
+/// 게임 데이터 디렉토리를 읽어 카드 마스터를 동기화합니다. (반영한 카드 수, 수집 불가로 바꾼 카드 수)
+pub async fn sync_cards_from_game_data(
+    pool: &PgPool,
+    data_dir: &str,
+) -> anyhow::Result<(u64, u64)> {
+    let game_data = GameDataBase::load_from_dir(data_dir)
+        .map_err(|e| anyhow::anyhow!("Failed to load game data from {}: {}", data_dir, e))?;
+    db_operation::sync_cards(pool, ABNORMALITY_CARD_TYPE, &card_seeds(&game_data)).await
+}
+
+/// 덱 편집 요청의 카드 1종
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct DeckEntry {
+    pub card_id: i32,
+    pub quantity: i32,
+}
+
+/// 덱 검사에 필요한 플레이어 소유 카드 정보
+#[derive(Debug, Clone, Copy)]
+pub struct OwnedCard {
+    pub rarity: CardRarity,
+    pub quantity: i32,
+}
+
+/// 덱 구성 검사: 중복 없음, 소유한 카드만, 등급별 장수 제한과 보유 장수 이내, 전체 장수 제한
+pub fn validate_deck_contents(
+    entries: &[DeckEntry],
+    owned: &HashMap<i32, OwnedCard>,
+    max_deck_size: i32,
+) -> Result<(), String> {
+    let mut seen = HashSet::new();
+    let mut total = 0;
+    for entry in entries {
+        if !seen.insert(entry.card_id) {
+            return Err(format!("card {} is listed more than once", entry.card_id));
+        }
+        if entry.quantity <= 0 {
+            return Err(format!(
+                "quantity of card {} must be positive",
+                entry.card_id
+            ));
+        }
+        let card = owned
+            .get(&entry.card_id)
+            .ok_or_else(|| format!("card {} is not in your collection", entry.card_id))?;
+        let limit = card.rarity.max_copies_per_deck().min(card.quantity);
+        if entry.quantity > limit {
+            return Err(format!(
+                "card {} allows at most {} copies ({:?}, {} owned)",
+                entry.card_id, limit, card.rarity, card.quantity
+            ));
+        }
+        total += entry.quantity;
+    }
+    if total > max_deck_size {
+        return Err(format!(
+            "deck has {} cards, the maximum is {}",
+            total, max_deck_size
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(card_id: i32, quantity: i32) -> DeckEntry {
+        DeckEntry { card_id, quantity }
+    }
+
+    #[test]
+    fn test_validate_deck_contents() {
+        let owned = HashMap::from([
+            (
+                1,
+                OwnedCard {
+                    rarity: CardRarity::Common,
+                    quantity: 5,
+                },
+            ),
+            (
+                2,
+                OwnedCard {
+                    rarity: CardRarity::Legendary,
+                    quantity: 3,
+                },
+            ),
+            (
+                3,
+                OwnedCard {
+                    rarity: CardRarity::Rare,
+                    quantity: 1,
+                },
+            ),
+        ]);
+
+        assert!(
+            validate_deck_contents(&[entry(1, 2), entry(2, 1), entry(3, 1)], &owned, 30).is_ok()
+        );
+        assert!(validate_deck_contents(&[], &owned, 30).is_ok());
+
+        // 등급 제한 (Legendary 1장)
+        assert!(validate_deck_contents(&[entry(2, 2)], &owned, 30).is_err());
+        // 보유 장수 초과 (Rare 1장 보유)
+        assert!(validate_deck_contents(&[entry(3, 2)], &owned, 30).is_err());
+        // 미보유 / 중복 / 0장
+        assert!(validate_deck_contents(&[entry(4, 1)], &owned, 30).is_err());
+        assert!(validate_deck_contents(&[entry(1, 1), entry(1, 1)], &owned, 30).is_err());
+        assert!(validate_deck_contents(&[entry(1, 0)], &owned, 30).is_err());
+        // 전체 장수 제한
+        assert!(validate_deck_contents(&[entry(1, 2), entry(2, 1)], &owned, 2).is_err());
+    }
+
+    #[test]
+    fn test_card_seeds_follow_game_data() {
+        let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/../game_resources/data");
+        let game_data = GameDataBase::load_from_dir(dir).unwrap();
+        let seeds = card_seeds(&game_data);
+        assert_eq!(seeds.len(), game_data.abnormality_data.items.len());
+
+        for (seed, meta) in seeds.iter().zip(&game_data.abnormality_data.items) {
+            assert_eq!(seed.internal_name, meta.id);
+            assert_eq!(seed.item_uuid, meta.uuid);
+            assert_eq!(seed.rarity, rarity_for_risk_level(meta.risk_level));
+        }
+    }
+}
@@ -1,7 +1,14 @@
 // src/auth/db_operation.rs
 
 use crate::auth_server::model::*;
+use crate::auth_server::rating::{
+    rate_match, tier_for_mmr, RatedParticipant, Rating, RatingSettings,
+};
+use crate::auth_server::season::{
+    reward_table, season_end, soft_reset, SeasonRollover, SeasonSettings, SEASON_ROLLOVER_LOCK_KEY,
+};
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use sqlx::PgPool;
 use uuid::Uuid;
 
@@ -29,7 +36,7 @@ pub async fn upsert_player_on_login(
         SET last_known_username = EXCLUDED.last_known_username,
             last_login_at = NOW(),
             updated_at = NOW()
-        RETURNING id, last_known_username, status AS "status: _", created_at, updated_at, last_login_at
+        RETURNING id, last_known_username, status AS "status: _", created_at, updated_at, last_login_at, suspended_until, status_reason
         "#,
         steam_id,
         username,
@@ -54,7 +61,7 @@ pub async fn upsert_player_on_login(
 pub async fn get_player_by_id(pool: &PgPool, player_id: i64) -> Result<Option<Player>> {
     sqlx::query_as!(
         Player,
-        r#"SELECT id, last_known_username, status AS "status: _", created_at, updated_at, last_login_at FROM players WHERE id = $1"#,
+        r#"SELECT id, last_known_username, status AS "status: _", created_at, updated_at, last_login_at, suspended_until, status_reason FROM players WHERE id = $1"#,
         player_id
     )
     .fetch_optional(pool)
@@ -70,8 +77,8 @@ pub async fn update_player_status(
     sqlx::query_as!(
         Player,
         r#"
-        UPDATE players SET status = $1 WHERE id = $2
-        RETURNING id, last_known_username, status AS "status: _", created_at, updated_at, last_login_at
+        UPDATE players SET status = $1, suspended_until = NULL, status_reason = NULL WHERE id = $2
+        RETURNING id, last_known_username, status AS "status: _", created_at, updated_at, last_login_at, suspended_until, status_reason
         "#,
         new_status as _,
         player_id
@@ -147,6 +154,26 @@ pub async fn update_player_mmr(
     .map_err(Into::into)
 }
 
+/// 전체 플레이어의 (player_id, 레이팅). 매칭용 레이팅 캐시를 다시 채울 때 사용합니다.
+pub async fn get_player_ratings(pool: &PgPool) -> Result<Vec<(i64, Rating)>> {
+    let rows = sqlx::query!("SELECT player_id, mmr, rd, volatility FROM player_profiles")
+        .fetch_all(pool)
+        .await?;
+    Ok(rows
+        .into_iter()
+        .map(|r| {
+            (
+                r.player_id,
+                Rating {
+                    mmr: r.mmr,
+                    rd: r.rd,
+                    volatility: r.volatility,
+                },
+            )
+        })
+        .collect())
+}
+
 // =================================================================
 // 3. 티어 정보 (Tiers) - 변경 없음
 // =================================================================
@@ -157,6 +184,41 @@ pub async fn get_all_tiers(pool: &PgPool) -> Result<Vec<Tier>> {
         .map_err(Into::into)
 }
 
+pub async fn get_tier_by_id(pool: &PgPool, tier_id: i32) -> Result<Option<Tier>> {
+    sqlx::query_as!(Tier, "SELECT * FROM tiers WHERE id = $1", tier_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(Into::into)
+}
+
+// =================================================================
+// 4. 게임 모드 (Game Modes)
+// =================================================================
+pub async fn get_game_mode_by_id(pool: &PgPool, game_mode_id: i32) -> Result<Option<GameMode>> {
+    sqlx::query_as!(
+        GameMode,
+        "SELECT * FROM game_modes WHERE id = $1",
+        game_mode_id
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(Into::into)
+}
+
+/// 주어진 SteamID 중 players 테이블에 없는 ID 목록을 반환합니다.
+pub async fn find_missing_players(pool: &PgPool, player_ids: &[i64]) -> Result<Vec<i64>> {
+    sqlx::query_scalar!(
+        r#"
+        SELECT id AS "id!" FROM UNNEST($1::BIGINT[]) AS ids(id)
+        WHERE NOT EXISTS (SELECT 1 FROM players p WHERE p.id = ids.id)
+        "#,
+        player_ids
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(Into::into)
+}
+
 // =================================================================
 // 5 & 6. 게임 기록 (Match History & Participants)
 // =================================================================
@@ -164,21 +226,43 @@ pub struct MatchResult<'a> {
     pub player_id: i64, // Uuid -> i64
     pub team_id: i32,
     pub is_winner: bool,
-    pub initial_mmr: f64,
-    pub final_mmr: f64,
     pub score: Option<i32>,
     pub stats: Option<&'a serde_json::Value>,
+    pub disconnected: bool,
 }
 
+/// 매치 1판으로 바뀐 참여자 레이팅
+#[derive(Debug, Clone)]
+pub struct RatingUpdate {
+    pub player_id: i64,
+    pub before: Rating,
+    pub after: Rating,
+    pub tier_id: i32,
+}
+
+/// 매치 기록과 참여자를 저장합니다.
+///
+/// Ranked 모드면 같은 트랜잭션 안에서 참여자 프로필을 잠그고 Glicko-2 로 레이팅과 티어를 갱신하므로,
+/// 매치 기록(initial_mmr / final_mmr)과 프로필 레이팅이 어긋나지 않습니다.
+/// 일반 모드는 현재 mmr 을 initial_mmr = final_mmr 로 기록만 합니다.
 pub async fn record_match_result(
     pool: &PgPool,
     game_mode_id: i32,
     winning_team_id: Option<i32>,
+    started_at: DateTime<Utc>,
+    ended_at: DateTime<Utc>,
     participants: &[MatchResult<'_>],
-) -> Result<MatchHistory> {
+    settings: &RatingSettings,
+) -> Result<(MatchHistory, Vec<RatingUpdate>)> {
     let mut tx = pool.begin().await?;
 
-    let now = chrono::Utc::now();
+    let is_ranked = sqlx::query_scalar!(
+        "SELECT is_ranked FROM game_modes WHERE id = $1",
+        game_mode_id
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
     let match_history = sqlx::query_as!(
         MatchHistory,
         r#"
@@ -186,49 +270,125 @@ pub async fn record_match_result(
         VALUES ($1, $2, $3, $4) RETURNING *
         "#,
         game_mode_id,
-        now,
-        now,
+        started_at,
+        ended_at,
         winning_team_id
     )
     .fetch_one(&mut *tx)
     .await?;
 
+    // 1. 참여자 프로필 잠금 (교착을 피하려고 player_id 순서로 잠급니다)
+    let player_ids: Vec<i64> = participants.iter().map(|p| p.player_id).collect();
+    let profiles = sqlx::query!(
+        r#"
+        SELECT player_id, mmr, rd, volatility, last_rating_update_at, tier_id
+        FROM player_profiles
+        WHERE player_id = ANY($1)
+        ORDER BY player_id
+        FOR UPDATE
+        "#,
+        &player_ids
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    let mut rated = Vec::with_capacity(participants.len());
+    let mut current_tiers = Vec::with_capacity(participants.len());
     for p in participants {
+        let profile = profiles
+            .iter()
+            .find(|profile| profile.player_id == p.player_id)
+            .ok_or_else(|| anyhow::anyhow!("Player profile {} not found", p.player_id))?;
+        let rating = Rating {
+            mmr: profile.mmr,
+            rd: profile.rd,
+            volatility: profile.volatility,
+        };
+        rated.push(RatedParticipant {
+            team_id: p.team_id,
+            rating: if is_ranked {
+                rating.inflate_for_inactivity(profile.last_rating_update_at, ended_at, settings)
+            } else {
+                rating
+            },
+        });
+        current_tiers.push(profile.tier_id);
+    }
+
+    // 2. 레이팅 / 티어 계산
+    let (after, tiers) = if is_ranked {
+        let tiers = sqlx::query_as!(Tier, "SELECT * FROM tiers ORDER BY order_key")
+            .fetch_all(&mut *tx)
+            .await?;
+        (rate_match(&rated, winning_team_id, settings), tiers)
+    } else {
+        (rated.iter().map(|r| r.rating).collect(), Vec::new())
+    };
+
+    // 3. 참여자 기록 및 프로필 갱신
+    let mut updates = Vec::with_capacity(participants.len());
+    for (((p, before), after), current_tier) in participants
+        .iter()
+        .zip(&rated)
+        .zip(after)
+        .zip(current_tiers)
+    {
         sqlx::query!(
             r#"
-            INSERT INTO match_participants (match_id, player_id, team_id, is_winner, initial_mmr, final_mmr, score, stats)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            INSERT INTO match_participants (match_id, player_id, team_id, is_winner, initial_mmr, final_mmr, score, stats, disconnected)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
             "#,
             match_history.id,
             p.player_id,
             p.team_id,
             p.is_winner,
-            p.initial_mmr,
-            p.final_mmr,
+            before.rating.mmr,
+            after.mmr,
             p.score,
-            p.stats
+            p.stats,
+            p.disconnected
         )
         .execute(&mut *tx)
         .await?;
 
-        // MMR 업데이트
-        sqlx::query!(
-            "UPDATE player_profiles SET mmr = $1 WHERE player_id = $2",
-            p.final_mmr,
-            p.player_id
-        )
-        .execute(&mut *tx)
-        .await?;
+        let tier_id = tier_for_mmr(&tiers, after.mmr).unwrap_or(current_tier);
+        if is_ranked {
+            sqlx::query!(
+                r#"
+                UPDATE player_profiles
+                SET mmr = $1, rd = $2, volatility = $3, tier_id = $4,
+                    last_rating_update_at = GREATEST(last_rating_update_at, $5)
+                WHERE player_id = $6
+                "#,
+                after.mmr,
+                after.rd,
+                after.volatility,
+                tier_id,
+                ended_at,
+                p.player_id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        updates.push(RatingUpdate {
+            player_id: p.player_id,
+            before: before.rating,
+            after,
+            tier_id,
+        });
     }
 
     tx.commit().await?;
-    Ok(match_history)
+    Ok((match_history, updates))
 }
 
+/// 플레이어가 참여한 매치를 최신순으로 페이지 단위로 가져옵니다.
 pub async fn get_player_match_history(
     pool: &PgPool,
     player_id: i64, // Uuid -> i64
     limit: i64,
+    offset: i64,
 ) -> Result<Vec<MatchHistory>> {
     sqlx::query_as!(
         MatchHistory,
@@ -237,17 +397,160 @@ pub async fn get_player_match_history(
         FROM match_history mh
         JOIN match_participants mp ON mh.id = mp.match_id
         WHERE mp.player_id = $1
-        ORDER BY mh.started_at DESC
-        LIMIT $2
+        ORDER BY mh.started_at DESC, mh.id
+        LIMIT $2 OFFSET $3
         "#,
         player_id,
-        limit
+        limit,
+        offset
     )
     .fetch_all(pool)
     .await
     .map_err(Into::into)
 }
 
+pub async fn count_player_matches(pool: &PgPool, player_id: i64) -> Result<i64> {
+    sqlx::query_scalar!(
+        r#"SELECT COUNT(*) AS "count!" FROM match_participants WHERE player_id = $1"#,
+        player_id
+    )
+    .fetch_one(pool)
+    .await
+    .map_err(Into::into)
+}
+
+/// 여러 매치의 참여자를 한 번에 가져옵니다.
+pub async fn get_match_participants(
+    pool: &PgPool,
+    match_ids: &[Uuid],
+) -> Result<Vec<MatchParticipant>> {
+    sqlx::query_as!(
+        MatchParticipant,
+        r#"
+        SELECT match_id, player_id, team_id, is_winner, initial_mmr, final_mmr,
+               mmr_change AS "mmr_change!", score, stats, disconnected
+        FROM match_participants
+        WHERE match_id = ANY($1)
+        ORDER BY team_id, player_id
+        "#,
+        match_ids
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(Into::into)
+}
+
+// =================================================================
+// 8. 카드 마스터 (Cards)
+// =================================================================
+
+/// 게임 데이터에서 만든 카드 1장 (internal_name 기준으로 동기화)
+#[derive(Debug, Clone)]
+pub struct CardSeed {
+    pub internal_name: String,
+    pub display_name: String,
+    pub rarity: CardRarity,
+    pub attack: i32,
+    pub health: i32,
+    pub item_uuid: Uuid,
+    pub attributes: serde_json::Value,
+}
+
+/// 카드 마스터를 게임 데이터와 맞춥니다. (반영한 카드 수, 수집 불가로 바꾼 카드 수)
+///
+/// 같은 card_type 인데 seeds 에 없는 카드는 지우지 않고 (소유 기록 보존) 수집 불가로 바꿉니다.
+pub async fn sync_cards(pool: &PgPool, card_type: &str, seeds: &[CardSeed]) -> Result<(u64, u64)> {
+    let mut tx = pool.begin().await?;
+
+    for seed in seeds {
+        sqlx::query!(
+            r#"
+            INSERT INTO cards (internal_name, display_name, rarity, attack, health, card_type, attributes, item_uuid, is_collectible)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, TRUE)
+            ON CONFLICT (internal_name) DO UPDATE SET
+                display_name = EXCLUDED.display_name,
+                rarity = EXCLUDED.rarity,
+                attack = EXCLUDED.attack,
+                health = EXCLUDED.health,
+                card_type = EXCLUDED.card_type,
+                attributes = EXCLUDED.attributes,
+                item_uuid = EXCLUDED.item_uuid,
+                is_collectible = TRUE
+            "#,
+            seed.internal_name,
+            seed.display_name,
+            seed.rarity as _,
+            seed.attack,
+            seed.health,
+            card_type,
+            seed.attributes,
+            seed.item_uuid
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    let names: Vec<String> = seeds.iter().map(|s| s.internal_name.clone()).collect();
+    let retired = sqlx::query!(
+        r#"
+        UPDATE cards SET is_collectible = FALSE
+        WHERE card_type = $1 AND is_collectible AND NOT (internal_name = ANY($2))
+        "#,
+        card_type,
+        &names
+    )
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+
+    tx.commit().await?;
+    Ok((seeds.len() as u64, retired))
+}
+
+pub async fn get_collectible_cards(pool: &PgPool) -> Result<Vec<Card>> {
+    sqlx::query_as!(
+        Card,
+        r#"
+        SELECT id, internal_name, display_name, description, rarity AS "rarity: _", mana_cost, attack, health,
+               card_type, image_url, attributes, is_collectible, item_uuid
+        FROM cards WHERE is_collectible ORDER BY id
+        "#
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(Into::into)
+}
+
+pub async fn get_cards_by_ids(pool: &PgPool, card_ids: &[i32]) -> Result<Vec<Card>> {
+    sqlx::query_as!(
+        Card,
+        r#"
+        SELECT id, internal_name, display_name, description, rarity AS "rarity: _", mana_cost, attack, health,
+               card_type, image_url, attributes, is_collectible, item_uuid
+        FROM cards WHERE id = ANY($1) ORDER BY id
+        "#,
+        card_ids
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(Into::into)
+}
+
+pub async fn get_card_by_internal_name(pool: &PgPool, internal_name: &str) -> Result<Option<Card>> {
+    sqlx::query_as!(
+        Card,
+        r#"
+        SELECT id, internal_name, display_name, description, rarity AS "rarity: _", mana_cost, attack, health,
+               card_type, image_url, attributes, is_collectible, item_uuid
+        FROM cards WHERE internal_name = $1
+        "#,
+        internal_name
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(Into::into)
+}
+
 // =================================================================
 // 9. 플레이어 카드 컬렉션 (Player Card Collection)
 // =================================================================
@@ -282,7 +585,7 @@ pub async fn get_player_card_collection(
 ) -> Result<Vec<PlayerCardCollection>> {
     sqlx::query_as!(
         PlayerCardCollection,
-        "SELECT * FROM player_card_collection WHERE player_id = $1",
+        "SELECT * FROM player_card_collection WHERE player_id = $1 ORDER BY card_id",
         player_id
     )
     .fetch_all(pool)
@@ -294,12 +597,18 @@ pub async fn get_player_card_collection(
 // 10 & 11. 덱 및 덱 카드 (Player Decks & Deck Cards)
 // =================================================================
 
-pub async fn create_deck(pool: &PgPool, player_id: i64, deck_name: &str) -> Result<PlayerDeck> {
+pub async fn create_deck(
+    pool: &PgPool,
+    player_id: i64,
+    deck_name: &str,
+    cover_card_id: Option<i32>,
+) -> Result<PlayerDeck> {
     sqlx::query_as!(
         PlayerDeck,
-        "INSERT INTO player_decks (player_id, deck_name) VALUES ($1, $2) RETURNING *",
+        "INSERT INTO player_decks (player_id, deck_name, cover_card_id) VALUES ($1, $2, $3) RETURNING *",
         player_id,
-        deck_name
+        deck_name,
+        cover_card_id
     )
     .fetch_one(pool)
     .await
@@ -363,10 +672,769 @@ pub async fn get_deck_with_cards(pool: &PgPool, deck_id: Uuid) -> Result<Option<
 pub async fn get_player_decks(pool: &PgPool, player_id: i64) -> Result<Vec<PlayerDeck>> {
     sqlx::query_as!(
         PlayerDeck,
-        "SELECT * FROM player_decks WHERE player_id = $1",
+        "SELECT * FROM player_decks WHERE player_id = $1 ORDER BY created_at",
+        player_id
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(Into::into)
+}
+
+pub async fn count_player_decks(pool: &PgPool, player_id: i64) -> Result<i64> {
+    sqlx::query_scalar!(
+        r#"SELECT COUNT(*) AS "count!" FROM player_decks WHERE player_id = $1"#,
         player_id
     )
+    .fetch_one(pool)
+    .await
+    .map_err(Into::into)
+}
+
+/// 여러 덱의 구성 카드를 한 번에 가져옵니다.
+pub async fn get_cards_of_decks(pool: &PgPool, deck_ids: &[Uuid]) -> Result<Vec<DeckCard>> {
+    sqlx::query_as!(
+        DeckCard,
+        "SELECT * FROM deck_cards WHERE deck_id = ANY($1) ORDER BY card_id",
+        deck_ids
+    )
     .fetch_all(pool)
     .await
     .map_err(Into::into)
 }
+
+/// 덱 이름 / 대표 카드를 바꿉니다. None 인 항목은 그대로 둡니다. 플레이어의 덱이 아니면 None.
+pub async fn update_deck(
+    pool: &PgPool,
+    deck_id: Uuid,
+    player_id: i64,
+    deck_name: Option<&str>,
+    cover_card_id: Option<i32>,
+) -> Result<Option<PlayerDeck>> {
+    sqlx::query_as!(
+        PlayerDeck,
+        r#"
+        UPDATE player_decks
+        SET deck_name = COALESCE($3, deck_name), cover_card_id = COALESCE($4, cover_card_id)
+        WHERE id = $1 AND player_id = $2
+        RETURNING *
+        "#,
+        deck_id,
+        player_id,
+        deck_name,
+        cover_card_id
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(Into::into)
+}
+
+/// 플레이어의 덱을 삭제합니다. (deck_cards 는 ON DELETE CASCADE) 삭제했으면 true.
+pub async fn delete_deck(pool: &PgPool, deck_id: Uuid, player_id: i64) -> Result<bool> {
+    let result = sqlx::query!(
+        "DELETE FROM player_decks WHERE id = $1 AND player_id = $2",
+        deck_id,
+        player_id
+    )
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// 덱 구성을 통째로 교체합니다. 플레이어의 덱이 아니면 None.
+pub async fn replace_deck_cards(
+    pool: &PgPool,
+    deck_id: Uuid,
+    player_id: i64,
+    cards: &[(i32, i32)],
+) -> Result<Option<Vec<DeckCard>>> {
+    let mut tx = pool.begin().await?;
+
+    // 덱 행을 잠가 동시 편집을 순서대로 처리하고, updated_at 도 갱신 (트리거)
+    let owned = sqlx::query_scalar!(
+        "UPDATE player_decks SET updated_at = NOW() WHERE id = $1 AND player_id = $2 RETURNING id",
+        deck_id,
+        player_id
+    )
+    .fetch_optional(&mut *tx)
+    .await?;
+    if owned.is_none() {
+        return Ok(None);
+    }
+
+    sqlx::query!("DELETE FROM deck_cards WHERE deck_id = $1", deck_id)
+        .execute(&mut *tx)
+        .await?;
+
+    let (card_ids, quantities): (Vec<i32>, Vec<i32>) = cards.iter().copied().unzip();
+    let inserted = sqlx::query_as!(
+        DeckCard,
+        r#"
+        INSERT INTO deck_cards (deck_id, card_id, quantity)
+        SELECT $1, card_id, quantity FROM UNNEST($2::INT[], $3::INT[]) AS t(card_id, quantity)
+        RETURNING *
+        "#,
+        deck_id,
+        &card_ids,
+        &quantities
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(Some(inserted))
+}
+
+// =================================================================
+// 12. 리프레시 토큰 (Refresh Tokens)
+// =================================================================
+
+pub async fn insert_refresh_token(
+    pool: &PgPool,
+    player_id: i64,
+    family_id: Uuid,
+    token_hash: &str,
+    expires_at: DateTime<Utc>,
+) -> Result<RefreshToken> {
+    sqlx::query_as!(
+        RefreshToken,
+        r#"
+        INSERT INTO refresh_tokens (player_id, family_id, token_hash, expires_at)
+        VALUES ($1, $2, $3, $4)
+        RETURNING *
+        "#,
+        player_id,
+        family_id,
+        token_hash,
+        expires_at
+    )
+    .fetch_one(pool)
+    .await
+    .map_err(Into::into)
+}
+
+/// 리프레시 토큰 교체 결과
+#[derive(Debug)]
+pub enum RefreshRotation {
+    /// 새 토큰으로 교체됨
+    Rotated {
+        player_id: i64,
+    },
+    /// 이미 사용한 토큰이 다시 들어옴 → 계열 전체를 폐기함
+    Reused {
+        player_id: i64,
+    },
+    /// 로그아웃 / 제재로 폐기된 토큰
+    Revoked {
+        player_id: i64,
+    },
+    Expired,
+    NotFound,
+}
+
+/// 리프레시 토큰을 사용 처리하고, 같은 계열의 새 토큰을 저장합니다.
+///
+/// 이미 사용된 토큰이 다시 들어오면 탈취로 보고 같은 계열의 토큰을 모두 폐기합니다.
+pub async fn rotate_refresh_token(
+    pool: &PgPool,
+    token_hash: &str,
+    new_token_hash: &str,
+    new_expires_at: DateTime<Utc>,
+) -> Result<RefreshRotation> {
+    let mut tx = pool.begin().await?;
+
+    let Some(current) = sqlx::query_as!(
+        RefreshToken,
+        "SELECT * FROM refresh_tokens WHERE token_hash = $1 FOR UPDATE",
+        token_hash
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    else {
+        return Ok(RefreshRotation::NotFound);
+    };
+
+    // 1. 재사용 감지
+    if current.used_at.is_some() {
+        sqlx::query!(
+            "UPDATE refresh_tokens SET revoked_at = NOW() WHERE family_id = $1 AND revoked_at IS NULL",
+            current.family_id
+        )
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        return Ok(RefreshRotation::Reused {
+            player_id: current.player_id,
+        });
+    }
+    if current.revoked_at.is_some() {
+        return Ok(RefreshRotation::Revoked {
+            player_id: current.player_id,
+        });
+    }
+    if current.expires_at <= Utc::now() {
+        return Ok(RefreshRotation::Expired);
+    }
+
+    // 2. 교체
+    sqlx::query!(
+        "UPDATE refresh_tokens SET used_at = NOW() WHERE id = $1",
+        current.id
+    )
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query!(
+        r#"
+        INSERT INTO refresh_tokens (player_id, family_id, token_hash, expires_at)
+        VALUES ($1, $2, $3, $4)
+        "#,
+        current.player_id,
+        current.family_id,
+        new_token_hash,
+        new_expires_at
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(RefreshRotation::Rotated {
+        player_id: current.player_id,
+    })
+}
+
+/// 토큰이 속한 계열을 폐기합니다 (로그아웃). 다른 플레이어의 토큰이면 아무것도 하지 않습니다.
+pub async fn revoke_refresh_family(pool: &PgPool, player_id: i64, token_hash: &str) -> Result<u64> {
+    let result = sqlx::query!(
+        r#"
+        UPDATE refresh_tokens SET revoked_at = NOW()
+        WHERE revoked_at IS NULL AND family_id = (
+            SELECT family_id FROM refresh_tokens WHERE token_hash = $1 AND player_id = $2
+        )
+        "#,
+        token_hash,
+        player_id
+    )
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected())
+}
+
+/// 플레이어의 모든 리프레시 토큰을 폐기합니다 (전체 로그아웃, 밴).
+pub async fn revoke_player_refresh_tokens(pool: &PgPool, player_id: i64) -> Result<u64> {
+    let result = sqlx::query!(
+        "UPDATE refresh_tokens SET revoked_at = NOW() WHERE player_id = $1 AND revoked_at IS NULL",
+        player_id
+    )
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected())
+}
+
+// =================================================================
+// 13. 제재 (Player Moderation)
+// =================================================================
+
+/// 플레이어 상태를 바꾸고 같은 트랜잭션에서 제재 기록을 남깁니다.
+/// 플레이어가 없으면 None 을 반환합니다.
+pub async fn apply_moderation(
+    pool: &PgPool,
+    player_id: i64,
+    actor: &str,
+    action: ModerationAction,
+    reason: &str,
+    suspended_until: Option<DateTime<Utc>>,
+) -> Result<Option<Player>> {
+    let (status, status_reason) = match action {
+        ModerationAction::Suspend => (PlayerStatus::Suspended, Some(reason)),
+        ModerationAction::Ban => (PlayerStatus::Banned, Some(reason)),
+        ModerationAction::Unban | ModerationAction::SuspensionExpired => {
+            (PlayerStatus::Active, None)
+        }
+    };
+    let suspended_until = suspended_until.filter(|_| action == ModerationAction::Suspend);
+
+    let mut tx = pool.begin().await?;
+
+    let Some(player) = sqlx::query_as!(
+        Player,
+        r#"
+        UPDATE players SET status = $1, suspended_until = $2, status_reason = $3 WHERE id = $4
+        RETURNING id, last_known_username, status AS "status: _", created_at, updated_at, last_login_at, suspended_until, status_reason
+        "#,
+        status as _,
+        suspended_until,
+        status_reason,
+        player_id
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    else {
+        return Ok(None);
+    };
+
+    sqlx::query!(
+        r#"
+        INSERT INTO player_moderation_audit (player_id, actor, action, reason, suspended_until)
+        VALUES ($1, $2, $3, $4, $5)
+        "#,
+        player_id,
+        actor,
+        action as _,
+        reason,
+        suspended_until
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(Some(player))
+}
+
+/// 기간이 끝난 정지를 해제하고 기록을 남깁니다. 해제된 플레이어 ID 를 반환합니다.
+/// player_id 를 주면 해당 플레이어만 확인합니다 (로그인 / 토큰 갱신 시).
+pub async fn lift_expired_suspensions(pool: &PgPool, player_id: Option<i64>) -> Result<Vec<i64>> {
+    let mut tx = pool.begin().await?;
+
+    let lifted = sqlx::query_scalar!(
+        r#"
+        UPDATE players SET status = 'active', suspended_until = NULL, status_reason = NULL
+        WHERE status = 'suspended' AND suspended_until <= NOW()
+          AND ($1::BIGINT IS NULL OR id = $1)
+        RETURNING id
+        "#,
+        player_id
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    if !lifted.is_empty() {
+        sqlx::query!(
+            r#"
+            INSERT INTO player_moderation_audit (player_id, actor, action, reason)
+            SELECT id, 'system', 'suspension_expired', 'Suspension period ended'
+            FROM UNNEST($1::BIGINT[]) AS ids(id)
+            "#,
+            &lifted
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+    Ok(lifted)
+}
+
+pub async fn get_moderation_audit(
+    pool: &PgPool,
+    player_id: i64,
+    limit: i64,
+) -> Result<Vec<ModerationAudit>> {
+    sqlx::query_as!(
+        ModerationAudit,
+        r#"
+        SELECT id, player_id, actor, action AS "action: _", reason, suspended_until, created_at
+        FROM player_moderation_audit
+        WHERE player_id = $1
+        ORDER BY created_at DESC, id DESC
+        LIMIT $2
+        "#,
+        player_id,
+        limit
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(Into::into)
+}
+
+// =================================================================
+// 14. 시즌 (Seasons)
+// =================================================================
+
+/// 시즌 롤오버 (멱등)
+///
+/// 1. 진행 중인 시즌이 없으면 `now` 에 첫 시즌을 시작합니다.
+/// 2. 진행 중인 시즌이 `now` 이전에 끝났으면 한 트랜잭션 안에서
+///    최종 순위 기록 → 티어별 보상 지급 → mmr / rd 소프트 리셋과 티어 재계산 → 시즌 마감 → 다음 시즌 시작을 수행합니다.
+/// 3. 그 외에는 아무것도 바꾸지 않습니다.
+///
+/// advisory lock 으로 여러 인스턴스가 동시에 실행해도 한 번만 적용됩니다.
+pub async fn rollover_season(
+    pool: &PgPool,
+    now: DateTime<Utc>,
+    seasons: &SeasonSettings,
+    rating: &RatingSettings,
+) -> Result<SeasonRollover> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query!("SELECT pg_advisory_xact_lock($1)", SEASON_ROLLOVER_LOCK_KEY)
+        .execute(&mut *tx)
+        .await?;
+
+    let open = sqlx::query_as!(Season, "SELECT * FROM seasons WHERE closed_at IS NULL")
+        .fetch_optional(&mut *tx)
+        .await?;
+
+    let Some(current) = open else {
+        let season = sqlx::query_as!(
+            Season,
+            "INSERT INTO seasons (starts_at, ends_at) VALUES ($1, $2) RETURNING *",
+            now,
+            season_end(now, now, seasons.length)
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        return Ok(SeasonRollover::Started { season });
+    };
+
+    if current.ends_at > now {
+        tx.commit().await?;
+        return Ok(SeasonRollover::InProgress { season: current });
+    }
+
+    // 1. 매치 결과 반영과 겹치지 않도록 프로필 잠금
+    let profiles = sqlx::query!(
+        r#"
+        SELECT player_id, mmr, rd, volatility, tier_id
+        FROM player_profiles
+        ORDER BY player_id
+        FOR UPDATE
+        "#
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    // 2. 최종 순위 기록 (시즌 기간 안에 끝난 랭크 매치 수 포함)
+    let standings = sqlx::query!(
+        r#"
+        INSERT INTO season_standings (season_id, player_id, rank, final_mmr, final_rd, tier_id, ranked_matches)
+        SELECT $1, pp.player_id,
+               ROW_NUMBER() OVER (ORDER BY pp.mmr DESC, pp.player_id)::INT,
+               pp.mmr, pp.rd, pp.tier_id, COALESCE(m.matches, 0)::INT
+        FROM player_profiles pp
+        LEFT JOIN (
+            SELECT mp.player_id, COUNT(*) AS matches
+            FROM match_participants mp
+            JOIN match_history mh ON mh.id = mp.match_id
+            JOIN game_modes gm ON gm.id = mh.game_mode_id
+            WHERE gm.is_ranked AND mh.ended_at >= $2 AND mh.ended_at < $3
+            GROUP BY mp.player_id
+        ) m ON m.player_id = pp.player_id
+        ON CONFLICT (season_id, player_id) DO NOTHING
+        "#,
+        current.id,
+        current.starts_at,
+        current.ends_at
+    )
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+
+    // 3. 최종 티어별 보상 (season_rewards 기본 키로 시즌당 한 번만 지급)
+    let tiers = sqlx::query_as!(Tier, "SELECT * FROM tiers ORDER BY order_key")
+        .fetch_all(&mut *tx)
+        .await?;
+    let (reward_tier_ids, reward_points) = reward_table(&tiers, seasons);
+    let rewards = sqlx::query!(
+        r#"
+        WITH granted AS (
+            INSERT INTO season_rewards (season_id, player_id, tier_id, experience_points)
+            SELECT ss.season_id, ss.player_id, ss.tier_id, r.points
+            FROM season_standings ss
+            JOIN UNNEST($2::INT[], $3::BIGINT[]) AS r(tier_id, points) ON r.tier_id = ss.tier_id
+            WHERE ss.season_id = $1 AND ss.ranked_matches >= $4
+            ON CONFLICT (season_id, player_id) DO NOTHING
+            RETURNING player_id, experience_points
+        )
+        UPDATE player_profiles pp
+        SET experience_points = pp.experience_points + g.experience_points
+        FROM granted g
+        WHERE pp.player_id = g.player_id
+        "#,
+        current.id,
+        &reward_tier_ids,
+        &reward_points,
+        seasons.reward_min_ranked_matches
+    )
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+
+    // 4. 소프트 리셋 / 티어 재계산 (레이팅이 그대로인 프로필은 last_rating_update_at 도 유지)
+    let mut player_ids = Vec::with_capacity(profiles.len());
+    let mut mmrs = Vec::with_capacity(profiles.len());
+    let mut rds = Vec::with_capacity(profiles.len());
+    let mut tier_ids = Vec::with_capacity(profiles.len());
+    for profile in &profiles {
+        let before = Rating {
+            mmr: profile.mmr,
+            rd: profile.rd,
+            volatility: profile.volatility,
+        };
+        let reset = soft_reset(before, seasons, rating.max_rd);
+        if reset == before {
+            continue;
+        }
+        player_ids.push(profile.player_id);
+        mmrs.push(reset.mmr);
+        rds.push(reset.rd);
+        tier_ids.push(tier_for_mmr(&tiers, reset.mmr).unwrap_or(profile.tier_id));
+    }
+    sqlx::query!(
+        r#"
+        UPDATE player_profiles pp
+        SET mmr = u.mmr, rd = u.rd, tier_id = u.tier_id, last_rating_update_at = $5
+        FROM UNNEST($1::BIGINT[], $2::FLOAT8[], $3::FLOAT8[], $4::INT[]) AS u(player_id, mmr, rd, tier_id)
+        WHERE pp.player_id = u.player_id
+        "#,
+        &player_ids,
+        &mmrs,
+        &rds,
+        &tier_ids,
+        now
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    // 5. 시즌 마감, 다음 시즌 시작
+    let closed = sqlx::query_as!(
+        Season,
+        "UPDATE seasons SET closed_at = $1 WHERE id = $2 RETURNING *",
+        now,
+        current.id
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+    let next = sqlx::query_as!(
+        Season,
+        "INSERT INTO seasons (starts_at, ends_at) VALUES ($1, $2) RETURNING *",
+        closed.ends_at,
+        season_end(closed.ends_at, now, seasons.length)
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(SeasonRollover::RolledOver {
+        closed,
+        next,
+        standings,
+        rewards,
+    })
+}
+
+pub async fn get_seasons(pool: &PgPool) -> Result<Vec<Season>> {
+    sqlx::query_as!(Season, "SELECT * FROM seasons ORDER BY id DESC")
+        .fetch_all(pool)
+        .await
+        .map_err(Into::into)
+}
+
+pub async fn get_season(pool: &PgPool, season_id: i32) -> Result<Option<Season>> {
+    sqlx::query_as!(Season, "SELECT * FROM seasons WHERE id = $1", season_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(Into::into)
+}
+
+/// 시즌 최종 순위를 순위순으로 페이지 단위로 가져옵니다.
+pub async fn get_season_standings(
+    pool: &PgPool,
+    season_id: i32,
+    limit: i64,
+    offset: i64,
+) -> Result<Vec<SeasonStanding>> {
+    sqlx::query_as!(
+        SeasonStanding,
+        r#"
+        SELECT ss.season_id, ss.player_id, p.last_known_username AS username, ss.rank,
+               ss.final_mmr, ss.final_rd, ss.tier_id, t.name AS tier_name, ss.ranked_matches,
+               sr.experience_points AS "reward_experience_points?"
+        FROM season_standings ss
+        JOIN players p ON p.id = ss.player_id
+        JOIN tiers t ON t.id = ss.tier_id
+        LEFT JOIN season_rewards sr ON sr.season_id = ss.season_id AND sr.player_id = ss.player_id
+        WHERE ss.season_id = $1
+        ORDER BY ss.rank
+        LIMIT $2 OFFSET $3
+        "#,
+        season_id,
+        limit,
+        offset
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(Into::into)
+}
+
+pub async fn count_season_standings(pool: &PgPool, season_id: i32) -> Result<i64> {
+    sqlx::query_scalar!(
+        r#"SELECT COUNT(*) AS "count!" FROM season_standings WHERE season_id = $1"#,
+        season_id
+    )
+    .fetch_one(pool)
+    .await
+    .map_err(Into::into)
+}
+
+/// 플레이어의 지난 시즌 기록을 최신 시즌순으로 가져옵니다.
+pub async fn get_player_season_history(
+    pool: &PgPool,
+    player_id: i64,
+) -> Result<Vec<SeasonStanding>> {
+    sqlx::query_as!(
+        SeasonStanding,
+        r#"
+        SELECT ss.season_id, ss.player_id, p.last_known_username AS username, ss.rank,
+               ss.final_mmr, ss.final_rd, ss.tier_id, t.name AS tier_name, ss.ranked_matches,
+               sr.experience_points AS "reward_experience_points?"
+        FROM season_standings ss
+        JOIN players p ON p.id = ss.player_id
+        JOIN tiers t ON t.id = ss.tier_id
+        LEFT JOIN season_rewards sr ON sr.season_id = ss.season_id AND sr.player_id = ss.player_id
+        WHERE ss.player_id = $1
+        ORDER BY ss.season_id DESC
+        "#,
+        player_id
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(Into::into)
+}
+
+// =================================================================
+// 15. 리더보드 (Leaderboards) - 밴 된 플레이어 제외
+// =================================================================
+
+/// 랭크 매치를 1판 이상 한 플레이어의 (player_id, mmr). tier_id 를 주면 해당 티어만
+pub async fn get_mmr_board(pool: &PgPool, tier_id: Option<i32>) -> Result<Vec<(i64, f64)>> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT pp.player_id, pp.mmr
+        FROM player_profiles pp
+        JOIN players p ON p.id = pp.player_id
+        WHERE p.status <> 'banned'
+          AND ($1::INT IS NULL OR pp.tier_id = $1)
+          AND EXISTS (
+              SELECT 1
+              FROM match_participants mp
+              JOIN match_history mh ON mh.id = mp.match_id
+              JOIN game_modes gm ON gm.id = mh.game_mode_id
+              WHERE mp.player_id = pp.player_id AND gm.is_ranked
+          )
+        "#,
+        tier_id
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(rows.into_iter().map(|r| (r.player_id, r.mmr)).collect())
+}
+
+/// 플레이어별 최고 도달 깊이 (시련 순번 * 10 + Phase)
+///
+/// `ordeals` / `phases` 는 run_results 의 reached_ordeal / reached_phase 값을 순서대로 나열한 것
+pub async fn get_deepest_ordeal_board(
+    pool: &PgPool,
+    ordeals: &[String],
+    phases: &[String],
+) -> Result<Vec<(i64, f64)>> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT rr.player_id,
+               MAX(array_position($1::TEXT[], rr.reached_ordeal::TEXT) * 10
+                   + array_position($2::TEXT[], rr.reached_phase::TEXT))::FLOAT8 AS "depth!"
+        FROM run_results rr
+        JOIN players p ON p.id = rr.player_id
+        WHERE p.status <> 'banned'
+          AND array_position($1::TEXT[], rr.reached_ordeal::TEXT) IS NOT NULL
+          AND array_position($2::TEXT[], rr.reached_phase::TEXT) IS NOT NULL
+        GROUP BY rr.player_id
+        "#,
+        ordeals,
+        phases
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(rows.into_iter().map(|r| (r.player_id, r.depth)).collect())
+}
+
+/// 플레이어별 가장 빠른 클리어 (game_server 가 잰 런 시간, 초). `cleared` 는 run_results 의 end_reason 값
+pub async fn get_fastest_clear_board(pool: &PgPool, cleared: &str) -> Result<Vec<(i64, f64)>> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT rr.player_id, MIN(rr.duration_seconds)::FLOAT8 AS "seconds!"
+        FROM run_results rr
+        JOIN players p ON p.id = rr.player_id
+        WHERE p.status <> 'banned'
+          AND rr.end_reason = $1
+        GROUP BY rr.player_id
+        "#,
+        cleared
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(rows.into_iter().map(|r| (r.player_id, r.seconds)).collect())
+}
+
+/// 리더보드 페이지에 표시할 이름 / 현재 티어
+pub async fn get_leaderboard_players(
+    pool: &PgPool,
+    player_ids: &[i64],
+) -> Result<Vec<LeaderboardPlayer>> {
+    sqlx::query_as!(
+        LeaderboardPlayer,
+        r#"
+        SELECT p.id AS player_id, p.last_known_username AS username, pp.tier_id
+        FROM players p
+        JOIN player_profiles pp ON pp.player_id = p.id
+        WHERE p.id = ANY($1)
+        "#,
+        player_ids
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(Into::into)
+}
+
+// =================================================================
+// 16. 런 결과 (Run Results)
+// =================================================================
+
+/// game_server 가 제출한 런 1개 (문자열 필드는 런 요약의 serde 값 그대로)
+pub struct RunResult<'a> {
+    pub player_id: i64,
+    pub run_seed: u64,
+    pub end_reason: &'a str,
+    pub reached_ordeal: &'a str,
+    pub reached_phase: &'a str,
+    pub duration_seconds: i32,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: DateTime<Utc>,
+    pub summary: &'a serde_json::Value,
+}
+
+/// 런 결과를 저장합니다. 같은 플레이어의 같은 run_seed 는 한 번만 저장하며, 새로 저장했으면 true.
+pub async fn record_run_result(pool: &PgPool, run: &RunResult<'_>) -> Result<bool> {
+    let inserted = sqlx::query!(
+        r#"
+        INSERT INTO run_results
+            (player_id, run_seed, end_reason, reached_ordeal, reached_phase,
+             duration_seconds, started_at, ended_at, summary)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        ON CONFLICT (player_id, run_seed) DO NOTHING
+        "#,
+        run.player_id,
+        // u64 seed 를 비트 그대로 BIGINT 에 저장
+        run.run_seed as i64,
+        run.end_reason,
+        run.reached_ordeal,
+        run.reached_phase,
+        run.duration_seconds,
+        run.started_at,
+        run.ended_at,
+        run.summary
+    )
+    .execute(pool)
+    .await?
+    .rows_affected();
+    Ok(inserted == 1)
+}
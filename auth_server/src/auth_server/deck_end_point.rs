@@ -0,0 +1,365 @@
+use std::collections::HashMap;
+
+use actix_web::{web, HttpResponse};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+use uuid::Uuid;
+
+use crate::auth_server::{
+    collection::{validate_deck_contents, DeckEntry, OwnedCard},
+    db_operation,
+    errors::AuthError,
+    extractor::AuthenticatedPlayer,
+    model::{Card, CardRarity, DeckCard, PlayerDeck},
+    types::AppState,
+};
+
+/// player_decks.deck_name 길이 (VARCHAR(100))
+const MAX_DECK_NAME_LEN: usize = 100;
+
+// --- HTTP 요청/응답 구조체 ---
+#[derive(Deserialize)]
+struct CreateDeckRequest {
+    name: String,
+    cover_card_id: Option<i32>,
+}
+
+#[derive(Deserialize)]
+struct UpdateDeckRequest {
+    name: Option<String>,
+    cover_card_id: Option<i32>,
+}
+
+#[derive(Deserialize)]
+struct ReplaceDeckCardsRequest {
+    cards: Vec<DeckEntry>,
+}
+
+#[derive(Serialize)]
+struct CollectionEntry {
+    card: Card,
+    quantity: i32,
+    is_new: bool,
+}
+
+#[derive(Serialize)]
+struct CollectionResponse {
+    cards: Vec<CollectionEntry>,
+}
+
+#[derive(Serialize)]
+struct DeckCardEntry {
+    card_id: i32,
+    quantity: i32,
+}
+
+#[derive(Serialize)]
+struct DeckResponse {
+    id: Uuid,
+    name: String,
+    cover_card_id: Option<i32>,
+    card_count: i32,
+    cards: Vec<DeckCardEntry>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl DeckResponse {
+    fn new(deck: PlayerDeck, cards: Vec<DeckCard>) -> Self {
+        Self {
+            id: deck.id,
+            name: deck.deck_name,
+            cover_card_id: deck.cover_card_id,
+            card_count: cards.iter().map(|c| c.quantity).sum(),
+            cards: cards
+                .into_iter()
+                .map(|c| DeckCardEntry {
+                    card_id: c.card_id,
+                    quantity: c.quantity,
+                })
+                .collect(),
+            created_at: deck.created_at,
+            updated_at: deck.updated_at,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct DeckListResponse {
+    max_decks: i64,
+    max_deck_size: i32,
+    decks: Vec<DeckResponse>,
+}
+
+#[derive(Serialize)]
+struct CardCatalogResponse {
+    cards: Vec<Card>,
+}
+
+fn validate_deck_name(name: &str) -> Result<&str, AuthError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(AuthError::BadRequest(
+            "deck name must not be empty".to_string(),
+        ));
+    }
+    if name.chars().count() > MAX_DECK_NAME_LEN {
+        return Err(AuthError::BadRequest(format!(
+            "deck name must be at most {} characters",
+            MAX_DECK_NAME_LEN
+        )));
+    }
+    Ok(name)
+}
+
+/// 같은 이름의 덱이 있으면 (UNIQUE (player_id, deck_name)) 409 로 바꿉니다.
+fn map_deck_name_conflict(e: anyhow::Error, name: &str) -> AuthError {
+    let unique_violation = e
+        .downcast_ref::<sqlx::Error>()
+        .and_then(|e| e.as_database_error())
+        .is_some_and(|e| e.is_unique_violation());
+    if unique_violation {
+        AuthError::Conflict(format!("A deck named `{}` already exists", name))
+    } else {
+        AuthError::InternalServerError(e)
+    }
+}
+
+/// 플레이어 소유 카드 (card_id → 등급, 보유 장수)
+async fn owned_cards(
+    state: &AppState,
+    steam_id: i64,
+) -> Result<HashMap<i32, OwnedCard>, AuthError> {
+    let collection = db_operation::get_player_card_collection(&state.db_pool, steam_id).await?;
+    let card_ids: Vec<i32> = collection.iter().map(|c| c.card_id).collect();
+    let rarities: HashMap<i32, CardRarity> =
+        db_operation::get_cards_by_ids(&state.db_pool, &card_ids)
+            .await?
+            .into_iter()
+            .map(|card| (card.id, card.rarity.unwrap_or(CardRarity::Common)))
+            .collect();
+
+    Ok(collection
+        .into_iter()
+        .filter_map(|c| {
+            rarities.get(&c.card_id).map(|&rarity| {
+                (
+                    c.card_id,
+                    OwnedCard {
+                        rarity,
+                        quantity: c.quantity,
+                    },
+                )
+            })
+        })
+        .collect())
+}
+
+async fn ensure_cover_card_owned(
+    state: &AppState,
+    steam_id: i64,
+    cover_card_id: Option<i32>,
+) -> Result<(), AuthError> {
+    if let Some(card_id) = cover_card_id {
+        if !owned_cards(state, steam_id).await?.contains_key(&card_id) {
+            return Err(AuthError::BadRequest(format!(
+                "cover card {} is not in your collection",
+                card_id
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn deck_not_found(deck_id: Uuid) -> AuthError {
+    AuthError::NotFound(format!("Deck {} not found", deck_id))
+}
+
+// --- 엔드포인트 핸들러 ---
+/// GET /cards
+/// 수집 가능한 카드 전체 목록 (게임 데이터의 환상체와 같음)
+#[actix_web::get("/cards")]
+pub async fn get_card_catalog_handler(
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, AuthError> {
+    let cards = db_operation::get_collectible_cards(&state.db_pool).await?;
+    Ok(HttpResponse::Ok().json(CardCatalogResponse { cards }))
+}
+
+/// GET /players/me/collection
+/// 토큰 주인이 보유한 카드와 장수를 반환합니다.
+#[actix_web::get("/me/collection")]
+pub async fn get_my_collection_handler(
+    state: web::Data<AppState>,
+    player: AuthenticatedPlayer,
+) -> Result<HttpResponse, AuthError> {
+    let collection =
+        db_operation::get_player_card_collection(&state.db_pool, player.steam_id).await?;
+    let card_ids: Vec<i32> = collection.iter().map(|c| c.card_id).collect();
+    let mut cards: HashMap<i32, Card> = db_operation::get_cards_by_ids(&state.db_pool, &card_ids)
+        .await?
+        .into_iter()
+        .map(|card| (card.id, card))
+        .collect();
+
+    let cards = collection
+        .into_iter()
+        .filter_map(|c| {
+            cards.remove(&c.card_id).map(|card| CollectionEntry {
+                card,
+                quantity: c.quantity,
+                is_new: c.is_new,
+            })
+        })
+        .collect();
+    Ok(HttpResponse::Ok().json(CollectionResponse { cards }))
+}
+
+/// GET /players/me/decks
+#[actix_web::get("/me/decks")]
+pub async fn get_my_decks_handler(
+    state: web::Data<AppState>,
+    player: AuthenticatedPlayer,
+) -> Result<HttpResponse, AuthError> {
+    let decks = db_operation::get_player_decks(&state.db_pool, player.steam_id).await?;
+    let deck_ids: Vec<Uuid> = decks.iter().map(|d| d.id).collect();
+    let mut cards_by_deck: HashMap<Uuid, Vec<DeckCard>> = HashMap::new();
+    for card in db_operation::get_cards_of_decks(&state.db_pool, &deck_ids).await? {
+        cards_by_deck.entry(card.deck_id).or_default().push(card);
+    }
+
+    Ok(HttpResponse::Ok().json(DeckListResponse {
+        max_decks: state.decks.max_decks,
+        max_deck_size: state.decks.max_deck_size,
+        decks: decks
+            .into_iter()
+            .map(|deck| {
+                let cards = cards_by_deck.remove(&deck.id).unwrap_or_default();
+                DeckResponse::new(deck, cards)
+            })
+            .collect(),
+    }))
+}
+
+/// POST /players/me/decks
+/// 빈 덱을 만듭니다.
+#[actix_web::post("/me/decks")]
+pub async fn create_deck_handler(
+    state: web::Data<AppState>,
+    player: AuthenticatedPlayer,
+    req_body: web::Json<CreateDeckRequest>,
+) -> Result<HttpResponse, AuthError> {
+    let name = validate_deck_name(&req_body.name)?;
+    if db_operation::count_player_decks(&state.db_pool, player.steam_id).await?
+        >= state.decks.max_decks
+    {
+        return Err(AuthError::BadRequest(format!(
+            "You can have at most {} decks",
+            state.decks.max_decks
+        )));
+    }
+    ensure_cover_card_owned(&state, player.steam_id, req_body.cover_card_id).await?;
+
+    let deck = db_operation::create_deck(
+        &state.db_pool,
+        player.steam_id,
+        name,
+        req_body.cover_card_id,
+    )
+    .await
+    .map_err(|e| map_deck_name_conflict(e, name))?;
+
+    info!("Player {} created deck {}", player.steam_id, deck.id);
+    Ok(HttpResponse::Created().json(DeckResponse::new(deck, Vec::new())))
+}
+
+/// GET /players/me/decks/{deck_id}
+#[actix_web::get("/me/decks/{deck_id}")]
+pub async fn get_my_deck_handler(
+    state: web::Data<AppState>,
+    player: AuthenticatedPlayer,
+    path: web::Path<Uuid>,
+) -> Result<HttpResponse, AuthError> {
+    let deck_id = path.into_inner();
+    let deck = db_operation::get_deck_with_cards(&state.db_pool, deck_id)
+        .await?
+        .filter(|deck| deck.deck_info.player_id == player.steam_id)
+        .ok_or_else(|| deck_not_found(deck_id))?;
+    Ok(HttpResponse::Ok().json(DeckResponse::new(deck.deck_info, deck.cards)))
+}
+
+/// PATCH /players/me/decks/{deck_id}
+/// 덱 이름 / 대표 카드를 바꿉니다.
+#[actix_web::patch("/me/decks/{deck_id}")]
+pub async fn update_deck_handler(
+    state: web::Data<AppState>,
+    player: AuthenticatedPlayer,
+    path: web::Path<Uuid>,
+    req_body: web::Json<UpdateDeckRequest>,
+) -> Result<HttpResponse, AuthError> {
+    let deck_id = path.into_inner();
+    let name = req_body
+        .name
+        .as_deref()
+        .map(validate_deck_name)
+        .transpose()?;
+    ensure_cover_card_owned(&state, player.steam_id, req_body.cover_card_id).await?;
+
+    let deck = db_operation::update_deck(
+        &state.db_pool,
+        deck_id,
+        player.steam_id,
+        name,
+        req_body.cover_card_id,
+    )
+    .await
+    .map_err(|e| map_deck_name_conflict(e, name.unwrap_or_default()))?
+    .ok_or_else(|| deck_not_found(deck_id))?;
+    let cards = db_operation::get_cards_of_decks(&state.db_pool, &[deck_id]).await?;
+    Ok(HttpResponse::Ok().json(DeckResponse::new(deck, cards)))
+}
+
+/// DELETE /players/me/decks/{deck_id}
+#[actix_web::delete("/me/decks/{deck_id}")]
+pub async fn delete_deck_handler(
+    state: web::Data<AppState>,
+    player: AuthenticatedPlayer,
+    path: web::Path<Uuid>,
+) -> Result<HttpResponse, AuthError> {
+    let deck_id = path.into_inner();
+    if !db_operation::delete_deck(&state.db_pool, deck_id, player.steam_id).await? {
+        return Err(deck_not_found(deck_id));
+    }
+    info!("Player {} deleted deck {}", player.steam_id, deck_id);
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// PUT /players/me/decks/{deck_id}/cards
+/// 덱 구성을 통째로 교체합니다. 등급별 장수 제한(`CardRarity`)과 보유 장수를 넘을 수 없습니다.
+#[actix_web::put("/me/decks/{deck_id}/cards")]
+pub async fn replace_deck_cards_handler(
+    state: web::Data<AppState>,
+    player: AuthenticatedPlayer,
+    path: web::Path<Uuid>,
+    req_body: web::Json<ReplaceDeckCardsRequest>,
+) -> Result<HttpResponse, AuthError> {
+    let deck_id = path.into_inner();
+    let owned = owned_cards(&state, player.steam_id).await?;
+    validate_deck_contents(&req_body.cards, &owned, state.decks.max_deck_size)
+        .map_err(AuthError::BadRequest)?;
+
+    let entries: Vec<(i32, i32)> = req_body
+        .cards
+        .iter()
+        .map(|e| (e.card_id, e.quantity))
+        .collect();
+    db_operation::replace_deck_cards(&state.db_pool, deck_id, player.steam_id, &entries)
+        .await?
+        .ok_or_else(|| deck_not_found(deck_id))?;
+
+    let deck = db_operation::get_deck_with_cards(&state.db_pool, deck_id)
+        .await?
+        .ok_or_else(|| deck_not_found(deck_id))?;
+    Ok(HttpResponse::Ok().json(DeckResponse::new(deck.deck_info, deck.cards)))
+}
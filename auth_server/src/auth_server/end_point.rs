@@ -1,13 +1,18 @@
 use actix_web::{web, HttpResponse};
-use chrono::{Duration, Utc};
-use jsonwebtoken::{encode, EncodingKey, Header};
+use chrono::Utc;
 use serde::{Deserialize, Serialize};
-use tracing::info;
+use tracing::{info, warn};
 
 use crate::auth_server::{
-    db_operation,
+    db_operation::{self, RefreshRotation},
     errors::AuthError,
-    types::{AppState, Claims, SteamApiResponse},
+    extractor::AuthenticatedPlayer,
+    model::{Player, PlayerStatus},
+    token::{
+        generate_refresh_token, hash_refresh_token, issue_access_token, issue_login_tokens,
+        IssuedTokens,
+    },
+    types::AppState,
 };
 
 // --- HTTP 요청 본문 구조체 ---
@@ -16,11 +21,38 @@ struct SteamAuthRequest {
     ticket: String,
 }
 
+#[derive(Deserialize)]
+struct RefreshRequest {
+    refresh_token: String,
+}
+
+#[derive(Deserialize)]
+struct LogoutRequest {
+    /// 없으면 모든 기기의 리프레시 토큰을 폐기합니다.
+    refresh_token: Option<String>,
+}
+
 #[derive(Deserialize, Serialize)]
 struct AuthSuccessResponse {
     message: String,
     steam_id: String,
+    /// 액세스 토큰 (JWT)
     token: String,
+    /// 액세스 토큰 수명 (초)
+    expires_in: i64,
+    refresh_token: String,
+}
+
+impl AuthSuccessResponse {
+    fn new(message: &str, steam_id: i64, tokens: IssuedTokens) -> Self {
+        Self {
+            message: message.to_string(),
+            steam_id: steam_id.to_string(),
+            token: tokens.access_token,
+            expires_in: tokens.expires_in,
+            refresh_token: tokens.refresh_token,
+        }
+    }
 }
 
 #[derive(Serialize)]
@@ -50,132 +82,182 @@ pub async fn test_authentication_handler(
         .unwrap_or(format!("test_user_{}", steam_id_i64));
 
     // 1. 실제 로그인과 동일한 DB 작업을 수행합니다.
-    db_operation::upsert_player_on_login(&state.db_pool, steam_id_i64, &username).await?;
+    let player =
+        db_operation::upsert_player_on_login(&state.db_pool, steam_id_i64, &username).await?;
     tracing::info!("[TEST] Upserted test player: {}", steam_id_i64);
+    ensure_player_active(&state, player).await?;
 
-    // 2. JWT를 생성합니다.
-    let now = Utc::now();
-    let claims = Claims {
-        sub: steam_id_i64.to_string(),
-        iat: now.timestamp() as usize,
-        exp: (now + Duration::days(1)).timestamp() as usize, // 테스트용 토큰은 하루 동안 유효
-    };
-
-    let token = encode(
-        &Header::default(),
-        &claims,
-        &EncodingKey::from_secret(state.jwt_secret.as_ref()),
-    )
-    .map_err(|e| AuthError::InternalServerError(anyhow::anyhow!(e)))?;
+    // 2. 액세스 / 리프레시 토큰을 발급합니다.
+    let tokens = issue_login_tokens(&state, steam_id_i64).await?;
 
     tracing::info!("[TEST] Generated JWT for test player: {}", steam_id_i64);
 
     // 3. 성공 응답에 토큰을 포함하여 반환합니다.
-    Ok(HttpResponse::Ok().json(AuthSuccessResponse {
-        message: "Test authentication successful.".to_string(),
-        steam_id: steam_id_i64.to_string(),
-        token,
-    }))
+    Ok(HttpResponse::Ok().json(AuthSuccessResponse::new(
+        "Test authentication successful.",
+        steam_id_i64,
+        tokens,
+    )))
+}
+
+/// 정지 / 밴 상태면 로그인과 토큰 갱신을 거부합니다. 기간이 끝난 정지는 여기서 해제합니다.
+async fn ensure_player_active(state: &AppState, player: Player) -> Result<(), AuthError> {
+    match player.status {
+        PlayerStatus::Active => Ok(()),
+        PlayerStatus::Suspended
+            if player
+                .suspended_until
+                .is_some_and(|until| until <= Utc::now()) =>
+        {
+            db_operation::lift_expired_suspensions(&state.db_pool, Some(player.id)).await?;
+            info!("Suspension of player {} expired and was lifted", player.id);
+            Ok(())
+        }
+        PlayerStatus::Suspended => Err(AuthError::Forbidden(format!(
+            "Account suspended until {}: {}",
+            player
+                .suspended_until
+                .map(|until| until.to_rfc3339())
+                .unwrap_or_else(|| "further notice".to_string()),
+            player.status_reason.as_deref().unwrap_or("no reason given")
+        ))),
+        PlayerStatus::Banned => Err(AuthError::Forbidden(format!(
+            "Account banned: {}",
+            player.status_reason.as_deref().unwrap_or("no reason given")
+        ))),
+    }
 }
 
 // --- 엔드포인트 핸들러 ---
 /// POST /auth/steam
 /// 클라이언트로부터 스팀 티켓을 받아 인증을 처리합니다.
+/// 티켓 검증은 `AppState::ticket_verifier` 가 담당합니다 (실서비스: Steam Web API).
 #[actix_web::post("/steam")]
 pub async fn steam_authentication_handler(
     state: web::Data<AppState>,
     req_body: web::Json<SteamAuthRequest>,
 ) -> Result<HttpResponse, AuthError> {
     info!("Received Steam authentication request with ticket",);
-    let api_url = "https://api.steampowered.com/ISteamUserAuth/AuthenticateUserTicket/v1/";
-
-    // 1. 스팀 웹 API에 GET 요청을 보냅니다.
-    let res = state
-        .http_client
-        .get(api_url)
-        .query(&[
-            ("key", &state.steam_web_api_key),
-            ("appid", &state.app_id.to_string()),
-            ("ticket", &req_body.ticket),
-            ("identity", &state.expected_identity),
-        ])
-        .send()
-        .await
-        .map_err(|e| AuthError::InternalServerError(anyhow::anyhow!(e)))?;
-
-    // 2. 응답 상태 코드 확인
-    if !res.status().is_success() {
-        return Err(AuthError::GatewayTimeout(format!(
-            "Steam API returned non-success status: {}",
-            res.status()
-        )));
-    }
 
-    // 3. JSON 응답 파싱
-    let steam_response = res
-        .json::<SteamApiResponse>()
-        .await
-        .map_err(|e| AuthError::InternalServerError(anyhow::anyhow!(e)))?;
-
-    // 4. 스팀 응답의 유효성 검사
-    if let Some(params) = steam_response.response.params {
-        if params.result == "OK" {
-            // 성공!
-            let steam_id_u64 = params.steamid.parse::<u64>().map_err(|_| {
-                AuthError::InternalServerError(anyhow::anyhow!("Steam returned invalid SteamID"))
-            })?;
-
-            info!(
-                "Steam Web API authentication successful for SteamID: {}",
-                steam_id_u64
+    // 1. 티켓 검증
+    let verified = state.ticket_verifier.verify(&req_body.ticket).await?;
+    let steam_id = verified.steam_id as i64;
+
+    // 2. DB 작업 수행
+    let temp_username = format!("user_{}", verified.steam_id);
+    let player =
+        db_operation::upsert_player_on_login(&state.db_pool, steam_id, &temp_username).await?;
+    ensure_player_active(&state, player).await?;
+
+    // 3. 액세스 / 리프레시 토큰 발급
+    let tokens = issue_login_tokens(&state, steam_id).await?;
+
+    Ok(HttpResponse::Ok().json(AuthSuccessResponse::new(
+        "Steam authentication successful.",
+        steam_id,
+        tokens,
+    )))
+}
+
+/// POST /auth/refresh
+/// 리프레시 토큰을 새 토큰 쌍으로 교체합니다. 사용한 리프레시 토큰은 다시 쓸 수 없습니다.
+/// 이미 사용한 토큰이 다시 들어오면 같은 로그인에서 나온 토큰과 플레이어의 액세스 토큰을 모두 폐기합니다.
+#[actix_web::post("/refresh")]
+pub async fn refresh_token_handler(
+    state: web::Data<AppState>,
+    req_body: web::Json<RefreshRequest>,
+) -> Result<HttpResponse, AuthError> {
+    let refresh_token = generate_refresh_token();
+    let rotation = db_operation::rotate_refresh_token(
+        &state.db_pool,
+        &hash_refresh_token(&req_body.refresh_token),
+        &hash_refresh_token(&refresh_token),
+        Utc::now() + state.tokens.refresh_ttl,
+    )
+    .await?;
+
+    let steam_id = match rotation {
+        RefreshRotation::Rotated { player_id } => player_id,
+        RefreshRotation::Reused { player_id } => {
+            warn!(
+                "Refresh token reuse detected for player {}, revoking sessions",
+                player_id
             );
+            state
+                .revocation
+                .revoke_player(player_id, state.tokens.access_ttl)
+                .await?;
+            return Err(AuthError::Unauthorized(
+                "Refresh token has already been used".to_string(),
+            ));
+        }
+        RefreshRotation::Revoked { player_id } => {
+            // 제재로 폐기된 경우 그 사유를 알려줌
+            if let Some(player) = db_operation::get_player_by_id(&state.db_pool, player_id).await? {
+                ensure_player_active(&state, player).await?;
+            }
+            return Err(AuthError::Unauthorized(
+                "Refresh token has been revoked".to_string(),
+            ));
+        }
+        RefreshRotation::Expired => {
+            return Err(AuthError::Unauthorized("Refresh token expired".to_string()))
+        }
+        RefreshRotation::NotFound => {
+            return Err(AuthError::Unauthorized("Invalid refresh token".to_string()))
+        }
+    };
+
+    let player = db_operation::get_player_by_id(&state.db_pool, steam_id)
+        .await?
+        .ok_or_else(|| AuthError::Unauthorized("Invalid refresh token".to_string()))?;
+    if let Err(e) = ensure_player_active(&state, player).await {
+        db_operation::revoke_player_refresh_tokens(&state.db_pool, steam_id).await?;
+        return Err(e);
+    }
+
+    let (access_token, _) =
+        issue_access_token(&state.jwt_secret, steam_id, state.tokens.access_ttl)?;
+    Ok(HttpResponse::Ok().json(AuthSuccessResponse::new(
+        "Token refreshed.",
+        steam_id,
+        IssuedTokens {
+            access_token,
+            expires_in: state.tokens.access_ttl.num_seconds(),
+            refresh_token,
+        },
+    )))
+}
 
-            // 5. DB 작업 수행
-            let temp_username = format!("user_{}", steam_id_u64);
-            db_operation::upsert_player_on_login(
+/// POST /auth/logout
+/// 현재 액세스 토큰을 폐기하고, 리프레시 토큰 계열(없으면 플레이어의 전체 리프레시 토큰)을 폐기합니다.
+#[actix_web::post("/logout")]
+pub async fn logout_handler(
+    state: web::Data<AppState>,
+    player: AuthenticatedPlayer,
+    req_body: Option<web::Json<LogoutRequest>>,
+) -> Result<HttpResponse, AuthError> {
+    let refresh_token = req_body.and_then(|body| body.into_inner().refresh_token);
+    let revoked = match refresh_token {
+        Some(token) => {
+            db_operation::revoke_refresh_family(
                 &state.db_pool,
-                steam_id_u64 as i64,
-                &temp_username,
-            )
-            .await?;
-
-            // JWT 생성
-            let now = Utc::now();
-            let claims = Claims {
-                sub: steam_id_u64.to_string(),
-                iat: now.timestamp() as usize,
-                exp: (now + Duration::days(1)).timestamp() as usize,
-            };
-            let token = encode(
-                &Header::default(),
-                &claims,
-                &EncodingKey::from_secret(state.jwt_secret.as_ref()),
+                player.steam_id,
+                &hash_refresh_token(&token),
             )
-            .map_err(|e| AuthError::InternalServerError(anyhow::anyhow!(e)))?;
-
-            Ok(HttpResponse::Ok().json(AuthSuccessResponse {
-                message: "Steam Web API authentication successful.".to_string(),
-                steam_id: steam_id_u64.to_string(),
-                token,
-            }))
-        } else {
-            // 결과가 "OK"가 아닌 경우
-            Err(AuthError::Unauthorized(format!(
-                "Steam validation failed with result: {}",
-                params.result
-            )))
+            .await?
         }
-    } else if let Some(error) = steam_response.response.error {
-        // 스팀 API가 에러를 반환한 경우
-        Err(AuthError::Unauthorized(format!(
-            "Steam API Error {}: {}",
-            error.errorcode, error.errordesc
-        )))
-    } else {
-        Err(AuthError::InternalServerError(anyhow::anyhow!(
-            "Invalid response structure from Steam API"
-        )))
-    }
+        None => db_operation::revoke_player_refresh_tokens(&state.db_pool, player.steam_id).await?,
+    };
+    state.revocation.revoke_token(&player.claims).await?;
+
+    info!(
+        "Player {} logged out ({} refresh tokens revoked)",
+        player.steam_id, revoked
+    );
+    Ok(HttpResponse::Ok().json(GenericSuccessResponse {
+        message: "Logged out.".to_string(),
+    }))
 }
 
 /// DELETE /test/player/{steam_id}
@@ -9,6 +9,9 @@ use std::fmt;
 pub enum AuthError {
     BadRequest(String),
     Unauthorized(String),
+    Forbidden(String),
+    NotFound(String),
+    Conflict(String),
     GatewayTimeout(String),
     InternalServerError(anyhow::Error),
 }
@@ -19,6 +22,9 @@ impl fmt::Display for AuthError {
         match self {
             AuthError::BadRequest(reason) => write!(f, "Bad Request: {}", reason),
             AuthError::Unauthorized(reason) => write!(f, "Unauthorized: {}", reason),
+            AuthError::Forbidden(reason) => write!(f, "Forbidden: {}", reason),
+            AuthError::NotFound(reason) => write!(f, "Not Found: {}", reason),
+            AuthError::Conflict(reason) => write!(f, "Conflict: {}", reason),
             AuthError::GatewayTimeout(reason) => write!(f, "Gateway Timeout: {}", reason),
             AuthError::InternalServerError(e) => write!(f, "Internal Server Error: {:?}", e),
         }
@@ -32,6 +38,9 @@ impl ResponseError for AuthError {
         match self {
             AuthError::BadRequest(_) => StatusCode::BAD_REQUEST,
             AuthError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            AuthError::Forbidden(_) => StatusCode::FORBIDDEN,
+            AuthError::NotFound(_) => StatusCode::NOT_FOUND,
+            AuthError::Conflict(_) => StatusCode::CONFLICT,
             AuthError::GatewayTimeout(_) => StatusCode::GATEWAY_TIMEOUT,
             AuthError::InternalServerError(_) => StatusCode::INTERNAL_SERVER_ERROR,
         }
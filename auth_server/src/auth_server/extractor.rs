@@ -0,0 +1,212 @@
+// src/auth/extractor.rs
+
+use std::future::{ready, Ready};
+
+use actix_web::{dev::Payload, web, FromRequest, HttpRequest};
+use futures::future::LocalBoxFuture;
+use jsonwebtoken::{decode, errors::ErrorKind, DecodingKey, Validation};
+
+use crate::auth_server::{
+    errors::AuthError,
+    types::{AppState, Claims},
+};
+
+/// 내부 서비스 인증 헤더 이름
+pub const SERVICE_TOKEN_HEADER: &str = "X-Service-Token";
+/// 관리자 인증 헤더 이름
+pub const ADMIN_KEY_HEADER: &str = "X-Admin-Key";
+
+fn app_state(req: &HttpRequest) -> Result<&web::Data<AppState>, AuthError> {
+    req.app_data::<web::Data<AppState>>().ok_or_else(|| {
+        AuthError::InternalServerError(anyhow::anyhow!("AppState is not registered"))
+    })
+}
+
+// =================================================================
+// 1. 플레이어 인증 (JWT)
+// =================================================================
+
+/// `Authorization: Bearer <token>` 으로 인증된 플레이어
+///
+/// 핸들러 인자로 받으면 토큰이 없거나, 유효하지 않거나, 폐기되었을 때 401 을 반환합니다.
+#[derive(Debug, Clone)]
+pub struct AuthenticatedPlayer {
+    pub steam_id: i64,
+    pub claims: Claims,
+}
+
+impl AuthenticatedPlayer {
+    fn verify(req: &HttpRequest) -> Result<(web::Data<AppState>, Self), AuthError> {
+        let state = app_state(req)?.clone();
+        let token = req
+            .headers()
+            .get("Authorization")
+            .and_then(|h| h.to_str().ok())
+            .and_then(|s| s.strip_prefix("Bearer "))
+            .map(str::trim)
+            .ok_or_else(|| AuthError::Unauthorized("Missing bearer token".to_string()))?;
+
+        let claims = decode::<Claims>(
+            token,
+            &DecodingKey::from_secret(state.jwt_secret.as_ref()),
+            &Validation::default(),
+        )
+        .map_err(|e| match e.kind() {
+            ErrorKind::ExpiredSignature => AuthError::Unauthorized("Token expired".to_string()),
+            _ => AuthError::Unauthorized("Invalid token".to_string()),
+        })?
+        .claims;
+
+        let steam_id = claims
+            .sub
+            .parse::<i64>()
+            .map_err(|_| AuthError::Unauthorized("Invalid token subject".to_string()))?;
+
+        Ok((state, Self { steam_id, claims }))
+    }
+}
+
+impl FromRequest for AuthenticatedPlayer {
+    type Error = AuthError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        let verified = Self::verify(req);
+        Box::pin(async move {
+            let (state, player) = verified?;
+            // Redis 장애 시에는 서명/만료 검증만으로 통과 (액세스 토큰 수명이 짧음)
+            match state.revocation.is_revoked(&player.claims).await {
+                Ok(true) => Err(AuthError::Unauthorized("Token revoked".to_string())),
+                Ok(false) => Ok(player),
+                Err(e) => {
+                    tracing::warn!("Token revocation check failed: {}", e);
+                    Ok(player)
+                }
+            }
+        })
+    }
+}
+
+// =================================================================
+// 2. 내부 서비스 인증 (Service Token)
+// =================================================================
+
+/// `X-Service-Token` 헤더로 인증된 내부 서비스 (game_server)
+#[derive(Debug, Clone, Copy)]
+pub struct ServiceCaller;
+
+impl ServiceCaller {
+    fn from_request_sync(req: &HttpRequest) -> Result<Self, AuthError> {
+        let state = app_state(req)?;
+        let token = req
+            .headers()
+            .get(SERVICE_TOKEN_HEADER)
+            .and_then(|h| h.to_str().ok())
+            .ok_or_else(|| AuthError::Unauthorized("Missing service token".to_string()))?;
+
+        // 키가 설정되지 않았으면 내부 API 를 열지 않습니다.
+        if state.service_token.is_empty()
+            || !constant_time_eq(token.as_bytes(), state.service_token.as_bytes())
+        {
+            return Err(AuthError::Forbidden("Invalid service token".to_string()));
+        }
+        Ok(Self)
+    }
+}
+
+impl FromRequest for ServiceCaller {
+    type Error = AuthError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        ready(Self::from_request_sync(req))
+    }
+}
+
+/// 길이 외의 정보가 응답 시간으로 새지 않도록 끝까지 비교합니다.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+// =================================================================
+// 3. 관리자 인증 (Admin Key)
+// =================================================================
+
+/// 관리자 1명의 이름과 키. 제재 기록의 actor 로 이름이 남습니다.
+#[derive(Debug, Clone)]
+pub struct AdminKey {
+    pub name: String,
+    pub key: String,
+}
+
+/// `name:key,name:key` 형식의 관리자 키 목록을 읽습니다.
+pub fn parse_admin_keys(value: &str) -> Result<Vec<AdminKey>, String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| match entry.split_once(':') {
+            Some((name, key)) if !name.trim().is_empty() && !key.trim().is_empty() => {
+                Ok(AdminKey {
+                    name: name.trim().to_string(),
+                    key: key.trim().to_string(),
+                })
+            }
+            _ => Err(format!(
+                "admin key entry must be `name:key`, got `{}`",
+                entry
+            )),
+        })
+        .collect()
+}
+
+/// `X-Admin-Key` 헤더로 인증된 관리자
+#[derive(Debug, Clone)]
+pub struct AdminActor {
+    pub name: String,
+}
+
+impl AdminActor {
+    fn from_request_sync(req: &HttpRequest) -> Result<Self, AuthError> {
+        let state = app_state(req)?;
+        let key = req
+            .headers()
+            .get(ADMIN_KEY_HEADER)
+            .and_then(|h| h.to_str().ok())
+            .ok_or_else(|| AuthError::Unauthorized("Missing admin key".to_string()))?;
+
+        state
+            .admin_keys
+            .iter()
+            .find(|admin| constant_time_eq(key.as_bytes(), admin.key.as_bytes()))
+            .map(|admin| Self {
+                name: admin.name.clone(),
+            })
+            .ok_or_else(|| AuthError::Forbidden("Invalid admin key".to_string()))
+    }
+}
+
+impl FromRequest for AdminActor {
+    type Error = AuthError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        ready(Self::from_request_sync(req))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_admin_keys() {
+        let keys = parse_admin_keys(" alice:k1, bob:k2 ,").unwrap();
+        assert_eq!(keys.len(), 2);
+        assert_eq!((keys[1].name.as_str(), keys[1].key.as_str()), ("bob", "k2"));
+
+        assert!(parse_admin_keys("").unwrap().is_empty());
+        assert!(parse_admin_keys("alice").is_err());
+        assert!(parse_admin_keys("alice:").is_err());
+    }
+}
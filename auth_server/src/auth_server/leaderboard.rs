@@ -0,0 +1,451 @@
+// src/auth/leaderboard.rs
+
+//! 리더보드 (Redis sorted set 캐시)
+//!
+//! 보드는 Postgres 에서 계산하고 Redis sorted set 에 `cache_ttl` 동안 캐시합니다.
+//! - `leaderboard:mmr`, `leaderboard:mmr:tier:{tier_id}`: 랭크 매치를 1판 이상 한 플레이어의 mmr (내림차순)
+//! - `leaderboard:deepest_ordeal`: game_server 가 제출한 런 결과(`run_results`)의 최고 도달 시련 / Phase (내림차순)
+//! - `leaderboard:fastest_clear`: 클리어한 런 중 가장 짧은 런 시간 (`run_results.duration_seconds`, 오름차순)
+//!
+//! 키가 없으면(만료 / 무효화) 요청 시점에 다시 만들고, Redis 를 쓸 수 없으면 Postgres 결과로 바로 응답합니다.
+//! 밴 된 플레이어는 제외하며, 런 결과는 최대 `cache_ttl` 만큼 늦게 반영됩니다.
+//! 멤버는 SteamID64 문자열(모두 17자리)이라 같은 점수끼리의 Redis 사전순 정렬이 숫자 정렬과 같습니다.
+
+use chrono::Duration;
+use game_core::game::enums::{OrdealType, PhaseType, RunEndReason};
+use redis::{aio::ConnectionManager, AsyncCommands};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use tracing::warn;
+
+use crate::{auth_server::db_operation, env::duration_from_seconds};
+
+/// 시련 깊이 점수 계산에 쓰는 순서 (점수 = 시련 순번 * 10 + Phase)
+const ORDEALS: [OrdealType; 5] = [
+    OrdealType::Dawn,
+    OrdealType::Noon,
+    OrdealType::Dusk,
+    OrdealType::Midnight,
+    OrdealType::White,
+];
+const PHASES: [PhaseType; 6] = [
+    PhaseType::I,
+    PhaseType::II,
+    PhaseType::III,
+    PhaseType::IV,
+    PhaseType::V,
+    PhaseType::VI,
+];
+/// 재구성 시 ZADD 1번에 넣는 멤버 수
+const ZADD_CHUNK: usize = 1000;
+
+/// 설정 파일 `[leaderboards]`
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct LeaderboardSettings {
+    /// 캐시한 보드를 Postgres 에서 다시 계산하기까지의 시간
+    #[serde(
+        rename = "cache_ttl_seconds",
+        deserialize_with = "duration_from_seconds"
+    )]
+    pub cache_ttl: Duration,
+}
+
+impl Default for LeaderboardSettings {
+    fn default() -> Self {
+        Self {
+            cache_ttl: Duration::seconds(60),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LeaderboardKind {
+    Mmr,
+    DeepestOrdeal,
+    FastestClear,
+}
+
+/// 보드 1개 (tier_id 는 Mmr 보드에서만 사용)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Board {
+    pub kind: LeaderboardKind,
+    pub tier_id: Option<i32>,
+}
+
+impl Board {
+    pub fn new(kind: LeaderboardKind, tier_id: Option<i32>) -> Result<Self, String> {
+        if tier_id.is_some() && kind != LeaderboardKind::Mmr {
+            return Err("tier_id is only supported by the mmr leaderboard".to_string());
+        }
+        Ok(Self { kind, tier_id })
+    }
+
+    pub fn key(&self) -> String {
+        match (self.kind, self.tier_id) {
+            (LeaderboardKind::Mmr, Some(tier_id)) => format!("leaderboard:mmr:tier:{}", tier_id),
+            (LeaderboardKind::Mmr, None) => "leaderboard:mmr".to_string(),
+            (LeaderboardKind::DeepestOrdeal, _) => "leaderboard:deepest_ordeal".to_string(),
+            (LeaderboardKind::FastestClear, _) => "leaderboard:fastest_clear".to_string(),
+        }
+    }
+
+    /// 점수가 낮을수록 높은 순위인지 여부
+    pub fn ascending(&self) -> bool {
+        self.kind == LeaderboardKind::FastestClear
+    }
+
+    /// 전체 보드 목록 (전체 mmr, 티어별 mmr, 런 기록)
+    pub async fn all(pool: &PgPool) -> anyhow::Result<Vec<Board>> {
+        let mut boards = vec![Board::new(LeaderboardKind::Mmr, None).expect("global board")];
+        for tier in db_operation::get_all_tiers(pool).await? {
+            boards.push(Board::new(LeaderboardKind::Mmr, Some(tier.id)).expect("mmr board"));
+        }
+        boards.push(Board::new(LeaderboardKind::DeepestOrdeal, None).expect("run board"));
+        boards.push(Board::new(LeaderboardKind::FastestClear, None).expect("run board"));
+        Ok(boards)
+    }
+}
+
+/// 순위 1줄 (rank 는 1부터)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RankedEntry {
+    pub rank: i64,
+    pub player_id: i64,
+    pub score: f64,
+}
+
+/// 보드의 일부 구간
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeaderboardSlice {
+    pub total: i64,
+    pub entries: Vec<RankedEntry>,
+}
+
+/// around-me 조회 결과
+#[derive(Debug, Clone, PartialEq)]
+pub struct AroundMe {
+    pub rank: i64,
+    pub slice: LeaderboardSlice,
+}
+
+/// unit enum 값의 serde 이름 (DB 에 저장되는 문자열)
+pub(crate) fn serde_name<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(name)) => name,
+        other => panic!("unit enum variant serializes to a string, got {:?}", other),
+    }
+}
+
+fn serde_names<T: Serialize>(values: &[T]) -> Vec<String> {
+    values.iter().map(serde_name).collect()
+}
+
+/// 시련 깊이 점수 → (시련, Phase)
+pub fn decode_depth(score: f64) -> Option<(OrdealType, PhaseType)> {
+    let score = score as usize;
+    let ordeal = ORDEALS.get((score / 10).checked_sub(1)?)?;
+    let phase = PHASES.get((score % 10).checked_sub(1)?)?;
+    Some((*ordeal, *phase))
+}
+
+/// 보드 순서로 정렬합니다. 같은 점수는 Redis 와 같게 (내림차순 보드는 player_id 내림차순)
+pub fn sort_board(board: Board, entries: &mut [(i64, f64)]) {
+    if board.ascending() {
+        entries.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+    } else {
+        entries.sort_by(|a, b| b.1.total_cmp(&a.1).then(b.0.cmp(&a.0)));
+    }
+}
+
+/// 정렬된 보드에서 [offset, offset + limit) 구간
+pub fn slice_sorted(sorted: &[(i64, f64)], offset: i64, limit: i64) -> LeaderboardSlice {
+    let entries = sorted
+        .iter()
+        .enumerate()
+        .skip(offset.max(0) as usize)
+        .take(limit.max(0) as usize)
+        .map(|(index, (player_id, score))| RankedEntry {
+            rank: index as i64 + 1,
+            player_id: *player_id,
+            score: *score,
+        })
+        .collect();
+    LeaderboardSlice {
+        total: sorted.len() as i64,
+        entries,
+    }
+}
+
+/// 0부터 시작하는 순위 index 의 앞뒤 radius 명 구간 (offset, limit)
+pub fn around_window(index: i64, radius: i64) -> (i64, i64) {
+    let offset = (index - radius).max(0);
+    (offset, index + radius + 1 - offset)
+}
+
+fn around_sorted(sorted: &[(i64, f64)], player_id: i64, radius: i64) -> Option<AroundMe> {
+    let index = sorted.iter().position(|(id, _)| *id == player_id)? as i64;
+    let (offset, limit) = around_window(index, radius);
+    Some(AroundMe {
+        rank: index + 1,
+        slice: slice_sorted(sorted, offset, limit),
+    })
+}
+
+/// Postgres 에서 보드를 계산해 정렬합니다.
+async fn load_board(pool: &PgPool, board: Board) -> anyhow::Result<Vec<(i64, f64)>> {
+    let mut entries = match board.kind {
+        LeaderboardKind::Mmr => db_operation::get_mmr_board(pool, board.tier_id).await?,
+        LeaderboardKind::DeepestOrdeal => {
+            db_operation::get_deepest_ordeal_board(
+                pool,
+                &serde_names(&ORDEALS),
+                &serde_names(&PHASES),
+            )
+            .await?
+        }
+        LeaderboardKind::FastestClear => {
+            db_operation::get_fastest_clear_board(pool, &serde_names(&[RunEndReason::Cleared])[0])
+                .await?
+        }
+    };
+    sort_board(board, &mut entries);
+    Ok(entries)
+}
+
+#[derive(Clone)]
+pub struct LeaderboardCache {
+    redis: Option<ConnectionManager>,
+    ttl: Duration,
+}
+
+impl LeaderboardCache {
+    pub fn new(redis: ConnectionManager, settings: &LeaderboardSettings) -> Self {
+        Self {
+            redis: Some(redis),
+            ttl: settings.cache_ttl,
+        }
+    }
+
+    /// 캐시 없이 매번 Postgres 에서 계산합니다 (통합 테스트용)
+    pub fn disabled() -> Self {
+        Self {
+            redis: None,
+            ttl: Duration::zero(),
+        }
+    }
+
+    /// 보드를 다시 계산해 캐시를 교체하고, 정렬된 전체 보드를 반환합니다.
+    pub async fn rebuild(&self, pool: &PgPool, board: Board) -> anyhow::Result<Vec<(i64, f64)>> {
+        let entries = load_board(pool, board).await?;
+        if let Some(mut redis) = self.redis.clone() {
+            if let Err(e) = self.store(&mut redis, board, &entries).await {
+                warn!("Failed to cache leaderboard {}: {}", board.key(), e);
+            }
+        }
+        Ok(entries)
+    }
+
+    async fn store(
+        &self,
+        redis: &mut ConnectionManager,
+        board: Board,
+        entries: &[(i64, f64)],
+    ) -> redis::RedisResult<()> {
+        let key = board.key();
+        let mut pipe = redis::pipe();
+        pipe.atomic().del(&key).ignore();
+        for chunk in entries.chunks(ZADD_CHUNK) {
+            let members: Vec<(f64, String)> = chunk
+                .iter()
+                .map(|(player_id, score)| (*score, player_id.to_string()))
+                .collect();
+            pipe.zadd_multiple(&key, &members).ignore();
+        }
+        if !entries.is_empty() {
+            pipe.expire(&key, self.ttl.num_seconds().max(1) as usize)
+                .ignore();
+        }
+        pipe.query_async(redis).await
+    }
+
+    /// 보드 캐시를 지웁니다. 다음 조회 때 다시 계산됩니다.
+    pub async fn invalidate(&self, boards: &[Board]) -> anyhow::Result<()> {
+        let Some(mut redis) = self.redis.clone() else {
+            return Ok(());
+        };
+        let keys: Vec<String> = boards.iter().map(Board::key).collect();
+        redis.del::<_, ()>(keys).await?;
+        Ok(())
+    }
+
+    /// mmr 보드(전체 / 티어별) 캐시를 지웁니다. (시즌 소프트 리셋 후)
+    pub async fn invalidate_mmr(&self, pool: &PgPool) -> anyhow::Result<()> {
+        let boards: Vec<Board> = Board::all(pool)
+            .await?
+            .into_iter()
+            .filter(|b| b.kind == LeaderboardKind::Mmr)
+            .collect();
+        self.invalidate(&boards).await
+    }
+
+    /// 순위 [offset, offset + limit) 구간
+    pub async fn page(
+        &self,
+        pool: &PgPool,
+        board: Board,
+        offset: i64,
+        limit: i64,
+    ) -> anyhow::Result<LeaderboardSlice> {
+        if let Some(mut redis) = self.redis.clone() {
+            match cached_range(&mut redis, board, offset, limit).await {
+                Ok(Some(slice)) => return Ok(slice),
+                Ok(None) => {}
+                Err(e) => warn!("Leaderboard cache read failed for {}: {}", board.key(), e),
+            }
+        }
+        let sorted = self.rebuild(pool, board).await?;
+        Ok(slice_sorted(&sorted, offset, limit))
+    }
+
+    /// 플레이어 앞뒤 radius 명. 보드에 없으면 None
+    pub async fn around(
+        &self,
+        pool: &PgPool,
+        board: Board,
+        player_id: i64,
+        radius: i64,
+    ) -> anyhow::Result<Option<AroundMe>> {
+        if let Some(mut redis) = self.redis.clone() {
+            match cached_rank(&mut redis, board, player_id).await {
+                Ok(Some(None)) => return Ok(None),
+                Ok(Some(Some(index))) => {
+                    let (offset, limit) = around_window(index, radius);
+                    match cached_range(&mut redis, board, offset, limit).await {
+                        Ok(Some(slice)) => {
+                            return Ok(Some(AroundMe {
+                                rank: index + 1,
+                                slice,
+                            }))
+                        }
+                        Ok(None) => {}
+                        Err(e) => {
+                            warn!("Leaderboard cache read failed for {}: {}", board.key(), e)
+                        }
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => warn!("Leaderboard cache read failed for {}: {}", board.key(), e),
+            }
+        }
+        let sorted = self.rebuild(pool, board).await?;
+        Ok(around_sorted(&sorted, player_id, radius))
+    }
+}
+
+/// 캐시된 구간. 캐시가 없으면 None
+async fn cached_range(
+    redis: &mut ConnectionManager,
+    board: Board,
+    offset: i64,
+    limit: i64,
+) -> redis::RedisResult<Option<LeaderboardSlice>> {
+    let key = board.key();
+    let (start, stop) = (offset as isize, (offset + limit - 1) as isize);
+    let mut pipe = redis::pipe();
+    pipe.atomic().zcard(&key);
+    if board.ascending() {
+        pipe.zrange_withscores(&key, start, stop);
+    } else {
+        pipe.zrevrange_withscores(&key, start, stop);
+    }
+    let (total, members): (i64, Vec<(String, f64)>) = pipe.query_async(redis).await?;
+    if total == 0 {
+        return Ok(None);
+    }
+
+    let entries = members
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, (member, score))| {
+            Some(RankedEntry {
+                rank: offset + i as i64 + 1,
+                player_id: member.parse().ok()?,
+                score,
+            })
+        })
+        .collect();
+    Ok(Some(LeaderboardSlice { total, entries }))
+}
+
+/// 캐시된 순위 index. 캐시가 없으면 None, 보드에 플레이어가 없으면 Some(None)
+async fn cached_rank(
+    redis: &mut ConnectionManager,
+    board: Board,
+    player_id: i64,
+) -> redis::RedisResult<Option<Option<i64>>> {
+    let key = board.key();
+    let mut pipe = redis::pipe();
+    pipe.atomic().zcard(&key);
+    if board.ascending() {
+        pipe.zrank(&key, player_id.to_string());
+    } else {
+        pipe.zrevrank(&key, player_id.to_string());
+    }
+    let (total, index): (i64, Option<i64>) = pipe.query_async(redis).await?;
+    Ok((total > 0).then_some(index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sort_slice_and_around_me() {
+        let mmr = Board::new(LeaderboardKind::Mmr, None).unwrap();
+        let mut entries = vec![(1, 1500.0), (2, 1800.0), (3, 1500.0), (4, 1200.0)];
+        sort_board(mmr, &mut entries);
+        assert_eq!(
+            entries,
+            vec![(2, 1800.0), (3, 1500.0), (1, 1500.0), (4, 1200.0)]
+        );
+
+        let fastest = Board::new(LeaderboardKind::FastestClear, None).unwrap();
+        let mut clears = vec![(1, 900.0), (2, 600.0), (3, 900.0)];
+        sort_board(fastest, &mut clears);
+        assert_eq!(clears, vec![(2, 600.0), (1, 900.0), (3, 900.0)]);
+
+        let page = slice_sorted(&entries, 1, 2);
+        assert_eq!(page.total, 4);
+        assert_eq!(
+            page.entries
+                .iter()
+                .map(|e| (e.rank, e.player_id))
+                .collect::<Vec<_>>(),
+            vec![(2, 3), (3, 1)]
+        );
+        assert!(slice_sorted(&entries, 10, 5).entries.is_empty());
+
+        let me = around_sorted(&entries, 2, 1).unwrap();
+        assert_eq!(me.rank, 1);
+        assert_eq!(me.slice.entries.len(), 2);
+        let me = around_sorted(&entries, 1, 1).unwrap();
+        assert_eq!(me.rank, 3);
+        assert_eq!(me.slice.entries.first().unwrap().rank, 2);
+        assert_eq!(me.slice.entries.last().unwrap().rank, 4);
+        assert!(around_sorted(&entries, 99, 1).is_none());
+
+        assert!(Board::new(LeaderboardKind::DeepestOrdeal, Some(1)).is_err());
+    }
+
+    #[test]
+    fn test_depth_score_round_trip() {
+        assert_eq!(serde_names(&ORDEALS)[4], "White");
+        assert_eq!(serde_names(&PHASES)[0], "I");
+        assert_eq!(decode_depth(11.0), Some((OrdealType::Dawn, PhaseType::I)));
+        assert_eq!(decode_depth(55.0), Some((OrdealType::White, PhaseType::V)));
+        assert_eq!(decode_depth(5.0), None);
+        assert_eq!(decode_depth(70.0), None);
+    }
+}
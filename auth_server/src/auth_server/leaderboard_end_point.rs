@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+
+use actix_web::{web, HttpResponse};
+use game_core::game::enums::{OrdealType, PhaseType};
+use serde::{Deserialize, Serialize};
+
+use crate::auth_server::{
+    db_operation,
+    errors::AuthError,
+    extractor::AuthenticatedPlayer,
+    leaderboard::{decode_depth, Board, LeaderboardKind, LeaderboardSlice},
+    model::LeaderboardPlayer,
+    player_end_point::PageQuery,
+    types::AppState,
+};
+
+const DEFAULT_RADIUS: i64 = 5;
+const MAX_RADIUS: i64 = 25;
+
+// --- HTTP 요청/응답 구조체 ---
+#[derive(Deserialize)]
+struct BoardQuery {
+    /// mmr 보드에서만 사용 (없으면 전체)
+    tier_id: Option<i32>,
+}
+
+#[derive(Deserialize)]
+struct AroundQuery {
+    tier_id: Option<i32>,
+    /// 앞뒤로 보여줄 인원 (0..=MAX_RADIUS)
+    radius: Option<i64>,
+}
+
+/// 보드 종류별 값
+#[derive(Serialize)]
+#[serde(untagged)]
+enum BoardValue {
+    Mmr {
+        mmr: f64,
+    },
+    Depth {
+        reached_ordeal: OrdealType,
+        reached_phase: PhaseType,
+    },
+    Clear {
+        clear_seconds: i64,
+    },
+}
+
+#[derive(Serialize)]
+struct LeaderboardEntry {
+    rank: i64,
+    steam_id: String,
+    username: Option<String>,
+    /// 현재 티어
+    tier_id: Option<i32>,
+    #[serde(flatten)]
+    value: BoardValue,
+}
+
+#[derive(Serialize)]
+struct LeaderboardPage {
+    board: LeaderboardKind,
+    tier_id: Option<i32>,
+    page: i64,
+    page_size: i64,
+    total: i64,
+    entries: Vec<LeaderboardEntry>,
+}
+
+#[derive(Serialize)]
+struct AroundMeResponse {
+    board: LeaderboardKind,
+    tier_id: Option<i32>,
+    total: i64,
+    /// 토큰 주인의 순위
+    rank: i64,
+    entries: Vec<LeaderboardEntry>,
+}
+
+/// 보드 확인 (티어별 보드는 티어가 있어야 함)
+async fn resolve_board(
+    state: &AppState,
+    kind: LeaderboardKind,
+    tier_id: Option<i32>,
+) -> Result<Board, AuthError> {
+    let board = Board::new(kind, tier_id).map_err(AuthError::BadRequest)?;
+    if let Some(tier_id) = tier_id {
+        db_operation::get_tier_by_id(&state.db_pool, tier_id)
+            .await?
+            .ok_or_else(|| AuthError::NotFound(format!("Tier {} not found", tier_id)))?;
+    }
+    Ok(board)
+}
+
+/// 순위 구간에 플레이어 이름 / 티어를 붙입니다.
+async fn to_entries(
+    state: &AppState,
+    board: Board,
+    slice: &LeaderboardSlice,
+) -> Result<Vec<LeaderboardEntry>, AuthError> {
+    let player_ids: Vec<i64> = slice.entries.iter().map(|e| e.player_id).collect();
+    let players: HashMap<i64, LeaderboardPlayer> =
+        db_operation::get_leaderboard_players(&state.db_pool, &player_ids)
+            .await?
+            .into_iter()
+            .map(|p| (p.player_id, p))
+            .collect();
+
+    slice
+        .entries
+        .iter()
+        .map(|entry| {
+            let value = match board.kind {
+                LeaderboardKind::Mmr => BoardValue::Mmr { mmr: entry.score },
+                LeaderboardKind::DeepestOrdeal => {
+                    let (reached_ordeal, reached_phase) =
+                        decode_depth(entry.score).ok_or_else(|| {
+                            AuthError::InternalServerError(anyhow::anyhow!(
+                                "Invalid depth score {} for player {}",
+                                entry.score,
+                                entry.player_id
+                            ))
+                        })?;
+                    BoardValue::Depth {
+                        reached_ordeal,
+                        reached_phase,
+                    }
+                }
+                LeaderboardKind::FastestClear => BoardValue::Clear {
+                    clear_seconds: entry.score as i64,
+                },
+            };
+            let player = players.get(&entry.player_id);
+            Ok(LeaderboardEntry {
+                rank: entry.rank,
+                steam_id: entry.player_id.to_string(),
+                username: player.and_then(|p| p.username.clone()),
+                tier_id: player.map(|p| p.tier_id),
+                value,
+            })
+        })
+        .collect()
+}
+
+// --- 엔드포인트 핸들러 ---
+/// GET /leaderboards/{board}?tier_id=3&page=1&page_size=20
+/// board: mmr | deepest_ordeal | fastest_clear
+#[actix_web::get("/{board}")]
+pub async fn get_leaderboard_handler(
+    state: web::Data<AppState>,
+    path: web::Path<LeaderboardKind>,
+    board_query: web::Query<BoardQuery>,
+    page_query: web::Query<PageQuery>,
+) -> Result<HttpResponse, AuthError> {
+    let board = resolve_board(&state, path.into_inner(), board_query.tier_id).await?;
+    let (page, page_size) = page_query.normalized();
+
+    let slice = state
+        .leaderboards
+        .page(&state.db_pool, board, (page - 1) * page_size, page_size)
+        .await?;
+    let entries = to_entries(&state, board, &slice).await?;
+
+    Ok(HttpResponse::Ok().json(LeaderboardPage {
+        board: board.kind,
+        tier_id: board.tier_id,
+        page,
+        page_size,
+        total: slice.total,
+        entries,
+    }))
+}
+
+/// GET /leaderboards/{board}/me?tier_id=3&radius=5
+/// 토큰 주인의 순위와 앞뒤 radius 명을 반환합니다. 보드에 없으면 404
+#[actix_web::get("/{board}/me")]
+pub async fn get_leaderboard_around_me_handler(
+    state: web::Data<AppState>,
+    player: AuthenticatedPlayer,
+    path: web::Path<LeaderboardKind>,
+    query: web::Query<AroundQuery>,
+) -> Result<HttpResponse, AuthError> {
+    let board = resolve_board(&state, path.into_inner(), query.tier_id).await?;
+    let radius = query.radius.unwrap_or(DEFAULT_RADIUS).clamp(0, MAX_RADIUS);
+
+    let around = state
+        .leaderboards
+        .around(&state.db_pool, board, player.steam_id, radius)
+        .await?
+        .ok_or_else(|| {
+            AuthError::NotFound(format!(
+                "Player {} is not on the {} leaderboard",
+                player.steam_id,
+                board.key()
+            ))
+        })?;
+    let entries = to_entries(&state, board, &around.slice).await?;
+
+    Ok(HttpResponse::Ok().json(AroundMeResponse {
+        board: board.kind,
+        tier_id: board.tier_id,
+        total: around.slice.total,
+        rank: around.rank,
+        entries,
+    }))
+}
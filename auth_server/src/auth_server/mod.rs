@@ -1,5 +1,21 @@
+pub mod admin_end_point;
+pub mod collection;
 pub mod db_operation;
+pub mod deck_end_point;
 pub mod end_point;
 pub mod errors;
+pub mod extractor;
+pub mod leaderboard;
+pub mod leaderboard_end_point;
 pub mod model;
+pub mod player_end_point;
+pub mod rating;
+pub mod rating_cache;
+pub mod revocation;
+pub mod routes;
+pub mod season;
+pub mod season_end_point;
+pub mod service_end_point;
+pub mod steam;
+pub mod token;
 pub mod types;
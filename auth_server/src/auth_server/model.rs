@@ -17,6 +17,17 @@ pub enum PlayerStatus {
     Banned,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "moderation_action", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ModerationAction {
+    Suspend,
+    Ban,
+    Unban,
+    /// 정지 기간 만료로 자동 해제 (actor: system)
+    SuspensionExpired,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
 #[sqlx(type_name = "card_rarity", rename_all = "snake_case")]
 pub enum CardRarity {
@@ -26,6 +37,16 @@ pub enum CardRarity {
     Legendary,
 }
 
+impl CardRarity {
+    /// 덱 1개에 넣을 수 있는 최대 장수
+    pub fn max_copies_per_deck(self) -> i32 {
+        match self {
+            CardRarity::Common | CardRarity::Rare => 2,
+            CardRarity::Epic | CardRarity::Legendary => 1,
+        }
+    }
+}
+
 // =================================================================
 // 테이블 매핑 구조체 (Table Mapping Structs)
 // =================================================================
@@ -39,6 +60,8 @@ pub struct Player {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub last_login_at: Option<DateTime<Utc>>,
+    pub suspended_until: Option<DateTime<Utc>>,
+    pub status_reason: Option<String>,
 }
 
 // 2. 플레이어 프로필 (Game-specific Profile)
@@ -56,13 +79,14 @@ pub struct PlayerProfile {
     pub updated_at: DateTime<Utc>,
 }
 
-// 3. 티어 정보 (Master Data - 변경 없음)
+// 3. 티어 정보 (Master Data)
 #[derive(Debug, FromRow, Serialize, Deserialize)]
 pub struct Tier {
     pub id: i32,
     pub name: String,
     pub order_key: i32,
     pub icon_url: Option<String>,
+    pub min_mmr: f64,
 }
 
 // 4. 게임 모드 (Master Data - 변경 없음)
@@ -122,6 +146,8 @@ pub struct Card {
     pub image_url: Option<String>,
     pub attributes: Option<serde_json::Value>,
     pub is_collectible: bool,
+    /// 런 안의 환상체 UUID (AbnormalityMetadata.uuid)
+    pub item_uuid: Option<Uuid>,
 }
 
 // 9. 플레이어 소유 카드 (Player Card Collection)
@@ -151,3 +177,63 @@ pub struct DeckCard {
     pub card_id: i32,
     pub quantity: i32,
 }
+
+// 12. 리프레시 토큰 (Refresh Tokens)
+#[derive(Debug, FromRow)]
+pub struct RefreshToken {
+    pub id: Uuid,
+    pub player_id: i64,
+    pub family_id: Uuid,
+    pub token_hash: String,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+// 13. 제재 기록 (Player Moderation Audit)
+#[derive(Debug, FromRow, Serialize, Deserialize)]
+pub struct ModerationAudit {
+    pub id: i64,
+    pub player_id: i64,
+    pub actor: String,
+    pub action: ModerationAction,
+    pub reason: String,
+    pub suspended_until: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+// 14. 시즌 (Seasons)
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct Season {
+    pub id: i32,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    /// 롤오버로 마감된 시각 (진행 중이면 None)
+    pub closed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+// 15. 시즌 최종 순위 (Season Standings) - 플레이어 이름 / 티어 이름 포함
+#[derive(Debug, FromRow, Serialize, Deserialize)]
+pub struct SeasonStanding {
+    pub season_id: i32,
+    pub player_id: i64,
+    pub username: Option<String>,
+    pub rank: i32,
+    pub final_mmr: f64,
+    pub final_rd: f64,
+    pub tier_id: i32,
+    pub tier_name: String,
+    pub ranked_matches: i32,
+    /// 시즌 마감 때 받은 보상 경험치 (보상 대상이 아니었으면 None)
+    pub reward_experience_points: Option<i64>,
+}
+
+// 16. 리더보드 표시용 플레이어 정보
+#[derive(Debug, FromRow, Serialize, Deserialize)]
+pub struct LeaderboardPlayer {
+    pub player_id: i64,
+    pub username: Option<String>,
+    pub tier_id: i32,
+}
@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+
+use actix_web::{web, HttpResponse};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::auth_server::{
+    db_operation,
+    errors::AuthError,
+    extractor::AuthenticatedPlayer,
+    model::{MatchHistory, MatchParticipant, PlayerProfile, PlayerStatus, Tier},
+    types::AppState,
+};
+
+const DEFAULT_PAGE_SIZE: i64 = 20;
+const MAX_PAGE_SIZE: i64 = 100;
+
+// --- HTTP 요청/응답 구조체 ---
+#[derive(Deserialize)]
+pub(crate) struct PageQuery {
+    page: Option<i64>,
+    page_size: Option<i64>,
+}
+
+impl PageQuery {
+    /// (page, page_size) - page 는 1부터 시작, page_size 는 1..=MAX_PAGE_SIZE
+    pub(crate) fn normalized(&self) -> (i64, i64) {
+        let page = self.page.unwrap_or(1).max(1);
+        let page_size = self
+            .page_size
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+            .clamp(1, MAX_PAGE_SIZE);
+        (page, page_size)
+    }
+}
+
+#[derive(Serialize)]
+struct ProfileResponse {
+    steam_id: String,
+    username: Option<String>,
+    status: PlayerStatus,
+    profile: PlayerProfile,
+    tier: Tier,
+}
+
+#[derive(Serialize)]
+struct MatchHistoryEntry {
+    #[serde(flatten)]
+    match_info: MatchHistory,
+    participants: Vec<MatchParticipant>,
+}
+
+#[derive(Serialize)]
+struct MatchHistoryPage {
+    page: i64,
+    page_size: i64,
+    total: i64,
+    matches: Vec<MatchHistoryEntry>,
+}
+
+// --- 엔드포인트 핸들러 ---
+/// GET /players/me
+/// 토큰 주인의 계정 정보, 프로필(MMR 등), 현재 티어를 반환합니다.
+#[actix_web::get("/me")]
+pub async fn get_my_profile_handler(
+    state: web::Data<AppState>,
+    player: AuthenticatedPlayer,
+) -> Result<HttpResponse, AuthError> {
+    let not_found = || AuthError::NotFound(format!("Player {} not found", player.steam_id));
+
+    let account = db_operation::get_player_by_id(&state.db_pool, player.steam_id)
+        .await?
+        .ok_or_else(not_found)?;
+    let profile = db_operation::get_player_profile(&state.db_pool, player.steam_id)
+        .await?
+        .ok_or_else(not_found)?;
+    let tier = db_operation::get_tier_by_id(&state.db_pool, profile.tier_id)
+        .await?
+        .ok_or_else(|| {
+            AuthError::InternalServerError(anyhow::anyhow!(
+                "Tier {} of player {} does not exist",
+                profile.tier_id,
+                player.steam_id
+            ))
+        })?;
+
+    Ok(HttpResponse::Ok().json(ProfileResponse {
+        steam_id: account.id.to_string(),
+        username: account.last_known_username,
+        status: account.status,
+        profile,
+        tier,
+    }))
+}
+
+/// GET /players/me/matches?page=1&page_size=20
+/// 토큰 주인의 매치 기록을 최신순으로, 매치별 참여자 목록과 함께 반환합니다.
+#[actix_web::get("/me/matches")]
+pub async fn get_my_match_history_handler(
+    state: web::Data<AppState>,
+    player: AuthenticatedPlayer,
+    query: web::Query<PageQuery>,
+) -> Result<HttpResponse, AuthError> {
+    let (page, page_size) = query.normalized();
+
+    let total = db_operation::count_player_matches(&state.db_pool, player.steam_id).await?;
+    let history = db_operation::get_player_match_history(
+        &state.db_pool,
+        player.steam_id,
+        page_size,
+        (page - 1) * page_size,
+    )
+    .await?;
+
+    // 페이지의 참여자를 한 번에 조회한 뒤 매치별로 묶습니다.
+    let match_ids: Vec<Uuid> = history.iter().map(|m| m.id).collect();
+    let mut participants: HashMap<Uuid, Vec<MatchParticipant>> = HashMap::new();
+    for participant in db_operation::get_match_participants(&state.db_pool, &match_ids).await? {
+        participants
+            .entry(participant.match_id)
+            .or_default()
+            .push(participant);
+    }
+
+    let matches = history
+        .into_iter()
+        .map(|match_info| MatchHistoryEntry {
+            participants: participants.remove(&match_info.id).unwrap_or_default(),
+            match_info,
+        })
+        .collect();
+
+    Ok(HttpResponse::Ok().json(MatchHistoryPage {
+        page,
+        page_size,
+        total,
+        matches,
+    }))
+}
+
+/// GET /tiers
+/// 전체 티어 목록을 order_key 순으로 반환합니다.
+#[actix_web::get("/tiers")]
+pub async fn get_tiers_handler(state: web::Data<AppState>) -> Result<HttpResponse, AuthError> {
+    let tiers = db_operation::get_all_tiers(&state.db_pool).await?;
+    Ok(HttpResponse::Ok().json(tiers))
+}
@@ -0,0 +1,311 @@
+// src/auth/rating.rs
+
+//! Glicko-2 레이팅 계산
+//!
+//! DB 에는 Glicko-1 스케일(mmr 1500 / rd 350)로 저장하고, 계산할 때만 Glicko-2 스케일로 변환합니다.
+//! 완료된 Ranked 매치 1판을 하나의 rating period 로 보고 갱신하며,
+//! 마지막 갱신 이후 쉰 기간만큼 rd 를 먼저 늘립니다 (비활성 플레이어의 불확실성 증가).
+//! 참고: Mark E. Glickman, "Example of the Glicko-2 system".
+
+use std::f64::consts::PI;
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::{auth_server::model::Tier, env::duration_from_seconds};
+
+/// Glicko-1 ↔ Glicko-2 스케일 변환 상수 (400 / ln 10)
+const SCALE: f64 = 173.7178;
+/// 신규 플레이어 mmr (Glicko-1 스케일 기준점)
+pub const BASE_RATING: f64 = 1500.0;
+/// volatility 반복 계산 수렴 기준
+const CONVERGENCE: f64 = 0.000001;
+
+/// 설정 파일 `[rating]`
+#[derive(Debug, Clone, Deserialize)]
+pub struct RatingSettings {
+    /// volatility 변화 제한 (0.3 ~ 1.2, 작을수록 보수적)
+    pub tau: f64,
+    /// 비활성 rd 증가 계산에 쓰는 rating period 길이
+    #[serde(
+        rename = "rating_period_seconds",
+        deserialize_with = "duration_from_seconds"
+    )]
+    pub rating_period: chrono::Duration,
+    /// rd 상한 (신규 플레이어 값과 같음)
+    pub max_rd: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rating {
+    pub mmr: f64,
+    pub rd: f64,
+    pub volatility: f64,
+}
+
+/// 상대 1명과의 경기 결과 (score: 승 1.0 / 무 0.5 / 패 0.0)
+#[derive(Debug, Clone, Copy)]
+pub struct GameOutcome {
+    pub opponent: Rating,
+    pub score: f64,
+}
+
+fn g(phi: f64) -> f64 {
+    1.0 / (1.0 + 3.0 * phi * phi / (PI * PI)).sqrt()
+}
+
+fn expected(mu: f64, mu_j: f64, phi_j: f64) -> f64 {
+    1.0 / (1.0 + (-g(phi_j) * (mu - mu_j)).exp())
+}
+
+impl Rating {
+    /// 마지막 갱신 이후 지난 rating period 수만큼 rd 를 늘립니다.
+    pub fn inflate_for_inactivity(
+        self,
+        last_update: DateTime<Utc>,
+        now: DateTime<Utc>,
+        settings: &RatingSettings,
+    ) -> Rating {
+        let period_secs = settings.rating_period.num_seconds().max(1) as f64;
+        let idle_periods = ((now - last_update).num_seconds().max(0) as f64 / period_secs).floor();
+        if idle_periods < 1.0 {
+            return self;
+        }
+
+        let phi = self.rd / SCALE;
+        let inflated = (phi * phi + idle_periods * self.volatility * self.volatility).sqrt();
+        Rating {
+            rd: (inflated * SCALE).min(settings.max_rd),
+            ..self
+        }
+    }
+
+    /// rating period 1회분의 결과로 새 레이팅을 계산합니다.
+    /// 결과가 없으면 rd 만 한 period 만큼 늘어납니다.
+    pub fn update(self, outcomes: &[GameOutcome], settings: &RatingSettings) -> Rating {
+        let mu = (self.mmr - BASE_RATING) / SCALE;
+        let phi = self.rd / SCALE;
+        let sigma = self.volatility;
+
+        if outcomes.is_empty() {
+            let phi_star = (phi * phi + sigma * sigma).sqrt();
+            return Rating {
+                rd: (phi_star * SCALE).min(settings.max_rd),
+                ..self
+            };
+        }
+
+        // 1. 추정 분산 v 와 개선량 delta
+        let mut v_inv = 0.0;
+        let mut improvement = 0.0;
+        for outcome in outcomes {
+            let mu_j = (outcome.opponent.mmr - BASE_RATING) / SCALE;
+            let phi_j = outcome.opponent.rd / SCALE;
+            let e = expected(mu, mu_j, phi_j);
+            v_inv += g(phi_j).powi(2) * e * (1.0 - e);
+            improvement += g(phi_j) * (outcome.score - e);
+        }
+        let v = 1.0 / v_inv;
+        let delta = v * improvement;
+
+        // 2. 새 volatility (Illinois 알고리즘)
+        let new_sigma = new_volatility(phi, sigma, v, delta, settings.tau);
+
+        // 3. 새 rd / mmr
+        let phi_star = (phi * phi + new_sigma * new_sigma).sqrt();
+        let new_phi = 1.0 / (1.0 / (phi_star * phi_star) + 1.0 / v).sqrt();
+        let new_mu = mu + new_phi * new_phi * improvement;
+
+        Rating {
+            mmr: new_mu * SCALE + BASE_RATING,
+            rd: (new_phi * SCALE).min(settings.max_rd),
+            volatility: new_sigma,
+        }
+    }
+}
+
+fn new_volatility(phi: f64, sigma: f64, v: f64, delta: f64, tau: f64) -> f64 {
+    let a = (sigma * sigma).ln();
+    let f = |x: f64| {
+        let ex = x.exp();
+        let denom = phi * phi + v + ex;
+        ex * (delta * delta - phi * phi - v - ex) / (2.0 * denom * denom) - (x - a) / (tau * tau)
+    };
+
+    let mut big_a = a;
+    let mut big_b = if delta * delta > phi * phi + v {
+        (delta * delta - phi * phi - v).ln()
+    } else {
+        let mut k = 1.0;
+        while f(a - k * tau) < 0.0 {
+            k += 1.0;
+        }
+        a - k * tau
+    };
+
+    let mut f_a = f(big_a);
+    let mut f_b = f(big_b);
+    while (big_b - big_a).abs() > CONVERGENCE {
+        let big_c = big_a + (big_a - big_b) * f_a / (f_b - f_a);
+        let f_c = f(big_c);
+        if f_c * f_b <= 0.0 {
+            big_a = big_b;
+            f_a = f_b;
+        } else {
+            f_a /= 2.0;
+        }
+        big_b = big_c;
+        f_b = f_c;
+    }
+
+    (big_a / 2.0).exp()
+}
+
+/// 매치 참여자 1명 (team_id, 매치 전 레이팅)
+#[derive(Debug, Clone, Copy)]
+pub struct RatedParticipant {
+    pub team_id: i32,
+    pub rating: Rating,
+}
+
+/// 한 매치의 참여자 전원의 새 레이팅 (입력 순서와 같음)
+///
+/// 다른 팀의 모든 참여자를 상대로 한 경기로 보며, 승리 팀이 없으면 무승부로 처리합니다.
+/// 상대가 없는 참여자는 레이팅이 바뀌지 않습니다.
+pub fn rate_match(
+    participants: &[RatedParticipant],
+    winning_team_id: Option<i32>,
+    settings: &RatingSettings,
+) -> Vec<Rating> {
+    participants
+        .iter()
+        .map(|p| {
+            let outcomes: Vec<GameOutcome> = participants
+                .iter()
+                .filter(|o| o.team_id != p.team_id)
+                .map(|o| GameOutcome {
+                    opponent: o.rating,
+                    score: match winning_team_id {
+                        Some(w) if w == p.team_id => 1.0,
+                        Some(w) if w == o.team_id => 0.0,
+                        _ => 0.5,
+                    },
+                })
+                .collect();
+            if outcomes.is_empty() {
+                p.rating
+            } else {
+                p.rating.update(&outcomes, settings)
+            }
+        })
+        .collect()
+}
+
+/// mmr 에 해당하는 티어 ID
+///
+/// min_mmr 을 넘는 티어 중 order_key 가 가장 높은 티어이며,
+/// 어느 기준에도 못 미치면 order_key 가 가장 낮은 티어입니다.
+pub fn tier_for_mmr(tiers: &[Tier], mmr: f64) -> Option<i32> {
+    tiers
+        .iter()
+        .filter(|t| t.min_mmr <= mmr)
+        .max_by_key(|t| t.order_key)
+        .or_else(|| tiers.iter().min_by_key(|t| t.order_key))
+        .map(|t| t.id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings() -> RatingSettings {
+        RatingSettings {
+            tau: 0.5,
+            rating_period: chrono::Duration::days(7),
+            max_rd: 350.0,
+        }
+    }
+
+    fn rating(mmr: f64, rd: f64) -> Rating {
+        Rating {
+            mmr,
+            rd,
+            volatility: 0.06,
+        }
+    }
+
+    #[test]
+    fn test_update_matches_glickman_example() {
+        let outcomes = [
+            GameOutcome {
+                opponent: rating(1400.0, 30.0),
+                score: 1.0,
+            },
+            GameOutcome {
+                opponent: rating(1550.0, 100.0),
+                score: 0.0,
+            },
+            GameOutcome {
+                opponent: rating(1700.0, 300.0),
+                score: 0.0,
+            },
+        ];
+        let updated = rating(1500.0, 200.0).update(&outcomes, &settings());
+
+        assert!((updated.mmr - 1464.06).abs() < 0.01, "{:?}", updated);
+        assert!((updated.rd - 151.52).abs() < 0.01, "{:?}", updated);
+        assert!(
+            (updated.volatility - 0.05999).abs() < 0.00001,
+            "{:?}",
+            updated
+        );
+    }
+
+    #[test]
+    fn test_inactivity_inflates_rd_up_to_max() {
+        let settings = settings();
+        let now = Utc::now();
+        let active = rating(1500.0, 50.0);
+
+        let same_period =
+            active.inflate_for_inactivity(now - chrono::Duration::days(3), now, &settings);
+        assert_eq!(same_period, active);
+
+        let idle = active.inflate_for_inactivity(now - chrono::Duration::days(70), now, &settings);
+        assert!(idle.rd > active.rd && idle.mmr == active.mmr);
+
+        let long_gone =
+            active.inflate_for_inactivity(now - chrono::Duration::days(100_000), now, &settings);
+        assert_eq!(long_gone.rd, settings.max_rd);
+    }
+
+    #[test]
+    fn test_rate_match_and_tier_mapping() {
+        let settings = settings();
+        let participants = [
+            RatedParticipant {
+                team_id: 1,
+                rating: rating(1500.0, 350.0),
+            },
+            RatedParticipant {
+                team_id: 2,
+                rating: rating(1500.0, 350.0),
+            },
+        ];
+        let rated = rate_match(&participants, Some(1), &settings);
+        assert!(rated[0].mmr > 1500.0 && rated[1].mmr < 1500.0);
+        assert!((rated[0].mmr - 1500.0 + rated[1].mmr - 1500.0).abs() < 1e-6);
+
+        let tier = |id, order_key, min_mmr| Tier {
+            id,
+            name: String::new(),
+            order_key,
+            icon_url: None,
+            min_mmr,
+        };
+        let tiers = [tier(1, 1, 0.0), tier(2, 2, 1400.0), tier(3, 3, 1600.0)];
+        assert_eq!(tier_for_mmr(&tiers, -20.0), Some(1));
+        assert_eq!(tier_for_mmr(&tiers, 1400.0), Some(2));
+        assert_eq!(tier_for_mmr(&tiers, 2500.0), Some(3));
+    }
+}
@@ -0,0 +1,73 @@
+// src/auth/rating_cache.rs
+
+//! 매칭용 레이팅 캐시 (Redis)
+//!
+//! game_server 의 MMR 매칭 큐는 enqueue 때 `rating:{player_id}` Hash(mmr, rd, volatility) 를 읽습니다.
+//! player_id 는 game_server 와 같은 규칙(`Uuid::from_u64_pair(0, steam_id)`)으로 SteamID64 에서 만듭니다.
+//!
+//! `player_profiles` 의 레이팅이 바뀌는 곳(랭크 매치 결과 반영, 시즌 소프트 리셋)에서 갱신합니다.
+//! 캐시가 없으면 game_server 는 기본 레이팅을 쓰므로 TTL 없이 저장합니다.
+//! Redis 없이 만든 캐시([`RatingCache::disabled`])는 아무것도 쓰지 않습니다 (통합 테스트용).
+
+use redis::aio::ConnectionManager;
+use uuid::Uuid;
+
+use crate::auth_server::rating::Rating;
+
+pub fn rating_key(steam_id: i64) -> String {
+    format!("rating:{}", Uuid::from_u64_pair(0, steam_id as u64))
+}
+
+#[derive(Clone)]
+pub struct RatingCache {
+    redis: Option<ConnectionManager>,
+}
+
+impl RatingCache {
+    pub fn new(redis: ConnectionManager) -> Self {
+        Self { redis: Some(redis) }
+    }
+
+    pub fn disabled() -> Self {
+        Self { redis: None }
+    }
+
+    /// (SteamID64, 레이팅) 목록을 한 번의 파이프라인으로 저장합니다.
+    pub async fn store(&self, ratings: &[(i64, Rating)]) -> anyhow::Result<()> {
+        let Some(mut redis) = self.redis.clone() else {
+            return Ok(());
+        };
+        if ratings.is_empty() {
+            return Ok(());
+        }
+
+        let mut pipe = redis::pipe();
+        for (steam_id, rating) in ratings {
+            pipe.cmd("HSET")
+                .arg(rating_key(*steam_id))
+                .arg("mmr")
+                .arg(rating.mmr)
+                .arg("rd")
+                .arg(rating.rd)
+                .arg("volatility")
+                .arg(rating.volatility)
+                .ignore();
+        }
+        pipe.query_async::<_, ()>(&mut redis).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rating_key_matches_game_server_player_id() {
+        // game_server: shared::auth::player_id_from_subject("76561197960265729")
+        assert_eq!(
+            rating_key(76561197960265729),
+            "rating:00000000-0000-0000-0110-000100000001"
+        );
+    }
+}
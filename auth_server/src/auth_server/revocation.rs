@@ -0,0 +1,81 @@
+// src/auth/revocation.rs
+
+//! 액세스 토큰 폐기 목록 (Redis)
+//!
+//! game_server 는 WebSocket 업그레이드 때 아래 두 키를 MGET 한 번으로 확인합니다.
+//! - `auth:revoked:jti:{jti}`: 토큰 1개 폐기 (로그아웃). 값은 "1"
+//! - `auth:revoked:sub:{steam_id}`: 이 시각(unix 초) 이전에 발급된 해당 플레이어의 모든 토큰 폐기
+//!   (리프레시 토큰 재사용 감지, 정지/밴)
+//!
+//! 두 키 모두 액세스 토큰 수명이 지나면 의미가 없으므로 그만큼의 TTL 로 저장합니다.
+//! Redis 없이 만든 목록([`RevocationList::disabled`])은 아무것도 폐기하지 않습니다 (통합 테스트용).
+
+use chrono::{Duration, Utc};
+use redis::{aio::ConnectionManager, AsyncCommands};
+
+use crate::auth_server::types::Claims;
+
+pub fn revoked_jti_key(jti: &str) -> String {
+    format!("auth:revoked:jti:{}", jti)
+}
+
+pub fn revoked_subject_key(sub: &str) -> String {
+    format!("auth:revoked:sub:{}", sub)
+}
+
+#[derive(Clone)]
+pub struct RevocationList {
+    redis: Option<ConnectionManager>,
+}
+
+impl RevocationList {
+    pub fn new(redis: ConnectionManager) -> Self {
+        Self { redis: Some(redis) }
+    }
+
+    pub fn disabled() -> Self {
+        Self { redis: None }
+    }
+
+    /// 토큰 1개를 만료 시각까지 폐기합니다.
+    pub async fn revoke_token(&self, claims: &Claims) -> anyhow::Result<()> {
+        let ttl = claims.exp as i64 - Utc::now().timestamp();
+        if ttl <= 0 {
+            return Ok(());
+        }
+        let Some(mut redis) = self.redis.clone() else {
+            return Ok(());
+        };
+        redis
+            .set_ex::<_, _, ()>(revoked_jti_key(&claims.jti), 1, ttl as usize)
+            .await?;
+        Ok(())
+    }
+
+    /// 지금까지 발급된 플레이어의 모든 액세스 토큰을 폐기합니다.
+    pub async fn revoke_player(&self, steam_id: i64, access_ttl: Duration) -> anyhow::Result<()> {
+        let Some(mut redis) = self.redis.clone() else {
+            return Ok(());
+        };
+        redis
+            .set_ex::<_, _, ()>(
+                revoked_subject_key(&steam_id.to_string()),
+                Utc::now().timestamp(),
+                access_ttl.num_seconds().max(1) as usize,
+            )
+            .await?;
+        Ok(())
+    }
+
+    pub async fn is_revoked(&self, claims: &Claims) -> anyhow::Result<bool> {
+        let Some(mut redis) = self.redis.clone() else {
+            return Ok(false);
+        };
+        let (jti, revoked_before): (Option<i64>, Option<i64>) = redis::cmd("MGET")
+            .arg(revoked_jti_key(&claims.jti))
+            .arg(revoked_subject_key(&claims.sub))
+            .query_async(&mut redis)
+            .await?;
+        Ok(jti.is_some() || revoked_before.is_some_and(|at| claims.iat as i64 <= at))
+    }
+}
@@ -0,0 +1,81 @@
+// src/auth/routes.rs
+
+//! HTTP 라우트 구성 (main 과 통합 테스트가 같은 구성을 사용합니다)
+
+use actix_web::web;
+
+use crate::auth_server::{
+    admin_end_point::{
+        ban_player_handler, player_audit_handler, rebuild_leaderboards_handler,
+        rollover_season_handler, suspend_player_handler, unban_player_handler,
+    },
+    deck_end_point::{
+        create_deck_handler, delete_deck_handler, get_card_catalog_handler,
+        get_my_collection_handler, get_my_deck_handler, get_my_decks_handler,
+        replace_deck_cards_handler, update_deck_handler,
+    },
+    end_point::{
+        delete_player_handler, logout_handler, refresh_token_handler, steam_authentication_handler,
+    },
+    leaderboard_end_point::{get_leaderboard_around_me_handler, get_leaderboard_handler},
+    player_end_point::{get_my_match_history_handler, get_my_profile_handler, get_tiers_handler},
+    season_end_point::{
+        get_my_season_history_handler, get_season_standings_handler, get_seasons_handler,
+    },
+    service_end_point::{
+        grant_card_handler, submit_match_result_handler, submit_run_result_handler,
+    },
+};
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    let auth_scope = web::scope("/auth")
+        .service(steam_authentication_handler)
+        .service(refresh_token_handler)
+        .service(logout_handler);
+
+    #[cfg(feature = "test-endpoints")]
+    let auth_scope = {
+        use crate::auth_server::end_point::test_authentication_handler;
+        auth_scope.service(test_authentication_handler)
+    };
+
+    cfg.service(auth_scope)
+        .service(
+            web::scope("/players")
+                .service(get_my_profile_handler)
+                .service(get_my_match_history_handler)
+                .service(get_my_season_history_handler)
+                .service(get_my_collection_handler)
+                .service(get_my_decks_handler)
+                .service(create_deck_handler)
+                .service(get_my_deck_handler)
+                .service(update_deck_handler)
+                .service(delete_deck_handler)
+                .service(replace_deck_cards_handler),
+        )
+        .service(get_tiers_handler)
+        .service(get_seasons_handler)
+        .service(get_season_standings_handler)
+        .service(
+            web::scope("/leaderboards")
+                .service(get_leaderboard_around_me_handler)
+                .service(get_leaderboard_handler),
+        )
+        .service(get_card_catalog_handler)
+        .service(
+            web::scope("/internal")
+                .service(submit_match_result_handler)
+                .service(submit_run_result_handler)
+                .service(grant_card_handler),
+        )
+        .service(
+            web::scope("/admin")
+                .service(suspend_player_handler)
+                .service(ban_player_handler)
+                .service(unban_player_handler)
+                .service(player_audit_handler)
+                .service(rollover_season_handler)
+                .service(rebuild_leaderboards_handler),
+        )
+        .service(web::scope("/test").service(delete_player_handler));
+}
@@ -0,0 +1,209 @@
+// src/auth/season.rs
+
+//! 랭크 시즌
+//!
+//! 시즌은 끊김 없이 이어집니다 (다음 시즌 시작 = 이전 시즌 종료).
+//! 롤오버 작업([`db_operation::rollover_season`](crate::auth_server::db_operation::rollover_season))은
+//! 끝난 시즌의 최종 mmr / 티어를 `season_standings` 에 남기고, 최종 티어별 보상을 `season_rewards` 에 지급하고,
+//! mmr / rd 를 소프트 리셋한 뒤 다음 시즌을 엽니다.
+//! 여러 번 실행해도 결과가 같으므로 주기 작업, 관리자 API, `auth_server rollover-season` 어디서 실행해도 됩니다.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use tracing::warn;
+
+use crate::{
+    auth_server::{
+        db_operation,
+        leaderboard::LeaderboardCache,
+        model::{Season, Tier},
+        rating::{Rating, BASE_RATING},
+        rating_cache::RatingCache,
+    },
+    env::duration_from_seconds,
+};
+
+/// 롤오버 작업끼리 겹치지 않게 잡는 advisory lock 키
+pub const SEASON_ROLLOVER_LOCK_KEY: i64 = 0x5345_4153_4f4e; // "SEASON"
+/// 롤오버 후 레이팅 캐시를 다시 쓸 때 파이프라인 1번에 넣는 플레이어 수
+const RATING_CACHE_CHUNK: usize = 1000;
+
+/// 설정 파일 `[seasons]`
+#[derive(Debug, Clone, Deserialize)]
+pub struct SeasonSettings {
+    /// 시즌 길이
+    #[serde(rename = "length_seconds", deserialize_with = "duration_from_seconds")]
+    pub length: Duration,
+    /// 롤오버 후 남기는 (mmr - 1500) 의 비율 (0.0 = 전원 1500, 1.0 = 그대로)
+    pub mmr_carryover: f64,
+    /// 롤오버 후 rd 하한 (배치 경기에서 mmr 이 빨리 움직이도록)
+    pub reset_rd: f64,
+    /// 서버가 주기적으로 롤오버를 확인할지 여부
+    pub auto_rollover: bool,
+    /// 롤오버 확인 주기
+    #[serde(
+        rename = "check_interval_seconds",
+        deserialize_with = "duration_from_seconds"
+    )]
+    pub check_interval: Duration,
+    /// 시즌 마감 시 최종 티어별 보상 경험치 (티어 이름 → 경험치, 없는 티어는 보상 없음)
+    #[serde(default)]
+    pub rewards: HashMap<String, i64>,
+    /// 보상을 받기 위한 시즌 중 최소 랭크 매치 수
+    #[serde(default = "default_reward_min_ranked_matches")]
+    pub reward_min_ranked_matches: i32,
+}
+
+fn default_reward_min_ranked_matches() -> i32 {
+    1
+}
+
+/// 롤오버 작업 결과
+#[derive(Debug, Serialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+pub enum SeasonRollover {
+    /// 시즌이 하나도 없어 첫 시즌을 시작함
+    Started { season: Season },
+    /// 진행 중인 시즌이 아직 끝나지 않음 (변경 없음)
+    InProgress { season: Season },
+    /// 끝난 시즌을 마감하고 다음 시즌을 시작함
+    RolledOver {
+        closed: Season,
+        next: Season,
+        /// 기록한 최종 순위 수
+        standings: u64,
+        /// 보상을 지급한 플레이어 수
+        rewards: u64,
+    },
+}
+
+/// 시즌 소프트 리셋: mmr 을 1500 쪽으로 당기고 rd 를 reset_rd 이상으로 올립니다. volatility 는 유지합니다.
+pub fn soft_reset(rating: Rating, settings: &SeasonSettings, max_rd: f64) -> Rating {
+    Rating {
+        mmr: BASE_RATING + (rating.mmr - BASE_RATING) * settings.mmr_carryover,
+        rd: rating.rd.max(settings.reset_rd).min(max_rd),
+        volatility: rating.volatility,
+    }
+}
+
+/// 보상이 있는 티어의 (tier_id 목록, 경험치 목록) - `UNNEST` 에 그대로 넘깁니다.
+pub fn reward_table(tiers: &[Tier], settings: &SeasonSettings) -> (Vec<i32>, Vec<i64>) {
+    tiers
+        .iter()
+        .filter_map(|tier| {
+            let points = *settings.rewards.get(&tier.name)?;
+            (points > 0).then_some((tier.id, points))
+        })
+        .unzip()
+}
+
+/// 소프트 리셋된 mmr 을 캐시에 반영합니다 (mmr 보드 삭제, 매칭용 레이팅 다시 쓰기).
+///
+/// 캐시 갱신이 실패해도 롤오버 자체는 이미 커밋되었으므로 경고만 남깁니다.
+pub async fn refresh_caches_after_rollover(
+    pool: &PgPool,
+    leaderboards: &LeaderboardCache,
+    rating_cache: &RatingCache,
+    outcome: &SeasonRollover,
+) {
+    if !matches!(outcome, SeasonRollover::RolledOver { .. }) {
+        return;
+    }
+
+    if let Err(e) = leaderboards.invalidate_mmr(pool).await {
+        warn!("Failed to invalidate mmr leaderboards: {}", e);
+    }
+
+    let ratings = match db_operation::get_player_ratings(pool).await {
+        Ok(ratings) => ratings,
+        Err(e) => {
+            warn!("Failed to load ratings for the rating cache: {}", e);
+            return;
+        }
+    };
+    for chunk in ratings.chunks(RATING_CACHE_CHUNK) {
+        if let Err(e) = rating_cache.store(chunk).await {
+            warn!("Failed to refresh rating cache: {}", e);
+            return;
+        }
+    }
+}
+
+/// `starts_at` 에 시작하는 시즌의 종료 시각
+///
+/// 롤오버가 오래 실행되지 않았다면 시즌 길이 단위로 늘려 `now` 이후에 끝나게 합니다.
+pub fn season_end(starts_at: DateTime<Utc>, now: DateTime<Utc>, length: Duration) -> DateTime<Utc> {
+    let length_secs = length.num_seconds().max(1);
+    let elapsed = (now - starts_at).num_seconds().max(0);
+    let periods = elapsed / length_secs + 1;
+    starts_at + Duration::seconds(length_secs * periods)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings() -> SeasonSettings {
+        SeasonSettings {
+            length: Duration::days(90),
+            mmr_carryover: 0.5,
+            reset_rd: 200.0,
+            auto_rollover: true,
+            check_interval: Duration::minutes(5),
+            rewards: HashMap::from([("Gold".to_string(), 300), ("Bronze".to_string(), 0)]),
+            reward_min_ranked_matches: 1,
+        }
+    }
+
+    #[test]
+    fn test_reward_table_skips_unconfigured_tiers() {
+        let tier = |id: i32, name: &str| Tier {
+            id,
+            name: name.to_string(),
+            order_key: id,
+            icon_url: None,
+            min_mmr: 0.0,
+        };
+        let tiers = [tier(1, "Bronze"), tier(2, "Silver"), tier(3, "Gold")];
+
+        // Bronze 는 0, Silver 는 설정 없음 → Gold 만 지급
+        assert_eq!(reward_table(&tiers, &settings()), (vec![3], vec![300]));
+    }
+
+    #[test]
+    fn test_soft_reset_pulls_mmr_toward_base_and_raises_rd() {
+        let settings = settings();
+        let rating = |mmr, rd| Rating {
+            mmr,
+            rd,
+            volatility: 0.07,
+        };
+
+        let reset = soft_reset(rating(2100.0, 60.0), &settings, 350.0);
+        assert_eq!(reset, rating(1800.0, 200.0));
+        assert_eq!(
+            soft_reset(rating(1300.0, 300.0), &settings, 350.0),
+            rating(1400.0, 300.0)
+        );
+        // rd 상한
+        assert_eq!(soft_reset(rating(1500.0, 60.0), &settings, 150.0).rd, 150.0);
+    }
+
+    #[test]
+    fn test_season_end_skips_missed_seasons() {
+        let start = Utc::now() - Duration::days(10);
+        let length = Duration::days(90);
+        assert_eq!(season_end(start, Utc::now(), length), start + length);
+        // 롤오버가 200일 동안 실행되지 않음 → 3번째 길이에서 끝남
+        let now = start + Duration::days(200);
+        assert_eq!(season_end(start, now, length), start + Duration::days(270));
+        // 종료 시각과 같으면 다음 길이로
+        assert_eq!(
+            season_end(start, start + length, length),
+            start + length * 2
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use actix_web::{web, HttpResponse};
+use serde::Serialize;
+
+use crate::auth_server::{
+    db_operation,
+    errors::AuthError,
+    extractor::AuthenticatedPlayer,
+    model::{Season, SeasonStanding},
+    player_end_point::PageQuery,
+    types::AppState,
+};
+
+// --- HTTP 요청/응답 구조체 ---
+#[derive(Serialize)]
+struct SeasonsResponse {
+    /// 진행 중인 시즌
+    current: Option<Season>,
+    /// 전체 시즌 (최신순)
+    seasons: Vec<Season>,
+}
+
+#[derive(Serialize)]
+struct StandingsPage {
+    season: Season,
+    page: i64,
+    page_size: i64,
+    total: i64,
+    standings: Vec<SeasonStanding>,
+}
+
+#[derive(Serialize)]
+struct SeasonHistoryResponse {
+    steam_id: String,
+    seasons: Vec<SeasonStanding>,
+}
+
+// --- 엔드포인트 핸들러 ---
+/// GET /seasons
+/// 전체 시즌 목록과 진행 중인 시즌을 반환합니다.
+#[actix_web::get("/seasons")]
+pub async fn get_seasons_handler(state: web::Data<AppState>) -> Result<HttpResponse, AuthError> {
+    let seasons = db_operation::get_seasons(&state.db_pool).await?;
+    let current = seasons.iter().find(|s| s.closed_at.is_none()).cloned();
+    Ok(HttpResponse::Ok().json(SeasonsResponse { current, seasons }))
+}
+
+/// GET /seasons/{season_id}/standings?page=1&page_size=20
+/// 마감된 시즌의 최종 순위를 순위순으로 반환합니다. 진행 중인 시즌은 409 를 반환합니다.
+#[actix_web::get("/seasons/{season_id}/standings")]
+pub async fn get_season_standings_handler(
+    state: web::Data<AppState>,
+    path: web::Path<i32>,
+    query: web::Query<PageQuery>,
+) -> Result<HttpResponse, AuthError> {
+    let season_id = path.into_inner();
+    let (page, page_size) = query.normalized();
+
+    let season = db_operation::get_season(&state.db_pool, season_id)
+        .await?
+        .ok_or_else(|| AuthError::NotFound(format!("Season {} not found", season_id)))?;
+    if season.closed_at.is_none() {
+        return Err(AuthError::Conflict(format!(
+            "Season {} is still in progress",
+            season_id
+        )));
+    }
+
+    let total = db_operation::count_season_standings(&state.db_pool, season_id).await?;
+    let standings = db_operation::get_season_standings(
+        &state.db_pool,
+        season_id,
+        page_size,
+        (page - 1) * page_size,
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(StandingsPage {
+        season,
+        page,
+        page_size,
+        total,
+        standings,
+    }))
+}
+
+/// GET /players/me/seasons
+/// 토큰 주인의 지난 시즌 최종 순위를 최신 시즌순으로 반환합니다.
+#[actix_web::get("/me/seasons")]
+pub async fn get_my_season_history_handler(
+    state: web::Data<AppState>,
+    player: AuthenticatedPlayer,
+) -> Result<HttpResponse, AuthError> {
+    let seasons = db_operation::get_player_season_history(&state.db_pool, player.steam_id).await?;
+    Ok(HttpResponse::Ok().json(SeasonHistoryResponse {
+        steam_id: player.steam_id.to_string(),
+        seasons,
+    }))
+}
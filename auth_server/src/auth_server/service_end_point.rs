@@ -0,0 +1,396 @@
+use std::collections::HashSet;
+
+use actix_web::{web, HttpResponse};
+use chrono::{DateTime, Utc};
+use game_core::game::enums::{OrdealType, PhaseType, RunEndReason};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use crate::auth_server::{
+    db_operation::{self, MatchResult, RunResult},
+    errors::AuthError,
+    extractor::ServiceCaller,
+    leaderboard::serde_name,
+    types::AppState,
+};
+
+// --- HTTP 요청/응답 구조체 ---
+#[derive(Deserialize)]
+pub struct SubmitMatchRequest {
+    pub game_mode_id: i32,
+    pub winning_team_id: Option<i32>,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: DateTime<Utc>,
+    pub participants: Vec<SubmitParticipant>,
+}
+
+#[derive(Deserialize)]
+pub struct SubmitParticipant {
+    /// SteamID64
+    pub player_id: i64,
+    pub team_id: i32,
+    pub is_winner: bool,
+    pub score: Option<i32>,
+    pub stats: Option<serde_json::Value>,
+    #[serde(default)]
+    pub disconnected: bool,
+}
+
+/// game_server 가 끝난 런 1개를 제출 (summary 는 game_core 의 RunSummary)
+#[derive(Deserialize)]
+pub struct SubmitRunRequest {
+    /// SteamID64
+    pub player_id: i64,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: DateTime<Utc>,
+    pub summary: serde_json::Value,
+}
+
+/// RunSummary 중 run_results 컬럼으로 저장하는 필드 (summary 전체는 JSONB 로 함께 저장)
+#[derive(Deserialize)]
+struct RunSummaryFields {
+    run_seed: u64,
+    end_reason: RunEndReason,
+    reached_ordeal: OrdealType,
+    reached_phase: PhaseType,
+    duration_seconds: u64,
+}
+
+#[derive(Serialize)]
+struct SubmitRunResponse {
+    /// false 면 같은 런(player_id, run_seed)이 이미 저장되어 있음
+    recorded: bool,
+}
+
+/// 메타 진행 보상으로 카드 지급 (internal_name = 환상체 ID)
+#[derive(Deserialize)]
+pub struct GrantCardRequest {
+    pub internal_name: String,
+    pub quantity: i32,
+}
+
+#[derive(Serialize)]
+struct GrantCardResponse {
+    player_id: i64,
+    card_id: i32,
+    internal_name: String,
+    quantity: i32,
+}
+
+#[derive(Serialize)]
+struct SubmitMatchResponse {
+    match_id: String,
+    ratings: Vec<ParticipantRating>,
+}
+
+/// 매치 반영 후 참여자 레이팅 (game_server 가 매칭용 레이팅을 맞출 때 사용)
+#[derive(Serialize)]
+struct ParticipantRating {
+    player_id: i64,
+    mmr: f64,
+    rd: f64,
+    mmr_change: f64,
+    tier_id: i32,
+}
+
+/// DB 를 보기 전에 확인할 수 있는 요청 형식 검사
+fn validate_submission(req: &SubmitMatchRequest) -> Result<(), String> {
+    if req.participants.is_empty() {
+        return Err("participants must not be empty".to_string());
+    }
+    if req.ended_at < req.started_at {
+        return Err("ended_at must not be earlier than started_at".to_string());
+    }
+
+    let mut seen = HashSet::new();
+    for p in &req.participants {
+        if !seen.insert(p.player_id) {
+            return Err(format!("duplicate participant {}", p.player_id));
+        }
+        if let Some(winning_team_id) = req.winning_team_id {
+            if p.is_winner != (p.team_id == winning_team_id) {
+                return Err(format!(
+                    "is_winner of participant {} does not match winning_team_id",
+                    p.player_id
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 런 제출 형식 검사. summary 에서 run_results 컬럼으로 저장할 필드를 꺼냄
+fn validate_run_submission(req: &SubmitRunRequest) -> Result<RunSummaryFields, String> {
+    if req.ended_at < req.started_at {
+        return Err("ended_at must not be earlier than started_at".to_string());
+    }
+    let fields = RunSummaryFields::deserialize(&req.summary)
+        .map_err(|e| format!("invalid run summary: {}", e))?;
+    if i32::try_from(fields.duration_seconds).is_err() {
+        return Err(format!(
+            "duration_seconds {} is out of range",
+            fields.duration_seconds
+        ));
+    }
+    Ok(fields)
+}
+
+// --- 엔드포인트 핸들러 ---
+/// POST /internal/matches
+/// game_server 가 끝난 매치 결과를 제출합니다. (`X-Service-Token` 필요)
+/// 매치 기록, 참여자, (Ranked 면) Glicko-2 레이팅 갱신이 하나의 트랜잭션으로 저장됩니다.
+#[actix_web::post("/matches")]
+pub async fn submit_match_result_handler(
+    state: web::Data<AppState>,
+    _caller: ServiceCaller,
+    req_body: web::Json<SubmitMatchRequest>,
+) -> Result<HttpResponse, AuthError> {
+    let req = req_body.into_inner();
+    validate_submission(&req).map_err(AuthError::BadRequest)?;
+
+    // 1. 참조 데이터 확인 (FK 위반을 500 대신 400 으로 돌려줌)
+    if db_operation::get_game_mode_by_id(&state.db_pool, req.game_mode_id)
+        .await?
+        .is_none()
+    {
+        return Err(AuthError::BadRequest(format!(
+            "Unknown game_mode_id {}",
+            req.game_mode_id
+        )));
+    }
+    let player_ids: Vec<i64> = req.participants.iter().map(|p| p.player_id).collect();
+    let missing = db_operation::find_missing_players(&state.db_pool, &player_ids).await?;
+    if !missing.is_empty() {
+        return Err(AuthError::BadRequest(format!(
+            "Unknown participants: {:?}",
+            missing
+        )));
+    }
+
+    // 2. 저장
+    let results: Vec<MatchResult> = req
+        .participants
+        .iter()
+        .map(|p| MatchResult {
+            player_id: p.player_id,
+            team_id: p.team_id,
+            is_winner: p.is_winner,
+            score: p.score,
+            stats: p.stats.as_ref(),
+            disconnected: p.disconnected,
+        })
+        .collect();
+    let (match_history, ratings) = db_operation::record_match_result(
+        &state.db_pool,
+        req.game_mode_id,
+        req.winning_team_id,
+        req.started_at,
+        req.ended_at,
+        &results,
+        &state.rating,
+    )
+    .await?;
+
+    info!(
+        "Recorded match {} ({} participants)",
+        match_history.id,
+        results.len()
+    );
+
+    // 3. 매칭용 레이팅 캐시 갱신 (실패해도 매치 기록은 이미 커밋됨)
+    let cached: Vec<_> = ratings.iter().map(|r| (r.player_id, r.after)).collect();
+    if let Err(e) = state.rating_cache.store(&cached).await {
+        warn!(
+            "Failed to update rating cache for match {}: {}",
+            match_history.id, e
+        );
+    }
+
+    Ok(HttpResponse::Created().json(SubmitMatchResponse {
+        match_id: match_history.id.to_string(),
+        ratings: ratings
+            .into_iter()
+            .map(|r| ParticipantRating {
+                player_id: r.player_id,
+                mmr: r.after.mmr,
+                rd: r.after.rd,
+                mmr_change: r.after.mmr - r.before.mmr,
+                tier_id: r.tier_id,
+            })
+            .collect(),
+    }))
+}
+
+/// POST /internal/runs
+/// game_server 가 끝난 런 요약을 제출합니다. (`X-Service-Token` 필요)
+/// 런 시간은 game_server 가 잰 summary.duration_seconds 를 쓰며, 같은 런을 다시 제출하면 무시합니다.
+#[actix_web::post("/runs")]
+pub async fn submit_run_result_handler(
+    state: web::Data<AppState>,
+    _caller: ServiceCaller,
+    req_body: web::Json<SubmitRunRequest>,
+) -> Result<HttpResponse, AuthError> {
+    let req = req_body.into_inner();
+    let fields = validate_run_submission(&req).map_err(AuthError::BadRequest)?;
+    if !db_operation::find_missing_players(&state.db_pool, &[req.player_id])
+        .await?
+        .is_empty()
+    {
+        return Err(AuthError::NotFound(format!(
+            "Player {} not found",
+            req.player_id
+        )));
+    }
+
+    let recorded = db_operation::record_run_result(
+        &state.db_pool,
+        &RunResult {
+            player_id: req.player_id,
+            run_seed: fields.run_seed,
+            end_reason: &serde_name(&fields.end_reason),
+            reached_ordeal: &serde_name(&fields.reached_ordeal),
+            reached_phase: &serde_name(&fields.reached_phase),
+            duration_seconds: fields.duration_seconds as i32,
+            started_at: req.started_at,
+            ended_at: req.ended_at,
+            summary: &req.summary,
+        },
+    )
+    .await?;
+
+    if !recorded {
+        info!(
+            "Run {} of player {} was already recorded",
+            fields.run_seed, req.player_id
+        );
+        return Ok(HttpResponse::Ok().json(SubmitRunResponse { recorded }));
+    }
+    info!(
+        "Recorded run {} of player {} ({:?}, {}s)",
+        fields.run_seed, req.player_id, fields.end_reason, fields.duration_seconds
+    );
+    Ok(HttpResponse::Created().json(SubmitRunResponse { recorded }))
+}
+
+/// POST /internal/players/{steam_id}/cards
+/// 플레이어 컬렉션에 카드를 추가합니다. (`X-Service-Token` 필요)
+#[actix_web::post("/players/{steam_id}/cards")]
+pub async fn grant_card_handler(
+    state: web::Data<AppState>,
+    _caller: ServiceCaller,
+    path: web::Path<i64>,
+    req_body: web::Json<GrantCardRequest>,
+) -> Result<HttpResponse, AuthError> {
+    let steam_id = path.into_inner();
+    if req_body.quantity <= 0 {
+        return Err(AuthError::BadRequest(
+            "quantity must be positive".to_string(),
+        ));
+    }
+    let card = db_operation::get_card_by_internal_name(&state.db_pool, &req_body.internal_name)
+        .await?
+        .filter(|card| card.is_collectible)
+        .ok_or_else(|| AuthError::BadRequest(format!("Unknown card {}", req_body.internal_name)))?;
+    if !db_operation::find_missing_players(&state.db_pool, &[steam_id])
+        .await?
+        .is_empty()
+    {
+        return Err(AuthError::NotFound(format!(
+            "Player {} not found",
+            steam_id
+        )));
+    }
+
+    let owned =
+        db_operation::add_card_to_collection(&state.db_pool, steam_id, card.id, req_body.quantity)
+            .await?;
+    info!(
+        "Granted {} x {} to player {}",
+        req_body.quantity, card.internal_name, steam_id
+    );
+    Ok(HttpResponse::Ok().json(GrantCardResponse {
+        player_id: steam_id,
+        card_id: card.id,
+        internal_name: card.internal_name,
+        quantity: owned.quantity,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn participant(player_id: i64, team_id: i32, is_winner: bool) -> SubmitParticipant {
+        SubmitParticipant {
+            player_id,
+            team_id,
+            is_winner,
+            score: None,
+            stats: None,
+            disconnected: false,
+        }
+    }
+
+    fn request(participants: Vec<SubmitParticipant>) -> SubmitMatchRequest {
+        let now = Utc::now();
+        SubmitMatchRequest {
+            game_mode_id: 1,
+            winning_team_id: Some(1),
+            started_at: now,
+            ended_at: now,
+            participants,
+        }
+    }
+
+    #[test]
+    fn test_validate_run_submission() {
+        let now = Utc::now();
+        let run = |summary: serde_json::Value| SubmitRunRequest {
+            player_id: 1,
+            started_at: now,
+            ended_at: now,
+            summary,
+        };
+        let summary = serde_json::json!({
+            "run_seed": 7,
+            "end_reason": "Cleared",
+            "reached_ordeal": "White",
+            "reached_phase": "V",
+            "duration_seconds": 754,
+        });
+
+        let fields = validate_run_submission(&run(summary.clone())).unwrap();
+        assert_eq!(fields.duration_seconds, 754);
+
+        // 런 시간이 없는 요약은 거부 (매치 시간으로 대신하지 않음)
+        let mut missing = summary.clone();
+        missing.as_object_mut().unwrap().remove("duration_seconds");
+        assert!(validate_run_submission(&run(missing)).is_err());
+
+        let mut unknown = summary;
+        unknown["end_reason"] = serde_json::json!("Unknown");
+        assert!(validate_run_submission(&run(unknown)).is_err());
+    }
+
+    #[test]
+    fn test_validate_submission() {
+        assert!(validate_submission(&request(vec![
+            participant(1, 1, true),
+            participant(2, 2, false)
+        ]))
+        .is_ok());
+
+        assert!(validate_submission(&request(Vec::new())).is_err());
+        assert!(validate_submission(&request(vec![
+            participant(1, 1, true),
+            participant(1, 2, false)
+        ]))
+        .is_err());
+        // 승리 팀이 아닌데 is_winner
+        assert!(validate_submission(&request(vec![
+            participant(1, 1, true),
+            participant(2, 2, true)
+        ]))
+        .is_err());
+    }
+}
@@ -0,0 +1,217 @@
+// src/auth/steam.rs
+
+//! Steam 인증 티켓 검증
+//!
+//! `/auth/steam` 은 [`TicketVerifier`] 로 티켓을 검증한 뒤 플레이어 upsert / 상태 확인 / 토큰 발급을 수행합니다.
+//! - [`SteamWebApiVerifier`]: 실제 Steam Web API (`ISteamUserAuth/AuthenticateUserTicket`) 호출
+//! - [`LocalTicketVerifier`]: 네트워크 없이 공유 비밀키로 서명한 가짜 티켓을 검증 (로컬 개발 / 통합 테스트용)
+//!
+//! 가짜 티켓 형식: `{steam_id}.{만료 unix 초}.{hex(HMAC-SHA256(secret, "{steam_id}.{만료}"))}`
+
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use tracing::info;
+
+use crate::auth_server::{errors::AuthError, types::SteamApiResponse};
+
+const STEAM_AUTH_URL: &str =
+    "https://api.steampowered.com/ISteamUserAuth/AuthenticateUserTicket/v1/";
+
+/// 검증에 성공한 티켓 정보
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerifiedTicket {
+    pub steam_id: u64,
+    /// 게임을 소유한 계정 (Family Sharing 이면 steam_id 와 다름)
+    pub owner_steam_id: u64,
+    pub vac_banned: bool,
+    pub publisher_banned: bool,
+}
+
+#[async_trait]
+pub trait TicketVerifier: Send + Sync {
+    /// 티켓이 유효하지 않으면 `AuthError::Unauthorized` 를 반환합니다.
+    async fn verify(&self, ticket: &str) -> Result<VerifiedTicket, AuthError>;
+}
+
+// ============================================================================
+// Steam Web API
+// ============================================================================
+
+pub struct SteamWebApiVerifier {
+    http_client: reqwest::Client,
+    web_api_key: String,
+    app_id: u32,
+    expected_identity: String,
+}
+
+impl SteamWebApiVerifier {
+    pub fn new(
+        http_client: reqwest::Client,
+        web_api_key: String,
+        app_id: u32,
+        expected_identity: String,
+    ) -> Self {
+        Self {
+            http_client,
+            web_api_key,
+            app_id,
+            expected_identity,
+        }
+    }
+}
+
+fn parse_steam_id(value: &str) -> Result<u64, AuthError> {
+    value.parse::<u64>().map_err(|_| {
+        AuthError::InternalServerError(anyhow::anyhow!("Steam returned invalid SteamID"))
+    })
+}
+
+#[async_trait]
+impl TicketVerifier for SteamWebApiVerifier {
+    async fn verify(&self, ticket: &str) -> Result<VerifiedTicket, AuthError> {
+        // 1. 스팀 웹 API에 GET 요청을 보냅니다.
+        let res = self
+            .http_client
+            .get(STEAM_AUTH_URL)
+            .query(&[
+                ("key", self.web_api_key.as_str()),
+                ("appid", &self.app_id.to_string()),
+                ("ticket", ticket),
+                ("identity", &self.expected_identity),
+            ])
+            .send()
+            .await
+            .map_err(|e| AuthError::InternalServerError(anyhow::anyhow!(e)))?;
+
+        // 2. 응답 상태 코드 확인
+        if !res.status().is_success() {
+            return Err(AuthError::GatewayTimeout(format!(
+                "Steam API returned non-success status: {}",
+                res.status()
+            )));
+        }
+
+        // 3. JSON 응답 파싱
+        let steam_response = res
+            .json::<SteamApiResponse>()
+            .await
+            .map_err(|e| AuthError::InternalServerError(anyhow::anyhow!(e)))?;
+
+        // 4. 스팀 응답의 유효성 검사
+        if let Some(params) = steam_response.response.params {
+            if params.result != "OK" {
+                return Err(AuthError::Unauthorized(format!(
+                    "Steam validation failed with result: {}",
+                    params.result
+                )));
+            }
+            let verified = VerifiedTicket {
+                steam_id: parse_steam_id(&params.steamid)?,
+                owner_steam_id: parse_steam_id(&params.ownersteamid)?,
+                vac_banned: params.vacbanned,
+                publisher_banned: params.publisherbanned,
+            };
+            info!(
+                "Steam Web API authentication successful for SteamID: {}",
+                verified.steam_id
+            );
+            Ok(verified)
+        } else if let Some(error) = steam_response.response.error {
+            Err(AuthError::Unauthorized(format!(
+                "Steam API Error {}: {}",
+                error.errorcode, error.errordesc
+            )))
+        } else {
+            Err(AuthError::InternalServerError(anyhow::anyhow!(
+                "Invalid response structure from Steam API"
+            )))
+        }
+    }
+}
+
+// ============================================================================
+// 로컬 (서명된 가짜 티켓)
+// ============================================================================
+
+/// 공유 비밀키로 서명한 가짜 티켓을 검증합니다. 실제 서비스에서는 사용하지 마세요.
+pub struct LocalTicketVerifier {
+    secret: String,
+}
+
+impl LocalTicketVerifier {
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self {
+            secret: secret.into(),
+        }
+    }
+
+    fn sign(&self, payload: &str) -> Hmac<Sha256> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.secret.as_bytes())
+            .expect("HMAC accepts keys of any length");
+        mac.update(payload.as_bytes());
+        mac
+    }
+
+    /// `ttl` 동안 유효한 가짜 티켓을 만듭니다. (테스트 / 로컬 클라이언트용)
+    pub fn issue_ticket(&self, steam_id: u64, ttl: Duration) -> String {
+        let payload = format!("{}.{}", steam_id, (Utc::now() + ttl).timestamp());
+        let signature = hex::encode(self.sign(&payload).finalize().into_bytes());
+        format!("{}.{}", payload, signature)
+    }
+}
+
+#[async_trait]
+impl TicketVerifier for LocalTicketVerifier {
+    async fn verify(&self, ticket: &str) -> Result<VerifiedTicket, AuthError> {
+        let invalid = || AuthError::Unauthorized("Invalid local ticket".to_string());
+
+        let (payload, signature) = ticket.rsplit_once('.').ok_or_else(invalid)?;
+        let signature = hex::decode(signature).map_err(|_| invalid())?;
+        // verify_slice 는 상수 시간 비교
+        self.sign(payload)
+            .verify_slice(&signature)
+            .map_err(|_| invalid())?;
+
+        let (steam_id, expires_at) = payload.split_once('.').ok_or_else(invalid)?;
+        let steam_id = steam_id.parse::<u64>().map_err(|_| invalid())?;
+        let expires_at = expires_at.parse::<i64>().map_err(|_| invalid())?;
+        if expires_at <= Utc::now().timestamp() {
+            return Err(AuthError::Unauthorized("Local ticket expired".to_string()));
+        }
+
+        Ok(VerifiedTicket {
+            steam_id,
+            owner_steam_id: steam_id,
+            vac_banned: false,
+            publisher_banned: false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[actix_web::test]
+    async fn test_local_ticket_round_trip_and_rejections() {
+        let verifier = LocalTicketVerifier::new("secret");
+        let ticket = verifier.issue_ticket(76561197960287930, Duration::minutes(5));
+        let verified = verifier.verify(&ticket).await.unwrap();
+        assert_eq!(verified.steam_id, 76561197960287930);
+        assert_eq!(verified.owner_steam_id, verified.steam_id);
+
+        // 다른 키로 서명
+        let forged = LocalTicketVerifier::new("other").issue_ticket(1, Duration::minutes(5));
+        assert!(verifier.verify(&forged).await.is_err());
+
+        // 서명은 그대로 두고 steam_id 만 변경
+        let tampered = ticket.replacen("76561197960287930", "76561197960287931", 1);
+        assert!(verifier.verify(&tampered).await.is_err());
+
+        let expired = verifier.issue_ticket(1, Duration::seconds(-1));
+        assert!(verifier.verify(&expired).await.is_err());
+        assert!(verifier.verify("garbage").await.is_err());
+    }
+}
@@ -0,0 +1,113 @@
+// src/auth/token.rs
+
+//! 액세스 토큰(JWT) / 리프레시 토큰 발급
+//!
+//! - 액세스 토큰: 수명이 짧은 HS256 JWT. `jti` 로 개별 폐기할 수 있습니다.
+//! - 리프레시 토큰: 임의의 256비트 값. DB 에는 SHA-256 해시만 저장하며, 한 번 쓰면 새 토큰으로 교체됩니다.
+
+use chrono::{Duration, Utc};
+use jsonwebtoken::{encode, EncodingKey, Header};
+use rand::RngCore;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::{
+    auth_server::{db_operation, errors::AuthError, types::AppState, types::Claims},
+    env::duration_from_seconds,
+};
+
+/// 토큰 수명 (설정 파일 `[tokens]`, 초 단위)
+#[derive(Debug, Clone, Deserialize)]
+pub struct TokenSettings {
+    #[serde(
+        rename = "access_ttl_seconds",
+        deserialize_with = "duration_from_seconds"
+    )]
+    pub access_ttl: Duration,
+    #[serde(
+        rename = "refresh_ttl_seconds",
+        deserialize_with = "duration_from_seconds"
+    )]
+    pub refresh_ttl: Duration,
+}
+
+/// 로그인 / 갱신 응답으로 내려주는 토큰 쌍
+#[derive(Debug)]
+pub struct IssuedTokens {
+    pub access_token: String,
+    /// 액세스 토큰 수명 (초)
+    pub expires_in: i64,
+    pub refresh_token: String,
+}
+
+pub fn issue_access_token(
+    secret: &str,
+    steam_id: i64,
+    ttl: Duration,
+) -> Result<(String, Claims), AuthError> {
+    let now = Utc::now();
+    let claims = Claims {
+        sub: steam_id.to_string(),
+        iat: now.timestamp() as usize,
+        exp: (now + ttl).timestamp() as usize,
+        jti: Uuid::new_v4().to_string(),
+    };
+    let token = encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(secret.as_ref()),
+    )
+    .map_err(|e| AuthError::InternalServerError(anyhow::anyhow!(e)))?;
+    Ok((token, claims))
+}
+
+pub fn generate_refresh_token() -> String {
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    hex::encode(bytes)
+}
+
+pub fn hash_refresh_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+/// 로그인 성공 시 새 리프레시 토큰 계열(family)과 액세스 토큰을 발급합니다.
+pub async fn issue_login_tokens(
+    state: &AppState,
+    steam_id: i64,
+) -> Result<IssuedTokens, AuthError> {
+    let refresh_token = generate_refresh_token();
+    db_operation::insert_refresh_token(
+        &state.db_pool,
+        steam_id,
+        Uuid::new_v4(),
+        &hash_refresh_token(&refresh_token),
+        Utc::now() + state.tokens.refresh_ttl,
+    )
+    .await?;
+
+    let (access_token, _) =
+        issue_access_token(&state.jwt_secret, steam_id, state.tokens.access_ttl)?;
+    Ok(IssuedTokens {
+        access_token,
+        expires_in: state.tokens.access_ttl.num_seconds(),
+        refresh_token,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_refresh_token_hash_is_stable_and_distinct() {
+        let a = generate_refresh_token();
+        let b = generate_refresh_token();
+        assert_ne!(a, b);
+        assert_eq!(a.len(), 64);
+        assert_eq!(hash_refresh_token(&a), hash_refresh_token(&a));
+        assert_ne!(hash_refresh_token(&a), hash_refresh_token(&b));
+        assert_ne!(hash_refresh_token(&a), a);
+    }
+}
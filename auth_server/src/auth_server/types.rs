@@ -1,15 +1,34 @@
+use std::sync::Arc;
+
 use sqlx::PgPool;
 
+use crate::auth_server::{
+    collection::DeckSettings, extractor::AdminKey, leaderboard::LeaderboardCache,
+    rating::RatingSettings, rating_cache::RatingCache, revocation::RevocationList,
+    season::SeasonSettings, steam::TicketVerifier, token::TokenSettings,
+};
+
 // --- AppState: 서버 전체에서 공유될 상태 ---
 // 액터 주소와 DB 커넥션 풀을 포함합니다.
 #[derive(Clone)]
 pub struct AppState {
-    pub http_client: reqwest::Client,
     pub db_pool: PgPool,
-    pub steam_web_api_key: String,
-    pub app_id: u32,
-    pub expected_identity: String,
+    /// Steam 인증 티켓 검증기 (Steam Web API 또는 로컬 가짜 티켓)
+    pub ticket_verifier: Arc<dyn TicketVerifier>,
     pub jwt_secret: String,
+    /// game_server 같은 내부 서비스가 `X-Service-Token` 헤더로 보내는 키
+    pub service_token: String,
+    pub rating: RatingSettings,
+    pub tokens: TokenSettings,
+    pub decks: DeckSettings,
+    pub seasons: SeasonSettings,
+    pub revocation: RevocationList,
+    /// 리더보드 캐시 (Redis sorted set)
+    pub leaderboards: LeaderboardCache,
+    /// game_server 매칭용 레이팅 캐시 (`rating:{player_id}`)
+    pub rating_cache: RatingCache,
+    /// 관리자 API 키 (비어 있으면 관리자 API 사용 불가)
+    pub admin_keys: Vec<AdminKey>,
 }
 
 #[derive(serde::Deserialize, Debug)]
@@ -39,9 +58,10 @@ pub struct SteamApiError {
 }
 
 // --- JWT Claims ---
-#[derive(serde::Serialize, serde::Deserialize, Debug)]
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
 pub struct Claims {
     pub sub: String, // Subject (user's steam_id)
     pub iat: usize,  // Issued at (timestamp)
     pub exp: usize,  // Expiration time (timestamp)
+    pub jti: String, // Token ID (폐기 목록 키)
 }
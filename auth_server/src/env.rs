@@ -0,0 +1,385 @@
+use config::{Config, ConfigError, Environment, File, FileFormat};
+use serde::{Deserialize, Deserializer};
+
+use crate::auth_server::{
+    collection::DeckSettings, extractor::parse_admin_keys, leaderboard::LeaderboardSettings,
+    rating::RatingSettings, season::SeasonSettings, token::TokenSettings,
+};
+
+/// JWT 서명 키 최소 길이 (HS256)
+const MIN_JWT_SECRET_LEN: usize = 32;
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Settings {
+    pub server: ServerSettings,
+    pub database: DatabaseSettings,
+    /// 토큰 폐기 목록용 Redis (game_server 와 같은 인스턴스)
+    pub redis: RedisSettings,
+    pub jwt: JwtSettings,
+    /// 액세스 / 리프레시 토큰 수명
+    pub tokens: TokenSettings,
+    pub steam: SteamSettings,
+    /// 내부 서비스 (game_server) 인증
+    pub service: ServiceSettings,
+    pub admin: AdminSettings,
+    /// Glicko-2 레이팅 설정
+    pub rating: RatingSettings,
+    /// 랭크 시즌 길이 / 소프트 리셋
+    pub seasons: SeasonSettings,
+    #[serde(default)]
+    pub cors: CorsSettings,
+    /// 리더보드 캐시
+    #[serde(default)]
+    pub leaderboards: LeaderboardSettings,
+    /// 덱 개수 / 크기 제한
+    #[serde(default)]
+    pub decks: DeckSettings,
+    /// 카드 마스터를 채울 게임 데이터
+    #[serde(default)]
+    pub game_data: GameDataSettings,
+}
+
+impl Settings {
+    pub fn new() -> Result<Self, ConfigError> {
+        let run_mode = std::env::var("RUN_MODE").unwrap_or_else(|_| "development".into());
+        println!("Loading configuration for RUN_MODE: {}", &run_mode);
+
+        let s = Config::builder()
+            // Load environment-specific file (e.g., development.toml, production.toml)
+            .add_source(
+                File::with_name(&format!("config/{}", run_mode))
+                    .format(FileFormat::Toml)
+                    .required(true),
+            )
+            // Add environment variables (e.g., APP__SERVER__PORT=3000)
+            .add_source(
+                Environment::with_prefix("APP")
+                    .separator("__")
+                    .try_parsing(true)
+                    .list_separator(",")
+                    .with_list_parse_key("cors.allowed_origins"),
+            )
+            .build()?;
+
+        let settings: Settings = s.try_deserialize()?;
+        settings.validate()?;
+        Ok(settings)
+    }
+
+    /// 값 사이의 관계까지 확인합니다. 문제를 모두 모아 한 번에 보고합니다.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let mut problems = Vec::new();
+
+        if self.server.bind_address.trim().is_empty() {
+            problems.push("server.bind_address must not be empty".to_string());
+        }
+        if self.server.port == 0 {
+            problems.push("server.port must not be 0".to_string());
+        }
+
+        if !(self.database.url.starts_with("postgres://")
+            || self.database.url.starts_with("postgresql://"))
+        {
+            problems.push("database.url must be a postgres:// URL".to_string());
+        }
+        if self.database.max_connections == 0 {
+            problems.push("database.max_connections must be at least 1".to_string());
+        }
+        if self.database.min_connections > self.database.max_connections {
+            problems.push(format!(
+                "database.min_connections ({}) must not exceed max_connections ({})",
+                self.database.min_connections, self.database.max_connections
+            ));
+        }
+        if self.database.acquire_timeout_seconds == 0 {
+            problems.push("database.acquire_timeout_seconds must be positive".to_string());
+        }
+
+        if !(self.redis.url.starts_with("redis://") || self.redis.url.starts_with("rediss://")) {
+            problems.push("redis.url must be a redis:// or rediss:// URL".to_string());
+        }
+
+        if self.jwt.secret.len() < MIN_JWT_SECRET_LEN {
+            problems.push(format!(
+                "jwt.secret must be at least {} bytes (set APP__JWT__SECRET)",
+                MIN_JWT_SECRET_LEN
+            ));
+        }
+
+        if self.tokens.access_ttl <= chrono::Duration::zero() {
+            problems.push("tokens.access_ttl_seconds must be positive".to_string());
+        }
+        if self.tokens.refresh_ttl <= self.tokens.access_ttl {
+            problems.push(
+                "tokens.refresh_ttl_seconds must be longer than access_ttl_seconds".to_string(),
+            );
+        }
+
+        if self.steam.app_id == 0 {
+            problems.push("steam.app_id must not be 0".to_string());
+        }
+        match self.steam.ticket_verifier {
+            TicketVerifierKind::Steam => {
+                if self.steam.web_api_key.trim().is_empty() {
+                    problems.push(
+                        "steam.web_api_key is required for the steam ticket verifier (set APP__STEAM__WEB_API_KEY)"
+                            .to_string(),
+                    );
+                }
+                if self.steam.expected_identity.trim().is_empty() {
+                    problems.push(
+                        "steam.expected_identity is required for the steam ticket verifier"
+                            .to_string(),
+                    );
+                }
+            }
+            TicketVerifierKind::Local => {
+                if self
+                    .steam
+                    .local_ticket_secret
+                    .as_deref()
+                    .is_none_or(|secret| secret.trim().is_empty())
+                {
+                    problems.push(
+                        "steam.local_ticket_secret is required for the local ticket verifier"
+                            .to_string(),
+                    );
+                }
+            }
+        }
+
+        if self.service.api_token.trim().is_empty() {
+            problems.push(
+                "service.api_token must not be empty (set APP__SERVICE__API_TOKEN)".to_string(),
+            );
+        }
+        if let Err(e) = parse_admin_keys(&self.admin.api_keys) {
+            problems.push(format!("admin.api_keys: {}", e));
+        }
+
+        if !(0.3..=1.2).contains(&self.rating.tau) {
+            problems.push(format!(
+                "rating.tau must be between 0.3 and 1.2, got {}",
+                self.rating.tau
+            ));
+        }
+        if self.rating.rating_period <= chrono::Duration::zero() {
+            problems.push("rating.rating_period_seconds must be positive".to_string());
+        }
+        if self.rating.max_rd <= 0.0 {
+            problems.push("rating.max_rd must be positive".to_string());
+        }
+
+        if self.seasons.length <= chrono::Duration::zero() {
+            problems.push("seasons.length_seconds must be positive".to_string());
+        }
+        if !(0.0..=1.0).contains(&self.seasons.mmr_carryover) {
+            problems.push(format!(
+                "seasons.mmr_carryover must be between 0.0 and 1.0, got {}",
+                self.seasons.mmr_carryover
+            ));
+        }
+        if !(self.seasons.reset_rd > 0.0 && self.seasons.reset_rd <= self.rating.max_rd) {
+            problems.push(format!(
+                "seasons.reset_rd must be in (0, rating.max_rd], got {}",
+                self.seasons.reset_rd
+            ));
+        }
+        if self.seasons.check_interval <= chrono::Duration::zero() {
+            problems.push("seasons.check_interval_seconds must be positive".to_string());
+        }
+        if self.seasons.reward_min_ranked_matches < 0 {
+            problems.push("seasons.reward_min_ranked_matches must not be negative".to_string());
+        }
+        let mut negative_rewards: Vec<&String> = self
+            .seasons
+            .rewards
+            .iter()
+            .filter(|(_, points)| **points < 0)
+            .map(|(tier, _)| tier)
+            .collect();
+        negative_rewards.sort();
+        for tier in negative_rewards {
+            problems.push(format!("seasons.rewards.{} must not be negative", tier));
+        }
+
+        if self.leaderboards.cache_ttl <= chrono::Duration::zero() {
+            problems.push("leaderboards.cache_ttl_seconds must be positive".to_string());
+        }
+
+        if self.decks.max_decks <= 0 {
+            problems.push("decks.max_decks must be positive".to_string());
+        }
+        if self.decks.max_deck_size <= 0 {
+            problems.push("decks.max_deck_size must be positive".to_string());
+        }
+
+        for origin in &self.cors.allowed_origins {
+            if origin != "*" && !(origin.starts_with("http://") || origin.starts_with("https://")) {
+                problems.push(format!(
+                    "cors.allowed_origins entry `{}` must be `*` or an http(s) origin",
+                    origin
+                ));
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(ConfigError::Message(format!(
+                "invalid settings:\n  - {}",
+                problems.join("\n  - ")
+            )))
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ServerSettings {
+    pub bind_address: String,
+    pub port: u16,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct DatabaseSettings {
+    pub url: String,
+    pub max_connections: u32,
+    #[serde(default)]
+    pub min_connections: u32,
+    /// 커넥션 풀에서 연결을 기다리는 최대 시간 (초)
+    pub acquire_timeout_seconds: u64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct RedisSettings {
+    pub url: String,
+}
+
+/// JWT 서명 설정 (game_server `[jwt] secret` 과 같은 값)
+#[derive(Debug, Deserialize, Clone)]
+pub struct JwtSettings {
+    pub secret: String,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TicketVerifierKind {
+    /// Steam Web API 로 검증
+    Steam,
+    /// 서명된 가짜 티켓 (로컬 개발 / 테스트 전용)
+    Local,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct SteamSettings {
+    pub app_id: u32,
+    #[serde(default)]
+    pub web_api_key: String,
+    /// 클라이언트가 티켓 발급 시 지정한 identity
+    #[serde(default)]
+    pub expected_identity: String,
+    pub ticket_verifier: TicketVerifierKind,
+    /// ticket_verifier = "local" 일 때 가짜 티켓 서명 키
+    #[serde(default)]
+    pub local_ticket_secret: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ServiceSettings {
+    /// game_server 가 `X-Service-Token` 헤더로 보내는 키
+    pub api_token: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AdminSettings {
+    /// `name:key,name:key` 형식 (비어 있으면 관리자 API 사용 불가)
+    #[serde(default)]
+    pub api_keys: String,
+}
+
+/// 브라우저 클라이언트용 CORS 설정
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct CorsSettings {
+    /// 허용할 Origin 목록. `*` 는 모든 Origin, 비어 있으면 교차 출처 요청을 허용하지 않음
+    pub allowed_origins: Vec<String>,
+    /// preflight 응답 캐시 시간 (초)
+    pub max_age_seconds: usize,
+}
+
+impl Default for CorsSettings {
+    fn default() -> Self {
+        Self {
+            allowed_origins: Vec::new(),
+            max_age_seconds: 3600,
+        }
+    }
+}
+
+/// 게임 데이터(RON) 설정 (game_server `server.game_data_dir` 과 같은 디렉토리)
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct GameDataSettings {
+    pub dir: String,
+    /// 시작 시 환상체 데이터로 카드 마스터(`cards`) 동기화
+    pub sync_cards_on_startup: bool,
+}
+
+impl Default for GameDataSettings {
+    fn default() -> Self {
+        Self {
+            dir: "../game_resources/data".to_string(),
+            sync_cards_on_startup: true,
+        }
+    }
+}
+
+/// 초 단위 정수를 `chrono::Duration` 으로 읽습니다.
+pub(crate) fn duration_from_seconds<'de, D>(deserializer: D) -> Result<chrono::Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let seconds = i64::deserialize(deserializer)?;
+    chrono::Duration::try_seconds(seconds)
+        .ok_or_else(|| serde::de::Error::custom(format!("{} seconds is out of range", seconds)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load(toml: &str) -> Result<Settings, ConfigError> {
+        let settings: Settings = Config::builder()
+            .add_source(File::from_str(toml, FileFormat::Toml))
+            .build()?
+            .try_deserialize()?;
+        settings.validate()?;
+        Ok(settings)
+    }
+
+    #[test]
+    fn test_development_config_is_valid_and_validation_reports_all_problems() {
+        let dev = include_str!("../config/development.toml");
+        let settings = load(dev).unwrap();
+        assert_eq!(settings.tokens.access_ttl, chrono::Duration::minutes(15));
+        assert_eq!(settings.rating.rating_period, chrono::Duration::days(7));
+        assert_eq!(settings.seasons.rewards.get("Diamond"), Some(&1000));
+
+        let broken = dev
+            .replace("port = 3000", "port = 0")
+            .replace("refresh_ttl_seconds = 2592000", "refresh_ttl_seconds = 60")
+            .replace("tau = 0.5", "tau = 5.0")
+            .replace("mmr_carryover = 0.5", "mmr_carryover = 1.5")
+            .replace("Gold = 400", "Gold = -400");
+        let message = load(&broken).unwrap_err().to_string();
+        assert!(message.contains("server.port"), "{}", message);
+        assert!(
+            message.contains("tokens.refresh_ttl_seconds"),
+            "{}",
+            message
+        );
+        assert!(message.contains("rating.tau"), "{}", message);
+        assert!(message.contains("seasons.mmr_carryover"), "{}", message);
+        assert!(message.contains("seasons.rewards.Gold"), "{}", message);
+    }
+}
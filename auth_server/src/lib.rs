@@ -1,4 +1,5 @@
 pub mod auth_server;
+pub mod env;
 
 use std::{io, sync::Once};
 
@@ -1,60 +1,210 @@
+use std::{sync::Arc, time::Duration};
+
+use actix_cors::Cors;
 use actix_web::{web, App, HttpServer};
 use auth_server::{
     auth_server::{
-        end_point::{delete_player_handler, steam_authentication_handler},
+        collection, db_operation,
+        extractor::parse_admin_keys,
+        leaderboard::LeaderboardCache,
+        rating_cache::RatingCache,
+        revocation::RevocationList,
+        routes,
+        season::{refresh_caches_after_rollover, SeasonRollover},
+        steam::{LocalTicketVerifier, SteamWebApiVerifier, TicketVerifier},
         types::AppState,
     },
+    env::{CorsSettings, Settings, TicketVerifierKind},
     setup_logger,
 };
 use sqlx::postgres::PgPoolOptions;
 
+/// 설정된 Origin 만 허용하는 CORS 미들웨어 (목록이 비어 있으면 교차 출처 요청 거부)
+fn build_cors(settings: &CorsSettings) -> Cors {
+    let cors = Cors::default()
+        .allow_any_method()
+        .allow_any_header()
+        .max_age(settings.max_age_seconds);
+    if settings.allowed_origins.iter().any(|origin| origin == "*") {
+        return cors.allow_any_origin();
+    }
+    settings
+        .allowed_origins
+        .iter()
+        .fold(cors, |cors, origin| cors.allowed_origin(origin))
+}
+
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
     dotenvy::dotenv().ok();
-    std::fs::write("steam_appid.txt", "480")?;
+
+    // 설정이 잘못되었으면 서버를 띄우지 않고 바로 종료합니다.
+    let settings = match Settings::new() {
+        Ok(settings) => settings,
+        Err(e) => {
+            eprintln!("Failed to load settings: {}", e);
+            std::process::exit(1);
+        }
+    };
+    std::fs::write("steam_appid.txt", settings.steam.app_id.to_string())?;
 
     // 로거 초기화는 한 번만 수행합니다. try_init()은 중복 초기화 패닉을 방지합니다.
     setup_logger();
     tracing::info!("Logger Initialized.");
 
-    let database_url =
-        std::env::var("DATABASE_URL").expect("DATABASE_URL must be set in .env file");
-    let steam_web_api_key =
-        std::env::var("STEAM_WEB_API_KEY").expect("STEAM_WEB_API_KEY must be set in .env file");
-    let jwt_secret = std::env::var("JWT_SECRET").expect("JWT_SECRET must be set in .env file");
-
     let db_pool = PgPoolOptions::new()
-        .max_connections(10)
-        .connect(&database_url)
+        .max_connections(settings.database.max_connections)
+        .min_connections(settings.database.min_connections)
+        .acquire_timeout(Duration::from_secs(
+            settings.database.acquire_timeout_seconds,
+        ))
+        .connect(&settings.database.url)
         .await
         .expect("Failed to create database connection pool");
     tracing::info!("Database connection pool created.");
 
+    // 토큰 폐기 목록, 리더보드, 매칭용 레이팅 캐시 (game_server 와 같은 Redis 사용)
+    let redis_client = redis::Client::open(settings.redis.url.as_str()).expect("Invalid redis.url");
+    let redis = redis::aio::ConnectionManager::new(redis_client)
+        .await
+        .expect("Failed to connect to Redis");
+    tracing::info!("Redis connection created.");
+    let leaderboards = LeaderboardCache::new(redis.clone(), &settings.leaderboards);
+    let rating_cache = RatingCache::new(redis.clone());
+
+    // `auth_server rollover-season`: 시즌 롤오버 작업만 1번 실행하고 종료 (여러 번 실행해도 안전)
+    if std::env::args().nth(1).as_deref() == Some("rollover-season") {
+        let outcome = db_operation::rollover_season(
+            &db_pool,
+            chrono::Utc::now(),
+            &settings.seasons,
+            &settings.rating,
+        )
+        .await
+        .map_err(std::io::Error::other)?;
+        refresh_caches_after_rollover(&db_pool, &leaderboards, &rating_cache, &outcome).await;
+        println!("{}", serde_json::to_string_pretty(&outcome)?);
+        return Ok(());
+    }
+
+    // 카드 마스터를 게임 데이터의 환상체와 맞춤
+    if settings.game_data.sync_cards_on_startup {
+        let (synced, retired) =
+            collection::sync_cards_from_game_data(&db_pool, &settings.game_data.dir)
+                .await
+                .expect("Failed to sync cards from game data");
+        tracing::info!(
+            "Synced {} cards from {} ({} retired)",
+            synced,
+            settings.game_data.dir,
+            retired
+        );
+    }
+
+    let ticket_verifier: Arc<dyn TicketVerifier> = match settings.steam.ticket_verifier {
+        TicketVerifierKind::Steam => Arc::new(SteamWebApiVerifier::new(
+            reqwest::Client::new(),
+            settings.steam.web_api_key.clone(),
+            settings.steam.app_id,
+            settings.steam.expected_identity.clone(),
+        )),
+        TicketVerifierKind::Local => {
+            tracing::warn!("Using local ticket verifier, Steam tickets are NOT checked");
+            Arc::new(LocalTicketVerifier::new(
+                settings
+                    .steam
+                    .local_ticket_secret
+                    .clone()
+                    .unwrap_or_default(),
+            ))
+        }
+    };
+
     let app_state = AppState {
-        http_client: reqwest::Client::new(),
         db_pool,
-        steam_web_api_key: steam_web_api_key.clone(),
-        app_id: 480,
-        expected_identity: std::env::var("EXPECTED_IDENTITY")
-            .expect("EXPECTED_IDENTITY must be set in .env file"),
-        jwt_secret,
+        ticket_verifier,
+        jwt_secret: settings.jwt.secret.clone(),
+        service_token: settings.service.api_token.clone(),
+        rating: settings.rating.clone(),
+        tokens: settings.tokens.clone(),
+        decks: settings.decks.clone(),
+        seasons: settings.seasons.clone(),
+        revocation: RevocationList::new(redis),
+        leaderboards,
+        rating_cache,
+        // Settings::new() 에서 형식을 확인함
+        admin_keys: parse_admin_keys(&settings.admin.api_keys)
+            .expect("admin.api_keys was validated"),
     };
-    let bind_address = "127.0.0.1:3000";
-    tracing::info!("Starting Actix-Web server on {}", bind_address);
+    if app_state.admin_keys.is_empty() {
+        tracing::warn!("admin.api_keys is empty, admin endpoints will reject every request");
+    }
 
-    HttpServer::new(move || {
-        let auth_scope = web::scope("/auth").service(steam_authentication_handler);
+    // 기간이 끝난 정지 해제 (로그인 / 토큰 갱신 시에도 개별 확인함)
+    let sweep_pool = app_state.db_pool.clone();
+    actix_web::rt::spawn(async move {
+        let mut interval = actix_web::rt::time::interval(Duration::from_secs(60));
+        loop {
+            interval.tick().await;
+            match db_operation::lift_expired_suspensions(&sweep_pool, None).await {
+                Ok(lifted) if !lifted.is_empty() => {
+                    tracing::info!("Lifted expired suspensions: {:?}", lifted)
+                }
+                Ok(_) => {}
+                Err(e) => tracing::warn!("Suspension sweep failed: {}", e),
+            }
+        }
+    });
 
-        #[cfg(feature = "test-endpoints")]
-        let auth_scope = {
-            use auth_server::auth_server::end_point::test_authentication_handler;
-            auth_scope.service(test_authentication_handler)
-        };
+    // 끝난 시즌 롤오버 (첫 실행 시 첫 시즌 시작)
+    if settings.seasons.auto_rollover {
+        let state = app_state.clone();
+        let period = settings
+            .seasons
+            .check_interval
+            .to_std()
+            .expect("seasons.check_interval_seconds was validated");
+        actix_web::rt::spawn(async move {
+            let mut interval = actix_web::rt::time::interval(period);
+            loop {
+                interval.tick().await;
+                match db_operation::rollover_season(
+                    &state.db_pool,
+                    chrono::Utc::now(),
+                    &state.seasons,
+                    &state.rating,
+                )
+                .await
+                {
+                    Ok(SeasonRollover::InProgress { .. }) => {}
+                    Ok(outcome) => {
+                        tracing::info!("Season rollover: {:?}", outcome);
+                        refresh_caches_after_rollover(
+                            &state.db_pool,
+                            &state.leaderboards,
+                            &state.rating_cache,
+                            &outcome,
+                        )
+                        .await;
+                    }
+                    Err(e) => tracing::warn!("Season rollover failed: {}", e),
+                }
+            }
+        });
+    }
 
+    let bind_address = (settings.server.bind_address.clone(), settings.server.port);
+    tracing::info!(
+        "Starting Actix-Web server on {}:{}",
+        bind_address.0,
+        bind_address.1
+    );
+
+    HttpServer::new(move || {
         App::new()
+            .wrap(build_cors(&settings.cors))
             .app_data(web::Data::new(app_state.clone()))
-            .service(auth_scope)
-            .service(web::scope("/test").service(delete_player_handler))
+            .configure(routes::configure)
     })
     .bind(bind_address)?
     .run()
@@ -1 +1,719 @@
+//! 통합 테스트 (`/auth/steam`, 컬렉션 / 덱, 시즌, 리더보드)
+//!
+//! 실제 라우트 구성에 [`LocalTicketVerifier`] 를 넣어 Steam 로그인 전체 흐름
+//! (티켓 검증 → 플레이어 upsert → 상태 확인 → JWT 발급)을 로컬 Postgres 로 확인합니다.
+//! `DATABASE_URL` (마이그레이션 적용된 DB)이 없으면 건너뜁니다. Redis 는 사용하지 않습니다.
 
+use std::{collections::HashMap, sync::Arc};
+
+use actix_web::{http::StatusCode, test, web, App};
+use auth_server::auth_server::{
+    collection::{self, DeckSettings},
+    db_operation,
+    leaderboard::LeaderboardCache,
+    model::{ModerationAction, PlayerStatus},
+    rating::RatingSettings,
+    rating_cache::RatingCache,
+    revocation::RevocationList,
+    routes,
+    season::{SeasonRollover, SeasonSettings},
+    steam::LocalTicketVerifier,
+    token::TokenSettings,
+    types::{AppState, Claims},
+};
+use chrono::{Duration, Utc};
+use jsonwebtoken::{decode, DecodingKey, Validation};
+use rand::Rng;
+use serde_json::{json, Value};
+use sqlx::postgres::PgPoolOptions;
+
+const JWT_SECRET: &str = "integration-test-jwt-secret";
+const TICKET_SECRET: &str = "integration-test-ticket-secret";
+const SERVICE_TOKEN: &str = "integration-test-service-token";
+
+async fn test_state() -> Option<AppState> {
+    let Ok(database_url) = std::env::var("DATABASE_URL") else {
+        eprintln!("DATABASE_URL is not set, skipping auth_server integration test");
+        return None;
+    };
+    let db_pool = PgPoolOptions::new()
+        .max_connections(2)
+        .connect(&database_url)
+        .await
+        .expect("Failed to connect to DATABASE_URL");
+
+    Some(AppState {
+        db_pool,
+        ticket_verifier: Arc::new(LocalTicketVerifier::new(TICKET_SECRET)),
+        jwt_secret: JWT_SECRET.to_string(),
+        service_token: SERVICE_TOKEN.to_string(),
+        rating: RatingSettings {
+            tau: 0.5,
+            rating_period: Duration::days(7),
+            max_rd: 350.0,
+        },
+        tokens: TokenSettings {
+            access_ttl: Duration::minutes(15),
+            refresh_ttl: Duration::days(30),
+        },
+        decks: DeckSettings::default(),
+        seasons: SeasonSettings {
+            length: Duration::days(90),
+            mmr_carryover: 0.5,
+            reset_rd: 200.0,
+            auto_rollover: false,
+            check_interval: Duration::minutes(5),
+            rewards: HashMap::from([("Diamond".to_string(), 500)]),
+            reward_min_ranked_matches: 0,
+        },
+        revocation: RevocationList::disabled(),
+        rating_cache: RatingCache::disabled(),
+        leaderboards: LeaderboardCache::disabled(),
+        admin_keys: Vec::new(),
+    })
+}
+
+/// 테스트끼리 겹치지 않는 SteamID64
+fn random_steam_id() -> u64 {
+    76561190000000000 + rand::thread_rng().gen_range(0..1_000_000_000u64)
+}
+
+fn ticket(steam_id: u64) -> String {
+    LocalTicketVerifier::new(TICKET_SECRET).issue_ticket(steam_id, Duration::minutes(5))
+}
+
+macro_rules! init_app {
+    ($state:expr) => {
+        test::init_service(
+            App::new()
+                .app_data(web::Data::new($state.clone()))
+                .configure(routes::configure),
+        )
+        .await
+    };
+}
+
+async fn steam_login<S>(app: &S, ticket: &str) -> (StatusCode, Value)
+where
+    S: actix_web::dev::Service<
+        actix_http::Request,
+        Response = actix_web::dev::ServiceResponse,
+        Error = actix_web::Error,
+    >,
+{
+    let req = test::TestRequest::post()
+        .uri("/auth/steam")
+        .set_json(json!({ "ticket": ticket }))
+        .to_request();
+    let res = test::call_service(app, req).await;
+    let status = res.status();
+    (status, test::read_body_json(res).await)
+}
+
+#[actix_web::test]
+async fn test_steam_login_upserts_player_and_issues_tokens() {
+    let Some(state) = test_state().await else {
+        return;
+    };
+    let app = init_app!(state);
+    let steam_id = random_steam_id();
+
+    // 1. 첫 로그인: 플레이어 / 프로필 생성 + 토큰 발급
+    let (status, body) = steam_login(&app, &ticket(steam_id)).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    assert_eq!(body["steam_id"], steam_id.to_string());
+
+    let claims = decode::<Claims>(
+        body["token"].as_str().unwrap(),
+        &DecodingKey::from_secret(JWT_SECRET.as_ref()),
+        &Validation::default(),
+    )
+    .expect("issued token must be a valid JWT")
+    .claims;
+    assert_eq!(claims.sub, steam_id.to_string());
+    assert_eq!(
+        body["expires_in"].as_i64(),
+        Some(state.tokens.access_ttl.num_seconds())
+    );
+
+    let player = db_operation::get_player_by_id(&state.db_pool, steam_id as i64)
+        .await
+        .unwrap()
+        .expect("player must be created on first login");
+    assert_eq!(player.status, PlayerStatus::Active);
+    let first_login_at = player.last_login_at;
+    assert!(
+        db_operation::get_player_profile(&state.db_pool, steam_id as i64)
+            .await
+            .unwrap()
+            .is_some()
+    );
+
+    // 2. 발급된 액세스 토큰으로 보호된 API 호출
+    let req = test::TestRequest::get()
+        .uri("/players/me")
+        .insert_header((
+            "Authorization",
+            format!("Bearer {}", body["token"].as_str().unwrap()),
+        ))
+        .to_request();
+    let me: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(me["steam_id"], steam_id.to_string());
+
+    // 3. 리프레시 토큰 교체
+    let req = test::TestRequest::post()
+        .uri("/auth/refresh")
+        .set_json(json!({ "refresh_token": body["refresh_token"] }))
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), StatusCode::OK);
+
+    // 4. 두 번째 로그인: 같은 플레이어 갱신
+    let (status, _) = steam_login(&app, &ticket(steam_id)).await;
+    assert_eq!(status, StatusCode::OK);
+    let player = db_operation::get_player_by_id(&state.db_pool, steam_id as i64)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(player.last_login_at >= first_login_at);
+
+    db_operation::delete_player_by_id(&state.db_pool, steam_id as i64)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_steam_login_rejects_invalid_tickets() {
+    let Some(state) = test_state().await else {
+        return;
+    };
+    let app = init_app!(state);
+    let steam_id = random_steam_id();
+
+    let forged =
+        LocalTicketVerifier::new("wrong-secret").issue_ticket(steam_id, Duration::minutes(5));
+    let expired =
+        LocalTicketVerifier::new(TICKET_SECRET).issue_ticket(steam_id, Duration::seconds(-1));
+    for ticket in [forged.as_str(), expired.as_str(), "not-a-ticket"] {
+        let (status, body) = steam_login(&app, ticket).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED, "{}: {}", ticket, body);
+    }
+
+    // 거부된 티켓으로는 플레이어가 생기지 않음
+    assert!(
+        db_operation::get_player_by_id(&state.db_pool, steam_id as i64)
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[actix_web::test]
+async fn test_steam_login_checks_player_status() {
+    let Some(state) = test_state().await else {
+        return;
+    };
+    let app = init_app!(state);
+    let steam_id = random_steam_id();
+
+    let (status, _) = steam_login(&app, &ticket(steam_id)).await;
+    assert_eq!(status, StatusCode::OK);
+
+    // 정지 중이면 403 + 사유
+    db_operation::apply_moderation(
+        &state.db_pool,
+        steam_id as i64,
+        "integration-test",
+        ModerationAction::Suspend,
+        "griefing",
+        Some(Utc::now() + Duration::hours(1)),
+    )
+    .await
+    .unwrap();
+    let (status, body) = steam_login(&app, &ticket(steam_id)).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert!(
+        body["error"].as_str().unwrap().contains("griefing"),
+        "{}",
+        body
+    );
+
+    // 밴
+    db_operation::apply_moderation(
+        &state.db_pool,
+        steam_id as i64,
+        "integration-test",
+        ModerationAction::Ban,
+        "cheating",
+        None,
+    )
+    .await
+    .unwrap();
+    let (status, body) = steam_login(&app, &ticket(steam_id)).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert!(
+        body["error"].as_str().unwrap().contains("banned"),
+        "{}",
+        body
+    );
+
+    // 기간이 끝난 정지는 로그인 시 해제
+    db_operation::apply_moderation(
+        &state.db_pool,
+        steam_id as i64,
+        "integration-test",
+        ModerationAction::Suspend,
+        "afk",
+        Some(Utc::now() - Duration::seconds(1)),
+    )
+    .await
+    .unwrap();
+    let (status, body) = steam_login(&app, &ticket(steam_id)).await;
+    assert_eq!(status, StatusCode::OK, "{}", body);
+    let player = db_operation::get_player_by_id(&state.db_pool, steam_id as i64)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(player.status, PlayerStatus::Active);
+
+    db_operation::delete_player_by_id(&state.db_pool, steam_id as i64)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_deck_building_with_rarity_limits() {
+    let Some(state) = test_state().await else {
+        return;
+    };
+    let app = init_app!(state);
+    let steam_id = random_steam_id();
+
+    // 카드 마스터를 게임 데이터로 채움
+    let data_dir = concat!(env!("CARGO_MANIFEST_DIR"), "/../game_resources/data");
+    collection::sync_cards_from_game_data(&state.db_pool, data_dir)
+        .await
+        .unwrap();
+
+    let (status, body) = steam_login(&app, &ticket(steam_id)).await;
+    assert_eq!(status, StatusCode::OK);
+    let bearer = format!("Bearer {}", body["token"].as_str().unwrap());
+
+    // game_server 가 메타 진행 보상으로 카드 지급 (TETH → Common, ALEPH → Legendary)
+    let mut card_ids = Vec::new();
+    for (internal_name, quantity) in [("f-05-52", 3), ("o-02-56", 2)] {
+        let req = test::TestRequest::post()
+            .uri(&format!("/internal/players/{}/cards", steam_id))
+            .insert_header(("X-Service-Token", SERVICE_TOKEN))
+            .set_json(json!({ "internal_name": internal_name, "quantity": quantity }))
+            .to_request();
+        let granted: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(granted["quantity"], quantity, "{}", granted);
+        card_ids.push(granted["card_id"].as_i64().unwrap());
+    }
+    let (common, legendary) = (card_ids[0], card_ids[1]);
+
+    let req = test::TestRequest::get()
+        .uri("/players/me/collection")
+        .insert_header(("Authorization", bearer.clone()))
+        .to_request();
+    let owned: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(owned["cards"].as_array().unwrap().len(), 2, "{}", owned);
+    assert!(owned["cards"][0]["card"]["item_uuid"].is_string());
+
+    // 덱 생성 (같은 이름은 409)
+    let create = |name: &str| {
+        test::TestRequest::post()
+            .uri("/players/me/decks")
+            .insert_header(("Authorization", bearer.clone()))
+            .set_json(json!({ "name": name, "cover_card_id": legendary }))
+            .to_request()
+    };
+    let res = test::call_service(&app, create("Main")).await;
+    assert_eq!(res.status(), StatusCode::CREATED);
+    let deck: Value = test::read_body_json(res).await;
+    let deck_id = deck["id"].as_str().unwrap().to_string();
+    assert_eq!(
+        test::call_service(&app, create(" Main ")).await.status(),
+        StatusCode::CONFLICT
+    );
+
+    // 덱 구성: Legendary 는 1장까지
+    let put_cards = |cards: Value| {
+        test::TestRequest::put()
+            .uri(&format!("/players/me/decks/{}/cards", deck_id))
+            .insert_header(("Authorization", bearer.clone()))
+            .set_json(json!({ "cards": cards }))
+            .to_request()
+    };
+    let res = test::call_service(
+        &app,
+        put_cards(json!([
+            { "card_id": common, "quantity": 2 },
+            { "card_id": legendary, "quantity": 1 }
+        ])),
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let deck: Value = test::read_body_json(res).await;
+    assert_eq!(deck["card_count"], 3, "{}", deck);
+
+    let res = test::call_service(
+        &app,
+        put_cards(json!([{ "card_id": legendary, "quantity": 2 }])),
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+    // 이름 변경 후 목록 확인
+    let req = test::TestRequest::patch()
+        .uri(&format!("/players/me/decks/{}", deck_id))
+        .insert_header(("Authorization", bearer.clone()))
+        .set_json(json!({ "name": "Renamed" }))
+        .to_request();
+    let renamed: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(renamed["name"], "Renamed");
+    assert_eq!(renamed["card_count"], 3, "{}", renamed);
+
+    let req = test::TestRequest::get()
+        .uri("/players/me/decks")
+        .insert_header(("Authorization", bearer.clone()))
+        .to_request();
+    let decks: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(decks["decks"].as_array().unwrap().len(), 1);
+
+    // 삭제
+    let req = test::TestRequest::delete()
+        .uri(&format!("/players/me/decks/{}", deck_id))
+        .insert_header(("Authorization", bearer.clone()))
+        .to_request();
+    assert_eq!(
+        test::call_service(&app, req).await.status(),
+        StatusCode::NO_CONTENT
+    );
+    let req = test::TestRequest::get()
+        .uri(&format!("/players/me/decks/{}", deck_id))
+        .insert_header(("Authorization", bearer.clone()))
+        .to_request();
+    assert_eq!(
+        test::call_service(&app, req).await.status(),
+        StatusCode::NOT_FOUND
+    );
+
+    db_operation::delete_player_by_id(&state.db_pool, steam_id as i64)
+        .await
+        .unwrap();
+}
+
+#[actix_web::test]
+async fn test_season_rollover_snapshots_and_soft_resets() {
+    let Some(state) = test_state().await else {
+        return;
+    };
+    let app = init_app!(state);
+    let (steam_id, steady_id) = (random_steam_id(), random_steam_id());
+
+    let (status, body) = steam_login(&app, &ticket(steam_id)).await;
+    assert_eq!(status, StatusCode::OK);
+    let bearer = format!("Bearer {}", body["token"].as_str().unwrap());
+    sqlx::query("UPDATE player_profiles SET mmr = 2100, rd = 60, tier_id = 5 WHERE player_id = $1")
+        .bind(steam_id as i64)
+        .execute(&state.db_pool)
+        .await
+        .unwrap();
+
+    // 소프트 리셋으로 바뀌지 않는 레이팅 (mmr 1500, rd >= reset_rd)
+    let (status, _) = steam_login(&app, &ticket(steady_id)).await;
+    assert_eq!(status, StatusCode::OK);
+    let steady_updated_at = Utc::now() - Duration::days(3);
+    sqlx::query(
+        "UPDATE player_profiles SET mmr = 1500, rd = 300, tier_id = 2, last_rating_update_at = $2 WHERE player_id = $1",
+    )
+    .bind(steady_id as i64)
+    .bind(steady_updated_at)
+    .execute(&state.db_pool)
+    .await
+    .unwrap();
+
+    // 진행 중인 시즌 (없으면 시작)
+    let season = match db_operation::rollover_season(
+        &state.db_pool,
+        Utc::now(),
+        &state.seasons,
+        &state.rating,
+    )
+    .await
+    .unwrap()
+    {
+        SeasonRollover::Started { season } | SeasonRollover::InProgress { season } => season,
+        other => panic!("unexpected rollover: {:?}", other),
+    };
+
+    // 시즌 종료 시각에 롤오버, 같은 시각에 다시 실행해도 변화 없음
+    let at = season.ends_at;
+    let next =
+        match db_operation::rollover_season(&state.db_pool, at, &state.seasons, &state.rating)
+            .await
+            .unwrap()
+        {
+            SeasonRollover::RolledOver {
+                closed,
+                next,
+                standings,
+                rewards,
+            } => {
+                assert_eq!(closed.id, season.id);
+                assert!(standings >= 2);
+                assert!(rewards >= 1);
+                assert_eq!(next.starts_at, season.ends_at);
+                next
+            }
+            other => panic!("unexpected rollover: {:?}", other),
+        };
+    match db_operation::rollover_season(&state.db_pool, at, &state.seasons, &state.rating)
+        .await
+        .unwrap()
+    {
+        SeasonRollover::InProgress { season } => assert_eq!(season.id, next.id),
+        other => panic!("rollover is not idempotent: {:?}", other),
+    }
+
+    // 소프트 리셋: 1500 + (2100 - 1500) * 0.5, rd 는 reset_rd 까지, 티어 재계산 (Platinum)
+    let profile = db_operation::get_player_profile(&state.db_pool, steam_id as i64)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!((profile.mmr - 1800.0).abs() < 1e-9, "{:?}", profile);
+    assert_eq!(profile.rd, 200.0);
+    assert_eq!(profile.tier_id, 4);
+    // Diamond 보상은 두 번 롤오버해도 한 번만 지급
+    assert_eq!(profile.experience_points, 500);
+
+    // 레이팅이 그대로면 last_rating_update_at 도 유지 (rd 증가 기준이 당겨지지 않음)
+    let steady = db_operation::get_player_profile(&state.db_pool, steady_id as i64)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(steady.mmr, 1500.0);
+    assert_eq!(
+        steady.last_rating_update_at.timestamp_micros(),
+        steady_updated_at.timestamp_micros()
+    );
+
+    // 지난 시즌 기록
+    let req = test::TestRequest::get()
+        .uri("/players/me/seasons")
+        .insert_header(("Authorization", bearer.clone()))
+        .to_request();
+    let history: Value = test::call_and_read_body_json(&app, req).await;
+    let mine = &history["seasons"][0];
+    assert_eq!(mine["season_id"], season.id, "{}", history);
+    assert_eq!(mine["final_mmr"], 2100.0);
+    assert_eq!(mine["tier_name"], "Diamond");
+    assert_eq!(mine["reward_experience_points"], 500);
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/seasons/{}/standings?page_size=100", season.id))
+        .to_request();
+    let standings: Value = test::call_and_read_body_json(&app, req).await;
+    assert!(standings["total"].as_i64().unwrap() >= 1);
+    let rank = mine["rank"].as_i64().unwrap();
+    assert!(rank >= 1 && rank <= standings["total"].as_i64().unwrap());
+
+    // 진행 중인 시즌은 순위가 없음
+    let req = test::TestRequest::get()
+        .uri(&format!("/seasons/{}/standings", next.id))
+        .to_request();
+    assert_eq!(
+        test::call_service(&app, req).await.status(),
+        StatusCode::CONFLICT
+    );
+
+    let req = test::TestRequest::get().uri("/seasons").to_request();
+    let seasons: Value = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(seasons["current"]["id"], next.id);
+
+    for player in [steam_id, steady_id] {
+        db_operation::delete_player_by_id(&state.db_pool, player as i64)
+            .await
+            .unwrap();
+    }
+}
+
+#[actix_web::test]
+async fn test_leaderboards_rank_mmr_and_run_results() {
+    let Some(state) = test_state().await else {
+        return;
+    };
+    let app = init_app!(state);
+    let (winner, loser) = (random_steam_id(), random_steam_id());
+
+    let mut bearers = Vec::new();
+    for steam_id in [winner, loser] {
+        let (status, body) = steam_login(&app, &ticket(steam_id)).await;
+        assert_eq!(status, StatusCode::OK);
+        bearers.push(format!("Bearer {}", body["token"].as_str().unwrap()));
+    }
+
+    // 랭크 매치 1판
+    let ended_at = Utc::now();
+    let req = test::TestRequest::post()
+        .uri("/internal/matches")
+        .insert_header(("X-Service-Token", SERVICE_TOKEN))
+        .set_json(json!({
+            "game_mode_id": 1,
+            "winning_team_id": 1,
+            "started_at": ended_at - Duration::seconds(600),
+            "ended_at": ended_at,
+            "participants": [
+                { "player_id": winner, "team_id": 1, "is_winner": true },
+                { "player_id": loser, "team_id": 2, "is_winner": false }
+            ]
+        }))
+        .to_request();
+    assert_eq!(
+        test::call_service(&app, req).await.status(),
+        StatusCode::CREATED
+    );
+
+    // 런 요약 제출 (런 시간은 매치 시간과 무관하게 game_server 가 잰 값)
+    let submit_run = |player_id: u64, run_seed: u64, summary: Value| {
+        let mut summary = summary;
+        summary["run_seed"] = json!(run_seed);
+        test::TestRequest::post()
+            .uri("/internal/runs")
+            .insert_header(("X-Service-Token", SERVICE_TOKEN))
+            .set_json(json!({
+                "player_id": player_id,
+                "started_at": ended_at - Duration::seconds(3600),
+                "ended_at": ended_at,
+                "summary": summary,
+            }))
+            .to_request()
+    };
+    let cleared = |duration_seconds: u64| {
+        json!({
+            "end_reason": "Cleared", "reached_ordeal": "White", "reached_phase": "V",
+            "duration_seconds": duration_seconds
+        })
+    };
+    for (player_id, run_seed, summary) in [
+        (winner, 1, cleared(900)),
+        (winner, 2, cleared(754)),
+        (
+            loser,
+            1,
+            json!({
+                "end_reason": "OrdealLossLimit", "reached_ordeal": "Noon", "reached_phase": "III",
+                "duration_seconds": 300
+            }),
+        ),
+    ] {
+        let res = test::call_service(&app, submit_run(player_id, run_seed, summary)).await;
+        assert_eq!(res.status(), StatusCode::CREATED);
+    }
+    // 같은 런을 다시 제출하면 무시
+    let res = test::call_service(&app, submit_run(winner, 2, cleared(10))).await;
+    assert_eq!(res.status(), StatusCode::OK);
+    // 런 시간이 없는 요약 / 없는 플레이어
+    let mut missing = cleared(0);
+    missing.as_object_mut().unwrap().remove("duration_seconds");
+    let res = test::call_service(&app, submit_run(winner, 3, missing)).await;
+    assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+    let res = test::call_service(&app, submit_run(random_steam_id(), 1, cleared(1))).await;
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+    let get = |uri: String, bearer: Option<&String>| {
+        let mut req = test::TestRequest::get().uri(&uri);
+        if let Some(bearer) = bearer {
+            req = req.insert_header(("Authorization", bearer.clone()));
+        }
+        req.to_request()
+    };
+    let find = |board: &Value, steam_id: u64| {
+        let steam_id = steam_id.to_string();
+        board["entries"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|e| e["steam_id"].as_str() == Some(steam_id.as_str()))
+            .cloned()
+            .unwrap_or_else(|| panic!("{} is not on {}", steam_id, board))
+    };
+
+    // 전체 mmr 보드: 승자가 더 높은 순위
+    let board: Value =
+        test::call_and_read_body_json(&app, get("/leaderboards/mmr?page_size=100".into(), None))
+            .await;
+    let (first, second) = (find(&board, winner), find(&board, loser));
+    assert!(
+        first["rank"].as_i64() < second["rank"].as_i64(),
+        "{}",
+        board
+    );
+    assert!(first["mmr"].as_f64().unwrap() > 1500.0);
+
+    // around-me 는 같은 순위
+    let me: Value = test::call_and_read_body_json(
+        &app,
+        get("/leaderboards/mmr/me?radius=1".into(), Some(&bearers[0])),
+    )
+    .await;
+    assert_eq!(me["rank"], first["rank"], "{}", me);
+    assert!(me["entries"].as_array().unwrap().len() <= 3);
+
+    // 티어별 보드
+    let tier_board: Value = test::call_and_read_body_json(
+        &app,
+        get(
+            format!(
+                "/leaderboards/mmr?tier_id={}&page_size=100",
+                first["tier_id"]
+            ),
+            None,
+        ),
+    )
+    .await;
+    assert_eq!(find(&tier_board, winner)["tier_id"], first["tier_id"]);
+
+    // 런 기록 보드
+    let depth: Value = test::call_and_read_body_json(
+        &app,
+        get("/leaderboards/deepest_ordeal?page_size=100".into(), None),
+    )
+    .await;
+    assert_eq!(find(&depth, winner)["reached_ordeal"], "White");
+    assert_eq!(find(&depth, loser)["reached_phase"], "III");
+
+    let fastest: Value = test::call_and_read_body_json(
+        &app,
+        get("/leaderboards/fastest_clear/me".into(), Some(&bearers[0])),
+    )
+    .await;
+    assert_eq!(find(&fastest, winner)["clear_seconds"], 754, "{}", fastest);
+    // 클리어 기록이 없으면 404
+    let res = test::call_service(
+        &app,
+        get("/leaderboards/fastest_clear/me".into(), Some(&bearers[1])),
+    )
+    .await;
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+    // 잘못된 보드 / 티어
+    for (uri, expected) in [
+        (
+            "/leaderboards/deepest_ordeal?tier_id=1",
+            StatusCode::BAD_REQUEST,
+        ),
+        ("/leaderboards/mmr?tier_id=999", StatusCode::NOT_FOUND),
+        ("/leaderboards/unknown", StatusCode::NOT_FOUND),
+    ] {
+        let res = test::call_service(&app, get(uri.into(), None)).await;
+        assert_eq!(res.status(), expected, "{}", uri);
+    }
+
+    for steam_id in [winner, loser] {
+        db_operation::delete_player_by_id(&state.db_pool, steam_id as i64)
+            .await
+            .unwrap();
+    }
+}
@@ -1,11 +1,19 @@
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::cell::RefCell;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+use crate::game::enums::{OrdealType, RewardEventKind, RewardOutcome, RiskLevel};
 
 /// 게임 밸런스 설정 전체
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameBalanceConfig {
     pub qliphoth: QliphothConfig,
+    #[serde(default = "RewardConfig::default")]
+    pub reward: RewardConfig,
+    #[serde(default)]
+    pub run: RunConfig,
 }
 
 /// 클리포트 시스템 설정
@@ -82,18 +90,302 @@ pub struct QliphothRewardMultipliers {
     pub critical_breach: f32,
 }
 
+/// 전투 보상 설정
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RewardConfig {
+    pub streak: StreakBonusConfig,
+    pub tiers: Vec<RewardTier>,
+}
+
+/// (Ordeal, 전투 종류, 결과) 조합별 보상 티어
+///
+/// 테이블에 없는 조합은 보상/페널티 없음으로 처리됨
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RewardTier {
+    pub ordeal: OrdealType,
+    pub kind: RewardEventKind,
+    pub outcome: RewardOutcome,
+    /// 지급 엔케팔린 범위 (seed 기반으로 결정)
+    #[serde(default)]
+    pub enkephalin_min: u32,
+    #[serde(default)]
+    pub enkephalin_max: u32,
+    /// 차감 엔케팔린 (0 미만으로 내려가지 않음)
+    #[serde(default)]
+    pub enkephalin_penalty: u32,
+    /// 지급 장비 등급 (None 이면 지급 안 함)
+    #[serde(default)]
+    pub item_rarity: Option<RiskLevel>,
+    /// 장비 지급 확률 (0~100)
+    #[serde(default)]
+    pub item_chance: u32,
+    /// 보유 환상체 1마리 손실 확률 (0~100)
+    #[serde(default)]
+    pub abnormality_loss_chance: u32,
+}
+
+impl RewardTier {
+    /// 보상/페널티가 없는 빈 티어
+    pub fn empty(ordeal: OrdealType, kind: RewardEventKind, outcome: RewardOutcome) -> Self {
+        Self {
+            ordeal,
+            kind,
+            outcome,
+            enkephalin_min: 0,
+            enkephalin_max: 0,
+            enkephalin_penalty: 0,
+            item_rarity: None,
+            item_chance: 0,
+            abnormality_loss_chance: 0,
+        }
+    }
+
+    fn win(
+        ordeal: OrdealType,
+        kind: RewardEventKind,
+        (enkephalin_min, enkephalin_max): (u32, u32),
+        item_rarity: RiskLevel,
+        item_chance: u32,
+    ) -> Self {
+        Self {
+            enkephalin_min,
+            enkephalin_max,
+            item_rarity: Some(item_rarity),
+            item_chance,
+            ..Self::empty(ordeal, kind, RewardOutcome::Win)
+        }
+    }
+
+    fn loss(
+        ordeal: OrdealType,
+        kind: RewardEventKind,
+        enkephalin_penalty: u32,
+        abnormality_loss_chance: u32,
+    ) -> Self {
+        Self {
+            enkephalin_penalty,
+            abnormality_loss_chance,
+            ..Self::empty(ordeal, kind, RewardOutcome::Loss)
+        }
+    }
+}
+
+/// 연승/연패 보너스 설정
+///
+/// streak >= threshold 부터 (streak - threshold + 1) * per_streak 만큼 지급 (max 까지)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StreakBonusConfig {
+    pub win_streak_threshold: u32,
+    pub win_bonus_per_streak: u32,
+    pub win_bonus_max: u32,
+    pub loss_streak_threshold: u32,
+    pub loss_bonus_per_streak: u32,
+    pub loss_bonus_max: u32,
+}
+
+impl StreakBonusConfig {
+    /// 연승 보너스 엔케팔린
+    pub fn win_bonus(&self, streak: u32) -> u32 {
+        Self::bonus(
+            streak,
+            self.win_streak_threshold,
+            self.win_bonus_per_streak,
+            self.win_bonus_max,
+        )
+    }
+
+    /// 연패 보너스 엔케팔린 (역전용 보정)
+    pub fn loss_bonus(&self, streak: u32) -> u32 {
+        Self::bonus(
+            streak,
+            self.loss_streak_threshold,
+            self.loss_bonus_per_streak,
+            self.loss_bonus_max,
+        )
+    }
+
+    fn bonus(streak: u32, threshold: u32, per_streak: u32, max: u32) -> u32 {
+        if threshold == 0 || streak < threshold {
+            return 0;
+        }
+        (streak - threshold + 1).saturating_mul(per_streak).min(max)
+    }
+}
+
+impl RewardConfig {
+    /// (Ordeal, 전투 종류, 결과) 에 해당하는 티어 조회
+    pub fn tier(
+        &self,
+        ordeal: OrdealType,
+        kind: RewardEventKind,
+        outcome: RewardOutcome,
+    ) -> Option<&RewardTier> {
+        self.tiers
+            .iter()
+            .find(|t| t.ordeal == ordeal && t.kind == kind && t.outcome == outcome)
+    }
+
+    /// 설정값이 유효한지 검증
+    pub fn validate(&self) -> Result<(), String> {
+        for (index, tier) in self.tiers.iter().enumerate() {
+            let key = format!("{:?}/{:?}/{:?}", tier.ordeal, tier.kind, tier.outcome);
+            if tier.enkephalin_min > tier.enkephalin_max {
+                return Err(format!(
+                    "Invalid reward tier {}: enkephalin_min {} > enkephalin_max {}",
+                    key, tier.enkephalin_min, tier.enkephalin_max
+                ));
+            }
+            if tier.item_chance > 100 || tier.abnormality_loss_chance > 100 {
+                return Err(format!(
+                    "Invalid reward tier {}: chances must be 0~100",
+                    key
+                ));
+            }
+            if self.tiers[..index].iter().any(|other| {
+                other.ordeal == tier.ordeal
+                    && other.kind == tier.kind
+                    && other.outcome == tier.outcome
+            }) {
+                return Err(format!("Duplicated reward tier {}", key));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for RewardConfig {
+    /// 기본 보상 테이블 (GAME_DESIGN.md 보상 시스템 기준)
+    fn default() -> Self {
+        use OrdealType::*;
+        use RewardEventKind::*;
+        use RiskLevel::*;
+
+        Self {
+            streak: StreakBonusConfig {
+                win_streak_threshold: 2,
+                win_bonus_per_streak: 100,
+                win_bonus_max: 500,
+                loss_streak_threshold: 2,
+                loss_bonus_per_streak: 150,
+                loss_bonus_max: 600,
+            },
+            tiers: vec![
+                // 시련 전투
+                RewardTier::win(Dawn, Ordeal, (500, 1000), TETH, 30),
+                RewardTier::win(Noon, Ordeal, (1000, 2000), HE, 50),
+                RewardTier::win(Dusk, Ordeal, (2500, 4000), WAW, 100),
+                RewardTier::win(Midnight, Ordeal, (4000, 6000), ALEPH, 100),
+                RewardTier::win(White, Ordeal, (8000, 12000), ALEPH, 100),
+                RewardTier::loss(Dawn, Ordeal, 0, 0),
+                RewardTier::loss(Noon, Ordeal, 0, 0),
+                RewardTier::loss(Dusk, Ordeal, 500, 0),
+                RewardTier::loss(Midnight, Ordeal, 1000, 50),
+                RewardTier::loss(White, Ordeal, 1000, 50),
+                // 진압 작업
+                RewardTier::win(Dawn, Suppression, (200, 400), ZAYIN, 20),
+                RewardTier::win(Noon, Suppression, (400, 700), TETH, 20),
+                RewardTier::win(Dusk, Suppression, (800, 1200), HE, 30),
+                RewardTier::win(Midnight, Suppression, (1200, 1800), WAW, 30),
+                RewardTier::win(White, Suppression, (2000, 3000), ALEPH, 30),
+                RewardTier::loss(Dawn, Suppression, 0, 0),
+                RewardTier::loss(Noon, Suppression, 0, 0),
+                RewardTier::loss(Dusk, Suppression, 200, 0),
+                RewardTier::loss(Midnight, Suppression, 400, 0),
+                RewardTier::loss(White, Suppression, 400, 0),
+                // 백야 (하이리스크 하이리턴)
+                RewardTier::win(Dawn, WhiteNights, (1000, 1500), HE, 100),
+                RewardTier::win(Noon, WhiteNights, (2000, 3000), WAW, 100),
+                RewardTier::win(Dusk, WhiteNights, (4000, 6000), WAW, 100),
+                RewardTier::win(Midnight, WhiteNights, (6000, 9000), ALEPH, 100),
+                RewardTier::win(White, WhiteNights, (10000, 15000), ALEPH, 100),
+                RewardTier::loss(Dawn, WhiteNights, 300, 30),
+                RewardTier::loss(Noon, WhiteNights, 500, 30),
+                RewardTier::loss(Dusk, WhiteNights, 1000, 50),
+                RewardTier::loss(Midnight, WhiteNights, 1500, 50),
+                RewardTier::loss(White, WhiteNights, 2000, 100),
+            ],
+        }
+    }
+}
+
+/// 런 종료 조건 설정
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunConfig {
+    /// 이 횟수만큼 시련 전투에서 패배하면 런 종료
+    pub max_ordeal_losses: u32,
+}
+
+impl Default for RunConfig {
+    fn default() -> Self {
+        Self {
+            max_ordeal_losses: 3,
+        }
+    }
+}
+
 /// 전역 게임 밸런스 설정 인스턴스
-static GAME_BALANCE: Lazy<GameBalanceConfig> = Lazy::new(|| {
-    GameBalanceConfig::load().unwrap_or_else(|e| {
+///
+/// 핫 리로드 시 `set_global` 로 통째로 교체된다. 진행 중인 런은
+/// `pin` 으로 자신이 시작할 때의 설정을 고정해서 사용한다.
+static GAME_BALANCE: Lazy<RwLock<Arc<GameBalanceConfig>>> = Lazy::new(|| {
+    let config = GameBalanceConfig::load().unwrap_or_else(|e| {
         eprintln!("Failed to load game balance config: {}. Using defaults.", e);
         GameBalanceConfig::default()
-    })
+    });
+    RwLock::new(Arc::new(config))
 });
 
+thread_local! {
+    /// 현재 스레드에 고정된 밸런스 설정 (GameCore 처리 중에만 설정됨)
+    static PINNED_BALANCE: RefCell<Option<Arc<GameBalanceConfig>>> = const { RefCell::new(None) };
+}
+
+/// `GameBalanceConfig::pin` 가드
+///
+/// drop 되면 이전에 고정돼 있던 설정으로 복원한다.
+#[must_use]
+pub struct BalancePin {
+    previous: Option<Arc<GameBalanceConfig>>,
+}
+
+impl Drop for BalancePin {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        PINNED_BALANCE.with(|pinned| *pinned.borrow_mut() = previous);
+    }
+}
+
 impl GameBalanceConfig {
-    /// 전역 설정 인스턴스 가져오기
-    pub fn global() -> &'static GameBalanceConfig {
-        &GAME_BALANCE
+    /// 전역 설정 인스턴스 가져오기 (새로 시작하는 런이 사용할 설정)
+    pub fn global() -> Arc<GameBalanceConfig> {
+        GAME_BALANCE
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// 전역 설정 교체
+    ///
+    /// 이미 pin 된 런에는 영향이 없고, 이후 `global()` 호출부터 적용된다.
+    pub fn set_global(config: Arc<GameBalanceConfig>) {
+        *GAME_BALANCE.write().unwrap_or_else(|e| e.into_inner()) = config;
+    }
+
+    /// 현재 적용 중인 설정 가져오기
+    ///
+    /// 현재 스레드에 고정된 설정이 있으면 그것을, 없으면 전역 설정을 반환
+    pub fn current() -> Arc<GameBalanceConfig> {
+        PINNED_BALANCE
+            .with(|pinned| pinned.borrow().clone())
+            .unwrap_or_else(Self::global)
+    }
+
+    /// 현재 스레드에 설정 고정
+    ///
+    /// 반환된 가드가 살아있는 동안 `balance::*` 헬퍼는 이 설정을 읽는다.
+    pub fn pin(config: Arc<GameBalanceConfig>) -> BalancePin {
+        let previous = PINNED_BALANCE.with(|pinned| pinned.borrow_mut().replace(config));
+        BalancePin { previous }
     }
 
     /// 설정 파일 로드
@@ -101,21 +393,33 @@ impl GameBalanceConfig {
         // 1. 설정 파일 경로 찾기
         let config_path = Self::find_config_file()?;
 
-        // 2. TOML 파일 읽기
-        let config_content = std::fs::read_to_string(&config_path)?;
+        // 2. 파일 로드 및 검증
+        Self::load_from(config_path)
+    }
 
-        // 3. TOML 파싱
+    /// 지정한 경로의 설정 파일 로드
+    pub fn load_from(config_path: impl AsRef<Path>) -> Result<Self, Box<dyn std::error::Error>> {
+        let config_path = config_path.as_ref();
+
+        // 1. TOML 파일 읽기
+        let config_content = std::fs::read_to_string(config_path)?;
+
+        // 2. TOML 파싱
         let config: GameBalanceConfig = toml::from_str(&config_content)?;
 
-        // 4. 설정값 검증
+        // 3. 설정값 검증
         config.qliphoth.suppress_chance.validate()?;
+        config.reward.validate()?;
+        if config.run.max_ordeal_losses == 0 {
+            return Err("run.max_ordeal_losses must be at least 1".into());
+        }
 
         tracing::info!("Game balance config loaded from: {:?}", config_path);
         Ok(config)
     }
 
     /// 설정 파일 위치 찾기
-    fn find_config_file() -> Result<PathBuf, Box<dyn std::error::Error>> {
+    pub fn find_config_file() -> Result<PathBuf, Box<dyn std::error::Error>> {
         // 1. 현재 작업 디렉토리에서 찾기
         let cwd = std::env::current_dir()?;
         let cwd_config = cwd.join("core/config/game_balance.toml");
@@ -171,37 +475,79 @@ impl GameBalanceConfig {
                     critical_breach: 2.5,
                 },
             },
+            reward: RewardConfig::default(),
+            run: RunConfig::default(),
         }
     }
 }
 
 /// 편의 헬퍼 함수들
+///
+/// 현재 스레드에 고정된 설정(없으면 전역 설정)의 일부를 복사 없이 빌려준다.
 pub mod balance {
+    use std::{ops::Deref, sync::Arc};
+
     use super::GameBalanceConfig;
 
+    /// 설정 일부에 대한 참조 (설정 Arc 를 함께 들고 있어 핫 리로드 중에도 유효)
+    pub struct BalanceRef<T: 'static> {
+        config: Arc<GameBalanceConfig>,
+        project: fn(&GameBalanceConfig) -> &T,
+    }
+
+    impl<T> Deref for BalanceRef<T> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            (self.project)(&self.config)
+        }
+    }
+
+    fn current<T>(project: fn(&GameBalanceConfig) -> &T) -> BalanceRef<T> {
+        BalanceRef {
+            config: GameBalanceConfig::current(),
+            project,
+        }
+    }
+
     /// 클리포트 설정 가져오기
-    pub fn qliphoth() -> &'static super::QliphothConfig {
-        &GameBalanceConfig::global().qliphoth
+    pub fn qliphoth() -> BalanceRef<super::QliphothConfig> {
+        current(|config| &config.qliphoth)
     }
 
     /// 클리포트 임계값 가져오기
-    pub fn qliphoth_thresholds() -> &'static super::QliphothThresholds {
-        &qliphoth().thresholds
+    pub fn qliphoth_thresholds() -> BalanceRef<super::QliphothThresholds> {
+        current(|config| &config.qliphoth.thresholds)
     }
 
     /// 클리포트 변화량 가져오기
-    pub fn qliphoth_changes() -> &'static super::QliphothChanges {
-        &qliphoth().changes
+    pub fn qliphoth_changes() -> BalanceRef<super::QliphothChanges> {
+        current(|config| &config.qliphoth.changes)
     }
 
     /// 클리포트 진압 확률 가져오기
-    pub fn qliphoth_suppress_chance() -> &'static super::QliphothSuppressChance {
-        &qliphoth().suppress_chance
+    pub fn qliphoth_suppress_chance() -> BalanceRef<super::QliphothSuppressChance> {
+        current(|config| &config.qliphoth.suppress_chance)
     }
 
     /// 클리포트 보상 배율 가져오기
-    pub fn qliphoth_reward_multipliers() -> &'static super::QliphothRewardMultipliers {
-        &qliphoth().reward_multipliers
+    pub fn qliphoth_reward_multipliers() -> BalanceRef<super::QliphothRewardMultipliers> {
+        current(|config| &config.qliphoth.reward_multipliers)
+    }
+
+    /// 전투 보상 설정 가져오기
+    pub fn reward() -> BalanceRef<super::RewardConfig> {
+        current(|config| &config.reward)
+    }
+
+    /// 연승/연패 보너스 설정 가져오기
+    pub fn reward_streak() -> BalanceRef<super::StreakBonusConfig> {
+        current(|config| &config.reward.streak)
+    }
+
+    /// 런 종료 조건 설정 가져오기
+    pub fn run() -> BalanceRef<super::RunConfig> {
+        current(|config| &config.run)
     }
 }
 
@@ -312,6 +658,84 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_default_reward_table_is_valid() {
+        let reward = RewardConfig::default();
+        assert!(reward.validate().is_ok());
+
+        // Then: 모든 (Ordeal, 종류, 결과) 조합이 정의되어 있어야 함
+        assert_eq!(reward.tiers.len(), 30);
+
+        // Then: GAME_DESIGN.md 기준 어스름 시련 패배 페널티 500
+        let dusk_loss = reward
+            .tier(
+                OrdealType::Dusk,
+                RewardEventKind::Ordeal,
+                RewardOutcome::Loss,
+            )
+            .unwrap();
+        assert_eq!(dusk_loss.enkephalin_penalty, 500);
+    }
+
+    #[test]
+    fn test_reward_validation_rejects_invalid_tiers() {
+        let mut reward = RewardConfig::default();
+        reward.tiers[0].enkephalin_min = reward.tiers[0].enkephalin_max + 1;
+        assert!(reward.validate().is_err());
+
+        let mut reward = RewardConfig::default();
+        reward.tiers[0].item_chance = 101;
+        assert!(reward.validate().is_err());
+
+        let mut reward = RewardConfig::default();
+        let duplicated = reward.tiers[0].clone();
+        reward.tiers.push(duplicated);
+        assert!(reward.validate().is_err());
+    }
+
+    #[test]
+    fn test_streak_bonus() {
+        let streak = RewardConfig::default().streak;
+
+        // Then: threshold(2) 미만은 보너스 없음
+        assert_eq!(streak.win_bonus(1), 0);
+        assert_eq!(streak.win_bonus(2), 100);
+        assert_eq!(streak.win_bonus(3), 200);
+        // Then: 최대값 제한
+        assert_eq!(streak.win_bonus(100), 500);
+
+        assert_eq!(streak.loss_bonus(1), 0);
+        assert_eq!(streak.loss_bonus(2), 150);
+        assert_eq!(streak.loss_bonus(100), 600);
+    }
+
+    #[test]
+    fn test_bundled_config_matches_defaults() {
+        let config: GameBalanceConfig =
+            toml::from_str(include_str!("../config/game_balance.toml")).unwrap();
+        assert!(config.reward.validate().is_ok());
+
+        let defaults = RewardConfig::default();
+        assert_eq!(config.reward.tiers.len(), defaults.tiers.len());
+        for tier in &defaults.tiers {
+            let loaded = config
+                .reward
+                .tier(tier.ordeal, tier.kind, tier.outcome)
+                .unwrap();
+            assert_eq!(loaded.enkephalin_min, tier.enkephalin_min);
+            assert_eq!(loaded.enkephalin_max, tier.enkephalin_max);
+            assert_eq!(loaded.enkephalin_penalty, tier.enkephalin_penalty);
+            assert_eq!(loaded.item_rarity, tier.item_rarity);
+            assert_eq!(loaded.item_chance, tier.item_chance);
+            assert_eq!(loaded.abnormality_loss_chance, tier.abnormality_loss_chance);
+        }
+
+        assert_eq!(
+            config.run.max_ordeal_losses,
+            RunConfig::default().max_ordeal_losses
+        );
+    }
+
     #[test]
     fn test_toml_serialization() {
         let config = GameBalanceConfig::default();
@@ -10,7 +10,7 @@ use crate::game::{
         equipment_data::EquipmentItem, Item,
     },
     enums::RiskLevel,
-    growth::GrowthStack,
+    growth::{GrowthId, GrowthStack},
 };
 use crate::ecs::resources::item_slot::ItemSlot;
 
@@ -53,6 +53,9 @@ pub struct AbnormalityItemDto {
     pub name: String,
     pub risk_level: RiskLevel,
     pub price: u32,
+    /// 성장 스택(이벤트 체력 증감 등)이 반영된 최대 체력
+    pub max_health: u32,
+    pub growth_stacks: GrowthStack,
 }
 
 impl AbnormalityItemDto {
@@ -63,6 +66,17 @@ impl AbnormalityItemDto {
             name: meta.name.clone(),
             risk_level: meta.risk_level,
             price: meta.price,
+            max_health: meta.max_health,
+            growth_stacks: GrowthStack::new(),
+        }
+    }
+
+    /// 보유 중인 환상체의 현재 상태로 생성
+    pub fn from_owned(owned: &OwnedAbnormality) -> Self {
+        Self {
+            max_health: owned.max_health(),
+            growth_stacks: owned.growth_stacks.clone(),
+            ..Self::from_metadata(&owned.meta)
         }
     }
 }
@@ -320,6 +334,10 @@ impl AbnormalityInventory {
     pub fn iter_owned(&self) -> impl Iterator<Item = &OwnedAbnormality> {
         self.items.values()
     }
+
+    pub fn iter_owned_mut(&mut self) -> impl Iterator<Item = &mut OwnedAbnormality> {
+        self.items.values_mut()
+    }
 
     pub fn len(&self) -> usize {
         self.items.len()
@@ -349,6 +367,12 @@ impl OwnedAbnormality {
             item_slot: ItemSlot::default(),
         }
     }
+
+    /// EventHealthStack 이 반영된 최대 체력
+    pub fn max_health(&self) -> u32 {
+        let stack = self.growth_stacks.get(GrowthId::EventHealthStack);
+        (self.meta.max_health as i64 + stack as i64).max(0) as u32
+    }
 }
 
 // ============================================================
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use bevy_ecs::resource::Resource;
+use bevy_ecs::world::World;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -8,7 +9,10 @@ use crate::game::behavior::{GameError, PlayerBehavior};
 use crate::game::data::bonus_data::BonusMetadata;
 use crate::game::data::random_event_data::RandomEventMetadata;
 use crate::game::data::shop_data::ShopMetadata;
-use crate::game::enums::{GameOption, OrdealType, PhaseType, Side};
+use crate::game::enums::{
+    EnkephalinFlow, GameOption, OrdealType, PhaseEventKind, PhaseType, RewardEventKind,
+    RewardOutcome, RunEndReason, Side,
+};
 
 pub mod inventory;
 pub mod item_slot;
@@ -18,7 +22,7 @@ pub use inventory::*;
 ///
 /// 현재 게임이 어떤 단계에 있는지 명확하게 표현
 /// ActionScheduler가 이 상태를 보고 allowed_actions를 결정함
-#[derive(Resource, Debug, Clone, PartialEq)]
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum GameState {
     /// 게임 시작 전
     NotStarted,
@@ -32,6 +36,10 @@ pub enum GameState {
     InBonus { bonus_uuid: Uuid },
     /// 보너스 수령 완료 (Exit만 가능)
     InBonusClaimed { bonus_uuid: Uuid },
+    /// 선택지형 랜덤 이벤트 진행 중
+    InRandomEvent { event_uuid: Uuid },
+    /// 선택지형 랜덤 이벤트 종료 (Exit만 가능)
+    InRandomEventFinished { event_uuid: Uuid },
     /// 진압 작업 진행 중
     InSuppression { abnormality_uuid: Uuid },
     /// 시련 전투 진행 중
@@ -269,7 +277,7 @@ impl Level {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum QliphothLevel {
     Stable,   // 안정 (10-8) - 정상 운영
     Caution,  // 주의 (7-5) - 경보 발령
@@ -376,6 +384,133 @@ impl WinCount {
     }
 }
 
+/// 연승/연패 기록 (보상 계산용)
+#[derive(Resource, Debug, Clone, Default)]
+pub struct BattleStreak {
+    pub wins: u32,
+    pub losses: u32,
+}
+
+impl BattleStreak {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 승리 기록 (연패 초기화). 갱신된 연승 수 반환
+    pub fn record_win(&mut self) -> u32 {
+        self.wins += 1;
+        self.losses = 0;
+        self.wins
+    }
+
+    /// 패배 기록 (연승 초기화). 갱신된 연패 수 반환
+    pub fn record_loss(&mut self) -> u32 {
+        self.losses += 1;
+        self.wins = 0;
+        self.losses
+    }
+}
+
+/// Phase 단위 진행 기록
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PhaseResult {
+    pub ordeal: OrdealType,
+    pub phase: PhaseType,
+    /// 해당 Phase 에서 선택한 이벤트 (없으면 None)
+    pub event: Option<PhaseEventKind>,
+    /// Phase 종료 시점 엔케팔린
+    pub enkephalin: u32,
+    /// Phase 종료 시점 클리포트
+    pub qliphoth: u32,
+}
+
+/// 전투 단위 기록
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BattleRecord {
+    pub ordeal: OrdealType,
+    pub phase: PhaseType,
+    pub kind: RewardEventKind,
+    pub outcome: RewardOutcome,
+    pub enkephalin_gained: u32,
+    pub enkephalin_lost: u32,
+}
+
+/// 엔케팔린 증감 1건 (게임 서버가 take_enkephalin_changes 로 가져가 메트릭에 반영)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnkephalinChange {
+    Earned(EnkephalinFlow, u32),
+    Spent(EnkephalinFlow, u32),
+}
+
+/// 런 전체 진행 기록 (RunSummary 생성용)
+#[derive(Resource, Debug, Clone, Default)]
+pub struct RunRecord {
+    pub phase_results: Vec<PhaseResult>,
+    pub battles: Vec<BattleRecord>,
+    /// 런 동안 획득한 엔케팔린 총량
+    pub enkephalin_earned: u64,
+    /// 런 동안 소비/손실한 엔케팔린 총량
+    pub enkephalin_spent: u64,
+    /// 시련 전투 패배 횟수
+    pub ordeal_losses: u32,
+    /// 런 종료 사유 (진행 중이면 None)
+    pub end_reason: Option<RunEndReason>,
+    /// 런 시작 시 고정된 데이터 버전 ID
+    pub data_version: u64,
+    /// 아직 가져가지 않은 엔케팔린 증감 (출처별)
+    pub enkephalin_changes: Vec<EnkephalinChange>,
+}
+
+impl RunRecord {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_data_version(data_version: u64) -> Self {
+        Self {
+            data_version,
+            ..Self::default()
+        }
+    }
+
+    /// 엔케팔린 획득 기록 (획득 지점에서 호출, RunRecord 가 없는 World 면 무시)
+    pub fn track_gain(world: &mut World, flow: EnkephalinFlow, amount: u32) {
+        if let Some(mut record) = world.get_resource_mut::<RunRecord>() {
+            record.enkephalin_earned += u64::from(amount);
+            if amount > 0 {
+                record
+                    .enkephalin_changes
+                    .push(EnkephalinChange::Earned(flow, amount));
+            }
+        }
+    }
+
+    /// 엔케팔린 소비/손실 기록 (소비 지점에서 호출, RunRecord 가 없는 World 면 무시)
+    pub fn track_spend(world: &mut World, flow: EnkephalinFlow, amount: u32) {
+        if let Some(mut record) = world.get_resource_mut::<RunRecord>() {
+            record.enkephalin_spent += u64::from(amount);
+            if amount > 0 {
+                record
+                    .enkephalin_changes
+                    .push(EnkephalinChange::Spent(flow, amount));
+            }
+        }
+    }
+
+    /// 전투 기록 추가. 시련 패배면 패배 횟수를 갱신해 반환
+    pub fn record_battle(&mut self, record: BattleRecord) -> u32 {
+        if record.kind == RewardEventKind::Ordeal && record.outcome == RewardOutcome::Loss {
+            self.ordeal_losses += 1;
+        }
+        self.battles.push(record);
+        self.ordeal_losses
+    }
+
+    pub fn is_ended(&self) -> bool {
+        self.end_reason.is_some()
+    }
+}
+
 #[derive(Resource)]
 pub struct SelectedEvent {
     pub event: GameOption,
@@ -426,6 +561,26 @@ impl SelectedEvent {
     }
 }
 
+/// 선택지형 랜덤 이벤트 진행 상황
+///
+/// `step` 은 지금까지 처리한 선택 횟수이며, 확률 분기 seed 계산에 사용됨
+#[derive(Resource, Debug, Clone)]
+pub struct RandomEventSession {
+    pub story_uuid: Uuid,
+    pub current_node: String,
+    pub step: u32,
+}
+
+impl RandomEventSession {
+    pub fn new(story_uuid: Uuid, start_node: String) -> Self {
+        Self {
+            story_uuid,
+            current_node: start_node,
+            step: 0,
+        }
+    }
+}
+
 #[derive(Resource, Default)]
 pub struct CurrentPhaseEvents {
     pub events: HashMap<Uuid, GameOption>,
@@ -601,6 +756,66 @@ mod tests {
         assert_eq!(win_count.count, 5);
     }
 
+    #[test]
+    fn test_battle_streak_resets_on_opposite_result() {
+        let mut streak = BattleStreak::new();
+
+        assert_eq!(streak.record_win(), 1);
+        assert_eq!(streak.record_win(), 2);
+        assert_eq!(streak.record_loss(), 1);
+        assert_eq!(streak.wins, 0);
+        assert_eq!(streak.record_win(), 1);
+        assert_eq!(streak.losses, 0);
+    }
+
+    #[test]
+    fn test_run_record_tracks_enkephalin_and_ordeal_losses() {
+        let mut world = World::new();
+        world.insert_resource(RunRecord::new());
+
+        RunRecord::track_gain(&mut world, EnkephalinFlow::Battle, 250);
+        RunRecord::track_spend(&mut world, EnkephalinFlow::ShopPurchase, 300);
+        RunRecord::track_gain(&mut world, EnkephalinFlow::ShopSell, 0);
+        let mut record = world.remove_resource::<RunRecord>().unwrap();
+        assert_eq!(record.enkephalin_earned, 250);
+        assert_eq!(record.enkephalin_spent, 300);
+        // 0 은 증감으로 남기지 않음
+        assert_eq!(
+            record.enkephalin_changes,
+            vec![
+                EnkephalinChange::Earned(EnkephalinFlow::Battle, 250),
+                EnkephalinChange::Spent(EnkephalinFlow::ShopPurchase, 300),
+            ]
+        );
+
+        // Then: RunRecord 가 없는 World 에서는 무시됨
+        RunRecord::track_gain(&mut world, EnkephalinFlow::Bonus, 10);
+
+        let battle = |kind, outcome| BattleRecord {
+            ordeal: OrdealType::Dawn,
+            phase: PhaseType::I,
+            kind,
+            outcome,
+            enkephalin_gained: 0,
+            enkephalin_lost: 0,
+        };
+
+        // Then: 진압 패배는 시련 패배 횟수에 포함되지 않음
+        assert_eq!(
+            record.record_battle(battle(RewardEventKind::Suppression, RewardOutcome::Loss)),
+            0
+        );
+        assert_eq!(
+            record.record_battle(battle(RewardEventKind::Ordeal, RewardOutcome::Loss)),
+            1
+        );
+        assert_eq!(
+            record.record_battle(battle(RewardEventKind::Ordeal, RewardOutcome::Win)),
+            1
+        );
+        assert_eq!(record.battles.len(), 3);
+    }
+
     // ============================================================
     // CurrentPhaseEvents Tests
     // ============================================================
@@ -34,7 +34,6 @@ struct RuntimeArtifact {
 #[derive(Debug, Clone)]
 struct RuntimeItem {
     instance_id: Uuid,
-    owner: Side,
     owner_unit_instance: Uuid,
     base_uuid: Uuid,
 }
@@ -92,6 +91,9 @@ pub struct BattleCore {
 
     game_data: Arc<GameDataBase>,
 
+    /// 인스턴스 ID 생성 seed (같은 seed 면 같은 타임라인)
+    seed: u64,
+
     pub timeline: Timeline,
     pub timeline_seq: u64,
     pub recording_cause_stack: Vec<TimelineCause>,
@@ -115,12 +117,25 @@ impl BattleCore {
             buffs: HashMap::new(),
             runtime_field: Field::new(field_size.0, field_size.1),
             game_data,
+            seed: 0,
             timeline: Timeline::new(),
             timeline_seq: 0,
             recording_cause_stack: Vec::new(),
         }
     }
 
+    /// 전투 seed 지정
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// 타임라인에 데이터 버전 ID 기록
+    pub fn with_data_version(mut self, data_version: u64) -> Self {
+        self.timeline.data_version = data_version;
+        self
+    }
+
     fn can_gain_resonance(unit: &RuntimeUnit, now_ms: u64) -> bool {
         now_ms >= unit.resonance_gain_locked_until_ms && now_ms >= unit.next_action_time
     }
@@ -0,0 +1,1263 @@
+use uuid::Uuid;
+
+use crate::{
+    ecs::resources::Position,
+    game::{
+        ability::SkillEffectDef,
+        battle::{
+            buffs::BuffId,
+            core::{
+                ActiveBuff, BattleCore, BuffInstanceKey, RuntimeArtifact, RuntimeItem, RuntimeUnit,
+            },
+            enums::BattleEvent,
+            timeline::{
+                AttackKind, HpChangeReason, TimelineCause, TimelineEvent, TimelineRootCause,
+            },
+            types::{BattleResult, BattleWinner, PlayerDeckInfo, UnitSnapshot},
+        },
+        behavior::GameError,
+        determinism::uuid_v4_from_seed,
+        enums::Side,
+        stats::{Effect, TriggerType},
+    },
+};
+
+/// 전투 제한 시간. 초과하면 남은 체력 합으로 승패를 판정한다.
+pub const MAX_BATTLE_TIME_MS: u64 = 60_000;
+
+/// 기본 공격 1회당 공격자가 얻는 공명
+const RESONANCE_PER_ATTACK: u32 = 10;
+/// 피격 1회당 피격자가 얻는 공명
+const RESONANCE_PER_HIT: u32 = 5;
+
+// 인스턴스 ID 생성용 네임스페이스 (같은 seed 라도 종류별로 독립적인 스트림)
+const INSTANCE_NAMESPACE_ARTIFACT: u64 = 0x4152_5446; // "ARTF"
+const INSTANCE_NAMESPACE_UNIT: u64 = 0x554E_4954; // "UNIT"
+const INSTANCE_NAMESPACE_ITEM: u64 = 0x4954_454D; // "ITEM"
+
+impl BattleCore {
+    /// 전투 실행
+    ///
+    /// 양측 덱을 배치한 뒤 이벤트 큐가 빌 때까지(또는 한쪽이 전멸하거나 제한 시간에
+    /// 도달할 때까지) 이벤트를 처리한다. 같은 덱/데이터/seed 라면 항상 같은 타임라인을 만든다.
+    pub fn run_battle(&mut self) -> Result<BattleResult, GameError> {
+        self.with_recording_root(TimelineRootCause::Init, |battle| battle.spawn_all())?;
+        self.with_recording_root(TimelineRootCause::Period, |battle| {
+            battle.schedule_initial_attacks()
+        });
+
+        let mut now_ms = 0;
+        while self.living_sides() == (true, true) {
+            let Some(event) = self.event_queue.pop() else {
+                break;
+            };
+            if event.time_ms() > MAX_BATTLE_TIME_MS {
+                now_ms = MAX_BATTLE_TIME_MS;
+                break;
+            }
+
+            now_ms = event.time_ms();
+            let cause = Self::event_cause(&event);
+            self.with_recording_context(cause, |battle| {
+                battle.handle_event(event);
+                battle.schedule_pending_autocasts(now_ms);
+            });
+        }
+
+        let winner = self.judge_winner();
+        self.with_recording_root(TimelineRootCause::System, |battle| {
+            battle.record_timeline(now_ms, TimelineEvent::BattleEnd { winner })
+        });
+
+        Ok(BattleResult {
+            winner,
+            timeline: self.timeline.clone(),
+        })
+    }
+
+    // ============================================================
+    // 초기화
+    // ============================================================
+
+    fn spawn_all(&mut self) -> Result<(), GameError> {
+        self.record_timeline(
+            0,
+            TimelineEvent::BattleStart {
+                width: self.runtime_field.width,
+                height: self.runtime_field.height,
+            },
+        );
+
+        let mut spawn_index = 0;
+        let player = self.player_info.clone();
+        let opponent = self.opponent_info.clone();
+        self.spawn_side(Side::Player, &player, &mut spawn_index)?;
+        self.spawn_side(Side::Opponent, &opponent, &mut spawn_index)?;
+        Ok(())
+    }
+
+    fn spawn_side(
+        &mut self,
+        side: Side,
+        deck: &PlayerDeckInfo,
+        spawn_index: &mut u64,
+    ) -> Result<(), GameError> {
+        let artifact_uuids: Vec<Uuid> = deck.artifacts.iter().map(|a| a.base_uuid).collect();
+
+        for artifact in &deck.artifacts {
+            let instance_id = self.next_instance_id(INSTANCE_NAMESPACE_ARTIFACT, spawn_index);
+            self.artifacts.insert(
+                instance_id,
+                RuntimeArtifact {
+                    instance_id,
+                    owner: side,
+                    base_uuid: artifact.base_uuid,
+                },
+            );
+            self.record_timeline(
+                0,
+                TimelineEvent::ArtifactSpawned {
+                    artifact_instance_id: instance_id,
+                    owner: side,
+                    base_uuid: artifact.base_uuid,
+                },
+            );
+        }
+
+        for unit in &deck.units {
+            let stats = unit.effective_stats(&self.game_data, &artifact_uuids)?;
+            let origin = self
+                .game_data
+                .abnormality_data
+                .get_by_uuid(&unit.base_uuid)
+                .ok_or(GameError::MissingResource("AbnormalityMetadata"))?;
+            let resonance = origin.resonance.clone();
+
+            let instance_id = self.next_instance_id(INSTANCE_NAMESPACE_UNIT, spawn_index);
+            let preferred = deck.positions.get(&unit.base_uuid).copied();
+            let position = self.place_unit(instance_id, side, preferred)?;
+
+            self.units.insert(
+                instance_id,
+                RuntimeUnit {
+                    instance_id,
+                    owner: side,
+                    base_uuid: unit.base_uuid,
+                    stats,
+                    position,
+                    current_target: None,
+                    resonance_current: resonance.start.min(resonance.max),
+                    resonance_max: resonance.max,
+                    resonance_lock_ms: resonance.gain_lock_ms,
+                    resonance_gain_locked_until_ms: 0,
+                    next_action_time: 0,
+                    pending_cast: false,
+                },
+            );
+            self.record_timeline(
+                0,
+                TimelineEvent::UnitSpawned {
+                    unit_instance_id: instance_id,
+                    owner: side,
+                    base_uuid: unit.base_uuid,
+                    position,
+                    stats,
+                },
+            );
+
+            for item_uuid in &unit.equipped_items {
+                let item_instance_id = self.next_instance_id(INSTANCE_NAMESPACE_ITEM, spawn_index);
+                self.items.insert(
+                    item_instance_id,
+                    RuntimeItem {
+                        instance_id: item_instance_id,
+                        owner_unit_instance: instance_id,
+                        base_uuid: *item_uuid,
+                    },
+                );
+                self.record_timeline(
+                    0,
+                    TimelineEvent::ItemSpawned {
+                        item_instance_id,
+                        owner: side,
+                        owner_unit_instance_id: instance_id,
+                        base_uuid: *item_uuid,
+                    },
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    fn next_instance_id(&self, namespace: u64, spawn_index: &mut u64) -> Uuid {
+        let id = uuid_v4_from_seed(self.seed, namespace, *spawn_index);
+        *spawn_index += 1;
+        id
+    }
+
+    /// 지정 위치에 배치하고, 비어있지 않으면 스캔 순서상 첫 빈 칸에 배치
+    fn place_unit(
+        &mut self,
+        instance_id: Uuid,
+        side: Side,
+        preferred: Option<Position>,
+    ) -> Result<Position, GameError> {
+        if let Some(position) = preferred {
+            if self
+                .runtime_field
+                .place(instance_id, side, position)
+                .is_ok()
+            {
+                return Ok(position);
+            }
+        }
+
+        for y in 0..self.runtime_field.height as i32 {
+            for x in 0..self.runtime_field.width as i32 {
+                let position = Position::new(x, y);
+                if self
+                    .runtime_field
+                    .place(instance_id, side, position)
+                    .is_ok()
+                {
+                    return Ok(position);
+                }
+            }
+        }
+
+        Err(GameError::PositionOccupied)
+    }
+
+    fn schedule_initial_attacks(&mut self) {
+        let mut attackers: Vec<(Uuid, u64)> = self
+            .units
+            .values()
+            .map(|unit| (unit.instance_id, unit.stats.attack_interval_ms))
+            .collect();
+        attackers.sort_by(|a, b| a.0.as_bytes().cmp(b.0.as_bytes()));
+
+        for (attacker_instance_id, interval_ms) in attackers {
+            self.event_queue.push(BattleEvent::Attack {
+                time_ms: interval_ms,
+                attacker_instance_id,
+                target_instance_id: None,
+                schedule_next: true,
+                cause: self.recording_cause().unwrap_or_default(),
+            });
+        }
+    }
+
+    // ============================================================
+    // 이벤트 처리
+    // ============================================================
+
+    fn event_cause(event: &BattleEvent) -> TimelineCause {
+        match event {
+            BattleEvent::Attack { cause, .. }
+            | BattleEvent::AutoCastStart { cause, .. }
+            | BattleEvent::AutoCastEnd { cause, .. }
+            | BattleEvent::ApplyBuff { cause, .. }
+            | BattleEvent::BuffTick { cause, .. }
+            | BattleEvent::BuffExpire { cause, .. } => *cause,
+        }
+    }
+
+    fn handle_event(&mut self, event: BattleEvent) {
+        match event {
+            BattleEvent::Attack {
+                time_ms,
+                attacker_instance_id,
+                target_instance_id,
+                schedule_next,
+                ..
+            } => self.handle_attack(
+                time_ms,
+                attacker_instance_id,
+                target_instance_id,
+                schedule_next,
+            ),
+            BattleEvent::AutoCastStart {
+                time_ms,
+                caster_instance_id,
+                ..
+            } => self.handle_autocast_start(time_ms, caster_instance_id),
+            BattleEvent::AutoCastEnd {
+                time_ms,
+                caster_instance_id,
+                ..
+            } => self.handle_autocast_end(time_ms, caster_instance_id),
+            BattleEvent::ApplyBuff {
+                time_ms,
+                caster_instance_id,
+                target_instance_id,
+                buff_id,
+                duration_ms,
+                ..
+            } => self.handle_apply_buff(
+                time_ms,
+                BuffInstanceKey {
+                    caster_instance_id,
+                    target_instance_id,
+                    buff_id,
+                },
+                duration_ms,
+            ),
+            BattleEvent::BuffTick {
+                time_ms,
+                caster_instance_id,
+                target_instance_id,
+                buff_id,
+                ..
+            } => self.handle_buff_tick(
+                time_ms,
+                BuffInstanceKey {
+                    caster_instance_id,
+                    target_instance_id,
+                    buff_id,
+                },
+            ),
+            BattleEvent::BuffExpire {
+                time_ms,
+                caster_instance_id,
+                target_instance_id,
+                buff_id,
+                ..
+            } => self.handle_buff_expire(
+                time_ms,
+                BuffInstanceKey {
+                    caster_instance_id,
+                    target_instance_id,
+                    buff_id,
+                },
+            ),
+        }
+    }
+
+    fn handle_attack(
+        &mut self,
+        now_ms: u64,
+        attacker_instance_id: Uuid,
+        target_hint: Option<Uuid>,
+        schedule_next: bool,
+    ) {
+        let Some(attacker) = self.units.get(&attacker_instance_id) else {
+            return;
+        };
+        let attacker_side = attacker.owner;
+        let interval_ms = attacker.stats.attack_interval_ms.max(1);
+
+        // 시전 중에는 자동 공격을 시전 종료 시점으로 미룬다
+        if schedule_next && attacker.next_action_time > now_ms {
+            self.event_queue.push(BattleEvent::Attack {
+                time_ms: attacker.next_action_time,
+                attacker_instance_id,
+                target_instance_id: None,
+                schedule_next: true,
+                cause: TimelineCause::Root {
+                    kind: TimelineRootCause::Period,
+                },
+            });
+            return;
+        }
+
+        let target_instance_id = target_hint
+            .filter(|id| self.is_alive_enemy(attacker_side, id))
+            .or_else(|| self.select_target(attacker_instance_id));
+
+        if let Some(target_instance_id) = target_instance_id {
+            if let Some(attacker) = self.units.get_mut(&attacker_instance_id) {
+                attacker.current_target = Some(target_instance_id);
+            }
+
+            let seq = self.record_timeline(
+                now_ms,
+                TimelineEvent::Attack {
+                    attacker_instance_id,
+                    target_instance_id,
+                    kind: Some(if schedule_next {
+                        AttackKind::Auto
+                    } else {
+                        AttackKind::Triggered
+                    }),
+                },
+            );
+
+            self.with_recording_cause(seq, |battle| {
+                battle.resolve_attack(now_ms, attacker_instance_id, target_instance_id)
+            });
+        }
+
+        if schedule_next {
+            self.event_queue.push(BattleEvent::Attack {
+                time_ms: now_ms + interval_ms,
+                attacker_instance_id,
+                target_instance_id: None,
+                schedule_next: true,
+                cause: TimelineCause::Root {
+                    kind: TimelineRootCause::Period,
+                },
+            });
+        }
+    }
+
+    fn resolve_attack(
+        &mut self,
+        now_ms: u64,
+        attacker_instance_id: Uuid,
+        target_instance_id: Uuid,
+    ) {
+        let (Some(attacker), Some(target)) = (
+            self.units.get(&attacker_instance_id),
+            self.units.get(&target_instance_id),
+        ) else {
+            return;
+        };
+        let damage = attacker
+            .stats
+            .attack
+            .saturating_sub(target.stats.defense)
+            .max(1);
+
+        self.add_resonance(attacker_instance_id, RESONANCE_PER_ATTACK, now_ms, true);
+        self.apply_damage(
+            now_ms,
+            Some(attacker_instance_id),
+            target_instance_id,
+            damage,
+            HpChangeReason::BasicAttack,
+        );
+        self.add_resonance(target_instance_id, RESONANCE_PER_HIT, now_ms, true);
+
+        let on_attack = self.collect_triggered_effects(attacker_instance_id, TriggerType::OnAttack);
+        self.apply_effects(
+            now_ms,
+            attacker_instance_id,
+            Some(target_instance_id),
+            &on_attack,
+        );
+
+        let on_hit = self.collect_triggered_effects(target_instance_id, TriggerType::OnHit);
+        self.apply_effects(
+            now_ms,
+            target_instance_id,
+            Some(attacker_instance_id),
+            &on_hit,
+        );
+    }
+
+    fn handle_autocast_start(&mut self, now_ms: u64, caster_instance_id: Uuid) {
+        let Some(caster) = self.units.get(&caster_instance_id) else {
+            return;
+        };
+        let skill = self
+            .game_data
+            .abnormality_data
+            .get_by_uuid(&caster.base_uuid)
+            .and_then(|origin| origin.skill_id.as_deref())
+            .and_then(|skill_id| self.game_data.skill_data.get_by_id(skill_id))
+            .cloned();
+        let target_instance_id = self.select_target(caster_instance_id);
+
+        let seq = self.record_timeline(
+            now_ms,
+            TimelineEvent::AutoCastStart {
+                caster_instance_id,
+                target_instance_id,
+            },
+        );
+
+        let cast_end_ms = self.with_recording_cause(seq, |battle| {
+            let Some(skill) = skill else {
+                return now_ms;
+            };
+
+            battle.record_timeline(
+                now_ms,
+                TimelineEvent::AbilityCast {
+                    caster_instance_id,
+                    target_instance_id,
+                },
+            );
+            let effects: Vec<Effect> = skill
+                .effects
+                .iter()
+                .filter_map(Self::skill_effect_to_effect)
+                .collect();
+            battle.apply_effects(now_ms, caster_instance_id, target_instance_id, &effects);
+
+            for effect in &skill.effects {
+                if let SkillEffectDef::ExtraAttack { count } = effect {
+                    for _ in 0..*count {
+                        battle.event_queue.push(BattleEvent::Attack {
+                            time_ms: now_ms,
+                            attacker_instance_id: caster_instance_id,
+                            target_instance_id,
+                            schedule_next: false,
+                            cause: battle.recording_cause().unwrap_or_default(),
+                        });
+                    }
+                }
+            }
+
+            now_ms + skill.cast_delay_ms as u64 + skill.focus_time_ms as u64
+        });
+
+        if let Some(caster) = self.units.get_mut(&caster_instance_id) {
+            caster.next_action_time = cast_end_ms;
+        }
+        self.event_queue.push(BattleEvent::AutoCastEnd {
+            time_ms: cast_end_ms,
+            caster_instance_id,
+            cause: TimelineCause::Parent { seq },
+        });
+    }
+
+    fn handle_autocast_end(&mut self, now_ms: u64, caster_instance_id: Uuid) {
+        let Some(caster) = self.units.get_mut(&caster_instance_id) else {
+            return;
+        };
+        caster.resonance_current = 0;
+        caster.resonance_gain_locked_until_ms = now_ms + caster.resonance_lock_ms;
+
+        self.record_timeline(now_ms, TimelineEvent::AutoCastEnd { caster_instance_id });
+    }
+
+    fn handle_apply_buff(&mut self, now_ms: u64, key: BuffInstanceKey, duration_ms: u64) {
+        if !self.units.contains_key(&key.target_instance_id) {
+            return;
+        }
+
+        let expires_at_ms = now_ms + duration_ms;
+        let buff = self.buffs.entry(key).or_insert(ActiveBuff {
+            stacks: 0,
+            expires_at_ms,
+            next_tick_ms: None,
+        });
+        buff.stacks = buff.stacks.saturating_add(1);
+        buff.expires_at_ms = expires_at_ms;
+
+        let seq = self.record_timeline(
+            now_ms,
+            TimelineEvent::BuffApplied {
+                caster_instance_id: key.caster_instance_id,
+                target_instance_id: key.target_instance_id,
+                buff_id: key.buff_id,
+                duration_ms,
+            },
+        );
+        self.event_queue.push(BattleEvent::BuffExpire {
+            time_ms: expires_at_ms,
+            caster_instance_id: key.caster_instance_id,
+            target_instance_id: key.target_instance_id,
+            buff_id: key.buff_id,
+            cause: TimelineCause::Parent { seq },
+        });
+    }
+
+    fn handle_buff_tick(&mut self, now_ms: u64, key: BuffInstanceKey) {
+        let Some(buff) = self.buffs.get(&key) else {
+            return;
+        };
+        if buff.next_tick_ms != Some(now_ms) {
+            return;
+        }
+
+        self.record_timeline(
+            now_ms,
+            TimelineEvent::BuffTick {
+                caster_instance_id: key.caster_instance_id,
+                target_instance_id: key.target_instance_id,
+                buff_id: key.buff_id,
+            },
+        );
+    }
+
+    fn handle_buff_expire(&mut self, now_ms: u64, key: BuffInstanceKey) {
+        // 갱신된 버프는 이전 만료 이벤트를 무시한다
+        match self.buffs.get(&key) {
+            Some(buff) if buff.expires_at_ms == now_ms => {}
+            _ => return,
+        }
+        self.buffs.remove(&key);
+
+        self.record_timeline(
+            now_ms,
+            TimelineEvent::BuffExpired {
+                caster_instance_id: key.caster_instance_id,
+                target_instance_id: key.target_instance_id,
+                buff_id: key.buff_id,
+            },
+        );
+    }
+
+    // ============================================================
+    // 효과 적용
+    // ============================================================
+
+    /// 유닛 소유 진영의 아티팩트 + 유닛이 장착한 장비에서 트리거 효과 수집
+    fn collect_triggered_effects(
+        &self,
+        unit_instance_id: Uuid,
+        trigger: TriggerType,
+    ) -> Vec<Effect> {
+        let Some(unit) = self.units.get(&unit_instance_id) else {
+            return Vec::new();
+        };
+
+        let mut artifacts: Vec<&RuntimeArtifact> = self
+            .artifacts
+            .values()
+            .filter(|artifact| artifact.owner == unit.owner)
+            .collect();
+        artifacts.sort_by(|a, b| a.instance_id.as_bytes().cmp(b.instance_id.as_bytes()));
+
+        let mut items: Vec<&RuntimeItem> = self
+            .items
+            .values()
+            .filter(|item| item.owner_unit_instance == unit_instance_id)
+            .collect();
+        items.sort_by(|a, b| a.instance_id.as_bytes().cmp(b.instance_id.as_bytes()));
+
+        let artifact_effects = artifacts.into_iter().filter_map(|artifact| {
+            self.game_data
+                .artifact_data
+                .get_by_uuid(&artifact.base_uuid)
+                .and_then(|origin| origin.triggered_effects.get(&trigger))
+        });
+        let item_effects = items.into_iter().filter_map(|item| {
+            self.game_data
+                .equipment_data
+                .get_by_uuid(&item.base_uuid)
+                .and_then(|origin| origin.triggered_effects.get(&trigger))
+        });
+
+        artifact_effects
+            .chain(item_effects)
+            .flatten()
+            .cloned()
+            .collect()
+    }
+
+    /// 스킬 효과를 트리거 효과로 변환 (추가 공격은 Attack 이벤트로 따로 예약)
+    fn skill_effect_to_effect(effect: &SkillEffectDef) -> Option<Effect> {
+        match effect {
+            SkillEffectDef::Damage { amount } => Some(Effect::BonusDamage {
+                flat: *amount,
+                percent: 0,
+            }),
+            SkillEffectDef::Heal { amount } => Some(Effect::Heal {
+                flat: *amount,
+                percent: 0,
+            }),
+            SkillEffectDef::ApplyBuff {
+                buff_id,
+                duration_ms,
+            } => Some(Effect::ApplyBuff {
+                buff_id: buff_id.clone(),
+                duration_ms: *duration_ms as u64,
+            }),
+            SkillEffectDef::ExtraAttack { .. } => None,
+        }
+    }
+
+    /// `owner` 가 발동한 효과 적용 (피해/버프는 `target`, 회복/스탯은 `owner` 대상)
+    fn apply_effects(
+        &mut self,
+        now_ms: u64,
+        owner_instance_id: Uuid,
+        target_instance_id: Option<Uuid>,
+        effects: &[Effect],
+    ) {
+        for effect in effects {
+            match effect {
+                Effect::Modifier(modifier) => {
+                    let Some(owner) = self.units.get_mut(&owner_instance_id) else {
+                        continue;
+                    };
+                    let stats_before = owner.stats;
+                    owner.stats.apply_modifier(*modifier);
+                    let stats_after = owner.stats;
+
+                    self.record_timeline(
+                        now_ms,
+                        TimelineEvent::StatChanged {
+                            source_instance_id: Some(owner_instance_id),
+                            target_instance_id: owner_instance_id,
+                            modifier: *modifier,
+                            stats_before,
+                            stats_after,
+                        },
+                    );
+                }
+                Effect::BonusDamage { flat, percent } => {
+                    let (Some(target_instance_id), Some(owner)) =
+                        (target_instance_id, self.units.get(&owner_instance_id))
+                    else {
+                        continue;
+                    };
+                    let amount = i64::from(*flat)
+                        + i64::from(owner.stats.attack) * i64::from(*percent) / 100;
+                    if amount > 0 {
+                        self.apply_damage(
+                            now_ms,
+                            Some(owner_instance_id),
+                            target_instance_id,
+                            amount.min(u32::MAX as i64) as u32,
+                            HpChangeReason::Command,
+                        );
+                    }
+                }
+                Effect::Heal { flat, percent } => {
+                    let Some(owner) = self.units.get(&owner_instance_id) else {
+                        continue;
+                    };
+                    let amount = i64::from(*flat)
+                        + i64::from(owner.stats.max_health) * i64::from(*percent) / 100;
+                    if amount > 0 {
+                        self.apply_heal(
+                            now_ms,
+                            owner_instance_id,
+                            amount.min(u32::MAX as i64) as u32,
+                        );
+                    }
+                }
+                Effect::ApplyBuff {
+                    buff_id,
+                    duration_ms,
+                } => {
+                    let Some(target_instance_id) = target_instance_id else {
+                        continue;
+                    };
+                    self.event_queue.push(BattleEvent::ApplyBuff {
+                        time_ms: now_ms,
+                        caster_instance_id: owner_instance_id,
+                        target_instance_id,
+                        buff_id: BuffId::from_name(buff_id),
+                        duration_ms: *duration_ms,
+                        cause: self.recording_cause().unwrap_or_default(),
+                    });
+                }
+                // 트리거형 스킬 실행은 아직 지원하지 않음 (자동 시전만 스킬 사용)
+                Effect::Skill(_) => {}
+            }
+        }
+    }
+
+    fn apply_damage(
+        &mut self,
+        now_ms: u64,
+        source_instance_id: Option<Uuid>,
+        target_instance_id: Uuid,
+        amount: u32,
+        reason: HpChangeReason,
+    ) {
+        let Some(target) = self.units.get_mut(&target_instance_id) else {
+            return;
+        };
+        let hp_before = target.stats.current_health;
+        let hp_after = hp_before.saturating_sub(amount);
+        target.stats.current_health = hp_after;
+
+        self.record_timeline(
+            now_ms,
+            TimelineEvent::HpChanged {
+                source_instance_id,
+                target_instance_id,
+                delta: -((hp_before - hp_after) as i32),
+                hp_before,
+                hp_after,
+                reason,
+            },
+        );
+
+        if hp_after == 0 {
+            self.handle_death(now_ms, target_instance_id, source_instance_id);
+        }
+    }
+
+    fn apply_heal(&mut self, now_ms: u64, target_instance_id: Uuid, amount: u32) {
+        let Some(target) = self.units.get_mut(&target_instance_id) else {
+            return;
+        };
+        let hp_before = target.stats.current_health;
+        let hp_after = hp_before
+            .saturating_add(amount)
+            .min(target.stats.max_health);
+        if hp_after == hp_before {
+            return;
+        }
+        target.stats.current_health = hp_after;
+
+        self.record_timeline(
+            now_ms,
+            TimelineEvent::HpChanged {
+                source_instance_id: Some(target_instance_id),
+                target_instance_id,
+                delta: (hp_after - hp_before) as i32,
+                hp_before,
+                hp_after,
+                reason: HpChangeReason::Command,
+            },
+        );
+    }
+
+    fn handle_death(&mut self, now_ms: u64, unit_instance_id: Uuid, killer: Option<Uuid>) {
+        let Some(unit) = self.units.remove(&unit_instance_id) else {
+            return;
+        };
+        self.runtime_field.remove(unit_instance_id);
+        self.buffs
+            .retain(|key, _| key.target_instance_id != unit_instance_id);
+        self.graveyard.insert(
+            unit_instance_id,
+            UnitSnapshot {
+                id: unit_instance_id,
+                owner: unit.owner,
+                position: unit.position,
+                stats: unit.stats,
+            },
+        );
+
+        self.record_timeline(
+            now_ms,
+            TimelineEvent::UnitDied {
+                unit_instance_id,
+                owner: unit.owner,
+                killer_instance_id: killer,
+            },
+        );
+
+        if let Some(killer_instance_id) = killer {
+            let on_kill = self.collect_triggered_effects(killer_instance_id, TriggerType::OnKill);
+            self.apply_effects(now_ms, killer_instance_id, None, &on_kill);
+        }
+    }
+
+    // ============================================================
+    // 타겟팅 / 판정
+    // ============================================================
+
+    fn is_alive_enemy(&self, side: Side, instance_id: &Uuid) -> bool {
+        self.units
+            .get(instance_id)
+            .is_some_and(|unit| unit.owner != side && unit.stats.current_health > 0)
+    }
+
+    /// 현재 타겟이 살아있으면 유지, 아니면 가장 가까운 적 (동률이면 instance_id 순)
+    fn select_target(&self, attacker_instance_id: Uuid) -> Option<Uuid> {
+        let attacker = self.units.get(&attacker_instance_id)?;
+
+        if let Some(current) = attacker.current_target {
+            if self.is_alive_enemy(attacker.owner, &current) {
+                return Some(current);
+            }
+        }
+
+        self.units
+            .values()
+            .filter(|unit| unit.owner != attacker.owner && unit.stats.current_health > 0)
+            .min_by(|a, b| {
+                a.position
+                    .manhattan(&attacker.position)
+                    .cmp(&b.position.manhattan(&attacker.position))
+                    .then_with(|| a.instance_id.as_bytes().cmp(b.instance_id.as_bytes()))
+            })
+            .map(|unit| unit.instance_id)
+    }
+
+    /// (플레이어 생존 여부, 상대 생존 여부)
+    fn living_sides(&self) -> (bool, bool) {
+        let alive = |side: Side| {
+            self.units
+                .values()
+                .any(|unit| unit.owner == side && unit.stats.current_health > 0)
+        };
+        (alive(Side::Player), alive(Side::Opponent))
+    }
+
+    /// 한쪽만 생존하면 그쪽 승리, 둘 다 생존(제한 시간 초과)이면 남은 체력 합 비교
+    fn judge_winner(&self) -> BattleWinner {
+        match self.living_sides() {
+            (true, false) => BattleWinner::Player,
+            (false, true) => BattleWinner::Opponent,
+            (false, false) => BattleWinner::Draw,
+            (true, true) => {
+                let remaining_health = |side: Side| -> u64 {
+                    self.units
+                        .values()
+                        .filter(|unit| unit.owner == side)
+                        .map(|unit| u64::from(unit.stats.current_health))
+                        .sum()
+                };
+                match remaining_health(Side::Player).cmp(&remaining_health(Side::Opponent)) {
+                    std::cmp::Ordering::Greater => BattleWinner::Player,
+                    std::cmp::Ordering::Less => BattleWinner::Opponent,
+                    std::cmp::Ordering::Equal => BattleWinner::Draw,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, path::Path, sync::Arc};
+
+    use super::*;
+    use crate::game::{
+        ability::{DeliveryDef, SkillDef, SkillKind},
+        battle::types::{OwnedArtifact, OwnedUnit},
+        data::{
+            abnormality_data::AbnormalityDatabase, artifact_data::ArtifactDatabase,
+            equipment_data::EquipmentDatabase, skill_data::SkillDatabase, GameDataBase,
+            GameDataTables,
+        },
+        enums::Tier,
+        growth::GrowthStack,
+        stats::{StatId, StatModifier, StatModifierKind},
+    };
+
+    fn bundled_game_data() -> Arc<GameDataBase> {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../game_resources/data");
+        Arc::new(GameDataBase::load_from_dir(dir).unwrap())
+    }
+
+    /// 번들 데이터를 복사해 테이블 일부를 고친 GameDataBase
+    fn game_data_with(edit: impl FnOnce(&mut GameDataTables)) -> Arc<GameDataBase> {
+        let base = bundled_game_data();
+        let mut tables = GameDataTables {
+            abnormality_data: base.abnormality_data.clone(),
+            artifact_data: base.artifact_data.clone(),
+            equipment_data: base.equipment_data.clone(),
+            shop_data: base.shop_data.clone(),
+            bonus_data: base.bonus_data.clone(),
+            random_event_data: base.random_event_data.clone(),
+            random_event_story_data: base.random_event_story_data.clone(),
+            pve_data: base.pve_data.clone(),
+            skill_data: base.skill_data.clone(),
+            event_pools: base.event_pools.clone(),
+        };
+        edit(&mut tables);
+        Arc::new(GameDataBase::new(tables))
+    }
+
+    /// 스킬 없이 고정 스탯만 가진 유닛으로 덮어쓰기
+    fn set_unit_stats(
+        tables: &mut GameDataTables,
+        id: &str,
+        max_health: u32,
+        attack: u32,
+        interval_ms: u64,
+    ) {
+        let db: &mut AbnormalityDatabase = Arc::make_mut(&mut tables.abnormality_data);
+        let unit = db.items.iter_mut().find(|item| item.id == id).unwrap();
+        unit.max_health = max_health;
+        unit.attack = attack;
+        unit.defense = 0;
+        unit.basic_attack.interval_ms = interval_ms;
+        unit.skill_id = None;
+    }
+
+    fn set_artifact_effects(
+        tables: &mut GameDataTables,
+        id: &str,
+        trigger: TriggerType,
+        effects: Vec<Effect>,
+    ) {
+        let db: &mut ArtifactDatabase = Arc::make_mut(&mut tables.artifact_data);
+        let artifact = db.items.iter_mut().find(|item| item.id == id).unwrap();
+        artifact.triggered_effects = HashMap::from([(trigger, effects)]);
+    }
+
+    fn set_equipment_effects(
+        tables: &mut GameDataTables,
+        id: &str,
+        trigger: TriggerType,
+        effects: Vec<Effect>,
+    ) {
+        let db: &mut EquipmentDatabase = Arc::make_mut(&mut tables.equipment_data);
+        let equipment = db.items.iter_mut().find(|item| item.id == id).unwrap();
+        equipment.triggered_effects = HashMap::from([(trigger, effects)]);
+    }
+
+    fn spawned_unit(result: &BattleResult, side: Side) -> Uuid {
+        result
+            .timeline
+            .entries
+            .iter()
+            .find_map(|entry| match entry.event {
+                TimelineEvent::UnitSpawned {
+                    unit_instance_id,
+                    owner,
+                    ..
+                } if owner == side => Some(unit_instance_id),
+                _ => None,
+            })
+            .unwrap()
+    }
+
+    fn command_hp_changes(result: &BattleResult, target: Uuid) -> Vec<(Option<Uuid>, i32)> {
+        result
+            .timeline
+            .entries
+            .iter()
+            .filter_map(|entry| match entry.event {
+                TimelineEvent::HpChanged {
+                    source_instance_id,
+                    target_instance_id,
+                    delta,
+                    reason: HpChangeReason::Command,
+                    ..
+                } if target_instance_id == target => Some((source_instance_id, delta)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn single_unit_deck(game_data: &GameDataBase, id: &str, position: Position) -> PlayerDeckInfo {
+        let base_uuid = game_data.abnormality_data.get_by_id(id).unwrap().uuid;
+        PlayerDeckInfo {
+            units: vec![OwnedUnit {
+                base_uuid,
+                level: Tier::I,
+                growth_stacks: GrowthStack::new(),
+                equipped_items: vec![],
+            }],
+            artifacts: vec![],
+            positions: HashMap::from([(base_uuid, position)]),
+        }
+    }
+
+    fn empty_deck() -> PlayerDeckInfo {
+        PlayerDeckInfo {
+            units: vec![],
+            artifacts: vec![],
+            positions: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_empty_decks_end_in_draw() {
+        let mut battle = BattleCore::new(&empty_deck(), &empty_deck(), bundled_game_data(), (3, 3));
+        let result = battle.run_battle().unwrap();
+
+        assert_eq!(result.winner, BattleWinner::Draw);
+        assert!(matches!(
+            result.timeline.entries.last().unwrap().event,
+            TimelineEvent::BattleEnd {
+                winner: BattleWinner::Draw
+            }
+        ));
+    }
+
+    #[test]
+    fn test_stronger_unit_wins_and_replay_is_deterministic() {
+        let game_data = bundled_game_data();
+        // Plague Doctor(ALEPH) vs Scorched Girl(HE)
+        let player = single_unit_deck(&game_data, "o-02-56", Position::new(1, 0));
+        let opponent = single_unit_deck(&game_data, "f-01-02", Position::new(1, 2));
+
+        let run = |seed: u64| {
+            BattleCore::new(&player, &opponent, game_data.clone(), (3, 3))
+                .with_seed(seed)
+                .run_battle()
+                .unwrap()
+        };
+
+        let first = run(42);
+        assert_eq!(first.winner, BattleWinner::Player);
+        assert!(first.timeline.entries.iter().any(|entry| matches!(
+            entry.event,
+            TimelineEvent::UnitDied {
+                owner: Side::Opponent,
+                ..
+            }
+        )));
+
+        // Then: 같은 seed 면 타임라인이 완전히 같다
+        let second = run(42);
+        assert_eq!(
+            first.timeline.to_json_string().unwrap(),
+            second.timeline.to_json_string().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_timeout_judges_by_remaining_health() {
+        // Given: 서로 1 씩만 깎는 유닛이라 제한 시간 안에 끝나지 않는다
+        let cases = [
+            (500, 400, BattleWinner::Player),
+            (400, 500, BattleWinner::Opponent),
+            (450, 450, BattleWinner::Draw),
+        ];
+
+        for (player_health, opponent_health, expected) in cases {
+            let game_data = game_data_with(|tables| {
+                set_unit_stats(tables, "o-02-56", player_health, 1, 1000);
+                set_unit_stats(tables, "f-01-02", opponent_health, 1, 1000);
+            });
+            let player = single_unit_deck(&game_data, "o-02-56", Position::new(1, 0));
+            let opponent = single_unit_deck(&game_data, "f-01-02", Position::new(1, 2));
+
+            let result = BattleCore::new(&player, &opponent, game_data, (3, 3))
+                .run_battle()
+                .unwrap();
+
+            // Then: 아무도 죽지 않고 제한 시간에 남은 체력 합으로 판정
+            assert_eq!(result.winner, expected);
+            assert!(!result
+                .timeline
+                .entries
+                .iter()
+                .any(|entry| matches!(entry.event, TimelineEvent::UnitDied { .. })));
+            let end = result.timeline.entries.last().unwrap();
+            assert_eq!(end.time_ms, MAX_BATTLE_TIME_MS);
+            assert!(matches!(
+                end.event,
+                TimelineEvent::BattleEnd { winner } if winner == expected
+            ));
+        }
+    }
+
+    #[test]
+    fn test_full_resonance_casts_skill() {
+        let game_data = game_data_with(|tables| {
+            set_unit_stats(tables, "o-02-56", 1000, 1, 1000);
+            // 상대는 제한 시간 안에 공격하지 않는다 (피격 공명 없음)
+            set_unit_stats(tables, "f-01-02", 1000, 1, MAX_BATTLE_TIME_MS * 2);
+
+            let abnormalities = Arc::make_mut(&mut tables.abnormality_data);
+            let caster = abnormalities
+                .items
+                .iter_mut()
+                .find(|item| item.id == "o-02-56")
+                .unwrap();
+            caster.skill_id = Some("test_strike".to_string());
+            caster.resonance.start = 0;
+            caster.resonance.max = RESONANCE_PER_ATTACK * 2;
+
+            let mut skills = tables.skill_data.skills.clone();
+            skills.push(SkillDef {
+                id: "test_strike".to_string(),
+                kind: SkillKind::Targeted,
+                range_tiles: 3,
+                cast_delay_ms: 0,
+                focus_time_ms: 0,
+                delivery: DeliveryDef::Instant,
+                effects: vec![SkillEffectDef::Damage { amount: 33 }],
+            });
+            tables.skill_data = Arc::new(SkillDatabase::new(skills));
+        });
+        let player = single_unit_deck(&game_data, "o-02-56", Position::new(1, 0));
+        let opponent = single_unit_deck(&game_data, "f-01-02", Position::new(1, 2));
+
+        let result = BattleCore::new(&player, &opponent, game_data, (3, 3))
+            .run_battle()
+            .unwrap();
+        let caster = spawned_unit(&result, Side::Player);
+        let target = spawned_unit(&result, Side::Opponent);
+
+        // Then: 두 번째 공격으로 공명이 가득 찬 시점에 처음 시전한다
+        let first_cast = result
+            .timeline
+            .entries
+            .iter()
+            .find(|entry| {
+                matches!(
+                    entry.event,
+                    TimelineEvent::AbilityCast { caster_instance_id, .. } if caster_instance_id == caster
+                )
+            })
+            .unwrap();
+        assert_eq!(first_cast.time_ms, 2000);
+        assert!(command_hp_changes(&result, target).contains(&(Some(caster), -33)));
+    }
+
+    #[test]
+    fn test_artifact_and_item_triggers_apply_effects() {
+        let game_data = game_data_with(|tables| {
+            set_unit_stats(tables, "o-02-56", 1000, 5, 1000);
+            set_unit_stats(tables, "f-01-02", 1000, 5, 1000);
+            set_artifact_effects(
+                tables,
+                "soda",
+                TriggerType::OnAttack,
+                vec![Effect::BonusDamage {
+                    flat: 7,
+                    percent: 0,
+                }],
+            );
+            set_equipment_effects(
+                tables,
+                "justitia",
+                TriggerType::OnHit,
+                vec![Effect::Heal {
+                    flat: 3,
+                    percent: 0,
+                }],
+            );
+        });
+        let mut player = single_unit_deck(&game_data, "o-02-56", Position::new(1, 0));
+        player.artifacts.push(OwnedArtifact {
+            base_uuid: game_data.artifact_data.get_by_id("soda").unwrap().uuid,
+        });
+        player.units[0]
+            .equipped_items
+            .push(game_data.equipment_data.get_by_id("justitia").unwrap().uuid);
+        let opponent = single_unit_deck(&game_data, "f-01-02", Position::new(1, 2));
+
+        let result = BattleCore::new(&player, &opponent, game_data, (3, 3))
+            .run_battle()
+            .unwrap();
+        let player_unit = spawned_unit(&result, Side::Player);
+        let opponent_unit = spawned_unit(&result, Side::Opponent);
+
+        // Then: 아티팩트 공격 시 추가 피해, 장비 피격 시 회복이 발동한다
+        assert!(command_hp_changes(&result, opponent_unit).contains(&(Some(player_unit), -7)));
+        assert!(command_hp_changes(&result, player_unit).contains(&(Some(player_unit), 3)));
+        // 상대에게는 트리거가 없다
+        assert!(command_hp_changes(&result, player_unit)
+            .iter()
+            .all(|(source, _)| *source == Some(player_unit)));
+    }
+
+    #[test]
+    fn test_mutual_wipe_is_draw() {
+        // Given: 처치 시 자신의 최대 체력을 모두 잃는 장비
+        let game_data = game_data_with(|tables| {
+            set_unit_stats(tables, "o-02-56", 1000, 100, 1000);
+            set_unit_stats(tables, "f-01-02", 1, 1, 5000);
+            set_equipment_effects(
+                tables,
+                "justitia",
+                TriggerType::OnKill,
+                vec![Effect::Modifier(StatModifier {
+                    stat: StatId::MaxHealth,
+                    kind: StatModifierKind::Percent,
+                    value: -100,
+                })],
+            );
+        });
+        let mut player = single_unit_deck(&game_data, "o-02-56", Position::new(1, 0));
+        player.units[0]
+            .equipped_items
+            .push(game_data.equipment_data.get_by_id("justitia").unwrap().uuid);
+        let opponent = single_unit_deck(&game_data, "f-01-02", Position::new(1, 2));
+
+        let result = BattleCore::new(&player, &opponent, game_data, (3, 3))
+            .run_battle()
+            .unwrap();
+
+        // Then: 같은 이벤트에서 양측이 모두 쓰러지면 무승부
+        assert_eq!(result.winner, BattleWinner::Draw);
+        let end = result.timeline.entries.last().unwrap();
+        assert_eq!(end.time_ms, 1000);
+        assert!(matches!(
+            end.event,
+            TimelineEvent::BattleEnd {
+                winner: BattleWinner::Draw
+            }
+        ));
+    }
+}
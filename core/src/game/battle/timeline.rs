@@ -70,17 +70,34 @@ pub enum AttackKind {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Timeline {
     pub version: u32,
+    /// 전투에 사용된 게임 데이터 버전 ID (리플레이 재현용)
+    #[serde(default)]
+    pub data_version: u64,
     pub entries: Vec<TimelineEntry>,
 }
 
 impl Timeline {
     pub fn new() -> Self {
+        Self::with_data_version(0)
+    }
+
+    pub fn with_data_version(data_version: u64) -> Self {
         Self {
             version: TIMELINE_VERSION,
+            data_version,
             entries: Vec::new(),
         }
     }
 
+    /// 마지막 이벤트 시각 = 전투 길이 (ms, 비어 있으면 0)
+    pub fn duration_ms(&self) -> u64 {
+        self.entries
+            .iter()
+            .map(|entry| entry.time_ms)
+            .max()
+            .unwrap_or(0)
+    }
+
     pub fn to_json_string(&self) -> Result<String, serde_json::Error> {
         serde_json::to_string(self)
     }
@@ -6,6 +6,7 @@ use uuid::Uuid;
 use crate::{
     ecs::resources::Position,
     game::{
+        battle::timeline::Timeline,
         behavior::GameError,
         data::GameDataBase,
         enums::{Side, Tier},
@@ -21,6 +22,13 @@ pub enum BattleWinner {
     Draw,
 }
 
+/// 전투 결과
+#[derive(Debug, Clone)]
+pub struct BattleResult {
+    pub winner: BattleWinner,
+    pub timeline: Timeline,
+}
+
 #[derive(Clone)]
 pub struct PlayerDeckInfo {
     pub units: Vec<OwnedUnit>,
@@ -88,6 +96,9 @@ impl OwnedUnit {
                 }
                 GrowthId::PveWinStack => {}
                 GrowthId::QuestRewardStack => {}
+                GrowthId::EventHealthStack => {
+                    stats.add_max_health(*value);
+                }
             }
         }
 
@@ -4,8 +4,14 @@ use uuid::Uuid;
 use crate::{
     ecs::resources::{InventoryDiffDto, Position},
     game::{
-        data::{random_event_data::RandomEventMetadata, shop_data::ShopMetadata},
+        battle::types::BattleWinner,
+        data::{
+            random_event_data::RandomEventMetadata,
+            random_event_story_data::{StoryNodeDto, StoryOutcomeDto},
+            shop_data::ShopMetadata,
+        },
         enums::{PhaseEvent, ZoneType},
+        managers::{reward_manager::RewardResolution, run_manager::RunSummary},
     },
 };
 
@@ -69,6 +75,15 @@ pub enum PlayerBehavior {
     /// 보너스 화면 나가기
     ExitBonus,
     // ============================================================
+    // 랜덤 이벤트 관련 행동
+    // ============================================================
+    /// 현재 노드의 선택지 선택
+    SelectEventChoice {
+        choice_id: String,
+    },
+    /// 랜덤 이벤트 나가기
+    ExitRandomEvent,
+    // ============================================================
     // 진압 관련 행동
     // ============================================================
     /// 진압 전투 시작
@@ -134,6 +149,13 @@ pub enum BehaviorResult {
     RandomEventState {
         event: RandomEventMetadata,
     },
+    /// 선택지형 랜덤 이벤트 진행
+    /// - 진입 시: outcome = None, node = 시작 노드
+    /// - 선택 시: outcome = 선택 결과, node = 다음 노드 (None 이면 이벤트 종료)
+    RandomEventStep {
+        outcome: Option<StoryOutcomeDto>,
+        node: Option<StoryNodeDto>,
+    },
     /// 보너스 결과 (자원 및 인벤토리 변경)
     BonusReward {
         enkephalin: u32,
@@ -146,10 +168,16 @@ pub enum BehaviorResult {
         suppress_result: String,
     },
 
-    /// 시련 전투 → 전투 결과
+    /// 진압 전투 → 승패와 적용된 보상/페널티 (전투 로그는 BattleTimeline 으로 전송)
+    SuppressionBattle {
+        winner: BattleWinner,
+        resolution: RewardResolution,
+    },
+
+    /// 시련 전투 → 승패와 적용된 보상/페널티 (전투 로그는 BattleTimeline 으로 전송)
     Ordeal {
-        // TODO: 승패, 보상, 전투 로그 등
-        battle_result: String,
+        winner: BattleWinner,
+        resolution: RewardResolution,
     },
 
     /// Phase 진행 → 다음 Phase 이벤트
@@ -158,6 +186,11 @@ pub enum BehaviorResult {
         next_phase_event: String,
     },
 
+    /// 런 종료 → 런 결과 요약
+    RunEnded {
+        summary: RunSummary,
+    },
+
     Ok,
 }
 
@@ -240,6 +273,18 @@ impl BehaviorResult {
         }
     }
 
+    /// RandomEventStep → (선택 결과, 다음 노드) 참조 반환
+    pub fn as_random_event_step(
+        &self,
+    ) -> Option<(Option<&StoryOutcomeDto>, Option<&StoryNodeDto>)> {
+        match self {
+            BehaviorResult::RandomEventStep { outcome, node } => {
+                Some((outcome.as_ref(), node.as_ref()))
+            }
+            _ => None,
+        }
+    }
+
     /// BonusReward → (남은 엔케팔린, 인벤토리 변경 사항) 반환
     pub fn as_bonus_reward(&self) -> Option<(u32, &InventoryDiffDto)> {
         match self {
@@ -259,10 +304,18 @@ impl BehaviorResult {
         }
     }
 
-    /// Ordeal → 전투 결과 문자열 참조 반환
-    pub fn as_ordeal(&self) -> Option<&str> {
+    /// SuppressionBattle → (승자, 보상 처리 결과) 반환
+    pub fn as_suppression_battle(&self) -> Option<(BattleWinner, &RewardResolution)> {
+        match self {
+            BehaviorResult::SuppressionBattle { winner, resolution } => Some((*winner, resolution)),
+            _ => None,
+        }
+    }
+
+    /// Ordeal → (승자, 보상 처리 결과) 반환
+    pub fn as_ordeal(&self) -> Option<(BattleWinner, &RewardResolution)> {
         match self {
-            BehaviorResult::Ordeal { battle_result } => Some(battle_result),
+            BehaviorResult::Ordeal { winner, resolution } => Some((*winner, resolution)),
             _ => None,
         }
     }
@@ -274,6 +327,22 @@ impl BehaviorResult {
             _ => None,
         }
     }
+
+    /// RunEnded → RunSummary 참조 반환
+    pub fn as_run_ended(&self) -> Option<&RunSummary> {
+        match self {
+            BehaviorResult::RunEnded { summary } => Some(summary),
+            _ => None,
+        }
+    }
+
+    /// RunEnded → RunSummary 가변 참조 반환 (게임 서버가 런 시간을 채울 때 사용)
+    pub fn as_run_ended_mut(&mut self) -> Option<&mut RunSummary> {
+        match self {
+            BehaviorResult::RunEnded { summary } => Some(summary),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -290,6 +359,10 @@ pub enum GameError {
     NotInShopState,
     /// 보너스 상태가 아니거나, SelectedEvent에 Bonus 정보가 없을 때
     NotInBonusState,
+    /// 선택지형 랜덤 이벤트 진행 중이 아닐 때
+    NotInRandomEventState,
+    /// 현재 노드에 해당 선택지가 없을 때
+    EventChoiceNotFound,
     /// 상점이 리롤을 지원하지 않을 때
     ShopRerollNotAllowed,
     /// 상점의 visible_items / uuid_lookup_table에서 아이템을 찾지 못했을 때
@@ -0,0 +1,948 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use serde::de::DeserializeOwned;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::game::{
+    data::{
+        abnormality_data::AbnormalityDatabase,
+        artifact_data::ArtifactDatabase,
+        bonus_data::BonusDatabase,
+        equipment_data::EquipmentDatabase,
+        event_pools::{EventPoolConfig, WeightedEvent},
+        pve_data::PveEncounterDatabase,
+        random_event_data::{RandomEventDatabase, RandomEventInnerMetadata},
+        random_event_story_data::{
+            ChoiceOutcome, OutcomeBranch, OutcomeEffect, RandomEventStoryDatabase,
+        },
+        shop_data::ShopDatabase,
+        skill_data::SkillDatabase,
+        GameDataBase, GameDataTables,
+    },
+    events::event_selection::random::RandomEventType,
+};
+
+// ============================================================
+// 데이터 디렉토리 구성
+// ============================================================
+
+/// 환상체 RON 파일 디렉토리 (디렉토리 안의 모든 .ron 을 합쳐서 로드)
+pub const ABNORMALITY_DIR: &str = "abnormalities";
+pub const ARTIFACTS_FILE: &str = "artifacts.ron";
+pub const EQUIPMENTS_FILE: &str = "equipments.ron";
+pub const SHOPS_FILE: &str = "events/shops.ron";
+pub const BONUSES_FILE: &str = "events/bonuses.ron";
+pub const RANDOM_EVENTS_FILE: &str = "events/random_events.ron";
+pub const RANDOM_EVENT_STORIES_FILE: &str = "events/random_event_stories.ron";
+pub const EVENT_POOLS_FILE: &str = "events/event_pools.ron";
+/// 선택 파일 (없으면 빈 DB)
+pub const SKILLS_FILE: &str = "skills.ron";
+/// 선택 파일 (없으면 빈 DB)
+pub const PVE_ENCOUNTERS_FILE: &str = "pve_encounters.ron";
+
+// ============================================================
+// 에러 타입
+// ============================================================
+
+/// 데이터 로드/검증 중 발견된 문제 하나
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataIssue {
+    pub file: PathBuf,
+    /// 1-based 줄 번호 (알 수 없으면 None)
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl fmt::Display for DataIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "{}:{}: {}", self.file.display(), line, self.message),
+            None => write!(f, "{}: {}", self.file.display(), self.message),
+        }
+    }
+}
+
+/// 데이터 로드 실패 (발견된 모든 문제를 모아서 반환)
+#[derive(Debug, Clone)]
+pub struct DataLoadError {
+    pub issues: Vec<DataIssue>,
+}
+
+impl fmt::Display for DataLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} game data issue(s):", self.issues.len())?;
+        for issue in &self.issues {
+            writeln!(f, "  {}", issue)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for DataLoadError {}
+
+// ============================================================
+// 로더
+// ============================================================
+
+/// 원본 텍스트 (검증 에러의 줄 번호 계산용)
+struct SourceFile {
+    path: PathBuf,
+    text: String,
+}
+
+impl SourceFile {
+    /// needle 이 n 번째(0-based)로 등장하는 줄 번호
+    fn line_of_nth(&self, needle: &str, n: usize) -> Option<usize> {
+        let (offset, _) = self.text.match_indices(needle).nth(n)?;
+        Some(self.text[..offset].matches('\n').count() + 1)
+    }
+}
+
+/// 로드된 파일과 해당 원본
+struct Loaded<T> {
+    value: T,
+    sources: Vec<SourceFile>,
+}
+
+impl<T> Loaded<T> {
+    /// needle 이 처음 등장하는 파일/줄
+    fn locate(&self, needle: &str) -> (PathBuf, Option<usize>) {
+        self.locate_nth(needle, 0)
+    }
+
+    /// needle 이 n 번째로 등장하는 파일/줄 (여러 파일에 걸쳐 셈)
+    fn locate_nth(&self, needle: &str, mut n: usize) -> (PathBuf, Option<usize>) {
+        for source in &self.sources {
+            let count = source.text.matches(needle).count();
+            if n < count {
+                return (source.path.clone(), source.line_of_nth(needle, n));
+            }
+            n -= count;
+        }
+        let path = self
+            .sources
+            .first()
+            .map(|s| s.path.clone())
+            .unwrap_or_default();
+        (path, None)
+    }
+}
+
+struct Loader {
+    dir: PathBuf,
+    issues: Vec<DataIssue>,
+}
+
+impl Loader {
+    fn read(&mut self, relative: &str) -> Option<SourceFile> {
+        let path = self.dir.join(relative);
+        match std::fs::read_to_string(&path) {
+            Ok(text) => Some(SourceFile { path, text }),
+            Err(e) => {
+                self.issues.push(DataIssue {
+                    file: path,
+                    line: None,
+                    message: format!("failed to read file: {}", e),
+                });
+                None
+            }
+        }
+    }
+
+    fn parse<T: DeserializeOwned>(&mut self, source: &SourceFile) -> Option<T> {
+        match ron::de::from_str::<T>(&source.text) {
+            Ok(value) => Some(value),
+            Err(e) => {
+                let line = match &e {
+                    ron::de::Error::Parser(_, pos) => Some(pos.line),
+                    _ => None,
+                };
+                self.issues.push(DataIssue {
+                    file: source.path.clone(),
+                    line,
+                    message: format!("failed to parse: {}", e),
+                });
+                None
+            }
+        }
+    }
+
+    /// 필수 파일 로드
+    fn load<T: DeserializeOwned>(&mut self, relative: &str) -> Option<Loaded<T>> {
+        let source = self.read(relative)?;
+        let value = self.parse(&source)?;
+        Some(Loaded {
+            value,
+            sources: vec![source],
+        })
+    }
+
+    /// 선택 파일 로드 (없으면 default)
+    fn load_optional<T: DeserializeOwned>(
+        &mut self,
+        relative: &str,
+        default: impl FnOnce() -> T,
+    ) -> Option<Loaded<T>> {
+        if !self.dir.join(relative).exists() {
+            return Some(Loaded {
+                value: default(),
+                sources: Vec::new(),
+            });
+        }
+        self.load(relative)
+    }
+
+    /// 디렉토리의 모든 환상체 파일을 합쳐서 로드
+    fn load_abnormalities(&mut self) -> Option<Loaded<AbnormalityDatabase>> {
+        let dir = self.dir.join(ABNORMALITY_DIR);
+        let mut paths: Vec<PathBuf> = match std::fs::read_dir(&dir) {
+            Ok(entries) => entries
+                .filter_map(|entry| entry.ok().map(|e| e.path()))
+                .filter(|path| path.extension().is_some_and(|ext| ext == "ron"))
+                .collect(),
+            Err(e) => {
+                self.issues.push(DataIssue {
+                    file: dir,
+                    line: None,
+                    message: format!("failed to read directory: {}", e),
+                });
+                return None;
+            }
+        };
+        // 파일 순서에 따라 결과가 바뀌지 않도록 정렬
+        paths.sort();
+
+        let mut items = Vec::new();
+        let mut sources = Vec::new();
+        let mut failed = false;
+        for path in paths {
+            let relative = path
+                .strip_prefix(&self.dir)
+                .unwrap_or(&path)
+                .to_string_lossy()
+                .into_owned();
+            let Some(source) = self.read(&relative) else {
+                failed = true;
+                continue;
+            };
+            match self.parse::<AbnormalityDatabase>(&source) {
+                Some(db) => items.extend(db.items),
+                None => failed = true,
+            }
+            sources.push(source);
+        }
+
+        (!failed).then(|| Loaded {
+            value: AbnormalityDatabase::new(items),
+            sources,
+        })
+    }
+}
+
+impl GameDataBase {
+    /// 데이터 디렉토리(game_resources/data)에서 전체 게임 데이터 로드
+    ///
+    /// 파싱/참조 검증 문제는 첫 번째에서 멈추지 않고 전부 모아서 반환한다.
+    pub fn load_from_dir(dir: impl AsRef<Path>) -> Result<Self, DataLoadError> {
+        let dir = dir.as_ref();
+        let mut loader = Loader {
+            dir: dir.to_path_buf(),
+            issues: Vec::new(),
+        };
+
+        let abnormalities = loader.load_abnormalities();
+        let artifacts = loader.load::<ArtifactDatabase>(ARTIFACTS_FILE);
+        let equipments = loader.load::<EquipmentDatabase>(EQUIPMENTS_FILE);
+        let shops = loader.load::<ShopDatabase>(SHOPS_FILE);
+        let bonuses = loader.load::<BonusDatabase>(BONUSES_FILE);
+        let random_events = loader.load::<RandomEventDatabase>(RANDOM_EVENTS_FILE);
+        let stories = loader.load::<RandomEventStoryDatabase>(RANDOM_EVENT_STORIES_FILE);
+        let event_pools = loader.load::<EventPoolConfig>(EVENT_POOLS_FILE);
+        let skills = loader.load_optional(SKILLS_FILE, || SkillDatabase::new(Vec::new()));
+        let pve =
+            loader.load_optional(
+                PVE_ENCOUNTERS_FILE,
+                || PveEncounterDatabase::new(Vec::new()),
+            );
+
+        // 파싱 실패가 하나라도 있으면 참조 검증은 의미가 없으므로 여기서 반환
+        let (
+            Some(abnormalities),
+            Some(artifacts),
+            Some(equipments),
+            Some(shops),
+            Some(bonuses),
+            Some(mut random_events),
+            Some(mut stories),
+            Some(event_pools),
+            Some(mut skills),
+            Some(pve),
+        ) = (
+            abnormalities,
+            artifacts,
+            equipments,
+            shops,
+            bonuses,
+            random_events,
+            stories,
+            event_pools,
+            skills,
+            pve,
+        )
+        else {
+            return Err(DataLoadError {
+                issues: loader.issues,
+            });
+        };
+
+        // RON 역직렬화 후 조회용 맵 초기화
+        random_events.value.init_map();
+        stories.value.init_map();
+        skills.value.init_map();
+
+        let data = DataSet {
+            abnormalities,
+            artifacts,
+            equipments,
+            shops,
+            bonuses,
+            random_events,
+            stories,
+            event_pools,
+            skills,
+            pve,
+        };
+        let issues = data.validate();
+        if !issues.is_empty() {
+            return Err(DataLoadError { issues });
+        }
+
+        info!(
+            "Game data loaded from {:?}: abnormalities={}, artifacts={}, equipments={}, shops={}, bonuses={}, random_events={}, stories={}",
+            dir,
+            data.abnormalities.value.items.len(),
+            data.artifacts.value.items.len(),
+            data.equipments.value.items.len(),
+            data.shops.value.shops.len(),
+            data.bonuses.value.bonuses.len(),
+            data.random_events.value.events.len(),
+            data.stories.value.stories.len(),
+        );
+
+        Ok(data.into_game_data())
+    }
+}
+
+// ============================================================
+// 참조 검증
+// ============================================================
+
+/// 검증 대상 전체 (원본 위치 포함)
+struct DataSet {
+    abnormalities: Loaded<AbnormalityDatabase>,
+    artifacts: Loaded<ArtifactDatabase>,
+    equipments: Loaded<EquipmentDatabase>,
+    shops: Loaded<ShopDatabase>,
+    bonuses: Loaded<BonusDatabase>,
+    random_events: Loaded<RandomEventDatabase>,
+    stories: Loaded<RandomEventStoryDatabase>,
+    event_pools: Loaded<EventPoolConfig>,
+    skills: Loaded<SkillDatabase>,
+    pve: Loaded<PveEncounterDatabase>,
+}
+
+/// 문제 누적 헬퍼
+#[derive(Default)]
+struct Issues(Vec<DataIssue>);
+
+impl Issues {
+    fn push<T>(&mut self, loaded: &Loaded<T>, needle: &str, message: String) {
+        let (file, line) = loaded.locate(needle);
+        self.0.push(DataIssue {
+            file,
+            line,
+            message,
+        });
+    }
+
+    fn push_nth<T>(&mut self, loaded: &Loaded<T>, needle: &str, n: usize, message: String) {
+        let (file, line) = loaded.locate_nth(needle, n);
+        self.0.push(DataIssue {
+            file,
+            line,
+            message,
+        });
+    }
+}
+
+/// RON 상에서 값을 찾기 위한 문자열 ("uuid" 형태)
+fn quoted(value: impl fmt::Display) -> String {
+    format!("\"{}\"", value)
+}
+
+impl DataSet {
+    fn validate(&self) -> Vec<DataIssue> {
+        let mut issues = Issues::default();
+
+        self.validate_unique_uuids(&mut issues);
+        self.validate_unique_ids(&mut issues);
+        self.validate_abnormalities(&mut issues);
+        self.validate_shops(&mut issues);
+        self.validate_random_events(&mut issues);
+        self.validate_stories(&mut issues);
+        self.validate_event_pools(&mut issues);
+        self.validate_pve(&mut issues);
+
+        issues.0
+    }
+
+    /// 모든 데이터의 UUID 는 전역적으로 유일해야 함
+    fn validate_unique_uuids(&self, issues: &mut Issues) {
+        let mut seen = HashMap::new();
+
+        check_unique_uuids(
+            &mut seen,
+            issues,
+            &self.abnormalities,
+            self.abnormalities.value.items.iter().map(|m| m.uuid),
+            "abnormality",
+        );
+        check_unique_uuids(
+            &mut seen,
+            issues,
+            &self.artifacts,
+            self.artifacts.value.items.iter().map(|m| m.uuid),
+            "artifact",
+        );
+        check_unique_uuids(
+            &mut seen,
+            issues,
+            &self.equipments,
+            self.equipments.value.items.iter().map(|m| m.uuid),
+            "equipment",
+        );
+        check_unique_uuids(
+            &mut seen,
+            issues,
+            &self.shops,
+            self.shops.value.shops.iter().map(|m| m.uuid),
+            "shop",
+        );
+        check_unique_uuids(
+            &mut seen,
+            issues,
+            &self.bonuses,
+            self.bonuses.value.bonuses.iter().map(|m| m.uuid),
+            "bonus",
+        );
+        check_unique_uuids(
+            &mut seen,
+            issues,
+            &self.random_events,
+            self.random_events.value.events.iter().map(|m| m.uuid),
+            "random event",
+        );
+        check_unique_uuids(
+            &mut seen,
+            issues,
+            &self.stories,
+            self.stories.value.stories.iter().map(|m| m.uuid),
+            "random event story",
+        );
+    }
+
+    /// 각 DB 안에서 id 는 유일해야 함
+    fn validate_unique_ids(&self, issues: &mut Issues) {
+        check_unique_ids(
+            issues,
+            &self.abnormalities,
+            self.abnormalities.value.items.iter().map(|m| m.id.as_str()),
+            "abnormality",
+        );
+        check_unique_ids(
+            issues,
+            &self.artifacts,
+            self.artifacts.value.items.iter().map(|m| m.id.as_str()),
+            "artifact",
+        );
+        check_unique_ids(
+            issues,
+            &self.equipments,
+            self.equipments.value.items.iter().map(|m| m.id.as_str()),
+            "equipment",
+        );
+        check_unique_ids(
+            issues,
+            &self.shops,
+            self.shops.value.shops.iter().map(|m| m.id.as_str()),
+            "shop",
+        );
+        check_unique_ids(
+            issues,
+            &self.bonuses,
+            self.bonuses.value.bonuses.iter().map(|m| m.id.as_str()),
+            "bonus",
+        );
+        check_unique_ids(
+            issues,
+            &self.random_events,
+            self.random_events
+                .value
+                .events
+                .iter()
+                .map(|m| m.id.as_str()),
+            "random event",
+        );
+        check_unique_ids(
+            issues,
+            &self.stories,
+            self.stories.value.stories.iter().map(|m| m.id.as_str()),
+            "random event story",
+        );
+    }
+
+    /// 환상체 skill_id → SkillDatabase
+    fn validate_abnormalities(&self, issues: &mut Issues) {
+        for meta in &self.abnormalities.value.items {
+            let Some(skill_id) = &meta.skill_id else {
+                continue;
+            };
+            if self.skills.value.get_by_id(skill_id).is_none() {
+                issues.push(
+                    &self.abnormalities,
+                    &quoted(skill_id),
+                    format!(
+                        "abnormality '{}' references unknown skill_id '{}'",
+                        meta.id, skill_id
+                    ),
+                );
+            }
+        }
+    }
+
+    /// 상점 visible_items → 아이템(환상체/장비/아티팩트)
+    fn validate_shops(&self, issues: &mut Issues) {
+        let items = self.item_uuids();
+        for shop in &self.shops.value.shops {
+            for uuid in &shop.visible_items {
+                if !items.contains(uuid) {
+                    issues.push(
+                        &self.shops,
+                        &quoted(uuid),
+                        format!("shop '{}' references unknown item {}", shop.id, uuid),
+                    );
+                }
+            }
+        }
+    }
+
+    /// RandomEventInnerMetadata → 실제 대상, event_type 과 종류 일치
+    fn validate_random_events(&self, issues: &mut Issues) {
+        for event in &self.random_events.value.events {
+            let (expected_type, target, exists) = match &event.inner_metadata {
+                RandomEventInnerMetadata::Shop(uuid) => (
+                    RandomEventType::Shop,
+                    uuid,
+                    self.shops.value.get_by_uuid(uuid).is_some(),
+                ),
+                RandomEventInnerMetadata::Bonus(uuid) => (
+                    RandomEventType::Bonus,
+                    uuid,
+                    self.bonuses.value.get_by_uuid(uuid).is_some(),
+                ),
+                RandomEventInnerMetadata::Suppress(uuid) => (
+                    RandomEventType::Suppress,
+                    uuid,
+                    self.abnormalities.value.get_by_uuid(uuid).is_some(),
+                ),
+                RandomEventInnerMetadata::Story(uuid) => (
+                    RandomEventType::Story,
+                    uuid,
+                    self.stories.value.get_by_uuid(uuid).is_some(),
+                ),
+            };
+
+            if !exists {
+                issues.push(
+                    &self.random_events,
+                    &quoted(target),
+                    format!(
+                        "random event '{}' inner_metadata references unknown {:?} target {}",
+                        event.id, expected_type, target
+                    ),
+                );
+            }
+            if event.event_type != expected_type {
+                issues.push(
+                    &self.random_events,
+                    &format!("id: {}", quoted(&event.id)),
+                    format!(
+                        "random event '{}' has event_type {:?} but inner_metadata is {:?}",
+                        event.id, event.event_type, expected_type
+                    ),
+                );
+            }
+        }
+    }
+
+    /// 스토리 노드 연결 / GrantItem 대상
+    fn validate_stories(&self, issues: &mut Issues) {
+        let items = self.item_uuids();
+        for story in &self.stories.value.stories {
+            let mut node_ids = HashSet::new();
+            for node in &story.nodes {
+                if !node_ids.insert(node.id.as_str()) {
+                    issues.push(
+                        &self.stories,
+                        &format!("id: {}", quoted(&story.id)),
+                        format!("story '{}' has duplicated node '{}'", story.id, node.id),
+                    );
+                }
+            }
+
+            if story.get_node(&story.start_node).is_none() {
+                issues.push(
+                    &self.stories,
+                    &format!("start_node: {}", quoted(&story.start_node)),
+                    format!(
+                        "story '{}' start_node '{}' does not exist",
+                        story.id, story.start_node
+                    ),
+                );
+            }
+
+            for node in &story.nodes {
+                for choice in &node.choices {
+                    let branches: Vec<&OutcomeBranch> = match &choice.outcome {
+                        ChoiceOutcome::Fixed(branch) => vec![branch],
+                        ChoiceOutcome::Chance(outcomes) => {
+                            if outcomes.iter().map(|o| o.weight).sum::<u32>() == 0 {
+                                issues.push(
+                                    &self.stories,
+                                    &format!("id: {}", quoted(&choice.id)),
+                                    format!(
+                                        "story '{}' choice '{}' has no positive weight",
+                                        story.id, choice.id
+                                    ),
+                                );
+                            }
+                            outcomes.iter().map(|o| &o.branch).collect()
+                        }
+                    };
+
+                    for branch in branches {
+                        if let Some(next) = &branch.next {
+                            if story.get_node(next).is_none() {
+                                issues.push(
+                                    &self.stories,
+                                    &format!("Some({})", quoted(next)),
+                                    format!(
+                                        "story '{}' choice '{}' leads to unknown node '{}'",
+                                        story.id, choice.id, next
+                                    ),
+                                );
+                            }
+                        }
+                        for effect in &branch.effects {
+                            if let OutcomeEffect::GrantItem(uuid) = effect {
+                                if !items.contains(uuid) {
+                                    issues.push(
+                                        &self.stories,
+                                        &quoted(uuid),
+                                        format!(
+                                            "story '{}' choice '{}' grants unknown item {}",
+                                            story.id, choice.id, uuid
+                                        ),
+                                    );
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// 이벤트 풀 uuid → 상점/보너스/랜덤 이벤트
+    fn validate_event_pools(&self, issues: &mut Issues) {
+        let pools = &self.event_pools.value;
+        for (name, pool) in [
+            ("dawn", &pools.dawn),
+            ("noon", &pools.noon),
+            ("dusk", &pools.dusk),
+            ("midnight", &pools.midnight),
+            ("white", &pools.white),
+        ] {
+            self.check_pool(issues, name, "shops", &pool.shops, |uuid| {
+                self.shops.value.get_by_uuid(uuid).is_some()
+            });
+            self.check_pool(issues, name, "bonuses", &pool.bonuses, |uuid| {
+                self.bonuses.value.get_by_uuid(uuid).is_some()
+            });
+            self.check_pool(issues, name, "random_events", &pool.random_events, |uuid| {
+                self.random_events.value.get_by_uuid(uuid).is_some()
+            });
+        }
+    }
+
+    fn check_pool(
+        &self,
+        issues: &mut Issues,
+        ordeal: &str,
+        kind: &str,
+        pool: &[WeightedEvent],
+        exists: impl Fn(&Uuid) -> bool,
+    ) {
+        for entry in pool {
+            if !exists(&entry.uuid) {
+                issues.push(
+                    &self.event_pools,
+                    &quoted(entry.uuid),
+                    format!("{}.{} references unknown uuid {}", ordeal, kind, entry.uuid),
+                );
+            }
+        }
+    }
+
+    /// PvE 인카운터 abnormality_id → 환상체
+    fn validate_pve(&self, issues: &mut Issues) {
+        let abnormalities = &self.abnormalities.value;
+        for encounter in &self.pve.value.encounters {
+            let unit_ids = encounter.units.iter().map(|u| &u.abnormality_id);
+            for abnormality_id in std::iter::once(&encounter.abnormality_id).chain(unit_ids) {
+                if abnormalities.get_by_id(abnormality_id).is_none() {
+                    issues.push(
+                        &self.pve,
+                        &quoted(abnormality_id),
+                        format!(
+                            "pve encounter '{}' references unknown abnormality_id '{}'",
+                            encounter.id, abnormality_id
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
+    /// ItemRegistry 에 등록될 UUID 목록
+    fn item_uuids(&self) -> HashSet<Uuid> {
+        self.abnormalities
+            .value
+            .items
+            .iter()
+            .map(|m| m.uuid)
+            .chain(self.artifacts.value.items.iter().map(|m| m.uuid))
+            .chain(self.equipments.value.items.iter().map(|m| m.uuid))
+            .collect()
+    }
+
+    fn into_game_data(self) -> GameDataBase {
+        GameDataBase::new(GameDataTables {
+            abnormality_data: Arc::new(self.abnormalities.value),
+            artifact_data: Arc::new(self.artifacts.value),
+            equipment_data: Arc::new(self.equipments.value),
+            shop_data: Arc::new(self.shops.value),
+            bonus_data: Arc::new(self.bonuses.value),
+            random_event_data: Arc::new(self.random_events.value),
+            random_event_story_data: Arc::new(self.stories.value),
+            pve_data: Arc::new(self.pve.value),
+            skill_data: Arc::new(self.skills.value),
+            event_pools: self.event_pools.value,
+        })
+    }
+}
+
+/// 한 DB 의 UUID 를 전역 seen 에 등록하며 중복 검사
+///
+/// 같은 파일 안의 중복이면 두 번째 등장 위치를, 다른 파일과의 중복이면 이 파일의 위치를 가리킨다.
+fn check_unique_uuids<T>(
+    seen: &mut HashMap<Uuid, &'static str>,
+    issues: &mut Issues,
+    loaded: &Loaded<T>,
+    uuids: impl Iterator<Item = Uuid>,
+    kind: &'static str,
+) {
+    let mut local: HashMap<Uuid, usize> = HashMap::new();
+    for uuid in uuids {
+        let count = local.entry(uuid).or_insert(0);
+        let nth = *count;
+        *count += 1;
+
+        if let Some(previous) = seen.insert(uuid, kind) {
+            issues.push_nth(
+                loaded,
+                &quoted(uuid),
+                nth,
+                format!(
+                    "duplicated uuid {} ({} already uses it as {})",
+                    uuid, kind, previous
+                ),
+            );
+        }
+    }
+}
+
+/// 한 DB 안의 id 중복 검사 (두 번째 등장 위치를 가리킨다)
+fn check_unique_ids<'a, T>(
+    issues: &mut Issues,
+    loaded: &Loaded<T>,
+    ids: impl Iterator<Item = &'a str>,
+    kind: &'static str,
+) {
+    let mut seen: HashMap<&str, usize> = HashMap::new();
+    for id in ids {
+        let count = seen.entry(id).or_insert(0);
+        if *count > 0 {
+            issues.push_nth(
+                loaded,
+                &format!("id: {}", quoted(id)),
+                *count,
+                format!("duplicated {} id '{}'", kind, id),
+            );
+        }
+        *count += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bundled_data_dir() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../game_resources/data")
+    }
+
+    /// 번들 데이터를 임시 디렉토리로 복사 (파일 변조 테스트용)
+    fn copy_bundled_data(name: &str) -> PathBuf {
+        fn copy_dir(from: &Path, to: &Path) {
+            std::fs::create_dir_all(to).unwrap();
+            for entry in std::fs::read_dir(from).unwrap() {
+                let path = entry.unwrap().path();
+                let dest = to.join(path.file_name().unwrap());
+                if path.is_dir() {
+                    copy_dir(&path, &dest);
+                } else {
+                    std::fs::copy(&path, &dest).unwrap();
+                }
+            }
+        }
+
+        let dir =
+            std::env::temp_dir().join(format!("game_core_loader_{}_{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        copy_dir(&bundled_data_dir(), &dir);
+        dir
+    }
+
+    fn replace_in(dir: &Path, relative: &str, from: &str, to: &str) {
+        let path = dir.join(relative);
+        let text = std::fs::read_to_string(&path).unwrap();
+        assert!(text.contains(from), "{} not found in {}", from, relative);
+        std::fs::write(&path, text.replacen(from, to, 1)).unwrap();
+    }
+
+    #[test]
+    fn test_bundled_data_loads_and_validates() {
+        let data =
+            GameDataBase::load_from_dir(bundled_data_dir()).unwrap_or_else(|e| panic!("{}", e));
+
+        assert!(!data.abnormality_data.items.is_empty());
+        assert!(!data.shop_data.shops.is_empty());
+
+        // Then: 랜덤 이벤트의 inner_metadata 는 모두 해석 가능
+        for event in &data.random_event_data.events {
+            assert!(event.inner_metadata.resolve(&data).is_ok(), "{}", event.id);
+        }
+    }
+
+    #[test]
+    fn test_reports_all_reference_issues_with_location() {
+        let dir = copy_bundled_data("refs");
+
+        // Given: 이벤트 풀에 없는 상점 uuid, 아티팩트와 중복된 장비 uuid
+        replace_in(
+            &dir,
+            EVENT_POOLS_FILE,
+            "550e8400-e29b-41d4-a716-446655440001",
+            "550e8400-e29b-41d4-a716-446655449999",
+        );
+        replace_in(
+            &dir,
+            EQUIPMENTS_FILE,
+            "650e8400-e29b-41d4-a716-446655440021",
+            "650e8400-e29b-41d4-a716-446655440011",
+        );
+
+        let err = match GameDataBase::load_from_dir(&dir) {
+            Ok(_) => panic!("expected validation errors"),
+            Err(e) => e,
+        };
+        let _ = std::fs::remove_dir_all(&dir);
+
+        // Then: 첫 번째 문제에서 멈추지 않고 모두 보고
+        let pool_issue = err
+            .issues
+            .iter()
+            .find(|i| i.file.ends_with(EVENT_POOLS_FILE))
+            .expect("event pool issue");
+        assert!(pool_issue.message.contains("446655449999"));
+        assert!(pool_issue.line.is_some());
+
+        let duplicate = err
+            .issues
+            .iter()
+            .find(|i| i.file.ends_with(EQUIPMENTS_FILE) && i.message.contains("duplicated uuid"))
+            .expect("duplicated uuid issue");
+        assert!(duplicate.line.is_some());
+
+        // Then: 장비가 사라졌으므로 justitia 를 파는 상점도 보고됨
+        assert!(err.issues.iter().any(|i| i.file.ends_with(SHOPS_FILE)));
+    }
+
+    #[test]
+    fn test_reports_parse_error_line() {
+        let dir = copy_bundled_data("parse");
+        replace_in(&dir, ARTIFACTS_FILE, "price: 100,", "price: ,");
+
+        let err = match GameDataBase::load_from_dir(&dir) {
+            Ok(_) => panic!("expected parse error"),
+            Err(e) => e,
+        };
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(err.issues.len(), 1);
+        assert!(err.issues[0].file.ends_with(ARTIFACTS_FILE));
+        assert!(err.issues[0].line.is_some());
+    }
+
+    #[test]
+    fn test_story_node_reference_checked() {
+        let dir = copy_bundled_data("story");
+        replace_in(
+            &dir,
+            RANDOM_EVENT_STORIES_FILE,
+            "next: Some(\"aftermath\")",
+            "next: Some(\"missing_node\")",
+        );
+
+        let err = match GameDataBase::load_from_dir(&dir) {
+            Ok(_) => panic!("expected validation errors"),
+            Err(e) => e,
+        };
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let issue = err
+            .issues
+            .iter()
+            .find(|i| i.message.contains("missing_node"))
+            .expect("story issue");
+        assert!(issue.file.ends_with(RANDOM_EVENT_STORIES_FILE));
+        assert_eq!(issue.line, Some(38));
+    }
+}
@@ -10,8 +10,9 @@ use crate::game::data::{
     event_pools::EventPoolConfig,
     pve_data::PveEncounterDatabase,
     random_event_data::RandomEventDatabase,
-    skill_data::SkillDatabase,
+    random_event_story_data::RandomEventStoryDatabase,
     shop_data::ShopDatabase,
+    skill_data::SkillDatabase,
 };
 
 // 환상체 (기물) 정보
@@ -29,12 +30,21 @@ pub mod equipment_data;
 // 이벤트 생성을 위한 pools
 pub mod event_pools;
 
+// 데이터 디렉토리 로더 (RON 로드 + 참조 검증)
+pub mod loader;
+
+// 버전 단위로 교체 가능한 데이터/밸런스 레지스트리 (핫 리로드)
+pub mod registry;
+
 // PvE 전투 데이터
 pub mod pve_data;
 
 // 랜덤 인카운트 이벤트 정보
 pub mod random_event_data;
 
+// 선택지형 랜덤 이벤트 (스토리 트리) 정보
+pub mod random_event_story_data;
+
 // 스킬 정보
 pub mod skill_data;
 
@@ -51,6 +61,7 @@ pub struct GameDataBase {
     pub shop_data: Arc<ShopDatabase>,
     pub bonus_data: Arc<BonusDatabase>,
     pub random_event_data: Arc<RandomEventDatabase>,
+    pub random_event_story_data: Arc<RandomEventStoryDatabase>,
 
     /// PvE 전투(Suppress) 데이터
     pub pve_data: Arc<PveEncounterDatabase>,
@@ -211,30 +222,39 @@ impl ItemRegistry {
     }
 }
 
+/// 파일별로 로드된 DB 묶음 (GameDataBase 생성 입력)
+pub struct GameDataTables {
+    pub abnormality_data: Arc<AbnormalityDatabase>,
+    pub artifact_data: Arc<ArtifactDatabase>,
+    pub equipment_data: Arc<EquipmentDatabase>,
+    pub shop_data: Arc<ShopDatabase>,
+    pub bonus_data: Arc<BonusDatabase>,
+    pub random_event_data: Arc<RandomEventDatabase>,
+    pub random_event_story_data: Arc<RandomEventStoryDatabase>,
+    pub pve_data: Arc<PveEncounterDatabase>,
+    pub skill_data: Arc<SkillDatabase>,
+    pub event_pools: EventPoolConfig,
+}
+
 impl GameDataBase {
-    pub fn new(
-        abnormality_data: Arc<AbnormalityDatabase>,
-        artifact_data: Arc<ArtifactDatabase>,
-        equipment_data: Arc<EquipmentDatabase>,
-        shop_data: Arc<ShopDatabase>,
-        bonus_data: Arc<BonusDatabase>,
-        random_event_data: Arc<RandomEventDatabase>,
-        pve_data: Arc<PveEncounterDatabase>,
-        skill_data: Arc<SkillDatabase>,
-        event_pools: EventPoolConfig,
-    ) -> Self {
-        let item_registry = ItemRegistry::new(&abnormality_data, &artifact_data, &equipment_data);
+    pub fn new(tables: GameDataTables) -> Self {
+        let item_registry = ItemRegistry::new(
+            &tables.abnormality_data,
+            &tables.artifact_data,
+            &tables.equipment_data,
+        );
 
         Self {
-            abnormality_data,
-            artifact_data,
-            equipment_data,
-            shop_data,
-            bonus_data,
-            random_event_data,
-            pve_data,
-            skill_data,
-            event_pools,
+            abnormality_data: tables.abnormality_data,
+            artifact_data: tables.artifact_data,
+            equipment_data: tables.equipment_data,
+            shop_data: tables.shop_data,
+            bonus_data: tables.bonus_data,
+            random_event_data: tables.random_event_data,
+            random_event_story_data: tables.random_event_story_data,
+            pve_data: tables.pve_data,
+            skill_data: tables.skill_data,
+            event_pools: tables.event_pools,
             item_registry,
         }
     }
@@ -5,8 +5,8 @@ use uuid::Uuid;
 
 use crate::game::{
     data::{
-        abnormality_data::AbnormalityMetadata, bonus_data::BonusMetadata, shop_data::ShopMetadata,
-        GameDataBase,
+        abnormality_data::AbnormalityMetadata, bonus_data::BonusMetadata,
+        random_event_story_data::RandomEventStory, shop_data::ShopMetadata, GameDataBase,
     },
     enums::RiskLevel,
     events::event_selection::random::RandomEventType,
@@ -17,6 +17,8 @@ pub enum RandomEventInnerMetadata {
     Shop(Uuid),
     Bonus(Uuid),
     Suppress(Uuid),
+    /// 선택지형 이벤트 (RandomEventStoryDatabase uuid)
+    Story(Uuid),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -37,6 +39,7 @@ pub enum RandomEventTarget<'a> {
     Shop(&'a ShopMetadata),
     Bonus(&'a BonusMetadata),
     Suppress(&'a AbnormalityMetadata),
+    Story(&'a RandomEventStory),
 }
 
 impl RandomEventInnerMetadata {
@@ -69,6 +72,13 @@ impl RandomEventInnerMetadata {
                     .ok_or(GameError::EventNotFound)?;
                 Ok(RandomEventTarget::Suppress(abnormality))
             }
+            RandomEventInnerMetadata::Story(uuid) => {
+                let story = data
+                    .random_event_story_data
+                    .get_by_uuid(uuid)
+                    .ok_or(GameError::EventNotFound)?;
+                Ok(RandomEventTarget::Story(story))
+            }
         }
     }
 }
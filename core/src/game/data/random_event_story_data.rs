@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::ecs::resources::InventoryDiffDto;
+
+/// 선택지를 고르기 위해 지불해야 하는 비용
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ChoiceCost {
+    /// 엔케팔린 소모 (부족하면 선택 불가)
+    Enkephalin(u32),
+    /// 보유 환상체 전원의 최대 체력 감소
+    Health(u32),
+}
+
+/// 선택 결과로 적용되는 효과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum OutcomeEffect {
+    /// 엔케팔린 획득
+    GainEnkephalin(u32),
+    /// 엔케팔린 손실 (0 미만으로 내려가지 않음)
+    LoseEnkephalin(u32),
+    /// 보유 환상체 전원의 최대 체력 증가
+    GainHealth(u32),
+    /// 보유 환상체 전원의 최대 체력 감소
+    LoseHealth(u32),
+    /// 아이템 지급 (ItemRegistry uuid)
+    GrantItem(Uuid),
+    /// 클리포트 증감 (양수: 회복, 음수: 감소)
+    ShiftQliphoth(i32),
+}
+
+/// 선택 결과 하나 (효과 + 다음 노드)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutcomeBranch {
+    pub text: String,
+    #[serde(default)]
+    pub effects: Vec<OutcomeEffect>,
+    /// 다음 노드 ID. None 이면 이벤트 종료
+    #[serde(default)]
+    pub next: Option<String>,
+}
+
+/// 확률 분기용 가중치 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeightedOutcome {
+    pub weight: u32,
+    pub branch: OutcomeBranch,
+}
+
+/// 선택지의 결과 결정 방식
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ChoiceOutcome {
+    /// 항상 같은 결과
+    Fixed(OutcomeBranch),
+    /// seed 기반 가중치 추첨
+    Chance(Vec<WeightedOutcome>),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoryChoice {
+    pub id: String,
+    pub text: String,
+    #[serde(default)]
+    pub costs: Vec<ChoiceCost>,
+    pub outcome: ChoiceOutcome,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoryNode {
+    pub id: String,
+    pub text: String,
+    pub choices: Vec<StoryChoice>,
+}
+
+impl StoryNode {
+    pub fn get_choice(&self, choice_id: &str) -> Option<&StoryChoice> {
+        self.choices.iter().find(|c| c.id == choice_id)
+    }
+}
+
+/// 선택지 트리로 구성된 랜덤 이벤트
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RandomEventStory {
+    pub id: String,
+    pub uuid: Uuid,
+    pub start_node: String,
+    pub nodes: Vec<StoryNode>,
+}
+
+impl RandomEventStory {
+    pub fn get_node(&self, node_id: &str) -> Option<&StoryNode> {
+        self.nodes.iter().find(|n| n.id == node_id)
+    }
+}
+
+/// RON 파일 최상위 구조체
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RandomEventStoryDatabase {
+    pub stories: Vec<RandomEventStory>,
+
+    #[serde(skip)]
+    story_map: HashMap<Uuid, RandomEventStory>,
+}
+
+impl RandomEventStoryDatabase {
+    /// Database 생성 (HashMap 초기화)
+    pub fn new(stories: Vec<RandomEventStory>) -> Self {
+        let story_map = stories.iter().map(|s| (s.uuid, s.clone())).collect();
+
+        Self { stories, story_map }
+    }
+
+    /// RON 역직렬화 후 HashMap 초기화
+    pub fn init_map(&mut self) {
+        self.story_map = self.stories.iter().map(|s| (s.uuid, s.clone())).collect();
+    }
+
+    pub fn get_by_id(&self, id: &str) -> Option<&RandomEventStory> {
+        self.stories.iter().find(|s| s.id == id)
+    }
+
+    pub fn get_by_uuid(&self, uuid: &Uuid) -> Option<&RandomEventStory> {
+        self.story_map.get(uuid)
+    }
+}
+
+// ============================================================
+// 클라이언트 전달용 DTO (결과/확률은 노출하지 않음)
+// ============================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoryChoiceDto {
+    pub id: String,
+    pub text: String,
+    pub costs: Vec<ChoiceCost>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoryNodeDto {
+    pub story_uuid: Uuid,
+    pub node_id: String,
+    pub text: String,
+    pub choices: Vec<StoryChoiceDto>,
+}
+
+impl StoryNodeDto {
+    pub fn from_node(story_uuid: Uuid, node: &StoryNode) -> Self {
+        Self {
+            story_uuid,
+            node_id: node.id.clone(),
+            text: node.text.clone(),
+            choices: node
+                .choices
+                .iter()
+                .map(|c| StoryChoiceDto {
+                    id: c.id.clone(),
+                    text: c.text.clone(),
+                    costs: c.costs.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// 선택지 하나를 처리한 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoryOutcomeDto {
+    pub choice_id: String,
+    pub text: String,
+    /// 처리 후 남은 엔케팔린
+    pub enkephalin: u32,
+    /// 처리 후 클리포트 양
+    pub qliphoth: u32,
+    pub inventory_diff: InventoryDiffDto,
+}
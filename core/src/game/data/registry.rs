@@ -0,0 +1,331 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
+    time::SystemTime,
+};
+
+use sha2::{Digest, Sha256};
+use tracing::info;
+
+use crate::{
+    config::GameBalanceConfig,
+    game::data::{
+        loader::{DataIssue, DataLoadError},
+        GameDataBase,
+    },
+};
+
+// ============================================================
+// 데이터 버전
+// ============================================================
+
+/// 게임 데이터 + 밸런스 설정 한 벌
+///
+/// GameCore 는 생성 시점의 DataVersion 을 Arc 로 잡고 런이 끝날 때까지 사용한다.
+/// 레지스트리가 새 버전을 게시해도 기존 Arc 는 그대로 유지된다.
+pub struct DataVersion {
+    /// 레지스트리 안에서 단조 증가하는 버전 ID (0 = 레지스트리 밖에서 만든 버전)
+    pub id: u64,
+    /// 데이터 디렉토리 + 밸런스 파일 내용의 SHA-256 (hex)
+    ///
+    /// ID 는 pod 마다 따로 증가하므로, 다른 pod 에서 같은 데이터인지 비교할 때는 이 값을 쓴다.
+    /// 레지스트리 밖에서 만든 버전은 빈 문자열.
+    pub revision: String,
+    pub data: Arc<GameDataBase>,
+    pub balance: Arc<GameBalanceConfig>,
+    pub loaded_at: SystemTime,
+}
+
+impl DataVersion {
+    /// 레지스트리 없이 만든 버전 (테스트, 단독 실행용)
+    pub fn unversioned(data: Arc<GameDataBase>, balance: Arc<GameBalanceConfig>) -> Self {
+        Self {
+            id: 0,
+            revision: String::new(),
+            data,
+            balance,
+            loaded_at: SystemTime::now(),
+        }
+    }
+}
+
+// ============================================================
+// 레지스트리
+// ============================================================
+
+/// 런타임에 교체 가능한 데이터 레지스트리
+///
+/// - `current()`: 새로 시작하는 런이 사용할 최신 버전
+/// - `reload()`: 디스크에서 다시 로드 후 검증에 성공하면 원자적으로 교체
+///
+/// 로드/검증에 실패하면 기존 버전을 그대로 유지한다.
+pub struct DataRegistry {
+    data_dir: PathBuf,
+    /// None 이면 GameBalanceConfig::find_config_file 로 탐색
+    balance_path: Option<PathBuf>,
+    current: RwLock<Arc<DataVersion>>,
+}
+
+impl DataRegistry {
+    /// 최초 로드 (버전 1)
+    pub fn load(
+        data_dir: impl Into<PathBuf>,
+        balance_path: Option<PathBuf>,
+    ) -> Result<Self, DataLoadError> {
+        let data_dir = data_dir.into();
+        let (data, balance, revision) = Self::load_sources(&data_dir, balance_path.as_deref())?;
+
+        let version = Arc::new(DataVersion {
+            id: 1,
+            revision,
+            data: Arc::new(data),
+            balance: Arc::new(balance),
+            loaded_at: SystemTime::now(),
+        });
+        GameBalanceConfig::set_global(version.balance.clone());
+        info!(
+            "Data registry initialized: version={} revision={}",
+            version.id, version.revision
+        );
+
+        Ok(Self {
+            data_dir,
+            balance_path,
+            current: RwLock::new(version),
+        })
+    }
+
+    pub fn data_dir(&self) -> &Path {
+        &self.data_dir
+    }
+
+    /// 최신 버전
+    pub fn current(&self) -> Arc<DataVersion> {
+        self.current
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// 최신 버전 ID
+    pub fn current_id(&self) -> u64 {
+        self.current().id
+    }
+
+    /// 디스크에서 다시 로드해서 새 버전 게시
+    pub fn reload(&self) -> Result<Arc<DataVersion>, DataLoadError> {
+        let (data, balance, revision) =
+            Self::load_sources(&self.data_dir, self.balance_path.as_deref())?;
+        Ok(self.publish(Arc::new(data), Arc::new(balance), revision))
+    }
+
+    /// 이미 검증된 데이터를 새 버전으로 게시
+    ///
+    /// 전역 밸런스 설정도 함께 교체된다 (pin 되지 않은 코드가 읽는 값).
+    pub fn publish(
+        &self,
+        data: Arc<GameDataBase>,
+        balance: Arc<GameBalanceConfig>,
+        revision: String,
+    ) -> Arc<DataVersion> {
+        let mut current = self.current.write().unwrap_or_else(|e| e.into_inner());
+
+        let version = Arc::new(DataVersion {
+            id: current.id + 1,
+            revision,
+            data,
+            balance,
+            loaded_at: SystemTime::now(),
+        });
+        GameBalanceConfig::set_global(version.balance.clone());
+        *current = version.clone();
+
+        info!(
+            "Data registry published: version={} revision={}",
+            version.id, version.revision
+        );
+        version
+    }
+
+    /// 데이터 디렉토리/밸런스 파일 중 가장 최근 수정 시각
+    ///
+    /// 파일 감시(polling)에서 변경 여부 판단에 사용
+    pub fn latest_modified(&self) -> Option<SystemTime> {
+        let mut latest = latest_modified_in(&self.data_dir);
+        if let Some(path) = self.resolve_balance_path() {
+            let modified = std::fs::metadata(path).and_then(|m| m.modified()).ok();
+            latest = latest.max(modified);
+        }
+        latest
+    }
+
+    fn resolve_balance_path(&self) -> Option<PathBuf> {
+        self.balance_path
+            .clone()
+            .or_else(|| GameBalanceConfig::find_config_file().ok())
+    }
+
+    fn load_sources(
+        data_dir: &Path,
+        balance_path: Option<&Path>,
+    ) -> Result<(GameDataBase, GameBalanceConfig, String), DataLoadError> {
+        let data = GameDataBase::load_from_dir(data_dir)?;
+
+        let balance_path = match balance_path {
+            Some(path) => Some(path.to_path_buf()),
+            None => GameBalanceConfig::find_config_file().ok(),
+        };
+        let balance = match &balance_path {
+            Some(path) => GameBalanceConfig::load_from(path).map_err(|e| DataLoadError {
+                issues: vec![DataIssue {
+                    file: path.clone(),
+                    line: None,
+                    message: e.to_string(),
+                }],
+            })?,
+            // 밸런스 파일이 없으면 현재 전역 설정 유지
+            None => GameBalanceConfig::global().as_ref().clone(),
+        };
+
+        let revision = content_revision(data_dir, balance_path.as_deref());
+        Ok((data, balance, revision))
+    }
+}
+
+/// 데이터 디렉토리 파일(상대 경로 순) + 밸런스 파일 내용의 SHA-256
+///
+/// 경로도 함께 해시하므로 파일 이름만 바뀌어도 다른 revision 이 된다.
+fn content_revision(data_dir: &Path, balance_path: Option<&Path>) -> String {
+    let mut files = Vec::new();
+    collect_files(data_dir, &mut files);
+    files.sort();
+
+    let mut hasher = Sha256::new();
+    for path in &files {
+        let relative = path.strip_prefix(data_dir).unwrap_or(path);
+        hasher.update(relative.to_string_lossy().as_bytes());
+        hasher.update([0]);
+        hasher.update(std::fs::read(path).unwrap_or_default());
+        hasher.update([0]);
+    }
+    if let Some(path) = balance_path {
+        hasher.update(b"balance\0");
+        hasher.update(std::fs::read(path).unwrap_or_default());
+    }
+    hex::encode(hasher.finalize())
+}
+
+/// 디렉토리 하위 파일 경로 (재귀)
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_files(&path, files);
+        } else {
+            files.push(path);
+        }
+    }
+}
+
+/// 디렉토리 하위 파일들의 최근 수정 시각 (재귀)
+fn latest_modified_in(dir: &Path) -> Option<SystemTime> {
+    let mut latest = None;
+    for entry in std::fs::read_dir(dir).ok()?.flatten() {
+        let path = entry.path();
+        let modified = if path.is_dir() {
+            latest_modified_in(&path)
+        } else {
+            entry.metadata().and_then(|m| m.modified()).ok()
+        };
+        latest = latest.max(modified);
+    }
+    latest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::balance;
+
+    fn bundled_data_dir() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../game_resources/data")
+    }
+
+    fn bundled_balance_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("config/game_balance.toml")
+    }
+
+    #[test]
+    fn test_reload_publishes_new_version_and_keeps_old() {
+        let registry =
+            DataRegistry::load(bundled_data_dir(), Some(bundled_balance_path())).unwrap();
+        let pinned = registry.current();
+        assert_eq!(pinned.id, 1);
+
+        let reloaded = registry.reload().unwrap();
+
+        // Then: 새 버전이 게시되고, 기존 Arc 는 그대로 유효
+        assert_eq!(reloaded.id, 2);
+        assert_eq!(registry.current_id(), 2);
+        assert_eq!(pinned.id, 1);
+        assert!(!Arc::ptr_eq(&pinned.data, &reloaded.data));
+        // 파일이 그대로면 revision 도 같음
+        assert_eq!(pinned.revision.len(), 64);
+        assert_eq!(pinned.revision, reloaded.revision);
+        assert!(registry.latest_modified().is_some());
+    }
+
+    #[test]
+    fn test_revision_changes_with_file_contents() {
+        let balance_path =
+            std::env::temp_dir().join(format!("game_balance_{}.toml", uuid::Uuid::new_v4()));
+        std::fs::copy(bundled_balance_path(), &balance_path).unwrap();
+
+        let registry = DataRegistry::load(bundled_data_dir(), Some(balance_path.clone())).unwrap();
+        let before = registry.current();
+
+        let mut contents = std::fs::read_to_string(&balance_path).unwrap();
+        contents.push_str("\n# edited\n");
+        std::fs::write(&balance_path, contents).unwrap();
+        let after = registry.reload().unwrap();
+        std::fs::remove_file(&balance_path).ok();
+
+        // Then: 내용이 바뀌면 ID 와 함께 revision 도 바뀜
+        assert_eq!(after.id, before.id + 1);
+        assert_ne!(after.revision, before.revision);
+    }
+
+    #[test]
+    fn test_failed_reload_keeps_current_version() {
+        let registry =
+            DataRegistry::load(bundled_data_dir(), Some(bundled_balance_path())).unwrap();
+        let broken = DataRegistry {
+            data_dir: registry.data_dir.join("missing"),
+            balance_path: registry.balance_path.clone(),
+            current: RwLock::new(registry.current()),
+        };
+
+        assert!(broken.reload().is_err());
+        assert_eq!(broken.current_id(), 1);
+    }
+
+    #[test]
+    fn test_pinned_balance_overrides_global() {
+        let mut config = GameBalanceConfig::default();
+        config.run.max_ordeal_losses = 7;
+
+        {
+            let _pin = GameBalanceConfig::pin(Arc::new(config));
+            assert_eq!(balance::run().max_ordeal_losses, 7);
+        }
+
+        // Then: 가드가 drop 되면 전역 설정으로 복원
+        assert_eq!(
+            balance::run().max_ordeal_losses,
+            GameBalanceConfig::global().run.max_ordeal_losses
+        );
+    }
+}
@@ -30,6 +30,12 @@ pub fn seed_for_phase(run_seed: u64, ordeal: OrdealType, phase: PhaseType) -> u6
     splitmix64(run_seed ^ tag.wrapping_mul(0xD1B5_4A32_D192_ED03))
 }
 
+/// 랜덤 이벤트 내부 선택 단계(step)별 seed.
+/// 같은 Phase 안에서도 선택 순서마다 독립적인 스트림을 보장한다.
+pub fn seed_for_event_step(phase_seed: u64, step: u32) -> u64 {
+    splitmix64(phase_seed ^ (step as u64 + 1).wrapping_mul(0xA076_1D64_78BD_642F))
+}
+
 pub fn uuid_v4_from_seed(seed: u64, namespace: u64, index: u64) -> Uuid {
     let hi = splitmix64(seed ^ namespace);
     let lo = splitmix64(seed ^ namespace.rotate_left(17) ^ index);
@@ -53,6 +59,19 @@ mod tests {
         assert_ne!(s1, s2);
     }
 
+    #[test]
+    fn seed_for_event_step_changes_across_steps() {
+        let phase_seed = seed_for_phase(123, OrdealType::Dawn, PhaseType::I);
+        assert_eq!(
+            seed_for_event_step(phase_seed, 0),
+            seed_for_event_step(phase_seed, 0)
+        );
+        assert_ne!(
+            seed_for_event_step(phase_seed, 0),
+            seed_for_event_step(phase_seed, 1)
+        );
+    }
+
     #[test]
     fn uuid_v4_from_seed_is_deterministic() {
         let a = uuid_v4_from_seed(123, 0x5355_5052, 0);
@@ -1,8 +1,11 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::game::data::{
-    bonus_data::BonusMetadata, random_event_data::RandomEventMetadata, shop_data::ShopMetadata,
+use crate::game::{
+    battle::types::BattleWinner,
+    data::{
+        bonus_data::BonusMetadata, random_event_data::RandomEventMetadata, shop_data::ShopMetadata,
+    },
 };
 
 pub trait MoveTo {
@@ -166,6 +169,94 @@ pub enum Side {
     Player,
 }
 
+// ============================================================
+// 보상 관련 Enums
+// ============================================================
+
+/// 보상 테이블 조회용 전투 종류
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum RewardEventKind {
+    /// 진압 작업 (PvE)
+    Suppression,
+    /// 시련 전투 (PvP)
+    Ordeal,
+    /// 백야 (클리포트 붕괴 시 강제 Breach)
+    WhiteNights,
+}
+
+/// 보상 테이블 조회용 전투 결과
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum RewardOutcome {
+    Win,
+    Loss,
+}
+
+impl From<BattleWinner> for RewardOutcome {
+    /// 무승부는 패배로 취급
+    fn from(winner: BattleWinner) -> Self {
+        match winner {
+            BattleWinner::Player => Self::Win,
+            BattleWinner::Opponent | BattleWinner::Draw => Self::Loss,
+        }
+    }
+}
+
+// ============================================================
+// 런(Run) 관련 Enums
+// ============================================================
+
+/// 런 종료 사유
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum RunEndReason {
+    /// 백색 시련 마지막 Phase 클리어
+    Cleared,
+    /// 시련 패배 횟수 한도 도달
+    OrdealLossLimit,
+    /// 클리포트 붕괴 상태에서 패배
+    Meltdown,
+}
+
+impl RunEndReason {
+    /// 승리로 끝난 런인지 여부
+    pub fn is_victory(&self) -> bool {
+        matches!(self, Self::Cleared)
+    }
+}
+
+/// 엔케팔린 획득처 / 소비처
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum EnkephalinFlow {
+    /// 전투 보상 / 패배 페널티
+    Battle,
+    ShopPurchase,
+    ShopSell,
+    Bonus,
+    /// 랜덤 이벤트 선택지 효과
+    RandomEvent,
+}
+
+/// Phase 에서 선택된 이벤트 종류 (런 기록용)
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum PhaseEventKind {
+    Shop,
+    Bonus,
+    Random,
+    Suppression,
+    Ordeal,
+}
+
+impl From<&GameOption> for PhaseEventKind {
+    fn from(option: &GameOption) -> Self {
+        match option {
+            GameOption::Shop { .. } => Self::Shop,
+            GameOption::Bonus { .. } => Self::Bonus,
+            GameOption::Random { .. } => Self::Random,
+            GameOption::SuppressAbnormality { .. } => Self::Suppression,
+            GameOption::OrdealBattle { .. } => Self::Ordeal,
+        }
+    }
+}
+
 // ============================================================
 // GameOption
 // ============================================================
@@ -3,14 +3,14 @@ use tracing::{debug, info, warn};
 use uuid::Uuid;
 
 use crate::{
-    ecs::resources::Enkephalin,
+    ecs::resources::{Enkephalin, RunRecord},
     game::{
         behavior::GameError,
         data::{
             bonus_data::{BonusMetadata, BonusType},
             event_pools::EventPhasePool,
         },
-        enums::GameOption,
+        enums::{EnkephalinFlow, GameOption},
         events::{EventGenerator, GeneratorContext},
     },
 };
@@ -120,6 +120,7 @@ impl BonusExecutor {
                     "Granted Enkephalin bonus: amount={}, new_total={}",
                     amount, enkephalin.amount
                 );
+                RunRecord::track_gain(world, EnkephalinFlow::Bonus, amount);
             }
             BonusType::Experience => {
                 // TODO: 경험치 추가
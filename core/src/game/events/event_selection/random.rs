@@ -1,13 +1,31 @@
+use bevy_ecs::world::World;
+use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
+use tracing::{debug, info, warn};
 use uuid::Uuid;
 
-use crate::game::{
-    data::{
-        event_pools::EventPhasePool,
-        random_event_data::{RandomEventInnerMetadata, RandomEventMetadata},
+use crate::{
+    ecs::resources::{
+        AbnormalityItemDto, Enkephalin, Inventory, InventoryDiffDto, InventoryItemDto, Qliphoth,
+        RandomEventSession, RunRecord,
+    },
+    game::{
+        behavior::GameError,
+        data::{
+            event_pools::EventPhasePool,
+            random_event_data::{RandomEventInnerMetadata, RandomEventMetadata},
+            random_event_story_data::{
+                ChoiceCost, ChoiceOutcome, OutcomeBranch, OutcomeEffect, RandomEventStory,
+                StoryNodeDto, StoryOutcomeDto,
+            },
+            Item, ItemRegistry,
+        },
+        determinism,
+        enums::{EnkephalinFlow, GameOption, RiskLevel},
+        events::{EventGenerator, GeneratorContext},
+        growth::GrowthId,
+        managers::{qliphoth_manager::QliphothManager, uuid_manager::UuidManager},
     },
-    enums::{GameOption, RiskLevel},
-    events::{EventGenerator, GeneratorContext},
 };
 
 /// 랜덤 이벤트로 발생 가능한 이벤트 유형
@@ -16,6 +34,7 @@ pub enum RandomEventType {
     Shop,
     Bonus,
     Suppress,
+    Story,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -71,7 +90,6 @@ impl EventGenerator for RandomEventGenerator {
     fn generate(&self, ctx: &GeneratorContext) -> Self::Output {
         use crate::ecs::resources::GameProgression;
         use crate::game::enums::OrdealType;
-        use rand::SeedableRng;
 
         // 1. 현재 Ordeal 가져오기
         let current_ordeal = ctx
@@ -134,3 +152,648 @@ impl EventGenerator for RandomEventGenerator {
         GameOption::Random { event }
     }
 }
+
+/// 선택지형 랜덤 이벤트 비즈니스 로직 헬퍼
+pub struct RandomEventExecutor;
+
+impl RandomEventExecutor {
+    /// 이벤트 진입: 세션을 생성하고 시작 노드를 반환
+    pub fn start(world: &mut World, story: &RandomEventStory) -> Result<StoryNodeDto, GameError> {
+        let node = story.get_node(&story.start_node).ok_or_else(|| {
+            warn!(
+                "Story '{}' has no start node '{}'",
+                story.id, story.start_node
+            );
+            GameError::EventNotFound
+        })?;
+
+        world.insert_resource(RandomEventSession::new(story.uuid, node.id.clone()));
+        info!(
+            "Entered random event story: id={}, uuid={}",
+            story.id, story.uuid
+        );
+
+        Ok(StoryNodeDto::from_node(story.uuid, node))
+    }
+
+    /// 선택지 처리
+    ///
+    /// # Arguments
+    /// * `world` - ECS World (Enkephalin, Inventory, Qliphoth 등 접근)
+    /// * `story` - 현재 진행 중인 이벤트 데이터
+    /// * `item_registry` - 아이템 지급 시 메타데이터 조회용
+    /// * `phase_seed` - 현재 Phase 의 seed (확률 분기 결정성 보장)
+    /// * `choice_id` - 선택한 선택지 ID
+    ///
+    /// # Returns
+    /// (선택 결과, 다음 노드). 다음 노드가 None 이면 이벤트 종료
+    pub fn select_choice(
+        world: &mut World,
+        story: &RandomEventStory,
+        item_registry: &ItemRegistry,
+        phase_seed: u64,
+        choice_id: &str,
+    ) -> Result<(StoryOutcomeDto, Option<StoryNodeDto>), GameError> {
+        // ============================================================
+        // 1단계: 검증
+        // ============================================================
+
+        // 1-1. 세션/노드/선택지 조회
+        let (node_id, step) = {
+            let session = world
+                .get_resource::<RandomEventSession>()
+                .ok_or(GameError::NotInRandomEventState)?;
+            if session.story_uuid != story.uuid {
+                warn!(
+                    "Story mismatch: session={}, requested={}",
+                    session.story_uuid, story.uuid
+                );
+                return Err(GameError::EventTypeMismatch);
+            }
+            (session.current_node.clone(), session.step)
+        };
+
+        let node = story.get_node(&node_id).ok_or(GameError::EventNotFound)?;
+        let choice = node.get_choice(choice_id).ok_or_else(|| {
+            warn!("Choice '{}' not found in node '{}'", choice_id, node.id);
+            GameError::EventChoiceNotFound
+        })?;
+
+        // 1-2. 비용 지불 가능 여부 확인
+        Self::validate_costs(world, &choice.costs)?;
+
+        // 1-3. 결과 결정 (seed 기반)
+        let step_seed = determinism::seed_for_event_step(phase_seed, step);
+        let branch = Self::resolve_outcome(&choice.outcome, step_seed)?;
+
+        // 1-4. 지급 아이템 검증 (전부 지급 가능할 때만 진행)
+        let granted_items = Self::validate_grants(world, item_registry, &branch.effects)?;
+
+        // ============================================================
+        // 2단계: 실행 (모든 검증 통과 후)
+        // ============================================================
+        let mut inventory_diff = InventoryDiffDto::default();
+
+        // 2-1. 비용 지불
+        for cost in &choice.costs {
+            match cost {
+                ChoiceCost::Enkephalin(amount) => {
+                    Self::change_enkephalin(world, -(*amount as i64))?
+                }
+                ChoiceCost::Health(amount) => {
+                    Self::change_health(world, -(*amount as i32), &mut inventory_diff)?
+                }
+            }
+        }
+
+        // 2-2. 효과 적용
+        let mut granted_items = granted_items.into_iter();
+        for effect in &branch.effects {
+            match effect {
+                OutcomeEffect::GainEnkephalin(amount) => {
+                    Self::change_enkephalin(world, *amount as i64)?
+                }
+                OutcomeEffect::LoseEnkephalin(amount) => {
+                    Self::change_enkephalin(world, -(*amount as i64))?
+                }
+                OutcomeEffect::GainHealth(amount) => {
+                    Self::change_health(world, *amount as i32, &mut inventory_diff)?
+                }
+                OutcomeEffect::LoseHealth(amount) => {
+                    Self::change_health(world, -(*amount as i32), &mut inventory_diff)?
+                }
+                OutcomeEffect::GrantItem(_) => {
+                    let item = granted_items
+                        .next()
+                        .ok_or(GameError::MissingResource("GrantedItem"))?;
+                    let dto = Self::grant_item(world, item)?;
+                    inventory_diff.added.push(dto);
+                }
+                OutcomeEffect::ShiftQliphoth(delta) => {
+                    let mut qliphoth = world
+                        .get_resource_mut::<Qliphoth>()
+                        .ok_or(GameError::MissingResource("Qliphoth"))?;
+                    QliphothManager::apply_event_shift(&mut qliphoth, *delta);
+                }
+            }
+        }
+
+        // 2-3. 다음 노드로 이동
+        let next_node = match &branch.next {
+            Some(next_id) => Some(story.get_node(next_id).ok_or_else(|| {
+                warn!("Story '{}' references missing node '{}'", story.id, next_id);
+                GameError::EventNotFound
+            })?),
+            None => None,
+        };
+
+        {
+            let mut session = world
+                .get_resource_mut::<RandomEventSession>()
+                .ok_or(GameError::NotInRandomEventState)?;
+            session.step += 1;
+            if let Some(next) = next_node {
+                session.current_node = next.id.clone();
+            }
+        }
+
+        let enkephalin = world
+            .get_resource::<Enkephalin>()
+            .map(|e| e.amount)
+            .ok_or(GameError::MissingResource("Enkephalin"))?;
+        let qliphoth = world
+            .get_resource::<Qliphoth>()
+            .map(|q| q.amount())
+            .ok_or(GameError::MissingResource("Qliphoth"))?;
+
+        info!(
+            "Story '{}' choice '{}' resolved (step={}, next={:?})",
+            story.id, choice.id, step, branch.next
+        );
+
+        let outcome = StoryOutcomeDto {
+            choice_id: choice.id.clone(),
+            text: branch.text.clone(),
+            enkephalin,
+            qliphoth,
+            inventory_diff,
+        };
+
+        Ok((
+            outcome,
+            next_node.map(|node| StoryNodeDto::from_node(story.uuid, node)),
+        ))
+    }
+
+    fn validate_costs(world: &World, costs: &[ChoiceCost]) -> Result<(), GameError> {
+        for cost in costs {
+            match cost {
+                ChoiceCost::Enkephalin(amount) => {
+                    let enkephalin = world
+                        .get_resource::<Enkephalin>()
+                        .ok_or(GameError::MissingResource("Enkephalin"))?;
+                    if enkephalin.amount < *amount {
+                        warn!(
+                            "Insufficient Enkephalin for choice: have={}, cost={}",
+                            enkephalin.amount, amount
+                        );
+                        return Err(GameError::InsufficientResources);
+                    }
+                }
+                ChoiceCost::Health(amount) => {
+                    // HP 비용은 보유 환상체 전원이 감당할 수 있어야 함 (최대 체력 1 이상 유지)
+                    let inventory = world
+                        .get_resource::<Inventory>()
+                        .ok_or(GameError::MissingResource("Inventory"))?;
+                    if inventory.abnormalities.is_empty() {
+                        warn!("Health cost requested but no abnormality is owned");
+                        return Err(GameError::InsufficientResources);
+                    }
+                    let affordable = inventory
+                        .abnormalities
+                        .iter_owned()
+                        .all(|owned| owned.max_health() > *amount);
+                    if !affordable {
+                        warn!("Insufficient health for choice: cost={}", amount);
+                        return Err(GameError::InsufficientResources);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn resolve_outcome(
+        outcome: &ChoiceOutcome,
+        step_seed: u64,
+    ) -> Result<&OutcomeBranch, GameError> {
+        match outcome {
+            ChoiceOutcome::Fixed(branch) => Ok(branch),
+            ChoiceOutcome::Chance(candidates) => {
+                let total_weight: u32 = candidates.iter().map(|c| c.weight).sum();
+                if total_weight == 0 {
+                    return Err(GameError::EventNotFound);
+                }
+
+                let mut rng = rand::rngs::StdRng::seed_from_u64(step_seed);
+                let roll = rng.gen_range(0..total_weight);
+                let mut accumulated = 0;
+                for candidate in candidates {
+                    accumulated += candidate.weight;
+                    if roll < accumulated {
+                        debug!("Chance outcome rolled {} / {}", roll, total_weight);
+                        return Ok(&candidate.branch);
+                    }
+                }
+
+                candidates
+                    .last()
+                    .map(|c| &c.branch)
+                    .ok_or(GameError::EventNotFound)
+            }
+        }
+    }
+
+    fn validate_grants(
+        world: &World,
+        item_registry: &ItemRegistry,
+        effects: &[OutcomeEffect],
+    ) -> Result<Vec<Item>, GameError> {
+        let inventory = world
+            .get_resource::<Inventory>()
+            .ok_or(GameError::MissingResource("Inventory"))?;
+
+        let mut items = Vec::new();
+        for effect in effects {
+            if let OutcomeEffect::GrantItem(item_uuid) = effect {
+                let item = item_registry.get(item_uuid).cloned().ok_or_else(|| {
+                    warn!("Granted item uuid {} not found in ItemRegistry", item_uuid);
+                    GameError::InventoryItemNotFound
+                })?;
+                if !inventory.can_add_item(&item) {
+                    warn!(
+                        "Inventory full: cannot grant item (item_uuid={})",
+                        item_uuid
+                    );
+                    return Err(GameError::InventoryFull);
+                }
+                items.push(item);
+            }
+        }
+        Ok(items)
+    }
+
+    fn change_enkephalin(world: &mut World, delta: i64) -> Result<(), GameError> {
+        let (before, after) = {
+            let mut enkephalin = world
+                .get_resource_mut::<Enkephalin>()
+                .ok_or(GameError::MissingResource("Enkephalin"))?;
+            let before = enkephalin.amount;
+            enkephalin.amount = (before as i64 + delta).clamp(0, u32::MAX as i64) as u32;
+            (before, enkephalin.amount)
+        };
+        if after > before {
+            RunRecord::track_gain(world, EnkephalinFlow::RandomEvent, after - before);
+        } else {
+            RunRecord::track_spend(world, EnkephalinFlow::RandomEvent, before - after);
+        }
+        Ok(())
+    }
+
+    /// 보유 환상체 전원의 최대 체력을 변경 (GrowthId::EventHealthStack)
+    ///
+    /// 감소량은 최대 체력이 1 아래로 내려가지 않도록 유닛별로 제한한다.
+    /// (비용은 검증 단계에서 거부되고, 결과 효과는 여기서 제한됨)
+    fn change_health(
+        world: &mut World,
+        delta: i32,
+        inventory_diff: &mut InventoryDiffDto,
+    ) -> Result<(), GameError> {
+        let mut inventory = world
+            .get_resource_mut::<Inventory>()
+            .ok_or(GameError::MissingResource("Inventory"))?;
+
+        for owned in inventory.abnormalities.iter_owned_mut() {
+            let floor = 1 - owned.max_health() as i32;
+            owned
+                .growth_stacks
+                .add(GrowthId::EventHealthStack, delta.max(floor));
+
+            // 같은 선택에서 여러 번 바뀌면 마지막 상태로 덮어씀
+            let dto = InventoryItemDto::Abnormality(AbnormalityItemDto::from_owned(owned));
+            let uuid = owned.meta.uuid;
+            match inventory_diff
+                .updated
+                .iter_mut()
+                .find(|existing| existing.uuid() == uuid)
+            {
+                Some(existing) => *existing = dto,
+                None => inventory_diff.updated.push(dto),
+            }
+        }
+        Ok(())
+    }
+
+    fn grant_item(world: &mut World, item: Item) -> Result<InventoryItemDto, GameError> {
+        let owned_uuid = match &item {
+            Item::Equipment(_) => {
+                let mut uuid_manager = world
+                    .get_resource_mut::<UuidManager>()
+                    .ok_or(GameError::MissingResource("UuidManager"))?;
+                uuid_manager.next_owned_equipment()
+            }
+            _ => item.uuid(),
+        };
+
+        let mut inventory = world
+            .get_resource_mut::<Inventory>()
+            .ok_or(GameError::MissingResource("Inventory"))?;
+        inventory.add_item_owned(owned_uuid, item.clone())?;
+
+        info!("Granted item from random event: item_uuid={}", item.uuid());
+
+        Ok(InventoryItemDto::from_item_with_uuid(&item, owned_uuid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::game::{
+        ability::DeliveryDef,
+        data::{
+            abnormality_data::{
+                AbnormalityDatabase, AbnormalityMetadata, BasicAttackDef, MovementDef, ResonanceDef,
+            },
+            artifact_data::ArtifactDatabase,
+            equipment_data::{EquipmentDatabase, EquipmentMetadata, EquipmentType},
+            random_event_story_data::{StoryChoice, StoryNode, WeightedOutcome},
+        },
+    };
+
+    /// 테스트용 World 생성 헬퍼
+    fn setup_world(enkephalin: u32) -> World {
+        let mut world = World::new();
+        world.insert_resource(Enkephalin::new(enkephalin));
+        world.insert_resource(Inventory::new());
+        world.insert_resource(Qliphoth::new());
+        world.insert_resource(UuidManager::new(123));
+        world
+    }
+
+    fn add_abnormality(world: &mut World, uuid: Uuid, max_health: u32) {
+        let meta = AbnormalityMetadata {
+            id: format!("abno_{}", uuid),
+            uuid,
+            name: "Test Abnormality".to_string(),
+            risk_level: RiskLevel::TETH,
+            price: 100,
+            max_health,
+            attack: 10,
+            defense: 0,
+            movement: MovementDef {
+                speed_units_per_ms: 3000,
+            },
+            basic_attack: BasicAttackDef {
+                range_tiles: 1,
+                interval_ms: 1000,
+                windup_ms: 0,
+                delivery: DeliveryDef::Instant,
+            },
+            resonance: ResonanceDef {
+                start: 0,
+                max: 100,
+                gain_lock_ms: 1000,
+            },
+            skill_id: None,
+        };
+        world
+            .get_resource_mut::<Inventory>()
+            .unwrap()
+            .add_item_owned(uuid, Item::Abnormality(Arc::new(meta)))
+            .unwrap();
+    }
+
+    fn test_equipment() -> EquipmentMetadata {
+        EquipmentMetadata {
+            id: "story_weapon".to_string(),
+            uuid: Uuid::from_u128(0xE001),
+            name: "Story Weapon".to_string(),
+            equipment_type: EquipmentType::Weapon,
+            rarity: RiskLevel::TETH,
+            price: 50,
+            allow_duplicate_equip: true,
+            triggered_effects: Default::default(),
+        }
+    }
+
+    fn test_registry() -> ItemRegistry {
+        ItemRegistry::new(
+            &AbnormalityDatabase::new(Vec::new()),
+            &ArtifactDatabase::new(Vec::new()),
+            &EquipmentDatabase::new(vec![test_equipment()]),
+        )
+    }
+
+    fn fixed(text: &str, effects: Vec<OutcomeEffect>, next: Option<&str>) -> ChoiceOutcome {
+        ChoiceOutcome::Fixed(OutcomeBranch {
+            text: text.to_string(),
+            effects,
+            next: next.map(str::to_string),
+        })
+    }
+
+    /// start -> (pay: 엔케팔린 30 지불 후 아이템 획득 / gamble: 50:50 확률) -> end
+    fn test_story() -> RandomEventStory {
+        RandomEventStory {
+            id: "test_story".to_string(),
+            uuid: Uuid::from_u128(0x5701),
+            start_node: "start".to_string(),
+            nodes: vec![
+                StoryNode {
+                    id: "start".to_string(),
+                    text: "상자가 놓여 있다".to_string(),
+                    choices: vec![
+                        StoryChoice {
+                            id: "pay".to_string(),
+                            text: "대가를 치르고 연다".to_string(),
+                            costs: vec![ChoiceCost::Enkephalin(30)],
+                            outcome: fixed(
+                                "장비를 얻었다",
+                                vec![OutcomeEffect::GrantItem(test_equipment().uuid)],
+                                Some("end"),
+                            ),
+                        },
+                        StoryChoice {
+                            id: "gamble".to_string(),
+                            text: "그냥 연다".to_string(),
+                            costs: Vec::new(),
+                            outcome: ChoiceOutcome::Chance(vec![
+                                WeightedOutcome {
+                                    weight: 50,
+                                    branch: OutcomeBranch {
+                                        text: "win".to_string(),
+                                        effects: vec![OutcomeEffect::GainEnkephalin(100)],
+                                        next: None,
+                                    },
+                                },
+                                WeightedOutcome {
+                                    weight: 50,
+                                    branch: OutcomeBranch {
+                                        text: "lose".to_string(),
+                                        effects: vec![OutcomeEffect::ShiftQliphoth(-2)],
+                                        next: None,
+                                    },
+                                },
+                            ]),
+                        },
+                    ],
+                },
+                StoryNode {
+                    id: "end".to_string(),
+                    text: "상자가 사라졌다".to_string(),
+                    choices: vec![StoryChoice {
+                        id: "leave".to_string(),
+                        text: "떠난다".to_string(),
+                        costs: Vec::new(),
+                        outcome: fixed("떠났다", Vec::new(), None),
+                    }],
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_start_creates_session_at_start_node() {
+        let mut world = setup_world(100);
+        let story = test_story();
+
+        let node = RandomEventExecutor::start(&mut world, &story).unwrap();
+
+        assert_eq!(node.node_id, "start");
+        assert_eq!(node.choices.len(), 2);
+        let session = world.get_resource::<RandomEventSession>().unwrap();
+        assert_eq!(session.story_uuid, story.uuid);
+        assert_eq!(session.step, 0);
+    }
+
+    #[test]
+    fn test_paid_choice_grants_item_and_branches() {
+        let mut world = setup_world(100);
+        let story = test_story();
+        RandomEventExecutor::start(&mut world, &story).unwrap();
+
+        let (outcome, next) =
+            RandomEventExecutor::select_choice(&mut world, &story, &test_registry(), 1, "pay")
+                .unwrap();
+
+        // Then: 비용 차감 + 장비 지급 + 다음 노드 이동
+        assert_eq!(outcome.enkephalin, 70);
+        assert_eq!(outcome.inventory_diff.added.len(), 1);
+        assert_eq!(next.unwrap().node_id, "end");
+
+        let inventory = world.get_resource::<Inventory>().unwrap();
+        assert_eq!(inventory.equipments.len(), 1);
+        let session = world.get_resource::<RandomEventSession>().unwrap();
+        assert_eq!(session.current_node, "end");
+        assert_eq!(session.step, 1);
+    }
+
+    #[test]
+    fn test_insufficient_enkephalin_rejected_without_side_effects() {
+        let mut world = setup_world(10);
+        let story = test_story();
+        RandomEventExecutor::start(&mut world, &story).unwrap();
+
+        let result =
+            RandomEventExecutor::select_choice(&mut world, &story, &test_registry(), 1, "pay");
+
+        assert!(matches!(result, Err(GameError::InsufficientResources)));
+        assert_eq!(world.get_resource::<Enkephalin>().unwrap().amount, 10);
+        assert!(world
+            .get_resource::<Inventory>()
+            .unwrap()
+            .equipments
+            .is_empty());
+        let session = world.get_resource::<RandomEventSession>().unwrap();
+        assert_eq!(session.current_node, "start");
+        assert_eq!(session.step, 0);
+    }
+
+    #[test]
+    fn test_health_cost_requires_abnormality() {
+        let mut world = setup_world(100);
+        let mut story = test_story();
+        story.nodes[0].choices[0].costs = vec![ChoiceCost::Health(10)];
+        RandomEventExecutor::start(&mut world, &story).unwrap();
+
+        // Given: 보유 환상체 없음
+        let result =
+            RandomEventExecutor::select_choice(&mut world, &story, &test_registry(), 1, "pay");
+
+        assert!(matches!(result, Err(GameError::InsufficientResources)));
+    }
+
+    #[test]
+    fn test_health_loss_is_reported_and_floored_at_one() {
+        let mut world = setup_world(100);
+        add_abnormality(&mut world, Uuid::from_u128(0xA001), 100);
+        add_abnormality(&mut world, Uuid::from_u128(0xA002), 30);
+        let mut story = test_story();
+        story.nodes[0].choices[0].costs = vec![ChoiceCost::Health(10)];
+        story.nodes[0].choices[0].outcome = fixed(
+            "피를 흘렸다",
+            vec![OutcomeEffect::LoseHealth(50)],
+            Some("end"),
+        );
+        RandomEventExecutor::start(&mut world, &story).unwrap();
+
+        let (outcome, _) =
+            RandomEventExecutor::select_choice(&mut world, &story, &test_registry(), 1, "pay")
+                .unwrap();
+
+        // Then: 비용 + 효과가 반영된 최종 상태가 유닛당 한 번만 전달됨
+        let mut reported: Vec<(Uuid, u32)> = outcome
+            .inventory_diff
+            .updated
+            .iter()
+            .map(|dto| match dto {
+                InventoryItemDto::Abnormality(abno) => (abno.uuid, abno.max_health),
+                _ => panic!("unexpected item in diff"),
+            })
+            .collect();
+        reported.sort();
+        assert_eq!(
+            reported,
+            vec![(Uuid::from_u128(0xA001), 40), (Uuid::from_u128(0xA002), 1)]
+        );
+
+        // Then: 어떤 유닛도 최대 체력 0 이하로 내려가지 않음
+        let inventory = world.get_resource::<Inventory>().unwrap();
+        assert!(inventory
+            .abnormalities
+            .iter_owned()
+            .all(|owned| owned.max_health() >= 1));
+    }
+
+    #[test]
+    fn test_unknown_choice_rejected() {
+        let mut world = setup_world(100);
+        let story = test_story();
+        RandomEventExecutor::start(&mut world, &story).unwrap();
+
+        let result =
+            RandomEventExecutor::select_choice(&mut world, &story, &test_registry(), 1, "leave");
+
+        assert!(matches!(result, Err(GameError::EventChoiceNotFound)));
+    }
+
+    #[test]
+    fn test_chance_outcome_is_deterministic_per_seed() {
+        let story = test_story();
+        let registry = test_registry();
+
+        let run = |phase_seed: u64| {
+            let mut world = setup_world(0);
+            RandomEventExecutor::start(&mut world, &story).unwrap();
+            let (outcome, next) = RandomEventExecutor::select_choice(
+                &mut world, &story, &registry, phase_seed, "gamble",
+            )
+            .unwrap();
+            assert!(next.is_none());
+            (outcome.text, outcome.enkephalin, outcome.qliphoth)
+        };
+
+        // Then: 같은 seed 는 항상 같은 결과
+        for seed in 0..16 {
+            assert_eq!(run(seed), run(seed));
+        }
+
+        // Then: seed 가 달라지면 양쪽 결과가 모두 등장
+        let texts: Vec<String> = (0..32).map(|seed| run(seed).0).collect();
+        assert!(texts.iter().any(|t| t == "win"));
+        assert!(texts.iter().any(|t| t == "lose"));
+    }
+}
@@ -4,7 +4,9 @@ use uuid::Uuid;
 
 use crate::game::managers::uuid_manager::UuidManager;
 use crate::{
-    ecs::resources::{Enkephalin, Inventory, InventoryDiffDto, InventoryItemDto, SelectedEvent},
+    ecs::resources::{
+        Enkephalin, Inventory, InventoryDiffDto, InventoryItemDto, RunRecord, SelectedEvent,
+    },
     game::{
         behavior::{BehaviorResult, GameError},
         data::{
@@ -12,7 +14,7 @@ use crate::{
             shop_data::{ShopMetadata, ShopType},
             GameDataBase,
         },
-        enums::GameOption,
+        enums::{EnkephalinFlow, GameOption},
         events::{EventGenerator, GeneratorContext},
     },
 };
@@ -241,6 +243,7 @@ impl ShopExecutor {
             enkephalin.amount -= price;
             enkephalin.amount
         };
+        RunRecord::track_spend(world, EnkephalinFlow::ShopPurchase, price);
 
         // 2-3. 인벤토리에 아이템 추가
         let owned_uuid = match &item {
@@ -359,6 +362,7 @@ impl ShopExecutor {
             enkephalin.amount += sell_price;
             enkephalin.amount
         };
+        RunRecord::track_gain(world, EnkephalinFlow::ShopSell, sell_price);
 
         info!(
             "Item sold successfully: item_uuid={}, sell_price={}, remaining_enkephalin={}",
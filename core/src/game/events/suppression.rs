@@ -6,14 +6,18 @@ use tracing::{debug, info, warn};
 use uuid::Uuid;
 
 use crate::{
-    ecs::resources::{Field, GameProgression, Inventory, Position},
+    ecs::resources::{Field, GameProgression, Position},
     game::{
-        // battle::{BattleCore, BattleResult, GrowthStack, OwnedArtifact, OwnedUnit, PlayerDeckInfo},
+        battle::{
+            core::BattleCore,
+            types::{BattleResult, OwnedUnit, PlayerDeckInfo},
+        },
         behavior::GameError,
         data::{pve_data::PveEncounter, GameDataBase},
         determinism,
-        enums::{GameOption, OrdealType, PhaseType, RiskLevel, Tier},
+        enums::{GameOption, OrdealType, PhaseType, RiskLevel},
         events::EventGenerator,
+        growth::GrowthStack,
     },
 };
 
@@ -107,106 +111,58 @@ impl EventGenerator for SuppressionGenerator {
 pub struct SuppressionExecutor;
 
 impl SuppressionExecutor {
-    /// PvE 전투 시작
+    /// PvE 전투 실행
     ///
     /// # Arguments
-    /// * `world` - ECS World
+    /// * `world` - ECS World (전투 필드 크기 조회)
     /// * `game_data` - 게임 데이터베이스
+    /// * `player_deck` - 출전하는 플레이어 덱
     /// * `abnormality_id` - 진압 대상 환상체 ID
+    /// * `battle_seed` - 현재 Phase 의 seed
+    /// * `data_version` - 런에 고정된 데이터 버전 ID
+    ///
+    /// # Returns
+    /// 승패와 타임라인. 보상/클리포트 처리는 호출자가 담당
     pub fn start_battle(
-        world: &mut World,
+        world: &World,
         game_data: Arc<GameDataBase>,
+        player_deck: &PlayerDeckInfo,
         abnormality_id: &str,
-    ) -> Result<(), GameError> {
-        // ) -> Result<BattleResult, GameError> {
+        battle_seed: u64,
+        data_version: u64,
+    ) -> Result<BattleResult, GameError> {
         info!(
             "Starting suppression battle for abnormality: {}",
             abnormality_id
         );
 
-        // 1. Player 덱 정보 구성
-        let player_deck = Self::build_player_deck(world)?;
-        // debug!(
-        //     "Player deck built: {} units, {} artifacts",
-        //     player_deck.units.len(),
-        //     player_deck.artifacts.len()
-        // );
-
-        // 2. Opponent 덱 정보 구성 (PvE 데이터에서 로드)
+        // 1. Opponent 덱 정보 구성 (PvE 데이터에서 로드)
         let opponent_deck = Self::build_opponent_deck(&game_data, abnormality_id)?;
-        // debug!(
-        //     "Opponent deck built: {} units from encounter '{}'",
-        //     opponent_deck.units.len(),
-        //     abnormality_id
-        // );
-
-        // 3. BattleCore 생성 및 전투 실행
-        let field_size = (3, 3);
-        // let mut battle = BattleCore::new(&player_deck, &opponent_deck, game_data, field_size);
-
-        // let result = battle.run_battle(world)?;
-
-        info!("Suppression battle completed");
-
-        // Ok(result)
-        Ok(())
-    }
+        debug!(
+            "Opponent deck built: {} units from encounter '{}'",
+            opponent_deck.units.len(),
+            abnormality_id
+        );
 
-    /// Player 덱 정보 구성
-    // fn build_player_deck(world: &World) -> Result<PlayerDeckInfo, GameError> {
-    fn build_player_deck(world: &World) -> Result<(), GameError> {
-        let field = world
+        // 2. BattleCore 생성 및 전투 실행
+        let field_size = world
             .get_resource::<Field>()
+            .map(|field| (field.width, field.height))
             .ok_or(GameError::MissingResource("Field"))?;
+        let result = BattleCore::new(player_deck, &opponent_deck, game_data, field_size)
+            .with_seed(battle_seed)
+            .with_data_version(data_version)
+            .run_battle()?;
 
-        let inventory = world
-            .get_resource::<Inventory>()
-            .ok_or(GameError::MissingResource("Inventory"))?;
-
-        // let mut units = Vec::new();
-        // let mut positions = HashMap::new();
-
-        let mut abnormality_items: Vec<_> = inventory.abnormalities.iter_owned().collect();
-        abnormality_items.sort_by(|a, b| a.meta.uuid.as_bytes().cmp(b.meta.uuid.as_bytes()));
-
-        Ok(())
-
-        // for abnormality in abnormality_items {
-        //     let unit_uuid = abnormality.meta.uuid;
-        //     if let Some(pos) = field.get_position(unit_uuid) {
-        //         let equipped_items: Vec<Uuid> =
-        //             abnormality.item_slot.iter().map(|r| r.base_uuid).collect();
-
-        //         units.push(OwnedUnit {
-        //             base_uuid: unit_uuid,
-        //             level: Tier::I,
-        //             growth_stacks: abnormality.growth_stacks.clone(),
-        //             equipped_items,
-        //         });
-        //         positions.insert(unit_uuid, pos);
-        //     }
-        // }
-
-        // let mut artifact_items = inventory.artifacts.get_all_items();
-        // artifact_items.sort_by(|a, b| a.uuid.as_bytes().cmp(b.uuid.as_bytes()));
-        // let artifacts: Vec<OwnedArtifact> = artifact_items
-        //     .into_iter()
-        //     .map(|a| OwnedArtifact { base_uuid: a.uuid })
-        //     .collect();
-
-        // Ok(PlayerDeckInfo {
-        //     units,
-        //     artifacts,
-        //     positions,
-        // })
+        info!("Suppression battle completed - Winner: {:?}", result.winner);
+        Ok(result)
     }
 
     /// Opponent 덱 정보 구성 (PvE 데이터에서)
     fn build_opponent_deck(
         game_data: &GameDataBase,
         abnormality_id: &str,
-    ) -> Result<(), GameError> {
-        // ) -> Result<PlayerDeckInfo, GameError> {
+    ) -> Result<PlayerDeckInfo, GameError> {
         let encounter = game_data
             .pve_data
             .get_by_abnormality_id(abnormality_id)
@@ -218,38 +174,36 @@ impl SuppressionExecutor {
                 GameError::MissingResource("PveEncounter")
             })?;
 
-        // let mut units = Vec::new();
-        // let mut positions = HashMap::new();
-
-        // for pve_unit in &encounter.units {
-        //     let abnormality_meta = game_data
-        //         .abnormality_data
-        //         .get_by_id(&pve_unit.abnormality_id)
-        //         .ok_or_else(|| {
-        //             warn!(
-        //                 "Abnormality metadata not found: {}",
-        //                 pve_unit.abnormality_id
-        //             );
-        //             GameError::MissingResource("AbnormalityMetadata")
-        //         })?;
-
-        //     units.push(OwnedUnit {
-        //         base_uuid: abnormality_meta.uuid,
-        //         level: pve_unit.tier,
-        //         growth_stacks: GrowthStack::new(),
-        //         equipped_items: vec![],
-        //     });
-
-        //     positions.insert(abnormality_meta.uuid, Position::from(pve_unit.position));
-        // }
-
-        // Ok(PlayerDeckInfo {
-        //     units,
-        //     artifacts: vec![],
-        //     positions,
-        // })
-
-        Ok(())
+        let mut units = Vec::new();
+        let mut positions = HashMap::new();
+
+        for pve_unit in &encounter.units {
+            let abnormality_meta = game_data
+                .abnormality_data
+                .get_by_id(&pve_unit.abnormality_id)
+                .ok_or_else(|| {
+                    warn!(
+                        "Abnormality metadata not found: {}",
+                        pve_unit.abnormality_id
+                    );
+                    GameError::MissingResource("AbnormalityMetadata")
+                })?;
+
+            units.push(OwnedUnit {
+                base_uuid: abnormality_meta.uuid,
+                level: pve_unit.tier,
+                growth_stacks: GrowthStack::new(),
+                equipped_items: vec![],
+            });
+
+            positions.insert(abnormality_meta.uuid, Position::from(pve_unit.position));
+        }
+
+        Ok(PlayerDeckInfo {
+            units,
+            artifacts: vec![],
+            positions,
+        })
     }
 
     /// 진압 작업 수행 (기존 메서드 - 향후 확장용)
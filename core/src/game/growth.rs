@@ -7,6 +7,8 @@ pub enum GrowthId {
     KillStack,
     PveWinStack,
     QuestRewardStack,
+    /// 랜덤 이벤트 선택지로 인한 최대 체력 증감
+    EventHealthStack,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -27,4 +29,8 @@ impl GrowthStack {
         }
         *self.stacks.entry(id).or_insert(0) += delta;
     }
+
+    pub fn get(&self, id: GrowthId) -> i32 {
+        self.stacks.get(&id).copied().unwrap_or(0)
+    }
 }
@@ -85,6 +85,21 @@ impl ActionScheduler {
                 vec![PlayerBehavior::ExitBonus]
             }
 
+            GameState::InRandomEvent { .. } => {
+                // 랜덤 이벤트 안: 선택지 선택 또는 나가기 가능
+                vec![
+                    PlayerBehavior::SelectEventChoice {
+                        choice_id: String::new(),
+                    },
+                    PlayerBehavior::ExitRandomEvent,
+                ]
+            }
+
+            GameState::InRandomEventFinished { .. } => {
+                // 랜덤 이벤트 종료: 나가기만 가능
+                vec![PlayerBehavior::ExitRandomEvent]
+            }
+
             GameState::InSuppression { .. } => {
                 // 진압 작업 중: 작업 타입 선택, 나가기 가능
                 // TODO: SelectWorkType, ExitSuppression 추가 후 활성화
@@ -185,6 +200,30 @@ mod tests {
             .any(|a| matches!(a, PlayerBehavior::StartSuppression { .. })));
     }
 
+    #[test]
+    fn test_in_random_event_allows_choice_and_exit() {
+        let state = GameState::InRandomEvent {
+            event_uuid: Uuid::nil(),
+        };
+        let allowed = ActionScheduler::get_allowed_actions(&state);
+
+        assert_eq!(allowed.len(), 2);
+        assert!(allowed
+            .iter()
+            .any(|a| matches!(a, PlayerBehavior::SelectEventChoice { .. })));
+        assert!(allowed
+            .iter()
+            .any(|a| matches!(a, PlayerBehavior::ExitRandomEvent)));
+
+        // 이벤트가 끝나면 나가기만 가능
+        let state = GameState::InRandomEventFinished {
+            event_uuid: Uuid::nil(),
+        };
+        let allowed = ActionScheduler::get_allowed_actions(&state);
+        assert_eq!(allowed.len(), 1);
+        assert!(matches!(allowed[0], PlayerBehavior::ExitRandomEvent));
+    }
+
     #[test]
     fn test_in_battle_allows_nothing_for_now() {
         let state = GameState::InBattle {
@@ -276,6 +315,12 @@ mod tests {
             GameState::InBonusClaimed {
                 bonus_uuid: Uuid::nil(),
             },
+            GameState::InRandomEvent {
+                event_uuid: Uuid::nil(),
+            },
+            GameState::InRandomEventFinished {
+                event_uuid: Uuid::nil(),
+            },
             GameState::InSuppression {
                 abnormality_uuid: Uuid::nil(),
             },
@@ -316,6 +361,18 @@ mod tests {
                 },
                 1,
             ),
+            (
+                GameState::InRandomEvent {
+                    event_uuid: Uuid::nil(),
+                },
+                2,
+            ),
+            (
+                GameState::InRandomEventFinished {
+                    event_uuid: Uuid::nil(),
+                },
+                1,
+            ),
             (
                 GameState::InSuppression {
                     abnormality_uuid: Uuid::nil(),
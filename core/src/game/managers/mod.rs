@@ -3,4 +3,6 @@ pub mod event_manager;
 pub mod ordeal_scheduler;
 pub mod phase_resolver;
 pub mod qliphoth_manager;
+pub mod reward_manager;
+pub mod run_manager;
 pub mod uuid_manager;
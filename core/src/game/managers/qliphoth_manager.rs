@@ -104,6 +104,23 @@ impl QliphothManager {
             changes.item_recovery
         );
     }
+
+    /// 랜덤 이벤트 선택지에 의한 증감 (양수: 회복, 음수: 감소)
+    pub fn apply_event_shift(qliphoth: &mut Qliphoth, delta: i32) {
+        let old_amount = qliphoth.amount();
+        if delta >= 0 {
+            qliphoth.increase(delta as u32);
+        } else {
+            qliphoth.decrease(delta.unsigned_abs());
+        }
+
+        info!(
+            "Random event shift: {} → {} (delta: {})",
+            old_amount,
+            qliphoth.amount(),
+            delta
+        );
+    }
 }
 
 #[cfg(test)]
@@ -179,4 +196,16 @@ mod tests {
         // Then: 기본값 phase_recovery=1
         assert_eq!(qliphoth.amount(), initial + 1);
     }
+
+    #[test]
+    fn test_event_shift() {
+        let mut qliphoth = Qliphoth::new();
+        qliphoth.set_amount(5);
+
+        QliphothManager::apply_event_shift(&mut qliphoth, -2);
+        assert_eq!(qliphoth.amount(), 3);
+
+        QliphothManager::apply_event_shift(&mut qliphoth, 1);
+        assert_eq!(qliphoth.amount(), 4);
+    }
 }
@@ -0,0 +1,556 @@
+use std::sync::Arc;
+
+use bevy_ecs::world::World;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::{
+    config::{balance, RewardConfig, RewardTier},
+    ecs::resources::{
+        BattleStreak, Enkephalin, Field, Inventory, InventoryDiffDto, InventoryItemDto, Qliphoth,
+        QliphothLevel, RunRecord,
+    },
+    game::{
+        behavior::GameError,
+        data::{equipment_data::EquipmentDatabase, Item},
+        enums::{EnkephalinFlow, OrdealType, RewardEventKind, RewardOutcome},
+        managers::{qliphoth_manager::QliphothManager, uuid_manager::UuidManager},
+    },
+};
+
+/// 보상 처리 결과 (클라이언트 전달용)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RewardResolution {
+    pub kind: RewardEventKind,
+    pub outcome: RewardOutcome,
+    /// 획득 엔케팔린 (기본 보상 + 연승/연패 보너스)
+    pub enkephalin_gained: u32,
+    /// 그 중 연승/연패 보너스
+    pub streak_bonus: u32,
+    /// 패배 페널티로 차감된 엔케팔린
+    pub enkephalin_lost: u32,
+    /// 처리 후 엔케팔린
+    pub enkephalin: u32,
+    /// 처리 후 클리포트 양
+    pub qliphoth: u32,
+    pub inventory_diff: InventoryDiffDto,
+}
+
+/// 전투 보상 처리 헬퍼
+///
+/// 전투 결과에 따른 엔케팔린/인벤토리/클리포트 변경은 모두 이곳을 거친다
+pub struct RewardManager;
+
+impl RewardManager {
+    /// 전역 밸런스 설정의 보상 테이블로 전투 결과 처리
+    ///
+    /// # Arguments
+    /// * `world` - ECS World (Enkephalin, Inventory, Qliphoth, BattleStreak 접근)
+    /// * `equipment_db` - 장비 보상 후보 조회용
+    /// * `ordeal` - 현재 Ordeal
+    /// * `kind` - 전투 종류
+    /// * `outcome` - 전투 결과
+    /// * `seed` - 보상 결정용 seed (보통 Phase seed)
+    pub fn resolve(
+        world: &mut World,
+        equipment_db: &EquipmentDatabase,
+        ordeal: OrdealType,
+        kind: RewardEventKind,
+        outcome: RewardOutcome,
+        seed: u64,
+    ) -> Result<RewardResolution, GameError> {
+        Self::resolve_with(
+            &balance::reward(),
+            world,
+            equipment_db,
+            ordeal,
+            kind,
+            outcome,
+            seed,
+        )
+    }
+
+    /// 지정한 보상 테이블로 전투 결과 처리
+    pub fn resolve_with(
+        config: &RewardConfig,
+        world: &mut World,
+        equipment_db: &EquipmentDatabase,
+        ordeal: OrdealType,
+        kind: RewardEventKind,
+        outcome: RewardOutcome,
+        seed: u64,
+    ) -> Result<RewardResolution, GameError> {
+        let tier = config
+            .tier(ordeal, kind, outcome)
+            .cloned()
+            .unwrap_or_else(|| {
+                warn!(
+                    "No reward tier for ordeal={:?}, kind={:?}, outcome={:?}; granting nothing",
+                    ordeal, kind, outcome
+                );
+                RewardTier::empty(ordeal, kind, outcome)
+            });
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+        let mut inventory_diff = InventoryDiffDto::default();
+
+        // 1. 보상 배율은 전투 전 클리포트 상태 기준
+        let multiplier = Self::reward_multiplier(world, kind)?;
+
+        // 2. 연승/연패 갱신
+        let streak = {
+            let mut streak = world
+                .get_resource_mut::<BattleStreak>()
+                .ok_or(GameError::MissingResource("BattleStreak"))?;
+            match outcome {
+                RewardOutcome::Win => streak.record_win(),
+                RewardOutcome::Loss => streak.record_loss(),
+            }
+        };
+
+        // 3. 클리포트 변화
+        {
+            let mut qliphoth = world
+                .get_resource_mut::<Qliphoth>()
+                .ok_or(GameError::MissingResource("Qliphoth"))?;
+            match (kind, outcome) {
+                (RewardEventKind::Suppression, RewardOutcome::Win) => {
+                    QliphothManager::apply_suppress_success(&mut qliphoth)
+                }
+                (RewardEventKind::Suppression, RewardOutcome::Loss) => {
+                    QliphothManager::apply_suppress_failure(&mut qliphoth)
+                }
+                (RewardEventKind::Ordeal, _) => QliphothManager::apply_battle_cost(&mut qliphoth),
+                (RewardEventKind::WhiteNights, RewardOutcome::Win) => {
+                    QliphothManager::apply_breach_success(&mut qliphoth)
+                }
+                (RewardEventKind::WhiteNights, RewardOutcome::Loss) => {
+                    QliphothManager::apply_breach_failure(&mut qliphoth)
+                }
+            }
+        }
+
+        // 4. 엔케팔린 / 인벤토리
+        let (base_gain, streak_bonus, enkephalin_lost) = match outcome {
+            RewardOutcome::Win => {
+                let base = rng.gen_range(tier.enkephalin_min..=tier.enkephalin_max);
+                let base = (base as f32 * multiplier).round() as u32;
+                let bonus = config.streak.win_bonus(streak);
+
+                if let Some(dto) = Self::roll_item(world, equipment_db, &tier, &mut rng)? {
+                    inventory_diff.added.push(dto);
+                }
+
+                (base, bonus, 0)
+            }
+            RewardOutcome::Loss => {
+                let lost = {
+                    let mut enkephalin = world
+                        .get_resource_mut::<Enkephalin>()
+                        .ok_or(GameError::MissingResource("Enkephalin"))?;
+                    let lost = tier.enkephalin_penalty.min(enkephalin.amount);
+                    enkephalin.amount -= lost;
+                    lost
+                };
+                RunRecord::track_spend(world, EnkephalinFlow::Battle, lost);
+                let bonus = config.streak.loss_bonus(streak);
+
+                inventory_diff
+                    .removed
+                    .extend(Self::roll_abnormality_loss(world, &tier, &mut rng)?);
+
+                (0, bonus, lost)
+            }
+        };
+
+        let (enkephalin, earned) = {
+            let mut enkephalin = world
+                .get_resource_mut::<Enkephalin>()
+                .ok_or(GameError::MissingResource("Enkephalin"))?;
+            let before = enkephalin.amount;
+            enkephalin.amount = before
+                .saturating_add(base_gain)
+                .saturating_add(streak_bonus);
+            (enkephalin.amount, enkephalin.amount - before)
+        };
+        RunRecord::track_gain(world, EnkephalinFlow::Battle, earned);
+        let qliphoth = world
+            .get_resource::<Qliphoth>()
+            .map(|q| q.amount())
+            .ok_or(GameError::MissingResource("Qliphoth"))?;
+
+        info!(
+            "Reward resolved: ordeal={:?}, kind={:?}, outcome={:?}, gained={}, streak_bonus={}, lost={}, streak={}",
+            ordeal, kind, outcome, base_gain, streak_bonus, enkephalin_lost, streak
+        );
+
+        Ok(RewardResolution {
+            kind,
+            outcome,
+            enkephalin_gained: base_gain + streak_bonus,
+            streak_bonus,
+            enkephalin_lost,
+            enkephalin,
+            qliphoth,
+            inventory_diff,
+        })
+    }
+
+    /// 클리포트 상태에 따른 보상 배율
+    fn reward_multiplier(world: &World, kind: RewardEventKind) -> Result<f32, GameError> {
+        let multipliers = balance::qliphoth_reward_multipliers();
+        let level = world
+            .get_resource::<Qliphoth>()
+            .map(|q| q.level())
+            .ok_or(GameError::MissingResource("Qliphoth"))?;
+
+        Ok(match (kind, level) {
+            (RewardEventKind::WhiteNights, _) => multipliers.critical_breach,
+            (RewardEventKind::Suppression, QliphothLevel::Caution) => multipliers.caution_suppress,
+            _ => multipliers.stable,
+        })
+    }
+
+    /// 승리 시 장비 지급 판정
+    fn roll_item(
+        world: &mut World,
+        equipment_db: &EquipmentDatabase,
+        tier: &RewardTier,
+        rng: &mut impl Rng,
+    ) -> Result<Option<InventoryItemDto>, GameError> {
+        let Some(rarity) = tier.item_rarity else {
+            return Ok(None);
+        };
+        if rng.gen_range(0..100) >= tier.item_chance {
+            return Ok(None);
+        }
+
+        let candidates: Vec<_> = equipment_db
+            .items
+            .iter()
+            .filter(|meta| meta.rarity == rarity)
+            .collect();
+        if candidates.is_empty() {
+            warn!("No equipment of rarity {:?} to grant as reward", rarity);
+            return Ok(None);
+        }
+        let meta = candidates[rng.gen_range(0..candidates.len())];
+        let item = Item::Equipment(Arc::new(meta.clone()));
+
+        {
+            let inventory = world
+                .get_resource::<Inventory>()
+                .ok_or(GameError::MissingResource("Inventory"))?;
+            if !inventory.can_add_item(&item) {
+                warn!(
+                    "Inventory full: reward item skipped (item_uuid={})",
+                    meta.uuid
+                );
+                return Ok(None);
+            }
+        }
+
+        let owned_uuid = world
+            .get_resource_mut::<UuidManager>()
+            .ok_or(GameError::MissingResource("UuidManager"))?
+            .next_owned_equipment();
+        world
+            .get_resource_mut::<Inventory>()
+            .ok_or(GameError::MissingResource("Inventory"))?
+            .add_item_owned(owned_uuid, item.clone())?;
+
+        info!("Granted reward item: item_uuid={}", meta.uuid);
+        Ok(Some(InventoryItemDto::from_item_with_uuid(
+            &item, owned_uuid,
+        )))
+    }
+
+    /// 패배 시 환상체 손실 판정
+    ///
+    /// 장착 중이던 장비는 귀속이므로 함께 사라진다.
+    /// 반환값은 인벤토리에서 제거된 UUID 목록
+    fn roll_abnormality_loss(
+        world: &mut World,
+        tier: &RewardTier,
+        rng: &mut impl Rng,
+    ) -> Result<Vec<Uuid>, GameError> {
+        if tier.abnormality_loss_chance == 0
+            || rng.gen_range(0..100) >= tier.abnormality_loss_chance
+        {
+            return Ok(Vec::new());
+        }
+
+        let mut inventory = world
+            .get_resource_mut::<Inventory>()
+            .ok_or(GameError::MissingResource("Inventory"))?;
+
+        // HashMap 순회 순서에 의존하지 않도록 정렬 후 선택
+        let mut owned: Vec<Uuid> = inventory.abnormalities.iter().map(|m| m.uuid).collect();
+        if owned.is_empty() {
+            return Ok(Vec::new());
+        }
+        owned.sort();
+        let lost_uuid = owned[rng.gen_range(0..owned.len())];
+
+        let bound_equipments: Vec<Uuid> = inventory
+            .equipments
+            .iter()
+            .filter(|e| e.equipped_to == Some(lost_uuid))
+            .map(|e| e.instance_uuid)
+            .collect();
+
+        let mut removed = Vec::new();
+        if inventory.remove_item(lost_uuid).is_some() {
+            removed.push(lost_uuid);
+        }
+        for equipment_uuid in bound_equipments {
+            if inventory.remove_item(equipment_uuid).is_some() {
+                removed.push(equipment_uuid);
+            }
+        }
+
+        if let Some(mut field) = world.get_resource_mut::<Field>() {
+            field.remove(lost_uuid);
+        }
+
+        warn!(
+            "Abnormality lost by defeat penalty: uuid={}, bound_equipments={}",
+            lost_uuid,
+            removed.len().saturating_sub(1)
+        );
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{
+        ability::DeliveryDef,
+        data::{
+            abnormality_data::{AbnormalityMetadata, BasicAttackDef, MovementDef, ResonanceDef},
+            equipment_data::{EquipmentMetadata, EquipmentType},
+        },
+        enums::RiskLevel,
+    };
+
+    /// 테스트용 World 생성 헬퍼
+    fn setup_world(enkephalin: u32) -> World {
+        let mut world = World::new();
+        world.insert_resource(Enkephalin::new(enkephalin));
+        world.insert_resource(Inventory::new());
+        world.insert_resource(Qliphoth::new());
+        world.insert_resource(BattleStreak::new());
+        world.insert_resource(UuidManager::new(123));
+        world
+    }
+
+    fn equipment_db() -> EquipmentDatabase {
+        EquipmentDatabase::new(vec![EquipmentMetadata {
+            id: "reward_weapon".to_string(),
+            uuid: Uuid::from_u128(0xE0),
+            name: "Reward Weapon".to_string(),
+            equipment_type: EquipmentType::Weapon,
+            rarity: RiskLevel::WAW,
+            price: 100,
+            allow_duplicate_equip: true,
+            triggered_effects: Default::default(),
+        }])
+    }
+
+    fn add_abnormality(world: &mut World, uuid: Uuid) {
+        let meta = AbnormalityMetadata {
+            id: format!("abno_{}", uuid),
+            uuid,
+            name: "Test Abnormality".to_string(),
+            risk_level: RiskLevel::TETH,
+            price: 100,
+            max_health: 100,
+            attack: 10,
+            defense: 0,
+            movement: MovementDef {
+                speed_units_per_ms: 3000,
+            },
+            basic_attack: BasicAttackDef {
+                range_tiles: 1,
+                interval_ms: 1000,
+                windup_ms: 0,
+                delivery: DeliveryDef::Instant,
+            },
+            resonance: ResonanceDef {
+                start: 0,
+                max: 100,
+                gain_lock_ms: 1000,
+            },
+            skill_id: None,
+        };
+        world
+            .get_resource_mut::<Inventory>()
+            .unwrap()
+            .add_item_owned(uuid, Item::Abnormality(Arc::new(meta)))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_win_grants_enkephalin_in_range_and_item() {
+        let mut world = setup_world(0);
+        let config = RewardConfig::default();
+
+        let result = RewardManager::resolve_with(
+            &config,
+            &mut world,
+            &equipment_db(),
+            OrdealType::Dusk,
+            RewardEventKind::Ordeal,
+            RewardOutcome::Win,
+            42,
+        )
+        .unwrap();
+
+        // Then: 어스름 시련 승리 2500~4000, 첫 승리라 연승 보너스 없음
+        assert!((2500..=4000).contains(&result.enkephalin_gained));
+        assert_eq!(result.streak_bonus, 0);
+        assert_eq!(result.enkephalin, result.enkephalin_gained);
+
+        // Then: WAW 장비 100% 지급
+        assert_eq!(result.inventory_diff.added.len(), 1);
+        assert_eq!(
+            world.get_resource::<Inventory>().unwrap().equipments.len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_same_seed_yields_same_reward() {
+        let config = RewardConfig::default();
+        let run = |seed: u64| {
+            let mut world = setup_world(0);
+            RewardManager::resolve_with(
+                &config,
+                &mut world,
+                &equipment_db(),
+                OrdealType::Noon,
+                RewardEventKind::Ordeal,
+                RewardOutcome::Win,
+                seed,
+            )
+            .unwrap()
+            .enkephalin_gained
+        };
+
+        assert_eq!(run(7), run(7));
+    }
+
+    #[test]
+    fn test_loss_applies_penalty_without_going_negative() {
+        let mut world = setup_world(300);
+        let config = RewardConfig::default();
+
+        let result = RewardManager::resolve_with(
+            &config,
+            &mut world,
+            &equipment_db(),
+            OrdealType::Dusk,
+            RewardEventKind::Ordeal,
+            RewardOutcome::Loss,
+            1,
+        )
+        .unwrap();
+
+        // Then: 페널티 500 이지만 보유량 300 까지만 차감
+        assert_eq!(result.enkephalin_lost, 300);
+        assert_eq!(result.enkephalin, 0);
+    }
+
+    #[test]
+    fn test_loss_can_remove_abnormality() {
+        let mut world = setup_world(5000);
+        let abno_uuid = Uuid::from_u128(0xA1);
+        add_abnormality(&mut world, abno_uuid);
+
+        let mut config = RewardConfig::default();
+        config.tiers.retain(|t| {
+            !(t.ordeal == OrdealType::Midnight
+                && t.kind == RewardEventKind::Ordeal
+                && t.outcome == RewardOutcome::Loss)
+        });
+        config.tiers.push(RewardTier {
+            enkephalin_penalty: 1000,
+            abnormality_loss_chance: 100,
+            ..RewardTier::empty(
+                OrdealType::Midnight,
+                RewardEventKind::Ordeal,
+                RewardOutcome::Loss,
+            )
+        });
+
+        let result = RewardManager::resolve_with(
+            &config,
+            &mut world,
+            &equipment_db(),
+            OrdealType::Midnight,
+            RewardEventKind::Ordeal,
+            RewardOutcome::Loss,
+            3,
+        )
+        .unwrap();
+
+        assert_eq!(result.enkephalin, 4000);
+        assert_eq!(result.inventory_diff.removed, vec![abno_uuid]);
+        assert!(world
+            .get_resource::<Inventory>()
+            .unwrap()
+            .abnormalities
+            .is_empty());
+    }
+
+    #[test]
+    fn test_streak_bonus_applied() {
+        let mut world = setup_world(0);
+        let config = RewardConfig::default();
+
+        let mut last = None;
+        for _ in 0..3 {
+            last = Some(
+                RewardManager::resolve_with(
+                    &config,
+                    &mut world,
+                    &equipment_db(),
+                    OrdealType::Dawn,
+                    RewardEventKind::Ordeal,
+                    RewardOutcome::Loss,
+                    0,
+                )
+                .unwrap(),
+            );
+        }
+
+        // Then: 3연패 → (3 - 2 + 1) * 150
+        let last = last.unwrap();
+        assert_eq!(last.streak_bonus, 300);
+        assert_eq!(world.get_resource::<BattleStreak>().unwrap().losses, 3);
+    }
+
+    #[test]
+    fn test_suppression_result_shifts_qliphoth() {
+        let mut world = setup_world(0);
+        world.get_resource_mut::<Qliphoth>().unwrap().set_amount(5);
+        let config = RewardConfig::default();
+
+        let result = RewardManager::resolve_with(
+            &config,
+            &mut world,
+            &equipment_db(),
+            OrdealType::Dawn,
+            RewardEventKind::Suppression,
+            RewardOutcome::Loss,
+            0,
+        )
+        .unwrap();
+
+        // Then: 진압 실패 기본값 suppress_failure=1
+        assert_eq!(result.qliphoth, 4);
+    }
+}
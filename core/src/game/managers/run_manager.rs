@@ -0,0 +1,301 @@
+use bevy_ecs::world::World;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    ecs::resources::{
+        BattleRecord, Enkephalin, Field, GameProgression, Inventory, PhaseResult, Position,
+        Qliphoth, QliphothLevel, RunRecord,
+    },
+    game::{
+        behavior::GameError,
+        enums::{OrdealType, PhaseType, RewardOutcome, RunEndReason},
+        growth::GrowthStack,
+    },
+};
+
+/// 런 종료 시점의 기물 1개
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildUnit {
+    pub uuid: Uuid,
+    pub id: String,
+    /// 필드 배치 위치 (배낭에 있으면 None)
+    pub position: Option<Position>,
+    /// 귀속된 장비 ID 목록
+    pub equipment: Vec<String>,
+    pub growth: GrowthStack,
+}
+
+/// 런 종료 시점의 빌드
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FinalBuild {
+    pub units: Vec<BuildUnit>,
+    /// 어떤 기물에도 장착되지 않은 장비 ID 목록
+    pub unequipped: Vec<String>,
+    pub artifacts: Vec<String>,
+}
+
+/// 런 결과 요약
+///
+/// 게임 서버는 런 종료 시 이 값을 auth 서버의 `POST /internal/runs` 로 제출하거나,
+/// auth DB 의 record_match_result 에 is_winner / score / stats 로 넘긴다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunSummary {
+    pub run_seed: u64,
+    /// 런이 사용한 데이터 버전 ID
+    #[serde(default)]
+    pub data_version: u64,
+    pub end_reason: RunEndReason,
+    pub reached_ordeal: OrdealType,
+    pub reached_phase: PhaseType,
+    pub final_enkephalin: u32,
+    pub final_qliphoth: u32,
+    pub enkephalin_earned: u64,
+    pub enkephalin_spent: u64,
+    pub final_build: FinalBuild,
+    pub phase_results: Vec<PhaseResult>,
+    pub battles: Vec<BattleRecord>,
+    /// 런 시작부터 종료까지 걸린 시간 (초)
+    ///
+    /// 코어는 시계를 갖지 않으므로 0 으로 만들고, 게임 서버가 런 종료 시 채운다.
+    #[serde(default)]
+    pub duration_seconds: u64,
+}
+
+impl RunSummary {
+    /// match_participants.is_winner
+    pub fn is_victory(&self) -> bool {
+        self.end_reason.is_victory()
+    }
+
+    /// match_participants.score (완료한 Phase 수)
+    pub fn score(&self) -> i32 {
+        self.phase_results.len() as i32
+    }
+
+    pub fn battles_won(&self) -> usize {
+        self.battles
+            .iter()
+            .filter(|b| b.outcome == RewardOutcome::Win)
+            .count()
+    }
+
+    /// match_participants.stats
+    pub fn to_stats(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or(serde_json::Value::Null)
+    }
+}
+
+/// 런 종료 조건 판정 및 RunSummary 생성 헬퍼
+pub struct RunManager;
+
+impl RunManager {
+    /// 전투 결과 반영 후 런 종료 여부 판정
+    ///
+    /// # Arguments
+    /// * `record` - 전투가 이미 기록된 RunRecord
+    /// * `qliphoth` - 보상 처리 후 클리포트
+    /// * `outcome` - 전투 결과
+    /// * `max_ordeal_losses` - 시련 패배 허용 횟수
+    pub fn check_battle_end(
+        record: &RunRecord,
+        qliphoth: &Qliphoth,
+        outcome: RewardOutcome,
+        max_ordeal_losses: u32,
+    ) -> Option<RunEndReason> {
+        if outcome != RewardOutcome::Loss {
+            return None;
+        }
+        if qliphoth.level() == QliphothLevel::Meltdown {
+            return Some(RunEndReason::Meltdown);
+        }
+        if record.ordeal_losses >= max_ordeal_losses {
+            return Some(RunEndReason::OrdealLossLimit);
+        }
+        None
+    }
+
+    /// 현재 World 상태로 RunSummary 생성
+    ///
+    /// 런이 끝나지 않았으면 PhaseNotReady
+    pub fn build_summary(world: &World, run_seed: u64) -> Result<RunSummary, GameError> {
+        let record = world
+            .get_resource::<RunRecord>()
+            .ok_or(GameError::MissingResource("RunRecord"))?;
+        let end_reason = record.end_reason.ok_or(GameError::PhaseNotReady)?;
+
+        let progression = world
+            .get_resource::<GameProgression>()
+            .ok_or(GameError::MissingResource("GameProgression"))?;
+        let enkephalin = world
+            .get_resource::<Enkephalin>()
+            .ok_or(GameError::MissingResource("Enkephalin"))?;
+        let qliphoth = world
+            .get_resource::<Qliphoth>()
+            .ok_or(GameError::MissingResource("Qliphoth"))?;
+
+        Ok(RunSummary {
+            run_seed,
+            data_version: record.data_version,
+            end_reason,
+            reached_ordeal: progression.current_ordeal,
+            reached_phase: progression.current_phase,
+            final_enkephalin: enkephalin.amount,
+            final_qliphoth: qliphoth.amount(),
+            enkephalin_earned: record.enkephalin_earned,
+            enkephalin_spent: record.enkephalin_spent,
+            final_build: Self::build_final_build(world)?,
+            phase_results: record.phase_results.clone(),
+            battles: record.battles.clone(),
+            duration_seconds: 0,
+        })
+    }
+
+    /// 인벤토리/필드로부터 최종 빌드 생성 (UUID 정렬로 순서 고정)
+    fn build_final_build(world: &World) -> Result<FinalBuild, GameError> {
+        let inventory = world
+            .get_resource::<Inventory>()
+            .ok_or(GameError::MissingResource("Inventory"))?;
+        let field = world.get_resource::<Field>();
+
+        let mut units: Vec<BuildUnit> = inventory
+            .abnormalities
+            .iter_owned()
+            .map(|owned| {
+                let uuid = owned.meta.uuid;
+                let mut equipment: Vec<String> = inventory
+                    .equipments
+                    .iter()
+                    .filter(|e| e.equipped_to == Some(uuid))
+                    .map(|e| e.meta.id.clone())
+                    .collect();
+                equipment.sort();
+
+                BuildUnit {
+                    uuid,
+                    id: owned.meta.id.clone(),
+                    position: field.and_then(|f| f.unit_positions.get(&uuid).copied()),
+                    equipment,
+                    growth: owned.growth_stacks.clone(),
+                }
+            })
+            .collect();
+        units.sort_by_key(|unit| unit.uuid);
+
+        let mut unequipped: Vec<String> = inventory
+            .equipments
+            .iter()
+            .filter(|e| e.equipped_to.is_none())
+            .map(|e| e.meta.id.clone())
+            .collect();
+        unequipped.sort();
+
+        let artifacts = inventory.artifacts.iter().map(|a| a.id.clone()).collect();
+
+        Ok(FinalBuild {
+            units,
+            unequipped,
+            artifacts,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::enums::{EnkephalinFlow, PhaseEventKind, RewardEventKind};
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.insert_resource(Enkephalin::new(500));
+        world.insert_resource(Inventory::new());
+        world.insert_resource(Qliphoth::new());
+        world.insert_resource(GameProgression::new());
+        world.insert_resource(Field::new(4, 4));
+        world.insert_resource(RunRecord::new());
+        world
+    }
+
+    #[test]
+    fn test_battle_end_by_ordeal_loss_limit() {
+        let mut record = RunRecord::new();
+        let qliphoth = Qliphoth::new();
+        record.ordeal_losses = 2;
+
+        assert_eq!(
+            RunManager::check_battle_end(&record, &qliphoth, RewardOutcome::Loss, 3),
+            None
+        );
+
+        record.ordeal_losses = 3;
+        assert_eq!(
+            RunManager::check_battle_end(&record, &qliphoth, RewardOutcome::Loss, 3),
+            Some(RunEndReason::OrdealLossLimit)
+        );
+        // Then: 승리로는 종료되지 않음
+        assert_eq!(
+            RunManager::check_battle_end(&record, &qliphoth, RewardOutcome::Win, 3),
+            None
+        );
+    }
+
+    #[test]
+    fn test_battle_end_by_meltdown_defeat() {
+        let record = RunRecord::new();
+        let mut qliphoth = Qliphoth::new();
+        qliphoth.set_amount(0);
+
+        assert_eq!(
+            RunManager::check_battle_end(&record, &qliphoth, RewardOutcome::Loss, 3),
+            Some(RunEndReason::Meltdown)
+        );
+    }
+
+    #[test]
+    fn test_summary_requires_ended_run() {
+        let world = setup_world();
+        assert!(matches!(
+            RunManager::build_summary(&world, 1),
+            Err(GameError::PhaseNotReady)
+        ));
+    }
+
+    #[test]
+    fn test_build_summary() {
+        let mut world = setup_world();
+        RunRecord::track_gain(&mut world, EnkephalinFlow::Battle, 800);
+        RunRecord::track_spend(&mut world, EnkephalinFlow::ShopPurchase, 300);
+        {
+            let mut record = world.get_resource_mut::<RunRecord>().unwrap();
+            record.phase_results.push(PhaseResult {
+                ordeal: OrdealType::Dawn,
+                phase: PhaseType::I,
+                event: Some(PhaseEventKind::Shop),
+                enkephalin: 500,
+                qliphoth: 10,
+            });
+            record.record_battle(BattleRecord {
+                ordeal: OrdealType::Dawn,
+                phase: PhaseType::II,
+                kind: RewardEventKind::Ordeal,
+                outcome: RewardOutcome::Win,
+                enkephalin_gained: 800,
+                enkephalin_lost: 0,
+            });
+            record.end_reason = Some(RunEndReason::Cleared);
+        }
+
+        let summary = RunManager::build_summary(&world, 42).unwrap();
+
+        assert_eq!(summary.run_seed, 42);
+        assert_eq!(summary.data_version, 0);
+        assert!(summary.is_victory());
+        assert_eq!(summary.score(), 1);
+        assert_eq!(summary.battles_won(), 1);
+        assert_eq!(summary.enkephalin_earned, 800);
+        assert_eq!(summary.enkephalin_spent, 300);
+        assert_eq!(summary.final_enkephalin, 500);
+        assert!(summary.final_build.units.is_empty());
+        assert_eq!(summary.to_stats()["end_reason"], "Cleared");
+    }
+}
@@ -1,23 +1,38 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 use tracing::{debug, info, warn};
 use uuid::Uuid;
 
+use crate::config::{balance, BalancePin, GameBalanceConfig};
 use crate::ecs::components::Player;
 use crate::ecs::resources::item_slot::EquippedRef;
 use crate::ecs::resources::{
-    ActionValidator, CurrentPhaseEvents, Enkephalin, Field, GameProgression, GameState, Inventory,
-    InventoryDiffDto, Position, Qliphoth, SelectedEvent,
+    ActionValidator, BattleRecord, BattleStreak, CurrentPhaseEvents, Enkephalin, EnkephalinChange,
+    Field, GameProgression, GameState, Inventory, InventoryDiffDto, PhaseResult, Position,
+    Qliphoth, QliphothLevel, RandomEventSession, RunRecord, SelectedEvent,
 };
 use crate::ecs::systems::{progression, spawn_player};
+use crate::game::battle::core::BattleCore;
+use crate::game::battle::timeline::Timeline;
+use crate::game::battle::types::{BattleWinner, OwnedArtifact, OwnedUnit, PlayerDeckInfo};
 use crate::game::behavior::{BehaviorResult, GameError, PlayerBehavior};
-use crate::game::data::{random_event_data::RandomEventTarget, GameDataBase};
-use crate::game::enums::{BonusAction, GameOption, OrdealType, PhaseType, ShopAction, ZoneType};
+use crate::game::data::{
+    random_event_data::RandomEventTarget, registry::DataVersion, GameDataBase,
+};
+use crate::game::enums::{
+    BonusAction, GameOption, OrdealType, PhaseEventKind, PhaseType, RandomEventAction,
+    RewardEventKind, RewardOutcome, RunEndReason, ShopAction, Tier, ZoneType,
+};
 use crate::game::events::event_selection::bonus::BonusExecutor;
+use crate::game::events::event_selection::random::RandomEventExecutor;
 use crate::game::events::event_selection::shop::ShopExecutor;
 use crate::game::events::suppression::SuppressionExecutor;
 use crate::game::events::GeneratorContext;
 use crate::game::managers::action_scheduler::ActionScheduler;
 use crate::game::managers::event_manager::EventManager;
+use crate::game::managers::qliphoth_manager::QliphothManager;
+use crate::game::managers::reward_manager::{RewardManager, RewardResolution};
+use crate::game::managers::run_manager::{RunManager, RunSummary};
 use crate::game::managers::uuid_manager::UuidManager;
 // use crate::game::{battle::BattleWinner, determinism};
 use crate::game::determinism;
@@ -25,16 +40,37 @@ use crate::game::determinism;
 pub struct GameCore {
     world: bevy_ecs::world::World,
     game_data: Arc<GameDataBase>,
+    /// 런 시작 시 고정된 데이터/밸런스 버전 (핫 리로드의 영향을 받지 않음)
+    data_version: Arc<DataVersion>,
     run_seed: u64,
+    /// 다음 시련 전투 상대 (game_server 가 Ghost 스냅샷으로 채움)
+    ordeal_opponent: Option<PlayerDeckInfo>,
+    /// 마지막 전투 타임라인 (클라이언트 재생용, take_battle_timeline 으로 가져감)
+    last_battle_timeline: Option<Timeline>,
 }
 
 impl GameCore {
     /// GameCore 생성
     ///
+    /// 현재 전역 밸런스 설정을 고정해서 사용한다.
+    ///
     /// # Arguments
     /// * `game_data` - game_server에서 로드한 게임 데이터 (Arc로 공유)
     pub fn new(game_data: Arc<GameDataBase>, run_seed: u64) -> Self {
-        info!("Initializing GameCore with run_seed={}", run_seed);
+        let version = DataVersion::unversioned(game_data, GameBalanceConfig::global());
+        Self::with_version(Arc::new(version), run_seed)
+    }
+
+    /// 레지스트리의 특정 데이터 버전으로 GameCore 생성
+    ///
+    /// # Arguments
+    /// * `data_version` - DataRegistry::current() 로 얻은 버전 (런이 끝날 때까지 고정)
+    pub fn with_version(data_version: Arc<DataVersion>, run_seed: u64) -> Self {
+        info!(
+            "Initializing GameCore with run_seed={}, data_version={}",
+            run_seed, data_version.id
+        );
+        let _pin = GameBalanceConfig::pin(data_version.balance.clone());
 
         let mut world = bevy_ecs::world::World::new();
 
@@ -47,6 +83,8 @@ impl GameCore {
         world.insert_resource(GameState::NotStarted);
         world.insert_resource(Inventory::new());
         world.insert_resource(Qliphoth::new());
+        world.insert_resource(BattleStreak::new());
+        world.insert_resource(RunRecord::with_data_version(data_version.id));
         world.insert_resource(Field::new(4, 4));
 
         // CurrentGameContext 초기화 (NotStarted 상태의 allowed_actions 설정)
@@ -59,17 +97,31 @@ impl GameCore {
 
         Self {
             world,
-            game_data,
+            game_data: data_version.data.clone(),
+            data_version,
             run_seed,
+            ordeal_opponent: None,
+            last_battle_timeline: None,
         }
     }
 
+    /// 이 런에 고정된 데이터 버전 ID
+    pub fn data_version(&self) -> u64 {
+        self.data_version.id
+    }
+
+    /// 처리 중 balance::* 가 이 런의 설정을 읽도록 고정
+    fn pin_balance(&self) -> BalancePin {
+        GameBalanceConfig::pin(self.data_version.balance.clone())
+    }
+
     pub fn execute(
         &mut self,
         player_id: Uuid,
         behavior: PlayerBehavior,
     ) -> Result<BehaviorResult, GameError> {
         debug!("Executing behavior {:?} for player {}", behavior, player_id);
+        let _pin = self.pin_balance();
         // 1. 행동 검증 (치팅 방지)
         let context = self
             .world
@@ -120,6 +172,14 @@ impl GameCore {
             PlayerBehavior::ClaimBonus => self.execute_bonus_action(BonusAction::Claim),
             PlayerBehavior::ExitBonus => self.execute_bonus_action(BonusAction::Exit),
 
+            // 랜덤 이벤트 관련 행동
+            PlayerBehavior::SelectEventChoice { choice_id } => {
+                self.execute_random_event_action(RandomEventAction::SelectChoice { choice_id })
+            }
+            PlayerBehavior::ExitRandomEvent => {
+                self.execute_random_event_action(RandomEventAction::Exit)
+            }
+
             // 진압 관련 행동
             PlayerBehavior::StartSuppression { abnormality_id } => {
                 self.handle_start_suppression(&abnormality_id)
@@ -245,6 +305,19 @@ impl GameCore {
             .get_resource_mut::<CurrentPhaseEvents>()
             .ok_or(GameError::MissingResource("CurrentPhaseEvents"))?;
 
+        // 시련 상대가 아직 없으면 선택지를 소비하지 않고 거절 (서버가 상대를 준비한 뒤 다시 선택)
+        if matches!(
+            current_phase_events.get_event(selected_event_id),
+            Some(GameOption::OrdealBattle { .. })
+        ) && self.ordeal_opponent.is_none()
+        {
+            warn!(
+                "Ordeal battle selected without opponent: {}",
+                selected_event_id
+            );
+            return Err(GameError::PhaseNotReady);
+        }
+
         let event = current_phase_events
             .remove_event(selected_event_id)
             .ok_or_else(|| {
@@ -357,6 +430,24 @@ impl GameCore {
                             ),
                         })
                     }
+                    RandomEventTarget::Story(story_meta) => {
+                        let story = story_meta.clone();
+                        let node = RandomEventExecutor::start(&mut self.world, &story)?;
+
+                        self.transition_to(GameState::InRandomEvent {
+                            event_uuid: story.uuid,
+                        })?;
+
+                        info!(
+                            "Random event '{}' routed to story: id={}, uuid={}",
+                            event.id, story.id, story.uuid
+                        );
+
+                        Ok(BehaviorResult::RandomEventStep {
+                            outcome: None,
+                            node: Some(node),
+                        })
+                    }
                 }
             }
 
@@ -389,12 +480,21 @@ impl GameCore {
                 difficulty,
                 uuid,
             } => {
-                // TODO: 전투 시스템 구현 전까지는 softlock 방지를 위해 즉시 Phase를 진행
-                warn!(
-                    "Ordeal battle not implemented yet (ordeal_type={:?}, difficulty={}, uuid={}); advancing phase",
-                    ordeal_type, difficulty, uuid
-                );
-                self.advance_to_next_phase()
+                let Some(opponent) = self.ordeal_opponent.take() else {
+                    warn!(
+                        "Ordeal battle without opponent (ordeal_type={:?}, difficulty={}, uuid={})",
+                        ordeal_type, difficulty, uuid
+                    );
+                    return Err(GameError::PhaseNotReady);
+                };
+
+                let winner = self.run_ordeal_battle(&opponent)?;
+                let resolution = self.apply_battle_result(RewardEventKind::Ordeal, winner)?;
+
+                match self.advance_to_next_phase()? {
+                    ended @ BehaviorResult::RunEnded { .. } => Ok(ended),
+                    _ => Ok(BehaviorResult::Ordeal { winner, resolution }),
+                }
             }
         }
     }
@@ -572,25 +672,86 @@ impl GameCore {
         }
     }
 
+    // ============================================================
+    // 랜덤 이벤트 관련 통합 핸들러
+    // ============================================================
+
+    fn execute_random_event_action(
+        &mut self,
+        action: RandomEventAction,
+    ) -> Result<BehaviorResult, GameError> {
+        match action {
+            RandomEventAction::SelectChoice { choice_id } => {
+                let story_uuid = self
+                    .world
+                    .get_resource::<RandomEventSession>()
+                    .map(|session| session.story_uuid)
+                    .ok_or(GameError::NotInRandomEventState)?;
+
+                let game_data = self.game_data.clone();
+                let story = game_data
+                    .random_event_story_data
+                    .get_by_uuid(&story_uuid)
+                    .ok_or(GameError::EventNotFound)?;
+
+                // 같은 Phase/같은 선택 순서면 항상 같은 확률 결과가 나오도록 phase seed 사용
+                let (ordeal, phase) = self.get_progression()?;
+                let phase_seed = determinism::seed_for_phase(self.run_seed, ordeal, phase);
+
+                let (outcome, node) = RandomEventExecutor::select_choice(
+                    &mut self.world,
+                    story,
+                    &game_data.item_registry,
+                    phase_seed,
+                    &choice_id,
+                )?;
+
+                if node.is_none() {
+                    // 마지막 노드: 나가기만 허용
+                    self.transition_to(GameState::InRandomEventFinished {
+                        event_uuid: story_uuid,
+                    })?;
+                }
+
+                Ok(BehaviorResult::RandomEventStep {
+                    outcome: Some(outcome),
+                    node,
+                })
+            }
+
+            RandomEventAction::Exit => self.advance_to_next_phase(),
+        }
+    }
+
     /// Phase 완료 후 다음 Phase로 진행
     ///
     /// 이벤트(상점/보너스/랜덤) 완료 후 호출되어 다음 Phase로 전환합니다.
     fn advance_to_next_phase(&mut self) -> Result<BehaviorResult, GameError> {
+        // 전투로 이미 런이 끝났으면 더 진행하지 않음
+        if self.is_run_ended() {
+            return Ok(BehaviorResult::RunEnded {
+                summary: RunManager::build_summary(&self.world, self.run_seed)?,
+            });
+        }
+
         // Phase 종료 시 클리포트 자동 회복
         {
-            // let mut qliphoth = self
-            //     .world
-            //     .get_resource_mut::<Qliphoth>()
-            //     .ok_or(GameError::MissingResource("Qliphoth"))?;
-            // QliphothManager::apply_phase_recovery(&mut qliphoth);
+            let mut qliphoth = self
+                .world
+                .get_resource_mut::<Qliphoth>()
+                .ok_or(GameError::MissingResource("Qliphoth"))?;
+            QliphothManager::apply_phase_recovery(&mut qliphoth);
         }
 
+        self.record_phase_result()?;
+
         // Phase가 끝났으므로 선택지/선택 이벤트 리소스는 폐기
         if let Some(mut current_phase_events) = self.world.get_resource_mut::<CurrentPhaseEvents>()
         {
             current_phase_events.clear();
         }
         let _ = self.world.remove_resource::<SelectedEvent>();
+        let _ = self.world.remove_resource::<RandomEventSession>();
 
         let mut game_progression = self
             .world
@@ -619,8 +780,8 @@ impl GameCore {
             progression::ProgressionResult::GameComplete => {
                 info!("Game completed!");
                 drop(game_progression);
-                self.transition_to(GameState::GameOver)?;
-                Ok(BehaviorResult::Ok)
+                let summary = self.end_run(RunEndReason::Cleared)?;
+                Ok(BehaviorResult::RunEnded { summary })
             }
         }
     }
@@ -672,39 +833,258 @@ impl GameCore {
 
         info!("Starting suppression for abnormality: {}", abnormality_id);
 
-        let battle_result = SuppressionExecutor::start_battle(
-            &mut self.world,
+        let player = self.battle_deck()?;
+        let (ordeal, phase) = self.get_progression()?;
+        let battle_seed = determinism::seed_for_phase(self.run_seed, ordeal, phase);
+        let battle = SuppressionExecutor::start_battle(
+            &self.world,
             self.game_data.clone(),
+            &player,
             abnormality_id,
+            battle_seed,
+            self.data_version.id,
+        )?;
+        self.last_battle_timeline = Some(battle.timeline);
+
+        // 클리포트 붕괴 상태에서의 진압은 백야(강제 Breach)로 처리
+        let kind = if self.get_qliphoth()?.level() == QliphothLevel::Meltdown {
+            RewardEventKind::WhiteNights
+        } else {
+            RewardEventKind::Suppression
+        };
+        let resolution = self.apply_battle_result(kind, battle.winner)?;
+
+        match self.advance_to_next_phase()? {
+            ended @ BehaviorResult::RunEnded { .. } => Ok(ended),
+            _ => Ok(BehaviorResult::SuppressionBattle {
+                winner: battle.winner,
+                resolution,
+            }),
+        }
+    }
+
+    // ============================================================
+    // 시련 전투 (Ghost)
+    // ============================================================
+
+    /// 현재 플레이어 덱 (전투/Ghost 스냅샷용)
+    ///
+    /// 필드에 배치된 기물만 출전한다. 배치된 기물이 없으면 보유 기물 전체가 출전하고
+    /// 위치는 전투 필드의 빈 칸에 순서대로 배정된다.
+    pub fn battle_deck(&self) -> Result<PlayerDeckInfo, GameError> {
+        let field = self
+            .world
+            .get_resource::<Field>()
+            .ok_or(GameError::MissingResource("Field"))?;
+        let inventory = self
+            .world
+            .get_resource::<Inventory>()
+            .ok_or(GameError::MissingResource("Inventory"))?;
+
+        // 결정성을 위해 UUID 순으로 정렬
+        let mut abnormalities: Vec<_> = inventory.abnormalities.iter_owned().collect();
+        abnormalities.sort_by(|a, b| a.meta.uuid.as_bytes().cmp(b.meta.uuid.as_bytes()));
+        let any_placed = abnormalities
+            .iter()
+            .any(|abnormality| field.get_position(abnormality.meta.uuid).is_some());
+
+        let mut units = Vec::new();
+        let mut positions = HashMap::new();
+        for abnormality in abnormalities {
+            let unit_uuid = abnormality.meta.uuid;
+            let position = field.get_position(unit_uuid);
+            if any_placed && position.is_none() {
+                continue;
+            }
+
+            units.push(OwnedUnit {
+                base_uuid: unit_uuid,
+                level: Tier::I,
+                growth_stacks: abnormality.growth_stacks.clone(),
+                equipped_items: abnormality.item_slot.iter().map(|r| r.base_uuid).collect(),
+            });
+            if let Some(position) = position {
+                positions.insert(unit_uuid, position);
+            }
+        }
+
+        let artifacts = inventory
+            .artifacts
+            .iter()
+            .map(|artifact| OwnedArtifact {
+                base_uuid: artifact.uuid,
+            })
+            .collect();
+
+        Ok(PlayerDeckInfo {
+            units,
+            artifacts,
+            positions,
+        })
+    }
+
+    /// 다음 시련 전투 상대 지정 (전투가 시작되면 소비됨)
+    pub fn set_ordeal_opponent(&mut self, opponent: PlayerDeckInfo) {
+        self.ordeal_opponent = Some(opponent);
+    }
+
+    pub fn has_ordeal_opponent(&self) -> bool {
+        self.ordeal_opponent.is_some()
+    }
+
+    /// 현재 Phase 선택지 중 `event_id` 가 시련 전투인지
+    pub fn is_ordeal_event(&self, event_id: Uuid) -> bool {
+        self.world
+            .get_resource::<CurrentPhaseEvents>()
+            .and_then(|events| events.get_event(event_id))
+            .is_some_and(|event| matches!(event, GameOption::OrdealBattle { .. }))
+    }
+
+    /// 마지막 전투 타임라인 (한 번만 반환)
+    pub fn take_battle_timeline(&mut self) -> Option<Timeline> {
+        self.last_battle_timeline.take()
+    }
+
+    /// 마지막으로 가져간 뒤 기록된 엔케팔린 증감 (한 번만 반환)
+    pub fn take_enkephalin_changes(&mut self) -> Vec<EnkephalinChange> {
+        self.world
+            .get_resource_mut::<RunRecord>()
+            .map(|mut record| std::mem::take(&mut record.enkephalin_changes))
+            .unwrap_or_default()
+    }
+
+    /// 현재 덱과 상대 덱으로 BattleCore 실행 (Phase seed 사용)
+    fn run_ordeal_battle(&mut self, opponent: &PlayerDeckInfo) -> Result<BattleWinner, GameError> {
+        let player = self.battle_deck()?;
+        let (ordeal, phase) = self.get_progression()?;
+        let battle_seed = determinism::seed_for_phase(self.run_seed, ordeal, phase);
+        let field_size = self
+            .world
+            .get_resource::<Field>()
+            .map(|field| (field.width, field.height))
+            .ok_or(GameError::MissingResource("Field"))?;
+
+        let battle = BattleCore::new(&player, opponent, self.game_data.clone(), field_size)
+            .with_seed(battle_seed)
+            .with_data_version(self.data_version.id)
+            .run_battle()?;
+
+        info!(
+            "Ordeal battle finished: ordeal={:?}, phase={:?}, winner={:?}",
+            ordeal, phase, battle.winner
+        );
+        self.last_battle_timeline = Some(battle.timeline);
+        Ok(battle.winner)
+    }
+
+    // ============================================================
+    // 전투 보상 통합 핸들러
+    // ============================================================
+
+    /// 전투 결과에 따른 보상/페널티 처리
+    ///
+    /// 진압/시련/백야 전투 결과는 모두 이 경로로 RewardManager 에 전달된다.
+    /// 같은 run_seed + 같은 Phase 면 항상 같은 보상이 나온다.
+    pub fn apply_battle_result(
+        &mut self,
+        kind: RewardEventKind,
+        winner: BattleWinner,
+    ) -> Result<RewardResolution, GameError> {
+        if self.is_run_ended() {
+            return Err(GameError::InvalidAction);
+        }
+        let _pin = self.pin_balance();
+
+        let (ordeal, phase) = self.get_progression()?;
+        let phase_seed = determinism::seed_for_phase(self.run_seed, ordeal, phase);
+        let outcome = RewardOutcome::from(winner);
+        let resolution = RewardManager::resolve(
+            &mut self.world,
+            &self.game_data.equipment_data,
+            ordeal,
+            kind,
+            outcome,
+            phase_seed,
         )?;
 
-        // 진압 작업 성공 시, 해당 몬스터의 드랍템 확률 발생
+        // 전투 기록 후 종료 조건 판정
+        let end_reason = {
+            let qliphoth = self.get_qliphoth()?;
+            let mut record = self
+                .world
+                .get_resource_mut::<RunRecord>()
+                .ok_or(GameError::MissingResource("RunRecord"))?;
+            record.record_battle(BattleRecord {
+                ordeal,
+                phase,
+                kind,
+                outcome,
+                enkephalin_gained: resolution.enkephalin_gained,
+                enkephalin_lost: resolution.enkephalin_lost,
+            });
+            RunManager::check_battle_end(
+                &record,
+                &qliphoth,
+                outcome,
+                balance::run().max_ordeal_losses,
+            )
+        };
+
+        if let Some(reason) = end_reason {
+            self.end_run(reason)?;
+        }
 
-        // info!(
-        //     "Suppression battle completed - Winner: {:?}",
-        //     battle_result.winner
-        // );
+        Ok(resolution)
+    }
 
-        // {
-        //     // 클리포드 감소는 게임 룰이 명확해지고 나서.
+    // ============================================================
+    // 런 기록 / 종료
+    // ============================================================
 
-        //     // let mut qliphoth = self
-        //     //     .world
-        //     //     .get_resource_mut::<Qliphoth>()
-        //     //     .ok_or(GameError::MissingResource("Qliphoth"))?;
-        //     // QliphothManager::apply_battle_cost(&mut qliphoth);
+    /// 런 종료 처리 → GameOver 전환 후 RunSummary 반환
+    fn end_run(&mut self, reason: RunEndReason) -> Result<RunSummary, GameError> {
+        info!("Run ended: reason={:?}", reason);
+        self.world
+            .get_resource_mut::<RunRecord>()
+            .ok_or(GameError::MissingResource("RunRecord"))?
+            .end_reason = Some(reason);
+        self.transition_to(GameState::GameOver)?;
 
-        //     match battle_result.winner {
-        //         BattleWinner::Player => {
-        //             // QliphothManager::apply_suppress_success(&mut qliphoth)
-        //         }
-        //         BattleWinner::Opponent | BattleWinner::Draw => {
-        //             // QliphothManager::apply_suppress_failure(&mut qliphoth)
-        //         }
-        //     }
-        // }
+        RunManager::build_summary(&self.world, self.run_seed)
+    }
 
-        self.advance_to_next_phase()
+    fn is_run_ended(&self) -> bool {
+        self.world
+            .get_resource::<RunRecord>()
+            .is_some_and(|record| record.is_ended())
+    }
+
+    /// 현재 Phase 결과 기록 (SelectedEvent 제거 전에 호출)
+    fn record_phase_result(&mut self) -> Result<(), GameError> {
+        let (ordeal, phase) = self.get_progression()?;
+        let event = self
+            .world
+            .get_resource::<SelectedEvent>()
+            .map(|selected| PhaseEventKind::from(&selected.event));
+        let result = PhaseResult {
+            ordeal,
+            phase,
+            event,
+            enkephalin: self.get_enkephalin(),
+            qliphoth: self.get_qliphoth()?.amount(),
+        };
+
+        self.world
+            .get_resource_mut::<RunRecord>()
+            .ok_or(GameError::MissingResource("RunRecord"))?
+            .phase_results
+            .push(result);
+        Ok(())
+    }
+
+    /// 런 결과 요약 조회 (런이 끝나지 않았으면 None)
+    pub fn run_summary(&self) -> Option<RunSummary> {
+        RunManager::build_summary(&self.world, self.run_seed).ok()
     }
 }
 
@@ -787,6 +1167,17 @@ impl GameCore {
             .unwrap_or(0)
     }
 
+    /// 런 동안 기록된 전투 목록 조회
+    ///
+    /// # Returns
+    /// RunRecord의 전투 기록. Resource가 없으면 빈 슬라이스
+    pub fn get_battle_records(&self) -> &[BattleRecord] {
+        self.world
+            .get_resource::<RunRecord>()
+            .map(|record| record.battles.as_slice())
+            .unwrap_or_default()
+    }
+
     /// 현재 Phase의 이벤트 개수 조회
     ///
     /// # Returns
@@ -823,3 +1214,241 @@ impl GameCore {
             .unwrap_or(false)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+    use crate::game::data::pve_data::{
+        PveEncounter, PveEncounterDatabase, PvePosition, PveUnitData,
+    };
+    use crate::game::data::Item;
+    use crate::game::enums::RiskLevel;
+
+    const ABNORMALITY_ID: &str = "f-01-02";
+
+    /// 번들 데이터 + 테스트용 PvE 조우로 시작된 GameCore
+    ///
+    /// `opponent_units` 개수만큼 진압 대상 환상체를 상대로 배치한다.
+    fn started_core(opponent_units: usize) -> (GameCore, Uuid) {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../game_resources/data");
+        let mut data = GameDataBase::load_from_dir(dir).unwrap_or_else(|e| panic!("{}", e));
+        data.pve_data = Arc::new(PveEncounterDatabase::new(vec![PveEncounter {
+            id: "test_encounter".to_string(),
+            abnormality_id: ABNORMALITY_ID.to_string(),
+            difficulty: 1,
+            risk_level: RiskLevel::ZAYIN,
+            units: (0..opponent_units)
+                .map(|i| PveUnitData {
+                    abnormality_id: ABNORMALITY_ID.to_string(),
+                    position: PvePosition { x: i as i32, y: 0 },
+                    tier: Tier::I,
+                })
+                .collect(),
+        }]));
+
+        let player_id = Uuid::from_u128(0x9001);
+        let mut core = GameCore::new(Arc::new(data), 42);
+        core.execute(player_id, PlayerBehavior::StartNewGame)
+            .unwrap();
+        (core, player_id)
+    }
+
+    fn battle_records(core: &GameCore) -> Vec<BattleRecord> {
+        core.world
+            .get_resource::<RunRecord>()
+            .unwrap()
+            .battles
+            .clone()
+    }
+
+    fn give_abnormality(core: &mut GameCore) {
+        let meta = core
+            .game_data
+            .abnormality_data
+            .get_by_id(ABNORMALITY_ID)
+            .cloned()
+            .unwrap();
+        core.world
+            .get_resource_mut::<Inventory>()
+            .unwrap()
+            .add_item_owned(meta.uuid, Item::Abnormality(Arc::new(meta)))
+            .unwrap();
+    }
+
+    /// 진압 후보를 현재 Phase 선택지로 올리고 SelectingEvent 로 전환
+    fn offer_suppression(core: &mut GameCore) {
+        core.world
+            .get_resource_mut::<CurrentPhaseEvents>()
+            .unwrap()
+            .add_event(GameOption::SuppressAbnormality {
+                abnormality_id: ABNORMALITY_ID.to_string(),
+                risk_level: RiskLevel::ZAYIN,
+                uuid: Uuid::from_u128(0x5001),
+            });
+        core.transition_to(GameState::SelectingEvent).unwrap();
+    }
+
+    fn start_suppression(core: &mut GameCore, player_id: Uuid) -> BehaviorResult {
+        core.execute(
+            player_id,
+            PlayerBehavior::StartSuppression {
+                abnormality_id: ABNORMALITY_ID.to_string(),
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_suppression_battle_is_resolved_and_recorded() {
+        // Given: 상대가 없는 조우 → 플레이어 승리
+        let (mut core, player_id) = started_core(0);
+        give_abnormality(&mut core);
+        offer_suppression(&mut core);
+        let (_, phase_before) = core.get_progression().unwrap();
+
+        let result = start_suppression(&mut core, player_id);
+
+        // Then: 승자와 적용된 보상이 결과로 전달됨
+        let (winner, resolution) = result
+            .as_suppression_battle()
+            .unwrap_or_else(|| panic!("expected SuppressionBattle, got {:?}", result));
+        assert_eq!(winner, BattleWinner::Player);
+        assert_eq!(resolution.kind, RewardEventKind::Suppression);
+        assert_eq!(resolution.outcome, RewardOutcome::Win);
+        assert_eq!(resolution.enkephalin, core.get_enkephalin());
+
+        // Then: 진압 전투 결과가 보상/기록 경로를 거치고 Phase 가 진행됨
+        let records = battle_records(&core);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].kind, RewardEventKind::Suppression);
+        assert_eq!(records[0].outcome, RewardOutcome::Win);
+        assert_eq!(records[0].phase, phase_before);
+        assert!(core.take_battle_timeline().is_some());
+        assert_ne!(core.get_progression().unwrap().1, phase_before);
+    }
+
+    #[test]
+    fn test_suppression_loss_to_meltdown_ends_run() {
+        // Given: 클리포트 Critical(1), 출전 기물 없음 → 진압 패배
+        let (mut core, player_id) = started_core(1);
+        core.world
+            .get_resource_mut::<Qliphoth>()
+            .unwrap()
+            .set_amount(1);
+        offer_suppression(&mut core);
+
+        let result = start_suppression(&mut core, player_id);
+
+        // Then: 진압 실패로 붕괴 → 런 종료 및 기록
+        let BehaviorResult::RunEnded { summary } = result else {
+            panic!("expected RunEnded, got {:?}", result);
+        };
+        assert_eq!(summary.end_reason, RunEndReason::Meltdown);
+        assert!(core.is_run_ended());
+        let records = battle_records(&core);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].kind, RewardEventKind::Suppression);
+        assert_eq!(records[0].outcome, RewardOutcome::Loss);
+    }
+
+    #[test]
+    fn test_suppression_win_tracks_enkephalin_once() {
+        let (mut core, player_id) = started_core(0);
+        give_abnormality(&mut core);
+        offer_suppression(&mut core);
+        let before = core.get_enkephalin();
+
+        start_suppression(&mut core, player_id);
+
+        // Then: 보상은 RewardManager 에서 한 번만 기록됨 (execute 에서 중복 누적되지 않음)
+        let record = core.world.get_resource::<RunRecord>().unwrap();
+        let gained = record.battles[0].enkephalin_gained;
+        assert!(gained > 0);
+        assert_eq!(core.get_enkephalin(), before + gained);
+        assert_eq!(record.enkephalin_earned, u64::from(gained));
+        assert_eq!(record.enkephalin_spent, 0);
+    }
+
+    #[test]
+    fn test_ordeal_loss_penalty_tracked_as_spent() {
+        // Given: Dusk 시련 패배 페널티 500
+        let (mut core, _) = started_core(0);
+        core.world
+            .get_resource_mut::<GameProgression>()
+            .unwrap()
+            .current_ordeal = OrdealType::Dusk;
+        core.set_enkephalin(800);
+
+        core.apply_battle_result(RewardEventKind::Ordeal, BattleWinner::Opponent)
+            .unwrap();
+
+        let record = core.world.get_resource::<RunRecord>().unwrap();
+        assert_eq!(core.get_enkephalin(), 300);
+        assert_eq!(record.enkephalin_spent, 500);
+        assert_eq!(record.enkephalin_earned, 0);
+    }
+
+    #[test]
+    fn test_suppression_during_meltdown_is_white_nights() {
+        let (mut core, player_id) = started_core(0);
+        give_abnormality(&mut core);
+        core.world
+            .get_resource_mut::<Qliphoth>()
+            .unwrap()
+            .set_amount(0);
+        offer_suppression(&mut core);
+
+        start_suppression(&mut core, player_id);
+
+        let records = battle_records(&core);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].kind, RewardEventKind::WhiteNights);
+    }
+
+    #[test]
+    fn test_ordeal_without_opponent_is_not_ready() {
+        let (mut core, player_id) = started_core(0);
+        let ordeal_event = Uuid::from_u128(0x6001);
+        core.world
+            .get_resource_mut::<CurrentPhaseEvents>()
+            .unwrap()
+            .add_event(GameOption::OrdealBattle {
+                ordeal_type: OrdealType::Dawn,
+                difficulty: 1,
+                uuid: ordeal_event,
+            });
+        core.transition_to(GameState::SelectingEvent).unwrap();
+        let progression = core.get_progression().unwrap();
+        let select = PlayerBehavior::SelectEvent {
+            event_id: ordeal_event,
+        };
+
+        assert!(core.is_ordeal_event(ordeal_event));
+
+        // Then: 상대가 없으면 거절되고 선택지와 Phase 는 그대로 남는다
+        let error = core.execute(player_id, select.clone()).unwrap_err();
+        assert!(matches!(error, GameError::PhaseNotReady));
+        assert_eq!(core.get_progression().unwrap(), progression);
+        assert!(core
+            .world
+            .get_resource::<CurrentPhaseEvents>()
+            .unwrap()
+            .get_event(ordeal_event)
+            .is_some());
+        assert!(battle_records(&core).is_empty());
+
+        // 상대가 준비되면 같은 선택지로 전투가 진행된다
+        core.set_ordeal_opponent(PlayerDeckInfo {
+            units: vec![],
+            artifacts: vec![],
+            positions: HashMap::new(),
+        });
+        core.execute(player_id, select).unwrap();
+        let records = battle_records(&core);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].kind, RewardEventKind::Ordeal);
+        assert!(!core.has_ordeal_opponent());
+    }
+}
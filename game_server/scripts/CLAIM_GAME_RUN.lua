@@ -0,0 +1,36 @@
+-- KEYS[1] = game_run:{player_id} (Hash: token, pod_id, run_seed, data_version, data_revision, started_at)
+-- KEYS[2] = game_run_log:{player_id} (Hash: index → RunLogEntry JSON)
+-- ARGV[1] = resume_token
+-- ARGV[2] = 새 소유 pod_id
+-- ARGV[3] = ttl (초)
+-- ARGV[4] = 요청한 pod 가 로드한 data_revision
+--
+-- 토큰이 맞고 런을 시작한 data_revision 이 같을 때만 런 소유권을 요청한 pod 로 옮긴다.
+-- revision 이 다르면 소유권을 건드리지 않는다 (이전 pod 의 런은 그대로 유지).
+--
+-- 반환: {'ok', previous_pod_id, run_seed, data_version, started_at}
+--     | {'not_found'} | {'invalid_token'} | {'data_mismatch', recorded_revision}
+
+local run_key = KEYS[1]
+local log_key = KEYS[2]
+local token = ARGV[1]
+local pod_id = ARGV[2]
+local ttl = tonumber(ARGV[3])
+local revision = ARGV[4]
+
+local stored = redis.call('HMGET', run_key, 'token', 'pod_id', 'run_seed', 'data_version', 'data_revision', 'started_at')
+if not stored[1] then
+    return {'not_found'}
+end
+if stored[1] ~= token then
+    return {'invalid_token'}
+end
+if stored[5] ~= revision then
+    return {'data_mismatch', stored[5] or ''}
+end
+
+redis.call('HSET', run_key, 'pod_id', pod_id)
+redis.call('EXPIRE', run_key, ttl)
+redis.call('EXPIRE', log_key, ttl)
+
+return {'ok', stored[2] or '', stored[3] or '0', stored[4] or '0', stored[6] or ''}
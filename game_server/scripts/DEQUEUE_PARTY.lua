@@ -0,0 +1,29 @@
+-- KEYS[1] = queue:{party} (Sorted Set, member = party_id)
+-- ARGV[1] = player_id (파티 멤버 누구나 가능)
+--
+-- 반환: {status, party_id, leader, members_json, state, queue_size}
+
+local queue_key = KEYS[1]
+local player_id = ARGV[1]
+
+local party_id = redis.call('GET', 'player_party:' .. player_id)
+if not party_id then
+    return {'not_in_party'}
+end
+
+local party_key = 'party:' .. party_id
+if redis.call('HGET', party_key, 'state') ~= 'queued' then
+    return {'not_in_queue', party_id}
+end
+
+local members_json = redis.call('HGET', party_key, 'members')
+local members = cjson.decode(members_json)
+
+redis.call('ZREM', queue_key, party_id)
+for _, member_id in ipairs(members) do
+    redis.call('DEL', 'metadata:' .. member_id)
+end
+redis.call('HSET', party_key, 'state', 'idle')
+
+local size = redis.call('ZCARD', queue_key)
+return {'ok', party_id, redis.call('HGET', party_key, 'leader'), members_json, 'idle', size}
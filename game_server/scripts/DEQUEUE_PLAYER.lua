@@ -1,4 +1,5 @@
 -- KEYS[1] = queue:{mode} (Sorted Set)
+-- KEYS[2] = queue_joined:{mode} (optional, MMR 매칭 모드의 대기 시작 시각)
 -- ARGV[1] = player_id
 
 local queue_key = KEYS[1]
@@ -10,6 +11,9 @@ local metadata = redis.call('GET', metadata_key)
 
 -- queue에서 제거
 local removed = redis.call('ZREM', queue_key, player_id)
+if KEYS[2] then
+    redis.call('ZREM', KEYS[2], player_id)
+end
 
 -- metadata 삭제
 if removed == 1 then
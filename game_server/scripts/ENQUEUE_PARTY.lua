@@ -0,0 +1,53 @@
+-- KEYS[1] = queue:{party} (Sorted Set, member = party_id, score = timestamp)
+-- ARGV[1] = leader_id
+-- ARGV[2] = timestamp (score)
+-- ARGV[3] = leader metadata JSON string
+--
+-- 파티 전체를 하나의 항목으로 대기열에 넣는다. 멤버 metadata 는 수락 시 저장된 값을 사용한다.
+-- 반환: {status, party_id, leader, members_json, state, queue_size}
+
+local queue_key = KEYS[1]
+local leader_id = ARGV[1]
+local timestamp = tonumber(ARGV[2])
+local leader_metadata = ARGV[3]
+
+if timestamp == nil or leader_metadata == nil or leader_metadata == "" then
+    return {'invalid_metadata'}
+end
+
+local party_id = redis.call('GET', 'player_party:' .. leader_id)
+if not party_id then
+    return {'not_in_party'}
+end
+
+local party_key = 'party:' .. party_id
+local party_metadata_key = 'party_metadata:' .. party_id
+if redis.call('HGET', party_key, 'leader') ~= leader_id then
+    return {'not_leader', party_id}
+end
+if redis.call('HGET', party_key, 'state') == 'queued' then
+    return {'already_in_queue', party_id}
+end
+
+local members_json = redis.call('HGET', party_key, 'members')
+local members = cjson.decode(members_json)
+
+-- 모든 멤버의 metadata 가 준비되어 있어야 함
+redis.call('HSET', party_metadata_key, leader_id, leader_metadata)
+local metadata = {}
+for _, member_id in ipairs(members) do
+    local member_metadata = redis.call('HGET', party_metadata_key, member_id)
+    if not member_metadata then
+        return {'invalid_metadata', party_id}
+    end
+    metadata[member_id] = member_metadata
+end
+
+for _, member_id in ipairs(members) do
+    redis.call('SET', 'metadata:' .. member_id, metadata[member_id])
+end
+redis.call('ZADD', queue_key, timestamp, party_id)
+redis.call('HSET', party_key, 'state', 'queued')
+
+local size = redis.call('ZCARD', queue_key)
+return {'ok', party_id, leader_id, members_json, 'queued', size}
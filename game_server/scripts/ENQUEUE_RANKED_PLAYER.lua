@@ -0,0 +1,38 @@
+-- KEYS[1] = queue:{mode} (Sorted Set, score = mmr)
+-- KEYS[2] = queue_joined:{mode} (Sorted Set, score = enqueue timestamp)
+-- ARGV[1] = player_id
+-- ARGV[2] = mmr (score)
+-- ARGV[3] = timestamp (최초 enqueue 시각, re-enqueue 시에도 유지)
+-- ARGV[4] = metadata JSON string
+
+local queue_key = KEYS[1]
+local joined_key = KEYS[2]
+local player_id = ARGV[1]
+local mmr = tonumber(ARGV[2])
+local timestamp = tonumber(ARGV[3])
+local metadata_json = ARGV[4]
+
+-- 유효성 검사
+if mmr == nil or timestamp == nil or metadata_json == nil or metadata_json == "" then
+    local size = redis.call('ZCARD', queue_key)
+    return {0, size}
+end
+
+-- 이미 큐에 있는지 확인
+local exists = redis.call('ZSCORE', queue_key, player_id)
+if exists then
+    local size = redis.call('ZCARD', queue_key)
+    return {0, size}
+end
+
+-- 레이팅 큐 + 대기 시작 시각 기록
+redis.call('ZADD', queue_key, mmr, player_id)
+redis.call('ZADD', joined_key, timestamp, player_id)
+
+-- metadata 저장 (JSON 문자열 그대로 저장)
+local metadata_key = 'metadata:' .. player_id
+redis.call('SET', metadata_key, metadata_json)
+
+-- 현재 큐 크기 반환
+local size = redis.call('ZCARD', queue_key)
+return {1, size}
@@ -0,0 +1,44 @@
+-- ARGV[1] = player_id
+-- ARGV[2] = party_id
+-- ARGV[3] = metadata JSON string (pod_id 포함, 파티 enqueue 시 사용)
+-- ARGV[4] = max_size
+--
+-- 반환: {status, party_id, leader, members_json, state}
+
+local player_id = ARGV[1]
+local party_id = ARGV[2]
+local metadata_json = ARGV[3]
+local max_size = tonumber(ARGV[4])
+
+local invite_key = 'party_invite:' .. party_id .. ':' .. player_id
+if redis.call('EXISTS', invite_key) == 0 then
+    return {'invite_not_found'}
+end
+
+if redis.call('EXISTS', 'player_party:' .. player_id) == 1 then
+    return {'already_in_party'}
+end
+
+local party_key = 'party:' .. party_id
+if redis.call('EXISTS', party_key) == 0 then
+    -- 초대 이후 파티가 해산됨
+    redis.call('DEL', invite_key)
+    return {'invite_not_found'}
+end
+
+if redis.call('HGET', party_key, 'state') == 'queued' then
+    return {'party_in_queue', party_id}
+end
+
+local members = cjson.decode(redis.call('HGET', party_key, 'members'))
+if #members >= max_size then
+    return {'party_full', party_id}
+end
+
+table.insert(members, player_id)
+redis.call('HSET', party_key, 'members', cjson.encode(members))
+redis.call('SET', 'player_party:' .. player_id, party_id)
+redis.call('HSET', 'party_metadata:' .. party_id, player_id, metadata_json)
+redis.call('DEL', invite_key)
+
+return {'ok', party_id, redis.call('HGET', party_key, 'leader'), cjson.encode(members), 'idle'}
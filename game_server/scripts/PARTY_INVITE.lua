@@ -0,0 +1,56 @@
+-- ARGV[1] = leader_id (초대하는 플레이어, 파티가 없으면 새 파티의 리더가 됨)
+-- ARGV[2] = target_id
+-- ARGV[3] = new_party_id (파티가 없을 때 사용할 ID)
+-- ARGV[4] = max_size
+-- ARGV[5] = invite_ttl_seconds
+-- ARGV[6] = leader routing metadata JSON (pod_id)
+--
+-- 반환: {status, party_id, leader, members_json, state}
+
+local leader_id = ARGV[1]
+local target_id = ARGV[2]
+local new_party_id = ARGV[3]
+local max_size = tonumber(ARGV[4])
+local invite_ttl = tonumber(ARGV[5])
+local leader_metadata = ARGV[6]
+
+if leader_id == target_id then
+    return {'invalid_target'}
+end
+
+-- 이미 다른 파티에 속한 플레이어는 초대 불가
+if redis.call('EXISTS', 'player_party:' .. target_id) == 1 then
+    return {'already_in_party'}
+end
+
+local party_id = redis.call('GET', 'player_party:' .. leader_id)
+local party_key
+local members
+
+if not party_id then
+    -- 파티가 없으면 초대하는 플레이어를 리더로 새로 생성
+    party_id = new_party_id
+    party_key = 'party:' .. party_id
+    members = {leader_id}
+    redis.call('HSET', party_key, 'leader', leader_id, 'members', cjson.encode(members), 'state', 'idle')
+    redis.call('SET', 'player_party:' .. leader_id, party_id)
+else
+    party_key = 'party:' .. party_id
+    if redis.call('HGET', party_key, 'leader') ~= leader_id then
+        return {'not_leader', party_id}
+    end
+    if redis.call('HGET', party_key, 'state') == 'queued' then
+        return {'party_in_queue', party_id}
+    end
+    members = cjson.decode(redis.call('HGET', party_key, 'members'))
+    if #members >= max_size then
+        return {'party_full', party_id}
+    end
+end
+
+-- 리더 라우팅 정보 (enqueue 시 전체 metadata 로 덮어씀)
+redis.call('HSETNX', 'party_metadata:' .. party_id, leader_id, leader_metadata)
+
+redis.call('SET', 'party_invite:' .. party_id .. ':' .. target_id, leader_id, 'EX', invite_ttl)
+
+return {'ok', party_id, leader_id, cjson.encode(members), redis.call('HGET', party_key, 'state')}
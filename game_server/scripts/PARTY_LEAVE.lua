@@ -0,0 +1,62 @@
+-- KEYS[1] = queue:{party} (Sorted Set, member = party_id)
+-- ARGV[1] = player_id
+--
+-- 파티가 대기열에 있으면 파티 전체를 먼저 dequeue 한다.
+-- 리더가 나가면 가장 먼저 들어온 멤버가 리더가 되고, 마지막 멤버가 나가면 파티를 삭제한다.
+--
+-- 반환: {status, party_id, leader, remaining_members_json, state, was_queued, queue_size, leaver_metadata}
+
+local queue_key = KEYS[1]
+local player_id = ARGV[1]
+
+local party_id = redis.call('GET', 'player_party:' .. player_id)
+if not party_id then
+    return {'not_in_party'}
+end
+
+local party_key = 'party:' .. party_id
+local party_metadata_key = 'party_metadata:' .. party_id
+local leader = redis.call('HGET', party_key, 'leader')
+local members_json = redis.call('HGET', party_key, 'members')
+local members = {}
+if members_json then
+    members = cjson.decode(members_json)
+end
+
+-- 대기 중이면 파티 전체 dequeue
+local was_queued = 0
+if redis.call('HGET', party_key, 'state') == 'queued' then
+    redis.call('ZREM', queue_key, party_id)
+    for _, member_id in ipairs(members) do
+        redis.call('DEL', 'metadata:' .. member_id)
+    end
+    redis.call('HSET', party_key, 'state', 'idle')
+    was_queued = 1
+end
+
+-- 멤버 제거
+local remaining = {}
+for _, member_id in ipairs(members) do
+    if member_id ~= player_id then
+        table.insert(remaining, member_id)
+    end
+end
+local leaver_metadata = redis.call('HGET', party_metadata_key, player_id) or ''
+redis.call('DEL', 'player_party:' .. player_id)
+redis.call('HDEL', party_metadata_key, player_id)
+
+local remaining_json = '[]'
+if #remaining == 0 then
+    redis.call('DEL', party_key, party_metadata_key)
+    leader = ''
+else
+    if leader == player_id then
+        leader = remaining[1]
+        redis.call('HSET', party_key, 'leader', leader)
+    end
+    remaining_json = cjson.encode(remaining)
+    redis.call('HSET', party_key, 'members', remaining_json)
+end
+
+local size = redis.call('ZCARD', queue_key)
+return {'ok', party_id, leader, remaining_json, 'idle', was_queued, size, leaver_metadata}
@@ -0,0 +1,33 @@
+-- KEYS[1] = rate_limit:{scope}:{subject}
+-- ARGV[1] = burst (버킷 크기)
+-- ARGV[2] = per_second (초당 회복 토큰 수)
+-- ARGV[3] = now (unix ms)
+--
+-- 토큰 버킷에서 1개를 꺼낸다. 버킷은 모든 pod 가 공유하며,
+-- 가득 찰 때까지 걸리는 시간이 지나면 만료된다.
+--
+-- 반환: 1 (허용) | 0 (한도 초과)
+
+local key = KEYS[1]
+local burst = tonumber(ARGV[1])
+local per_second = tonumber(ARGV[2])
+local now = tonumber(ARGV[3])
+
+local bucket = redis.call('HMGET', key, 'tokens', 'updated_at')
+local tokens = tonumber(bucket[1]) or burst
+local updated_at = tonumber(bucket[2]) or now
+
+if now > updated_at then
+    tokens = math.min(burst, tokens + (now - updated_at) / 1000 * per_second)
+end
+
+local allowed = 0
+if tokens >= 1 then
+    tokens = tokens - 1
+    allowed = 1
+end
+
+redis.call('HSET', key, 'tokens', tostring(tokens), 'updated_at', tostring(math.max(now, updated_at)))
+local ttl_ms = math.ceil(burst / math.max(per_second, 0.001) * 1000) + 1000
+redis.call('PEXPIRE', key, ttl_ms)
+return allowed
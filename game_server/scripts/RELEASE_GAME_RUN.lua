@@ -0,0 +1,20 @@
+-- KEYS[1] = game_run:{player_id}
+-- KEYS[2] = game_run_log:{player_id}
+-- ARGV[1] = resume_token
+-- ARGV[2] = pod_id
+--
+-- 런이 끝났거나 재접속 유예 시간이 지났을 때 기록 삭제.
+-- 다른 pod 로 이전됐거나 새 런으로 교체된 경우에는 건드리지 않는다.
+--
+-- 반환: 1 (삭제) | 0 (소유자가 아님)
+
+local run_key = KEYS[1]
+local log_key = KEYS[2]
+
+local stored = redis.call('HMGET', run_key, 'token', 'pod_id')
+if stored[1] ~= ARGV[1] or stored[2] ~= ARGV[2] then
+    return 0
+end
+
+redis.call('DEL', run_key, log_key)
+return 1
@@ -0,0 +1,75 @@
+-- KEYS[1] = queue:{party} (Sorted Set, member = party_id, score = timestamp)
+-- ARGV[1] = max_matches (integer)
+-- ARGV[2] = scan_limit (integer)
+--
+-- 오래 기다린 파티부터 같은 인원의 파티끼리 매칭한다.
+-- 결과 형식: [player_id, score, metadata_json, ...]
+-- 두 파티의 i 번째 멤버끼리 한 쌍이 되도록 번갈아 넣는다 (A1, B1, A2, B2, ...).
+
+local queue_key = KEYS[1]
+local max_matches = tonumber(ARGV[1])
+local scan_limit = tonumber(ARGV[2])
+
+if max_matches == nil or max_matches <= 0 or scan_limit == nil or scan_limit <= 0 then
+    return {}
+end
+
+local waiting = redis.call('ZRANGE', queue_key, 0, scan_limit - 1, 'WITHSCORES')
+
+-- 파티를 대기열에서 꺼내고 멤버별 [player_id, score, metadata] 반환
+local function pop_party(party)
+    redis.call('ZREM', queue_key, party.id)
+    redis.call('HSET', 'party:' .. party.id, 'state', 'idle')
+
+    local popped = {}
+    for _, member_id in ipairs(party.members) do
+        local metadata_key = 'metadata:' .. member_id
+        local metadata_json = redis.call('GET', metadata_key)
+        if not metadata_json then
+            metadata_json = "{}"
+        end
+        redis.call('DEL', metadata_key)
+        table.insert(popped, {member_id, party.score, metadata_json})
+    end
+    return popped
+end
+
+local result = {}
+local waiting_by_size = {}
+local match_count = 0
+
+for idx = 1, #waiting, 2 do
+    if match_count >= max_matches then
+        break
+    end
+
+    local party_id = waiting[idx]
+    local members_json = redis.call('HGET', 'party:' .. party_id, 'members')
+
+    if not members_json then
+        -- 해산된 파티의 잔여 항목 정리
+        redis.call('ZREM', queue_key, party_id)
+    else
+        local party = { id = party_id, score = waiting[idx + 1], members = cjson.decode(members_json) }
+        local size = #party.members
+        local opponent = waiting_by_size[size]
+
+        if opponent then
+            waiting_by_size[size] = nil
+            local first = pop_party(opponent)
+            local second = pop_party(party)
+            for i = 1, size do
+                for _, entry in ipairs({first[i], second[i]}) do
+                    table.insert(result, entry[1])
+                    table.insert(result, entry[2])
+                    table.insert(result, entry[3])
+                end
+            end
+            match_count = match_count + 1
+        else
+            waiting_by_size[size] = party
+        end
+    end
+end
+
+return result
@@ -0,0 +1,125 @@
+-- KEYS[1] = queue:{mode} (Sorted Set, score = mmr)
+-- KEYS[2] = queue_joined:{mode} (Sorted Set, score = enqueue timestamp)
+-- ARGV[1] = max_pairs (integer)
+-- ARGV[2] = now (unix timestamp, seconds)
+-- ARGV[3] = base_window
+-- ARGV[4] = window_growth_per_second
+-- ARGV[5] = max_window
+-- ARGV[6] = rd_weight
+-- ARGV[7] = scan_limit (integer)
+--
+-- 오래 기다린 플레이어부터 레이팅 윈도우 안에서 가장 가까운 상대를 찾는다.
+-- 윈도우 = min(base + growth * 대기시간, max) + rd * rd_weight
+-- 상대의 윈도우에도 들어가야 매칭된다 (양방향).
+
+local queue_key = KEYS[1]
+local joined_key = KEYS[2]
+local max_pairs = tonumber(ARGV[1])
+local now = tonumber(ARGV[2])
+local base_window = tonumber(ARGV[3])
+local window_growth = tonumber(ARGV[4])
+local max_window = tonumber(ARGV[5])
+local rd_weight = tonumber(ARGV[6])
+local scan_limit = tonumber(ARGV[7])
+
+-- 유효성 검사
+if max_pairs == nil or max_pairs <= 0 or now == nil or scan_limit == nil or scan_limit <= 0 then
+    return {}
+end
+
+-- metadata 의 rating.rd 읽기 (없으면 0)
+local function read_rd(player_id)
+    local metadata_json = redis.call('GET', 'metadata:' .. player_id)
+    if not metadata_json then
+        return 0
+    end
+    local ok, metadata = pcall(cjson.decode, metadata_json)
+    if ok and type(metadata) == 'table' and type(metadata.rating) == 'table' then
+        local rd = tonumber(metadata.rating.rd)
+        if rd then
+            return rd
+        end
+    end
+    return 0
+end
+
+-- 1. 대기 순서대로 후보 정보 수집
+local waiting = redis.call('ZRANGE', joined_key, 0, scan_limit - 1, 'WITHSCORES')
+local order = {}
+local info = {}
+
+for idx = 1, #waiting, 2 do
+    local player_id = waiting[idx]
+    local joined_at = tonumber(waiting[idx + 1])
+    local score = redis.call('ZSCORE', queue_key, player_id)
+
+    if score then
+        local mmr = tonumber(score)
+        local wait = math.max(0, now - joined_at)
+        local window = math.min(base_window + window_growth * wait, max_window)
+            + read_rd(player_id) * rd_weight
+
+        info[player_id] = { score = score, mmr = mmr, window = window }
+        table.insert(order, player_id)
+    else
+        -- 레이팅 큐에서 빠진 플레이어의 잔여 기록 정리
+        redis.call('ZREM', joined_key, player_id)
+    end
+end
+
+-- 2. 매칭된 플레이어를 큐에서 제거하고 결과에 추가
+local result = {}
+local matched = {}
+
+local function pop_player(player_id)
+    redis.call('ZREM', queue_key, player_id)
+    redis.call('ZREM', joined_key, player_id)
+
+    local metadata_key = 'metadata:' .. player_id
+    local metadata_json = redis.call('GET', metadata_key)
+    if not metadata_json then
+        metadata_json = "{}"
+    end
+    redis.call('DEL', metadata_key)
+
+    -- 결과 형식: [player_id, score, metadata_json, ...] (2명씩 한 쌍)
+    table.insert(result, player_id)
+    table.insert(result, info[player_id].score)
+    table.insert(result, metadata_json)
+end
+
+-- 3. 오래 기다린 순으로 가장 가까운 상대 탐색
+local pair_count = 0
+for _, player_id in ipairs(order) do
+    if pair_count >= max_pairs then
+        break
+    end
+
+    if not matched[player_id] then
+        local me = info[player_id]
+        local nearby = redis.call('ZRANGEBYSCORE', queue_key, me.mmr - me.window, me.mmr + me.window)
+
+        local best = nil
+        local best_gap = nil
+        for _, other_id in ipairs(nearby) do
+            local other = info[other_id]
+            if other_id ~= player_id and other and not matched[other_id] then
+                local gap = math.abs(other.mmr - me.mmr)
+                if gap <= other.window and (best_gap == nil or gap < best_gap) then
+                    best = other_id
+                    best_gap = gap
+                end
+            end
+        end
+
+        if best then
+            matched[player_id] = true
+            matched[best] = true
+            pop_player(player_id)
+            pop_player(best)
+            pair_count = pair_count + 1
+        end
+    end
+end
+
+return result
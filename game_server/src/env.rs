@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use config::{Config, ConfigError, Environment, File, FileFormat};
 use serde::Deserialize;
 
@@ -10,6 +12,23 @@ pub struct Settings {
     pub matchmaking: MatchmakingSettings,
     pub redis: RedisSettings,
     pub retry: RetrySettings,
+    /// auth_server 가 발급한 JWT 검증 설정
+    pub jwt: JwtSettings,
+    /// 비동기 PvP (Ghost 스냅샷) 설정
+    #[serde(default)]
+    pub ghost: GhostSettings,
+    /// MatchCoordinator 대기열 설정
+    #[serde(default)]
+    pub match_coordinator: CoordinatorSettings,
+    /// 게임 연결 재접속/런 복구 설정
+    #[serde(default)]
+    pub resume: ResumeSettings,
+    /// 연결/메시지 속도 제한 설정
+    #[serde(default)]
+    pub rate_limit: RateLimitSettings,
+    /// 런 종료 시 auth_server 로 런 요약 제출
+    #[serde(default)]
+    pub run_report: RunReportSettings,
 }
 
 impl Settings {
@@ -68,6 +87,29 @@ pub struct ServerSettings {
     pub port: u16,
     pub log_level: String,
     pub metrics_auth_token: Option<String>,
+    /// 게임 데이터(RON) 디렉토리
+    #[serde(default = "default_game_data_dir")]
+    pub game_data_dir: String,
+    /// 밸런스 설정 파일 경로 (미설정 시 core/config/game_balance.toml 탐색)
+    #[serde(default)]
+    pub balance_config_path: Option<String>,
+    /// 데이터 파일 변경 감시 주기 (초). 미설정 시 감시하지 않음
+    #[serde(default)]
+    pub data_watch_interval_secs: Option<u64>,
+    /// 관리자 API 토큰 (미설정 시 /admin/* 비활성화)
+    #[serde(default)]
+    pub admin_token: Option<String>,
+    /// 게임 행동이 없을 때 PlayerGameActor 를 종료하기까지의 시간 (초)
+    #[serde(default = "default_player_idle_timeout_secs")]
+    pub player_idle_timeout_secs: u64,
+}
+
+fn default_game_data_dir() -> String {
+    "../game_resources/data".to_string()
+}
+
+fn default_player_idle_timeout_secs() -> u64 {
+    600
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -81,6 +123,235 @@ pub struct MatchModeSettings {
     pub game_mode: GameMode,
     pub required_players: u32,
     pub use_mmr_matching: bool,
+    /// use_mmr_matching = true 일 때 사용하는 레이팅 윈도우 설정
+    #[serde(default)]
+    pub mmr: MmrMatchingSettings,
+    /// Party 모드 설정
+    #[serde(default)]
+    pub party: PartySettings,
+}
+
+/// 파티 설정
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct PartySettings {
+    /// 파티 최대 인원 (리더 포함)
+    pub max_size: usize,
+    /// 초대 유효 시간 (초)
+    pub invite_ttl_seconds: u64,
+    /// TryMatch 1회에 검사할 최대 대기 파티 수 (오래 기다린 순)
+    pub scan_limit: usize,
+}
+
+impl Default for PartySettings {
+    fn default() -> Self {
+        Self {
+            max_size: 4,
+            invite_ttl_seconds: 60,
+            scan_limit: 200,
+        }
+    }
+}
+
+/// MMR 기반 매칭 설정
+///
+/// 허용 레이팅 차이 = min(base_window + window_growth_per_second * 대기시간, max_window) + rd * rd_weight
+/// 두 플레이어 모두의 허용 범위 안에 들어야 매칭된다.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct MmrMatchingSettings {
+    /// 대기 직후 허용 레이팅 차이
+    pub base_window: f64,
+    /// 대기 1초당 늘어나는 허용 폭
+    pub window_growth_per_second: f64,
+    /// 대기시간에 의한 허용 폭 상한 (RD 보정 제외)
+    pub max_window: f64,
+    /// RD(rating deviation) 반영 비율
+    pub rd_weight: f64,
+    /// TryMatch 1회에 검사할 최대 대기자 수 (오래 기다린 순)
+    pub scan_limit: usize,
+    /// 대기시간 메트릭을 집계할 레이팅 구간 폭
+    pub bracket_size: f64,
+}
+
+impl Default for MmrMatchingSettings {
+    fn default() -> Self {
+        Self {
+            base_window: 100.0,
+            window_growth_per_second: 10.0,
+            max_window: 400.0,
+            rd_weight: 0.5,
+            scan_limit: 200,
+            bracket_size: 200.0,
+        }
+    }
+}
+
+/// Ghost 스냅샷 풀 설정
+///
+/// 풀은 (시련, 페이즈, 레이팅 구간) 별로 나뉘며, 구간 폭은 rating_bucket_size 이다.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct GhostSettings {
+    /// 스냅샷 유지 시간 (초). 지난 스냅샷은 상대로 뽑히지 않는다
+    pub ttl_seconds: u64,
+    /// 풀 1개에 유지할 최대 스냅샷 수 (오래된 것부터 제거)
+    pub max_pool_size: usize,
+    /// 레이팅 구간 폭
+    pub rating_bucket_size: f64,
+    /// 상대 선택 시 검사할 최대 스냅샷 수 (최신 순)
+    pub scan_limit: usize,
+}
+
+impl Default for GhostSettings {
+    fn default() -> Self {
+        Self {
+            ttl_seconds: 86400,
+            max_pool_size: 500,
+            rating_bucket_size: 200.0,
+            scan_limit: 100,
+        }
+    }
+}
+
+/// `/game` 연결의 PvP 대기열 설정 (MatchCoordinator)
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct CoordinatorSettings {
+    /// 실시간 매칭을 쓰는 모드. 나머지 모드나 Redis 장애 시에는 Ghost 매칭
+    pub live_modes: Vec<GameMode>,
+}
+
+impl Default for CoordinatorSettings {
+    fn default() -> Self {
+        Self {
+            live_modes: vec![GameMode::Normal, GameMode::Ranked],
+        }
+    }
+}
+
+/// 게임 연결 재접속 설정
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ResumeSettings {
+    /// 연결이 끊긴 뒤 PlayerGameActor 를 유지하는 시간 (초)
+    pub grace_period_seconds: u64,
+    /// 재접속 시 다시 보내줄 수 있는 최근 서버 메시지 수
+    pub replay_buffer_size: usize,
+    /// Redis 런 기록(토큰, 행동 로그) 유지 시간 (초). 행동마다 갱신된다
+    pub run_ttl_seconds: u64,
+}
+
+impl Default for ResumeSettings {
+    fn default() -> Self {
+        Self {
+            grace_period_seconds: 60,
+            replay_buffer_size: 256,
+            run_ttl_seconds: 86400,
+        }
+    }
+}
+
+/// 런 요약 제출 설정 (auth_server `POST /internal/runs`)
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RunReportSettings {
+    /// auth_server 주소 (예: http://127.0.0.1:3000). 비어 있으면 제출하지 않음
+    pub auth_server_url: String,
+    /// auth_server 의 [service] api_token (X-Service-Token)
+    pub service_token: String,
+    /// 요청 1회 타임아웃 (초)
+    pub request_timeout_seconds: u64,
+    /// 재시도를 포함해 제출을 포기하기까지의 시간 (초)
+    pub max_elapsed_seconds: u64,
+}
+
+impl Default for RunReportSettings {
+    fn default() -> Self {
+        Self {
+            auth_server_url: String::new(),
+            service_token: String::new(),
+            request_timeout_seconds: 5,
+            max_elapsed_seconds: 60,
+        }
+    }
+}
+
+/// JWT 검증 설정 (auth_server 와 같은 secret 사용)
+#[derive(Debug, Deserialize, Clone)]
+pub struct JwtSettings {
+    pub secret: String,
+    /// false 면 토큰 없는 연결도 허용 (개발/테스트용, 토큰이 있으면 검증)
+    #[serde(default = "default_jwt_required")]
+    pub required: bool,
+    /// exp 검사 시 허용 오차 (초)
+    #[serde(default)]
+    pub leeway_seconds: u64,
+    /// 업그레이드 시 auth_server 가 Redis 에 기록한 폐기 목록 확인
+    #[serde(default = "default_check_revocation")]
+    pub check_revocation: bool,
+}
+
+fn default_jwt_required() -> bool {
+    true
+}
+
+fn default_check_revocation() -> bool {
+    true
+}
+
+/// 토큰 버킷 규칙: 최대 burst 개까지 연속 허용, 이후 초당 per_second 개씩 회복
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+pub struct RateLimitRule {
+    pub burst: u32,
+    pub per_second: f64,
+}
+
+/// 연결/메시지 속도 제한 설정
+///
+/// 연결 시도는 IP 별, 메시지는 플레이어별로 제한한다.
+/// 메시지 한도는 메시지 `type` (예: enqueue, party_invite, select_event) 별로 지정할 수 있고,
+/// 지정하지 않은 종류는 message 규칙을 따른다.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RateLimitSettings {
+    pub enabled: bool,
+    /// true 면 Redis 버킷을 사용해 모든 pod 가 같은 한도를 공유 (Redis 오류 시 pod 로컬 버킷)
+    pub use_redis: bool,
+    /// IP 별 WebSocket 연결 시도 (/ws/, /game)
+    pub connection: RateLimitRule,
+    /// 플레이어별 메시지 기본 한도
+    pub message: RateLimitRule,
+    /// 메시지 종류별 한도
+    pub messages: HashMap<String, RateLimitRule>,
+    /// 이 시간 동안 쓰이지 않은 로컬 버킷은 정리 (초)
+    pub idle_bucket_seconds: u64,
+}
+
+impl Default for RateLimitSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            use_redis: false,
+            connection: RateLimitRule {
+                burst: 10,
+                per_second: 1.0,
+            },
+            message: RateLimitRule {
+                burst: 20,
+                per_second: 10.0,
+            },
+            messages: HashMap::new(),
+            idle_bucket_seconds: 600,
+        }
+    }
+}
+
+impl RateLimitSettings {
+    /// 메시지 종류에 적용할 규칙
+    pub fn message_rule(&self, kind: &str) -> RateLimitRule {
+        self.messages.get(kind).copied().unwrap_or(self.message)
+    }
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -1,6 +1,11 @@
-use tracing::info;
+use std::sync::Arc;
+
+use game_core::game::{battle::types::BattleWinner, data::registry::DataVersion};
+use tracing::{error, info};
+use uuid::Uuid;
 
 use crate::{
+    game::battle_actor::simulator::{battle_seed, simulate, BattleBuild},
     matchmaking::matchmaker::operations::try_match::PlayerCandidate,
     GameMode,
 };
@@ -11,44 +16,194 @@ pub mod simulator;
 /// Battle 결과 데이터
 #[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
 pub struct BattleResult {
+    pub match_id: Uuid,
+    pub winner: BattleWinner,
+    /// 승자 player_id (무승부면 빈 문자열)
     pub winner_id: String,
     pub battle_data: Option<serde_json::Value>,
 }
 
-/// Battle 실행 로직 (순수 함수)
+/// Battle 실행 로직
+///
+/// 두 플레이어의 빌드로 BattleCore 를 실행한다. player1 이 Player 측, player2 가 Opponent 측이다.
+/// 시뮬레이션은 CPU 작업이므로 blocking 스레드에서 실행한다.
 pub async fn execute_battle(
     player1: &PlayerCandidate,
     player2: &PlayerCandidate,
     game_mode: GameMode,
+    match_id: Uuid,
+    data_version: Arc<DataVersion>,
+) -> BattleResult {
+    execute_build_battle(
+        &player1.player_id,
+        &BattleBuild::from_metadata(&player1.player_id, &player1.metadata),
+        &player2.player_id,
+        &BattleBuild::from_metadata(&player2.player_id, &player2.metadata),
+        game_mode,
+        match_id,
+        data_version,
+    )
+    .await
+}
+
+/// 빌드끼리 전투 실행 (Ghost 매칭처럼 metadata 가 없는 경우)
+pub async fn execute_build_battle(
+    player1_id: &str,
+    build1: &BattleBuild,
+    player2_id: &str,
+    build2: &BattleBuild,
+    game_mode: GameMode,
+    match_id: Uuid,
+    data_version: Arc<DataVersion>,
 ) -> BattleResult {
     info!(
-        "Executing battle: {} vs {} (mode: {:?})",
-        player1.player_id, player2.player_id, game_mode
+        "Executing battle {}: {} vs {} (mode: {:?}, data_version: {})",
+        match_id, player1_id, player2_id, game_mode, data_version.id
     );
 
-    // Battle 시뮬레이션 실행
-    let winner_id = simulate_battle(player1, player2).await;
+    let player_deck = build1.to_deck_info(player1_id, &data_version.data);
+    let opponent_deck = build2.to_deck_info(player2_id, &data_version.data);
+    let seed = battle_seed(player1_id, player2_id, match_id);
+
+    let simulation = tokio::task::spawn_blocking(move || {
+        simulate(&player_deck, &opponent_deck, &data_version, seed)
+    })
+    .await;
+
+    let battle = match simulation {
+        Ok(Ok(battle)) => battle,
+        Ok(Err(e)) => {
+            error!("Battle {} failed: {:?}", match_id, e);
+            return BattleResult::aborted(match_id);
+        }
+        Err(e) => {
+            error!("Battle {} task panicked: {}", match_id, e);
+            return BattleResult::aborted(match_id);
+        }
+    };
+
+    let winner_id = match battle.winner {
+        BattleWinner::Player => player1_id.to_string(),
+        BattleWinner::Opponent => player2_id.to_string(),
+        BattleWinner::Draw => String::new(),
+    };
 
     info!(
-        "Battle completed: {} vs {}, winner: {}",
-        player1.player_id, player2.player_id, winner_id
+        "Battle {} completed: {} vs {}, winner: {:?} ({} timeline entries)",
+        match_id,
+        player1_id,
+        player2_id,
+        battle.winner,
+        battle.timeline.entries.len()
     );
 
+    let timeline = match serde_json::to_value(&battle.timeline) {
+        Ok(timeline) => Some(timeline),
+        Err(e) => {
+            error!("Failed to encode timeline for battle {}: {}", match_id, e);
+            None
+        }
+    };
+
     BattleResult {
+        match_id,
+        winner: battle.winner,
         winner_id,
         battle_data: Some(serde_json::json!({
             "mode": format!("{:?}", game_mode),
+            "match_id": match_id,
+            "seed": seed,
+            "winner": battle.winner,
+            "timeline": timeline,
         })),
     }
 }
 
-/// Battle 시뮬레이션 로직 (승자 결정)
-async fn simulate_battle(player1: &PlayerCandidate, player2: &PlayerCandidate) -> String {
-    // TODO: 실제 battle 로직 구현
-    // 임시로 player1을 승자로 반환
-    info!(
-        "Simulating battle (stub): {} vs {}",
-        player1.player_id, player2.player_id
-    );
-    player1.player_id.clone()
+impl BattleResult {
+    /// 시뮬레이션 실패 시 무승부 처리
+    fn aborted(match_id: Uuid) -> Self {
+        Self {
+            match_id,
+            winner: BattleWinner::Draw,
+            winner_id: String::new(),
+            battle_data: None,
+        }
+    }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use game_core::{
+        config::GameBalanceConfig,
+        ecs::resources::Position,
+        game::{data::GameDataBase, enums::Tier, growth::GrowthStack},
+    };
+
+    use super::*;
+    use crate::game::battle_actor::simulator::BuildUnit;
+
+    fn bundled_data_version() -> Arc<DataVersion> {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../game_resources/data");
+        let data = GameDataBase::load_from_dir(dir).unwrap();
+        Arc::new(DataVersion::unversioned(
+            Arc::new(data),
+            GameBalanceConfig::global(),
+        ))
+    }
+
+    fn build_of(base_uuid: Uuid, count: usize) -> BattleBuild {
+        BattleBuild {
+            units: (0..count)
+                .map(|_| BuildUnit {
+                    base_uuid,
+                    tier: Tier::I,
+                    growth_stacks: GrowthStack::default(),
+                    equipped_items: Vec::new(),
+                    position: Position { x: 1, y: 0 },
+                })
+                .collect(),
+            artifacts: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_simulation_error_aborts_as_draw() {
+        let data_version = bundled_data_version();
+        let base_uuid = data_version.data.abnormality_data.items[0].uuid;
+        let match_id = Uuid::from_u128(7);
+
+        // Given: 3x3 필드에 다 들어가지 않는 빌드 (배치 실패 → 시뮬레이션 에러)
+        let overflowing = build_of(base_uuid, 10);
+        let result = execute_build_battle(
+            "p1",
+            &overflowing,
+            "p2",
+            &build_of(base_uuid, 1),
+            GameMode::Normal,
+            match_id,
+            data_version.clone(),
+        )
+        .await;
+
+        // Then: 무승부로 중단되고 전투 데이터는 남기지 않는다
+        assert_eq!(result.match_id, match_id);
+        assert_eq!(result.winner, BattleWinner::Draw);
+        assert!(result.winner_id.is_empty());
+        assert!(result.battle_data.is_none());
+
+        // 정상 빌드는 타임라인을 남긴다
+        let result = execute_build_battle(
+            "p1",
+            &build_of(base_uuid, 1),
+            "p2",
+            &build_of(base_uuid, 1),
+            GameMode::Normal,
+            match_id,
+            data_version,
+        )
+        .await;
+        assert!(result.battle_data.is_some());
+    }
+}
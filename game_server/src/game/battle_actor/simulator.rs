@@ -0,0 +1,263 @@
+use std::collections::HashMap;
+
+use game_core::{
+    ecs::resources::Position,
+    game::{
+        battle::{
+            core::BattleCore,
+            types::{BattleResult, OwnedArtifact, OwnedUnit, PlayerDeckInfo},
+        },
+        behavior::GameError,
+        data::{registry::DataVersion, GameDataBase},
+        enums::Tier,
+        growth::GrowthStack,
+    },
+};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+use uuid::Uuid;
+
+/// PvP 전투 필드 크기
+pub const BATTLE_FIELD_SIZE: (u8, u8) = (3, 3);
+
+/// 매칭 metadata 의 `build` 필드에 담긴 플레이어 빌드
+///
+/// ```json
+/// { "build": { "units": [{ "base_uuid": "...", "position": { "x": 1, "y": 0 } }], "artifacts": ["..."] } }
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BattleBuild {
+    #[serde(default)]
+    pub units: Vec<BuildUnit>,
+    #[serde(default)]
+    pub artifacts: Vec<Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildUnit {
+    pub base_uuid: Uuid,
+    #[serde(default = "default_tier")]
+    pub tier: Tier,
+    #[serde(default)]
+    pub growth_stacks: GrowthStack,
+    #[serde(default)]
+    pub equipped_items: Vec<Uuid>,
+    pub position: Position,
+}
+
+fn default_tier() -> Tier {
+    Tier::I
+}
+
+impl BattleBuild {
+    /// metadata 에서 빌드 추출 (없거나 형식이 잘못되면 빈 빌드)
+    pub fn from_metadata(player_id: &str, metadata: &serde_json::Value) -> Self {
+        let Some(build) = metadata.get("build") else {
+            warn!("Player {} has no build in metadata", player_id);
+            return Self::default();
+        };
+
+        match serde_json::from_value(build.clone()) {
+            Ok(build) => build,
+            Err(e) => {
+                warn!("Invalid build for player {}: {}", player_id, e);
+                Self::default()
+            }
+        }
+    }
+
+    /// GameCore::battle_deck 결과를 저장용 빌드로 변환
+    ///
+    /// 위치가 없는 유닛은 전투 필드의 빈 칸에 앞줄부터 배치하고, 빈 칸이 없으면 제외한다.
+    pub fn from_deck_info(deck: &PlayerDeckInfo) -> Self {
+        let (width, height) = (BATTLE_FIELD_SIZE.0 as i32, BATTLE_FIELD_SIZE.1 as i32);
+        let mut free_cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Position { x, y }))
+            .filter(|cell| !deck.positions.values().any(|used| used == cell));
+
+        let units = deck
+            .units
+            .iter()
+            .filter_map(|unit| {
+                let position = match deck.positions.get(&unit.base_uuid) {
+                    Some(position) => *position,
+                    None => free_cells.next()?,
+                };
+                Some(BuildUnit {
+                    base_uuid: unit.base_uuid,
+                    tier: unit.level,
+                    growth_stacks: unit.growth_stacks.clone(),
+                    equipped_items: unit.equipped_items.clone(),
+                    position,
+                })
+            })
+            .collect();
+
+        Self {
+            units,
+            artifacts: deck
+                .artifacts
+                .iter()
+                .map(|artifact| artifact.base_uuid)
+                .collect(),
+        }
+    }
+
+    /// 현재 게임 데이터에 존재하지 않는 유닛/장비/아티팩트는 제외하고 덱 구성
+    pub fn to_deck_info(&self, player_id: &str, game_data: &GameDataBase) -> PlayerDeckInfo {
+        let mut units = Vec::new();
+        let mut positions = HashMap::new();
+
+        for unit in &self.units {
+            if game_data
+                .abnormality_data
+                .get_by_uuid(&unit.base_uuid)
+                .is_none()
+            {
+                warn!(
+                    "Player {} build references unknown unit {}",
+                    player_id, unit.base_uuid
+                );
+                continue;
+            }
+
+            let equipped_items = unit
+                .equipped_items
+                .iter()
+                .copied()
+                .filter(|uuid| game_data.equipment_data.get_by_uuid(uuid).is_some())
+                .collect();
+
+            positions.insert(unit.base_uuid, unit.position);
+            units.push(OwnedUnit {
+                base_uuid: unit.base_uuid,
+                level: unit.tier,
+                growth_stacks: unit.growth_stacks.clone(),
+                equipped_items,
+            });
+        }
+
+        let artifacts = self
+            .artifacts
+            .iter()
+            .filter(|uuid| game_data.artifact_data.get_by_uuid(uuid).is_some())
+            .map(|uuid| OwnedArtifact { base_uuid: *uuid })
+            .collect();
+
+        PlayerDeckInfo {
+            units,
+            artifacts,
+            positions,
+        }
+    }
+}
+
+/// 두 플레이어 ID 와 매치 ID 로 전투 seed 생성 (FNV-1a)
+pub fn battle_seed(player1_id: &str, player2_id: &str, match_id: Uuid) -> u64 {
+    const FNV_OFFSET_BASIS: u64 = 14695981039346656037;
+    const FNV_PRIME: u64 = 1099511628211;
+
+    let mut hash = FNV_OFFSET_BASIS;
+    for part in [
+        player1_id.as_bytes(),
+        &[0],
+        player2_id.as_bytes(),
+        &[0],
+        match_id.as_bytes(),
+    ] {
+        for byte in part {
+            hash ^= *byte as u64;
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+    }
+    hash
+}
+
+/// BattleCore 실행 (blocking, 런타임 밖에서 호출)
+///
+/// player 측이 player1, opponent 측이 player2 이다.
+pub fn simulate(
+    player: &PlayerDeckInfo,
+    opponent: &PlayerDeckInfo,
+    data_version: &DataVersion,
+    seed: u64,
+) -> Result<BattleResult, GameError> {
+    BattleCore::new(
+        player,
+        opponent,
+        data_version.data.clone(),
+        BATTLE_FIELD_SIZE,
+    )
+    .with_seed(seed)
+    .with_data_version(data_version.id)
+    .run_battle()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_battle_seed_depends_on_players_and_match() {
+        let match_id = Uuid::from_u128(1);
+        let seed = battle_seed("a", "b", match_id);
+
+        assert_eq!(seed, battle_seed("a", "b", match_id));
+        assert_ne!(seed, battle_seed("b", "a", match_id));
+        assert_ne!(seed, battle_seed("a", "b", Uuid::from_u128(2)));
+        // 구분자 덕분에 경계가 달라지면 seed 도 달라진다
+        assert_ne!(
+            battle_seed("ab", "c", match_id),
+            battle_seed("a", "bc", match_id)
+        );
+    }
+
+    #[test]
+    fn test_build_from_metadata_falls_back_to_empty() {
+        let missing = BattleBuild::from_metadata("p1", &serde_json::json!({ "pod_id": "pod" }));
+        assert!(missing.units.is_empty());
+
+        let invalid = BattleBuild::from_metadata("p1", &serde_json::json!({ "build": 3 }));
+        assert!(invalid.units.is_empty());
+
+        let build = BattleBuild::from_metadata(
+            "p1",
+            &serde_json::json!({
+                "build": {
+                    "units": [{
+                        "base_uuid": "f0102000-0000-0000-0000-000000000001",
+                        "position": { "x": 1, "y": 0 }
+                    }]
+                }
+            }),
+        );
+        assert_eq!(build.units.len(), 1);
+        assert_eq!(build.units[0].tier, Tier::I);
+    }
+
+    #[test]
+    fn test_build_from_deck_info_fills_missing_positions() {
+        let placed = Uuid::from_u128(1);
+        let unplaced = Uuid::from_u128(2);
+        let unit = |base_uuid| OwnedUnit {
+            base_uuid,
+            level: Tier::I,
+            growth_stacks: GrowthStack::default(),
+            equipped_items: Vec::new(),
+        };
+        let deck = PlayerDeckInfo {
+            units: vec![unit(placed), unit(unplaced)],
+            artifacts: vec![OwnedArtifact {
+                base_uuid: Uuid::from_u128(3),
+            }],
+            positions: HashMap::from([(placed, Position { x: 0, y: 0 })]),
+        };
+
+        let build = BattleBuild::from_deck_info(&deck);
+        assert_eq!(build.units.len(), 2);
+        assert_eq!(build.units[0].position, Position { x: 0, y: 0 });
+        // 이미 사용 중인 칸은 건너뛴다
+        assert_eq!(build.units[1].position, Position { x: 1, y: 0 });
+        assert_eq!(build.artifacts, vec![Uuid::from_u128(3)]);
+    }
+}
@@ -0,0 +1,96 @@
+use game_core::{
+    ecs::resources::Position,
+    game::{
+        data::GameDataBase,
+        determinism,
+        enums::{OrdealType, PhaseType, Tier},
+        growth::GrowthStack,
+    },
+};
+use uuid::Uuid;
+
+use super::GhostSnapshot;
+use crate::game::battle_actor::simulator::{BattleBuild, BuildUnit, BATTLE_FIELD_SIZE};
+
+/// 시련이 깊어질수록 봇 유닛 수 증가 (필드 칸 수가 상한)
+fn bot_unit_count(ordeal: OrdealType, phase: PhaseType) -> usize {
+    let base = match ordeal {
+        OrdealType::Dawn => 2,
+        OrdealType::Noon => 3,
+        OrdealType::Dusk => 4,
+        OrdealType::Midnight => 5,
+        OrdealType::White => 6,
+    };
+    let max = BATTLE_FIELD_SIZE.0 as usize * BATTLE_FIELD_SIZE.1 as usize;
+    (base + phase.value() as usize / 3).min(max)
+}
+
+/// 풀이 비었을 때 쓰는 봇 Ghost
+///
+/// 기물 데이터를 UUID 순으로 정렬한 뒤 seed 로 뽑고, 필드 앞줄부터 채운다.
+/// 같은 seed/데이터면 항상 같은 빌드가 나온다.
+pub fn bot_ghost(
+    ordeal: OrdealType,
+    phase: PhaseType,
+    seed: u64,
+    mmr: f64,
+    game_data: &GameDataBase,
+    data_version: u64,
+) -> GhostSnapshot {
+    let mut pool: Vec<Uuid> = game_data
+        .abnormality_data
+        .items
+        .iter()
+        .map(|abnormality| abnormality.uuid)
+        .collect();
+    pool.sort();
+
+    let count = bot_unit_count(ordeal, phase).min(pool.len());
+    let mut units = Vec::with_capacity(count);
+    for index in 0..count {
+        let step_seed = determinism::seed_for_event_step(seed, index as u32);
+        let base_uuid = pool.remove((step_seed % pool.len() as u64) as usize);
+        let width = BATTLE_FIELD_SIZE.0 as usize;
+        units.push(BuildUnit {
+            base_uuid,
+            tier: Tier::I,
+            growth_stacks: GrowthStack::default(),
+            equipped_items: Vec::new(),
+            position: Position {
+                x: (index % width) as i32,
+                y: (index / width) as i32,
+            },
+        });
+    }
+
+    GhostSnapshot {
+        snapshot_id: format!("bot:{}:{:?}:{:?}", seed, ordeal, phase),
+        player_id: Uuid::nil(),
+        ordeal,
+        phase,
+        mmr,
+        build: BattleBuild {
+            units,
+            artifacts: Vec::new(),
+        },
+        data_version,
+        created_at: chrono::Utc::now().timestamp(),
+        is_bot: true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bot_unit_count_fits_field() {
+        assert_eq!(bot_unit_count(OrdealType::Dawn, PhaseType::I), 2);
+        assert_eq!(bot_unit_count(OrdealType::Noon, PhaseType::III), 4);
+        assert_eq!(bot_unit_count(OrdealType::White, PhaseType::VI), 8);
+        assert!(
+            bot_unit_count(OrdealType::White, PhaseType::VI)
+                <= (BATTLE_FIELD_SIZE.0 * BATTLE_FIELD_SIZE.1) as usize
+        );
+    }
+}
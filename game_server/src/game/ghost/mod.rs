@@ -0,0 +1,281 @@
+//! 비동기 PvP 용 Ghost 스냅샷 저장소
+//!
+//! 플레이어가 시련(Ordeal) Phase 에 도달하면 그 시점의 빌드를 스냅샷으로 남기고,
+//! 같은 (시련, 페이즈, 레이팅 구간) 풀에서 다른 플레이어의 스냅샷을 상대로 뽑는다.
+//! 두 플레이어가 동시에 대기할 필요가 없으므로 전투가 즉시 시작된다.
+//!
+//! Redis 키:
+//! - `ghost_pool:{ordeal}:{phase}:{bucket}` (Sorted Set): member = snapshot_id, score = 등록 시각
+//! - `ghost:{snapshot_id}` (String, TTL): GhostSnapshot JSON
+
+pub mod bot;
+
+use game_core::game::{
+    determinism,
+    enums::{OrdealType, PhaseType},
+};
+use redis::aio::ConnectionManager;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::{
+    env::GhostSettings, game::battle_actor::simulator::BattleBuild,
+    matchmaking::matchmaker::operations::with_redis_timeout,
+};
+
+/// 특정 시련/페이즈 시점의 플레이어 빌드
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GhostSnapshot {
+    pub snapshot_id: String,
+    /// 봇 Ghost 는 Uuid::nil()
+    pub player_id: Uuid,
+    pub ordeal: OrdealType,
+    pub phase: PhaseType,
+    pub mmr: f64,
+    pub build: BattleBuild,
+    /// 스냅샷을 만든 런의 데이터 버전
+    pub data_version: u64,
+    /// 등록 시각 (unix 초)
+    pub created_at: i64,
+    #[serde(default)]
+    pub is_bot: bool,
+}
+
+impl GhostSnapshot {
+    /// 플레이어 스냅샷 생성 (같은 런/페이즈에서 다시 만들면 같은 ID)
+    pub fn new(
+        player_id: Uuid,
+        run_seed: u64,
+        ordeal: OrdealType,
+        phase: PhaseType,
+        mmr: f64,
+        build: BattleBuild,
+        data_version: u64,
+    ) -> Self {
+        Self {
+            snapshot_id: snapshot_id(player_id, run_seed, ordeal, phase),
+            player_id,
+            ordeal,
+            phase,
+            mmr,
+            build,
+            data_version,
+            created_at: chrono::Utc::now().timestamp(),
+            is_bot: false,
+        }
+    }
+}
+
+/// 스냅샷 ID. 플레이어 ID 로 시작하므로 prefix 로 본인 스냅샷을 걸러낼 수 있다
+pub fn snapshot_id(player_id: Uuid, run_seed: u64, ordeal: OrdealType, phase: PhaseType) -> String {
+    format!("{}:{}:{:?}:{:?}", player_id, run_seed, ordeal, phase)
+}
+
+pub fn snapshot_key(snapshot_id: &str) -> String {
+    format!("ghost:{}", snapshot_id)
+}
+
+pub fn pool_key(ordeal: OrdealType, phase: PhaseType, bucket: i64) -> String {
+    format!("ghost_pool:{:?}:{:?}:{}", ordeal, phase, bucket)
+}
+
+/// 레이팅 구간 하한 (예: bucket_size 200, mmr 1530 → 1400)
+pub fn rating_bucket(mmr: f64, bucket_size: f64) -> i64 {
+    let size = bucket_size.max(1.0);
+    ((mmr / size).floor() * size) as i64
+}
+
+/// 상대 선택용 seed (같은 런/페이즈면 항상 같은 값)
+pub fn pick_seed(run_seed: u64, ordeal: OrdealType, phase: PhaseType) -> u64 {
+    determinism::seed_for_phase(run_seed, ordeal, phase)
+}
+
+/// 후보 스냅샷 ID 를 시도 순서대로 정렬
+///
+/// 본인 스냅샷을 제외하고 ID 순으로 정렬한 뒤 seed 위치부터 회전한다.
+/// 풀 내용이 같으면 같은 seed 에 대해 항상 같은 순서가 나온다.
+pub fn pick_order(mut candidates: Vec<String>, player_id: Uuid, seed: u64) -> Vec<String> {
+    let own_prefix = format!("{}:", player_id);
+    candidates.retain(|id| !id.starts_with(&own_prefix));
+    if candidates.is_empty() {
+        return candidates;
+    }
+
+    candidates.sort();
+    candidates.dedup();
+    let start = (seed % candidates.len() as u64) as usize;
+    candidates.rotate_left(start);
+    candidates
+}
+
+/// Ghost 스냅샷 저장소 (MatchCoordinator 가 소유)
+#[derive(Clone)]
+pub struct GhostStore {
+    redis: ConnectionManager,
+    settings: GhostSettings,
+    timeout_secs: u64,
+}
+
+impl GhostStore {
+    pub fn new(redis: ConnectionManager, settings: GhostSettings, timeout_secs: u64) -> Self {
+        Self {
+            redis,
+            settings,
+            timeout_secs,
+        }
+    }
+
+    /// 스냅샷 등록 후 풀 정리 (TTL 지난 항목 제거, 최신 max_pool_size 개만 유지)
+    pub async fn submit(&mut self, snapshot: &GhostSnapshot) -> Result<(), String> {
+        let json = serde_json::to_string(snapshot)
+            .map_err(|e| format!("Failed to serialize ghost snapshot: {}", e))?;
+        let pool = pool_key(
+            snapshot.ordeal,
+            snapshot.phase,
+            rating_bucket(snapshot.mmr, self.settings.rating_bucket_size),
+        );
+        let ttl = self.settings.ttl_seconds.max(1);
+        let expired_before = snapshot.created_at - ttl as i64;
+        let keep = self.settings.max_pool_size.max(1) as isize;
+
+        let redis = &mut self.redis;
+        with_redis_timeout("ghost_submit", self.timeout_secs, async {
+            redis::pipe()
+                .cmd("SET")
+                .arg(snapshot_key(&snapshot.snapshot_id))
+                .arg(&json)
+                .arg("EX")
+                .arg(ttl)
+                .ignore()
+                .cmd("ZADD")
+                .arg(&pool)
+                .arg(snapshot.created_at)
+                .arg(&snapshot.snapshot_id)
+                .ignore()
+                .cmd("ZREMRANGEBYSCORE")
+                .arg(&pool)
+                .arg("-inf")
+                .arg(format!("({}", expired_before))
+                .ignore()
+                .cmd("ZREMRANGEBYRANK")
+                .arg(&pool)
+                .arg(0)
+                .arg(-keep - 1)
+                .ignore()
+                .cmd("EXPIRE")
+                .arg(&pool)
+                .arg(ttl)
+                .ignore()
+                .query_async::<_, ()>(redis)
+                .await
+        })
+        .await
+    }
+
+    /// 풀에서 상대 스냅샷 선택 (없으면 None)
+    ///
+    /// 최신 scan_limit 개 중 pick_order 순서로 시도하며, 본문이 만료된 항목은 건너뛴다.
+    pub async fn find(
+        &mut self,
+        player_id: Uuid,
+        ordeal: OrdealType,
+        phase: PhaseType,
+        mmr: f64,
+        seed: u64,
+    ) -> Result<Option<GhostSnapshot>, String> {
+        let pool = pool_key(
+            ordeal,
+            phase,
+            rating_bucket(mmr, self.settings.rating_bucket_size),
+        );
+        let now = chrono::Utc::now().timestamp();
+        let min_score = now - self.settings.ttl_seconds as i64;
+        let scan_limit = self.settings.scan_limit.max(1);
+
+        let redis = &mut self.redis;
+        let members: Vec<String> = with_redis_timeout("ghost_scan", self.timeout_secs, async {
+            redis::cmd("ZREVRANGEBYSCORE")
+                .arg(&pool)
+                .arg("+inf")
+                .arg(min_score)
+                .arg("LIMIT")
+                .arg(0)
+                .arg(scan_limit)
+                .query_async(redis)
+                .await
+        })
+        .await?;
+
+        let order = pick_order(members, player_id, seed);
+        if order.is_empty() {
+            return Ok(None);
+        }
+
+        let keys: Vec<String> = order.iter().map(|id| snapshot_key(id)).collect();
+        let bodies: Vec<Option<String>> =
+            with_redis_timeout("ghost_fetch", self.timeout_secs, async {
+                redis::cmd("MGET").arg(&keys).query_async(redis).await
+            })
+            .await?;
+
+        for (id, body) in order.iter().zip(bodies) {
+            let Some(body) = body else {
+                continue;
+            };
+            match serde_json::from_str::<GhostSnapshot>(&body) {
+                Ok(snapshot) => return Ok(Some(snapshot)),
+                Err(e) => warn!("Invalid ghost snapshot {}: {}", id, e),
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pick_order_is_deterministic_and_excludes_own() {
+        let me = Uuid::from_u128(1);
+        let other_a = Uuid::from_u128(2);
+        let other_b = Uuid::from_u128(3);
+        let members = vec![
+            snapshot_id(other_b, 7, OrdealType::Dawn, PhaseType::I),
+            snapshot_id(me, 7, OrdealType::Dawn, PhaseType::I),
+            snapshot_id(other_a, 9, OrdealType::Dawn, PhaseType::I),
+        ];
+        let seed = pick_seed(42, OrdealType::Dawn, PhaseType::I);
+
+        let order = pick_order(members.clone(), me, seed);
+        assert_eq!(order.len(), 2);
+        assert!(order.iter().all(|id| !id.starts_with(&me.to_string())));
+
+        // Redis 반환 순서와 무관하게 같은 결과
+        let mut reversed = members;
+        reversed.reverse();
+        assert_eq!(order, pick_order(reversed, me, seed));
+    }
+
+    #[test]
+    fn test_pick_order_only_own_snapshots_is_empty() {
+        let me = Uuid::from_u128(1);
+        let members = vec![
+            snapshot_id(me, 1, OrdealType::Noon, PhaseType::II),
+            snapshot_id(me, 2, OrdealType::Noon, PhaseType::II),
+        ];
+        assert!(pick_order(members, me, 5).is_empty());
+    }
+
+    #[test]
+    fn test_rating_bucket() {
+        assert_eq!(rating_bucket(1530.0, 200.0), 1400);
+        assert_eq!(rating_bucket(1400.0, 200.0), 1400);
+        assert_eq!(rating_bucket(1399.9, 200.0), 1200);
+        assert_eq!(
+            pool_key(OrdealType::Dusk, PhaseType::III, 1400),
+            "ghost_pool:Dusk:III:1400"
+        );
+    }
+}
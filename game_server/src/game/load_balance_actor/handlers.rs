@@ -1,8 +1,9 @@
-use actix::Handler;
+use actix::{Addr, Handler};
 use tracing::{info, warn};
 
 use super::messages::*;
 use super::LoadBalanceActor;
+use crate::game::player_game_actor::PlayerGameActor;
 
 impl Handler<Register> for LoadBalanceActor {
     type Result = ();
@@ -35,6 +36,14 @@ impl Handler<RouteToPlayer> for LoadBalanceActor {
     }
 }
 
+impl Handler<FindPlayer> for LoadBalanceActor {
+    type Result = Option<Addr<PlayerGameActor>>;
+
+    fn handle(&mut self, msg: FindPlayer, _ctx: &mut Self::Context) -> Self::Result {
+        self.players.get(&msg.player_id).cloned()
+    }
+}
+
 impl Handler<GetPlayerCount> for LoadBalanceActor {
     type Result = usize;
 
@@ -26,3 +26,9 @@ pub struct RouteToPlayer {
 #[derive(Message)]
 #[rtype(result = "usize")]
 pub struct GetPlayerCount;
+
+#[derive(Message)]
+#[rtype(result = "Option<Addr<PlayerGameActor>>")]
+pub struct FindPlayer {
+    pub player_id: Uuid,
+}
@@ -1,56 +1,292 @@
-use actix::Handler;
+use std::time::Instant;
+
+use actix::{ActorFutureExt, Context, ContextFutureSpawner, Handler, ResponseFuture, WrapFuture};
+use game_core::game::{
+    data::registry::DataVersion,
+    enums::{OrdealType, PhaseType},
+};
 use serde_json::json;
-use tracing::info;
+use tracing::{info, warn};
+use uuid::Uuid;
 
 use super::messages::*;
-use super::MatchCoordinator;
-use crate::matchmaking::matchmaker::messages::Enqueue;
+use super::{check_readiness, select_queue_mode, MatchCoordinator, QueueEntry, QueueMode};
+use crate::game::battle_actor::execute_build_battle;
+use crate::game::ghost::{bot::bot_ghost, pick_seed, GhostSnapshot, GhostStore};
+use crate::game::load_balance_actor::messages::RouteToPlayer;
+use crate::matchmaking::matchmaker::messages::{Dequeue, Enqueue};
+use crate::matchmaking::matchmaker::operations::{
+    rating::fetch_rating, try_match::PlayerCandidate,
+};
+use crate::shared::protocol::{ErrorCode, ServerMessage};
 
 impl Handler<EnqueuePlayer> for MatchCoordinator {
-    type Result = Result<(), String>;
+    type Result = Result<QueueMode, ErrorCode>;
+
+    fn handle(&mut self, msg: EnqueuePlayer, ctx: &mut Self::Context) -> Self::Result {
+        // 1. 대기 상태 / 런 준비 상태 확인
+        if self.queue.contains_key(&msg.player_id) {
+            return Err(ErrorCode::AlreadyInQueue);
+        }
+        check_readiness(&msg.readiness)?;
+
+        // 2. 매칭 방식 선택
+        let mode = select_queue_mode(msg.game_mode, self.live_available(msg.game_mode))?;
+        let ticket = Uuid::new_v4();
+        self.queue.insert(
+            msg.player_id,
+            QueueEntry {
+                game_mode: msg.game_mode,
+                mode,
+                ticket,
+                enqueued_at: Instant::now(),
+                leaving: false,
+            },
+        );
+
+        info!(
+            "Player {} queued for {:?} ({:?})",
+            msg.player_id, msg.game_mode, mode
+        );
+        match mode {
+            QueueMode::Live => self.enqueue_live(msg),
+            QueueMode::Ghost => self.start_ghost_match(ctx, ticket, msg),
+        }
+        Ok(mode)
+    }
+}
+
+impl Handler<DequeuePlayer> for MatchCoordinator {
+    type Result = Result<(), ErrorCode>;
+
+    fn handle(&mut self, msg: DequeuePlayer, _ctx: &mut Self::Context) -> Self::Result {
+        let entry = self
+            .queue
+            .get_mut(&msg.player_id)
+            .ok_or(ErrorCode::NotInQueue)?;
+
+        match entry.mode {
+            // 진행 중인 Ghost 전투 결과는 버림
+            QueueMode::Ghost => {
+                self.queue.remove(&msg.player_id);
+            }
+            // Matchmaker 가 DeQueued 를 보내면 QueueSettled 로 정리
+            QueueMode::Live if !entry.leaving => {
+                entry.leaving = true;
+                let game_mode = entry.game_mode;
+                if let Some(matchmaker) = self.matchmakers.get(&game_mode) {
+                    matchmaker.do_send_dequeue(Dequeue {
+                        player_id: msg.player_id,
+                        game_mode,
+                    });
+                }
+            }
+            QueueMode::Live => {}
+        }
+
+        info!("Player {} left the queue", msg.player_id);
+        Ok(())
+    }
+}
+
+impl Handler<QueueSettled> for MatchCoordinator {
+    type Result = ();
+
+    fn handle(&mut self, msg: QueueSettled, _ctx: &mut Self::Context) -> Self::Result {
+        // Ghost 매칭은 결과를 보낼 때 이미 정리됨
+        if let Some(entry) = self.queue.get(&msg.player_id) {
+            if entry.mode == QueueMode::Live {
+                info!(
+                    "Player {} live queue settled after {:?}",
+                    msg.player_id,
+                    entry.enqueued_at.elapsed()
+                );
+                self.queue.remove(&msg.player_id);
+            }
+        }
+    }
+}
 
-    fn handle(&mut self, msg: EnqueuePlayer, _ctx: &mut Self::Context) -> Self::Result {
-        // 1. GameMode에 맞는 Matchmaker 찾기
-        let matchmaker = self
-            .matchmakers
-            .get(&msg.game_mode)
-            .ok_or_else(|| format!("Unsupported game mode: {:?}", msg.game_mode))?;
+impl Handler<PlayerLeft> for MatchCoordinator {
+    type Result = ();
+
+    fn handle(&mut self, msg: PlayerLeft, _ctx: &mut Self::Context) -> Self::Result {
+        let Some(entry) = self.queue.remove(&msg.player_id) else {
+            return;
+        };
+        if entry.mode == QueueMode::Live && !entry.leaving {
+            if let Some(matchmaker) = self.matchmakers.get(&entry.game_mode) {
+                matchmaker.do_send_dequeue(Dequeue {
+                    player_id: msg.player_id,
+                    game_mode: entry.game_mode,
+                });
+            }
+        }
+    }
+}
+
+impl MatchCoordinator {
+    /// 실시간 대기열 등록 (metadata 는 서버에서 생성)
+    fn enqueue_live(&self, msg: EnqueuePlayer) {
+        let Some(matchmaker) = self.matchmakers.get(&msg.game_mode) else {
+            return;
+        };
 
-        // 2. 서버에서 metadata 생성 (중요!)
-        let pod_id = std::env::var("POD_ID").unwrap_or_else(|_| "default-pod".to_string());
         let metadata = json!({
-            "pod_id": pod_id,
+            "pod_id": PlayerCandidate::current_pod_id(),
             "player_id": msg.player_id.to_string(),
-            // 추가 서버 측 데이터...
+            "build": msg.readiness.build,
         });
-
-        // 3. Matchmaker에 Enqueue 요청
         matchmaker.do_send_enqueue(Enqueue {
             player_id: msg.player_id,
             game_mode: msg.game_mode,
             metadata: metadata.to_string(),
         });
+    }
 
-        info!("Player {} enqueued for {:?}", msg.player_id, msg.game_mode);
-        Ok(())
+    /// Ghost 상대와 바로 전투 후 MatchFound 를 플레이어에게 전달
+    fn start_ghost_match(&self, ctx: &mut Context<Self>, ticket: Uuid, msg: EnqueuePlayer) {
+        let Some((ordeal, phase)) = msg.readiness.progression else {
+            return;
+        };
+        let mut redis = self.redis.clone();
+        let mut store = self.ghost_store.clone();
+        let timeout_secs = self.redis_timeout_secs;
+        let player_id = msg.player_id;
+
+        async move {
+            let rating = fetch_rating(&mut redis, player_id, timeout_secs).await;
+            // 매칭마다 다른 상대가 나오도록 match_id 로 선택
+            let seed = ticket.as_u64_pair().1;
+            let ghost = find_or_bot_ghost(
+                &mut store,
+                player_id,
+                ordeal,
+                phase,
+                rating.mmr,
+                seed,
+                &msg.data_version,
+            )
+            .await;
+            let result = execute_build_battle(
+                &player_id.to_string(),
+                &msg.readiness.build,
+                &ghost.player_id.to_string(),
+                &ghost.build,
+                msg.game_mode,
+                ticket,
+                msg.data_version,
+            )
+            .await;
+            (ghost, result)
+        }
+        .into_actor(self)
+        .map(move |(ghost, result), act, _ctx| {
+            // LeaveQueue 로 취소되었으면 결과를 버림
+            if act.queue.get(&player_id).map(|entry| entry.ticket) != Some(ticket) {
+                info!(
+                    "Ghost match {} for player {} was cancelled",
+                    ticket, player_id
+                );
+                return;
+            }
+            act.queue.remove(&player_id);
+            act.load_balance_addr.do_send(RouteToPlayer {
+                player_id,
+                message: ServerMessage::MatchFound {
+                    winner_id: result.winner_id,
+                    opponent_id: ghost.player_id.to_string(),
+                    battle_data: result.battle_data,
+                },
+            });
+        })
+        .spawn(ctx);
     }
 }
 
-impl Handler<DequeuePlayer> for MatchCoordinator {
-    type Result = Result<(), String>;
+impl Handler<FindGhost> for MatchCoordinator {
+    type Result = ResponseFuture<GhostSnapshot>;
 
-    fn handle(&mut self, msg: DequeuePlayer, _ctx: &mut Self::Context) -> Self::Result {
-        let matchmaker = self
-            .matchmakers
-            .get(&msg.game_mode)
-            .ok_or_else(|| format!("Unsupported game mode: {:?}", msg.game_mode))?;
+    fn handle(&mut self, msg: FindGhost, _ctx: &mut Self::Context) -> Self::Result {
+        let mut redis = self.redis.clone();
+        let mut store = self.ghost_store.clone();
+        let timeout_secs = self.redis_timeout_secs;
 
-        matchmaker.do_send_dequeue(crate::matchmaking::matchmaker::messages::Dequeue {
-            player_id: msg.player_id,
-            game_mode: msg.game_mode,
+        Box::pin(async move {
+            let rating = fetch_rating(&mut redis, msg.player_id, timeout_secs).await;
+            let seed = pick_seed(msg.run_seed, msg.ordeal, msg.phase);
+
+            // 1. 상대 선택 (본인 스냅샷은 제외되므로 등록보다 먼저 해도 결과가 같다)
+            let ghost = find_or_bot_ghost(
+                &mut store,
+                msg.player_id,
+                msg.ordeal,
+                msg.phase,
+                rating.mmr,
+                seed,
+                &msg.data_version,
+            )
+            .await;
+
+            // 2. 플레이어 빌드 등록
+            if !msg.build.units.is_empty() {
+                let snapshot = GhostSnapshot::new(
+                    msg.player_id,
+                    msg.run_seed,
+                    msg.ordeal,
+                    msg.phase,
+                    rating.mmr,
+                    msg.build,
+                    msg.data_version.id,
+                );
+                if let Err(e) = store.submit(&snapshot).await {
+                    warn!("Ghost submit failed for player {}: {}", msg.player_id, e);
+                }
+            }
+            ghost
+        })
+    }
+}
+
+/// 풀에서 상대 Ghost 선택 (풀이 비었거나 Redis 가 실패하면 봇 Ghost)
+async fn find_or_bot_ghost(
+    store: &mut GhostStore,
+    player_id: Uuid,
+    ordeal: OrdealType,
+    phase: PhaseType,
+    mmr: f64,
+    seed: u64,
+    data_version: &DataVersion,
+) -> GhostSnapshot {
+    let found = store
+        .find(player_id, ordeal, phase, mmr, seed)
+        .await
+        .unwrap_or_else(|e| {
+            warn!("Ghost lookup failed for player {}: {}", player_id, e);
+            None
         });
 
-        info!("Player {} dequeued from {:?}", msg.player_id, msg.game_mode);
-        Ok(())
+    match found {
+        Some(ghost) => {
+            info!(
+                "Ghost {} picked for player {} ({:?} {:?})",
+                ghost.snapshot_id, player_id, ordeal, phase
+            );
+            ghost
+        }
+        None => {
+            info!(
+                "Ghost pool empty for player {} ({:?} {:?}), using bot ghost",
+                player_id, ordeal, phase
+            );
+            bot_ghost(
+                ordeal,
+                phase,
+                seed,
+                mmr,
+                &data_version.data,
+                data_version.id,
+            )
+        }
     }
 }
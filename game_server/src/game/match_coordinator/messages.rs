@@ -1,18 +1,78 @@
+use std::sync::Arc;
+
+use crate::game::{battle_actor::simulator::BattleBuild, ghost::GhostSnapshot};
+use crate::shared::protocol::ErrorCode;
 use crate::GameMode;
 use actix::Message;
+use game_core::{
+    ecs::resources::GameState,
+    game::{
+        data::registry::DataVersion,
+        enums::{OrdealType, PhaseType},
+    },
+};
 use uuid::Uuid;
 
+use super::QueueMode;
+
+/// 대기열 진입 시점의 런 상태 (PlayerGameActor 가 GameCore 에서 채움)
+#[derive(Debug, Clone)]
+pub struct QueueReadiness {
+    pub state: GameState,
+    /// 현재 시련/페이즈 (런 시작 전이면 None)
+    pub progression: Option<(OrdealType, PhaseType)>,
+    /// 출전 빌드 (대기열 진입 시점 기준, 이후 배치를 바꿔도 반영되지 않음)
+    pub build: BattleBuild,
+}
+
+/// PvP 대기열 진입 요청 (PlayerGameActor → MatchCoordinator)
+///
+/// 준비 상태를 확인한 뒤 실시간 매칭 또는 Ghost 매칭을 선택한다.
+/// 결과(EnQueued / MatchFound / Error)는 LoadBalanceActor 를 거쳐 PlayerGameActor 로 전달된다.
 #[derive(Message)]
-#[rtype(result = "Result<(), String>")]
+#[rtype(result = "Result<QueueMode, ErrorCode>")]
 pub struct EnqueuePlayer {
     pub player_id: Uuid,
     pub game_mode: GameMode,
-    // 클라이언트가 보낸 데이터는 여기서 검증 후 metadata로 변환
+    pub run_seed: u64,
+    pub readiness: QueueReadiness,
+    /// 플레이어 런의 데이터 버전 (Ghost 전투용)
+    pub data_version: Arc<DataVersion>,
 }
 
+/// 대기열 이탈 요청
 #[derive(Message)]
-#[rtype(result = "Result<(), String>")]
+#[rtype(result = "Result<(), ErrorCode>")]
 pub struct DequeuePlayer {
     pub player_id: Uuid,
-    pub game_mode: GameMode,
+}
+
+/// 대기열 종료 알림 (MatchFound / DeQueued / Error 를 받은 PlayerGameActor → MatchCoordinator)
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct QueueSettled {
+    pub player_id: Uuid,
+}
+
+/// PlayerGameActor 종료 (대기 중이면 매칭 대기열에서도 제거)
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct PlayerLeft {
+    pub player_id: Uuid,
+}
+
+/// 시련 Phase 진입 시 플레이어 빌드를 Ghost 풀에 등록하고 상대 Ghost 를 고른다
+///
+/// 풀이 비어 있거나 Redis 가 실패하면 봇 Ghost 를 돌려주므로 항상 상대가 있다.
+#[derive(Message)]
+#[rtype(result = "GhostSnapshot")]
+pub struct FindGhost {
+    pub player_id: Uuid,
+    pub run_seed: u64,
+    pub ordeal: OrdealType,
+    pub phase: PhaseType,
+    /// 등록할 플레이어 빌드 (유닛이 없으면 등록하지 않음)
+    pub build: BattleBuild,
+    /// 플레이어 런의 데이터 버전 (봇 Ghost 생성용)
+    pub data_version: Arc<DataVersion>,
 }
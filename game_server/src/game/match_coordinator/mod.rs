@@ -1,19 +1,60 @@
 use actix::{Actor, Addr, Context};
+use game_core::ecs::resources::GameState;
 use redis::aio::ConnectionManager;
+use serde::Serialize;
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Instant;
 use tracing::info;
+use uuid::Uuid;
 
+use crate::env::{CoordinatorSettings, GhostSettings};
+use crate::game::ghost::GhostStore;
 use crate::game::load_balance_actor::LoadBalanceActor;
 use crate::matchmaking::matchmaker::MatchmakerAddr;
+use crate::shared::{circuit_breaker::CircuitBreaker, protocol::ErrorCode};
 use crate::GameMode;
 
+use messages::QueueReadiness;
+
 pub mod handlers;
 pub mod messages;
 
+/// 대기열 매칭 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QueueMode {
+    /// Redis 대기열에서 다른 플레이어와 실시간 매칭
+    Live,
+    /// Ghost 스냅샷 상대와 즉시 전투
+    Ghost,
+}
+
+/// 대기 중인 플레이어 1명
+#[derive(Debug, Clone)]
+struct QueueEntry {
+    game_mode: GameMode,
+    mode: QueueMode,
+    /// Ghost 매칭의 match_id (늦게 끝난 이전 매칭 결과를 걸러냄)
+    ticket: Uuid,
+    enqueued_at: Instant,
+    /// LeaveQueue 후 DeQueued 를 기다리는 중
+    leaving: bool,
+}
+
+/// `/game` 플레이어의 PvP 대기열 진입을 관리하는 액터
+///
+/// 준비 상태 확인, 실시간/Ghost 매칭 선택, 플레이어별 대기 상태를 한곳에서 관리한다.
+/// 매칭 결과는 LoadBalanceActor 를 거쳐 플레이어의 PlayerGameActor 로 전달된다.
 pub struct MatchCoordinator {
     matchmakers: HashMap<GameMode, MatchmakerAddr>,
     load_balance_addr: Addr<LoadBalanceActor>,
     redis: ConnectionManager,
+    ghost_store: GhostStore,
+    settings: CoordinatorSettings,
+    redis_circuit: Arc<CircuitBreaker>,
+    redis_timeout_secs: u64,
+    queue: HashMap<Uuid, QueueEntry>,
 }
 
 impl MatchCoordinator {
@@ -21,13 +62,60 @@ impl MatchCoordinator {
         matchmakers: HashMap<GameMode, MatchmakerAddr>,
         load_balance_addr: Addr<LoadBalanceActor>,
         redis: ConnectionManager,
+        ghost_settings: GhostSettings,
+        settings: CoordinatorSettings,
+        redis_circuit: Arc<CircuitBreaker>,
+        redis_timeout_secs: u64,
     ) -> Self {
         Self {
             matchmakers,
             load_balance_addr,
+            ghost_store: GhostStore::new(redis.clone(), ghost_settings, redis_timeout_secs),
             redis,
+            settings,
+            redis_circuit,
+            redis_timeout_secs,
+            queue: HashMap::new(),
         }
     }
+
+    /// 실시간 매칭 가능 여부 (설정된 모드 + Matchmaker 존재 + Redis 정상)
+    fn live_available(&self, game_mode: GameMode) -> bool {
+        self.settings.live_modes.contains(&game_mode)
+            && self.matchmakers.contains_key(&game_mode)
+            && !self.redis_circuit.is_open()
+    }
+}
+
+/// 대기열에 들어갈 수 있는 런 상태인지 확인
+///
+/// 이벤트 사이(Phase 요청 대기 / 이벤트 선택)에서만 허용하고, 출전할 기물이 있어야 한다.
+pub fn check_readiness(readiness: &QueueReadiness) -> Result<(), ErrorCode> {
+    let between_events = matches!(
+        readiness.state,
+        GameState::WaitingPhaseRequest | GameState::SelectingEvent
+    );
+    if !between_events || readiness.progression.is_none() {
+        return Err(ErrorCode::NotReadyForQueue);
+    }
+    if readiness.build.units.is_empty() {
+        return Err(ErrorCode::EmptyBoard);
+    }
+    Ok(())
+}
+
+/// 게임 모드별 매칭 방식 선택
+///
+/// 파티 매칭은 `/ws/` 파티 흐름으로만 가능하다.
+pub fn select_queue_mode(
+    game_mode: GameMode,
+    live_available: bool,
+) -> Result<QueueMode, ErrorCode> {
+    match game_mode {
+        GameMode::None | GameMode::Party => Err(ErrorCode::InvalidGameMode),
+        GameMode::Normal | GameMode::Ranked if live_available => Ok(QueueMode::Live),
+        GameMode::Normal | GameMode::Ranked => Ok(QueueMode::Ghost),
+    }
 }
 
 impl Actor for MatchCoordinator {
@@ -37,3 +125,74 @@ impl Actor for MatchCoordinator {
         info!("MatchCoordinator started");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::battle_actor::simulator::{BattleBuild, BuildUnit};
+    use game_core::{
+        ecs::resources::Position,
+        game::{
+            enums::{OrdealType, PhaseType, Tier},
+            growth::GrowthStack,
+        },
+    };
+
+    fn readiness(state: GameState, units: usize) -> QueueReadiness {
+        QueueReadiness {
+            state,
+            progression: Some((OrdealType::Dawn, PhaseType::I)),
+            build: BattleBuild {
+                units: (0..units)
+                    .map(|x| BuildUnit {
+                        base_uuid: Uuid::from_u128(x as u128 + 1),
+                        tier: Tier::I,
+                        growth_stacks: GrowthStack::default(),
+                        equipped_items: Vec::new(),
+                        position: Position { x: x as i32, y: 0 },
+                    })
+                    .collect(),
+                artifacts: Vec::new(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_check_readiness() {
+        assert_eq!(
+            check_readiness(&readiness(GameState::SelectingEvent, 2)),
+            Ok(())
+        );
+        assert_eq!(
+            check_readiness(&readiness(GameState::WaitingPhaseRequest, 0)),
+            Err(ErrorCode::EmptyBoard)
+        );
+        assert_eq!(
+            check_readiness(&readiness(GameState::GameOver, 2)),
+            Err(ErrorCode::NotReadyForQueue)
+        );
+
+        let mut not_started = readiness(GameState::WaitingPhaseRequest, 2);
+        not_started.progression = None;
+        assert_eq!(
+            check_readiness(&not_started),
+            Err(ErrorCode::NotReadyForQueue)
+        );
+    }
+
+    #[test]
+    fn test_select_queue_mode() {
+        assert_eq!(
+            select_queue_mode(GameMode::Ranked, true),
+            Ok(QueueMode::Live)
+        );
+        assert_eq!(
+            select_queue_mode(GameMode::Ranked, false),
+            Ok(QueueMode::Ghost)
+        );
+        assert_eq!(
+            select_queue_mode(GameMode::Party, true),
+            Err(ErrorCode::InvalidGameMode)
+        );
+    }
+}
@@ -1,5 +1,6 @@
 // Game Server modules (new)
 pub mod battle_actor;
+pub mod ghost;
 pub mod load_balance_actor;
 pub mod match_coordinator;
 pub mod player_game_actor;
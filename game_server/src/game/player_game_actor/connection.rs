@@ -0,0 +1,152 @@
+use std::time::{Duration, Instant};
+
+use actix::{Actor, ActorContext, Addr, AsyncContext, Handler, Running, StreamHandler};
+use actix_web_actors::ws::{self, Message, ProtocolError};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::shared::protocol::{ErrorCode, GameServerMessage};
+
+use super::{
+    messages::{Attach, ClientText, CloseConnection, Detach, OutboundText, ResumeRequest},
+    PlayerGameActor,
+};
+
+/// `/game` WebSocket 연결 1개
+///
+/// 런 상태는 PlayerGameActor 가 가지고, 이 액터는 프레임 송수신과 heartbeat 만 담당한다.
+/// 연결이 끊겨도 PlayerGameActor 는 재접속 유예 시간 동안 유지된다.
+pub struct GameConnection {
+    connection_id: Uuid,
+    player_id: Uuid,
+    game_addr: Addr<PlayerGameActor>,
+    resume: Option<ResumeRequest>,
+    heartbeat_interval: Duration,
+    heartbeat_timeout: Duration,
+    last_heartbeat: Instant,
+    /// JWT 만료까지 남은 시간 (지나면 연결 종료, 런은 재접속 유예 시간 동안 유지)
+    token_ttl: Option<Duration>,
+}
+
+impl GameConnection {
+    pub fn new(
+        player_id: Uuid,
+        game_addr: Addr<PlayerGameActor>,
+        resume: Option<ResumeRequest>,
+        heartbeat_interval: Duration,
+        heartbeat_timeout: Duration,
+        token_ttl: Option<Duration>,
+    ) -> Self {
+        Self {
+            connection_id: Uuid::new_v4(),
+            player_id,
+            game_addr,
+            resume,
+            heartbeat_interval,
+            heartbeat_timeout,
+            last_heartbeat: Instant::now(),
+            token_ttl,
+        }
+    }
+
+    fn hb(&self, ctx: &mut ws::WebsocketContext<Self>) {
+        ctx.run_interval(self.heartbeat_interval, |act, ctx| {
+            if act.last_heartbeat.elapsed() > act.heartbeat_timeout {
+                info!("Player {} heartbeat failed, disconnecting!", act.player_id);
+                ctx.close(Some(ws::CloseCode::Away.into()));
+                ctx.stop();
+                return;
+            }
+            ctx.ping(b"");
+        });
+    }
+}
+
+impl Actor for GameConnection {
+    type Context = ws::WebsocketContext<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        // Metrics: WebSocket 연결 증가
+        metrics::ACTIVE_WS_CONNECTIONS.inc();
+
+        self.game_addr.do_send(Attach {
+            connection_id: self.connection_id,
+            connection: ctx.address(),
+            resume: self.resume,
+        });
+        self.hb(ctx);
+
+        if let Some(ttl) = self.token_ttl {
+            ctx.run_later(ttl, |act, ctx| {
+                info!(
+                    "Token expired for player {}, closing game connection",
+                    act.player_id
+                );
+                if let Ok(json) = serde_json::to_string(&GameServerMessage::error(
+                    ErrorCode::TokenExpired,
+                    "Token expired",
+                )) {
+                    ctx.text(json);
+                }
+                ctx.close(Some(ws::CloseCode::Policy.into()));
+                ctx.stop();
+            });
+        }
+    }
+
+    fn stopping(&mut self, _ctx: &mut Self::Context) -> Running {
+        // Metrics: WebSocket 연결 감소
+        metrics::ACTIVE_WS_CONNECTIONS.dec();
+
+        self.game_addr.do_send(Detach {
+            connection_id: self.connection_id,
+        });
+        Running::Stop
+    }
+}
+
+impl Handler<OutboundText> for GameConnection {
+    type Result = ();
+
+    fn handle(&mut self, msg: OutboundText, ctx: &mut Self::Context) -> Self::Result {
+        ctx.text(msg.0);
+    }
+}
+
+impl Handler<CloseConnection> for GameConnection {
+    type Result = ();
+
+    fn handle(&mut self, _msg: CloseConnection, ctx: &mut Self::Context) -> Self::Result {
+        ctx.close(Some(ws::CloseCode::Normal.into()));
+        ctx.stop();
+    }
+}
+
+impl StreamHandler<Result<Message, ProtocolError>> for GameConnection {
+    fn handle(&mut self, msg: Result<Message, ProtocolError>, ctx: &mut Self::Context) {
+        match msg {
+            Ok(ws::Message::Ping(msg)) => {
+                self.last_heartbeat = Instant::now();
+                ctx.pong(&msg);
+            }
+            Ok(ws::Message::Pong(_)) => {
+                self.last_heartbeat = Instant::now();
+            }
+            Ok(ws::Message::Text(text)) => {
+                self.game_addr.do_send(ClientText {
+                    connection_id: self.connection_id,
+                    text: text.to_string(),
+                });
+            }
+            Ok(ws::Message::Close(reason)) => {
+                ctx.close(reason);
+                ctx.stop();
+            }
+            Err(e) => {
+                warn!("WebSocket error for player {}: {}", self.player_id, e);
+                ctx.stop();
+            }
+            _ => ctx.stop(),
+        }
+    }
+}
@@ -1,11 +1,155 @@
-use actix::Handler;
-
-use crate::{game::player_game_actor::PlayerGameActor, shared::protocol::ServerMessage};
-
-impl Handler<ServerMessage> for PlayerGameActor {
-    type Result = ();
-
-    fn handle(&mut self, _msg: ServerMessage, _ctx: &mut Self::Context) -> Self::Result {
-        todo!()
-    }
-}
+use actix::{ActorContext, ActorFutureExt, ContextFutureSpawner, Handler, WrapFuture};
+use tracing::{info, warn};
+
+use crate::{
+    game::match_coordinator::messages::QueueSettled,
+    game::player_game_actor::{
+        messages::{Attach, CheckResumeToken, ClientText, Detach, PushBattleTimeline},
+        PlayerGameActor,
+    },
+    shared::protocol::{ErrorCode, GameClientMessage, GameServerMessage, ServerMessage},
+    Stop,
+};
+
+impl Handler<Stop> for PlayerGameActor {
+    type Result = ();
+
+    fn handle(&mut self, msg: Stop, ctx: &mut Self::Context) -> Self::Result {
+        info!(
+            "Stop message received in PlayerGameActor. Stopping actor. {:?}",
+            msg.reason
+        );
+        ctx.stop();
+    }
+}
+
+// LoadBalanceActor 를 통해 라우팅된 매칭 메시지는 그대로 클라이언트에 전달
+impl Handler<ServerMessage> for PlayerGameActor {
+    type Result = ();
+
+    fn handle(&mut self, msg: ServerMessage, ctx: &mut Self::Context) -> Self::Result {
+        // 다른 pod 로 런이 이전됨 → 기록은 새 소유자가 관리하므로 그대로 종료
+        if let ServerMessage::RunMigrated { pod_id } = &msg {
+            info!(
+                "Run of player {} migrated to pod {}",
+                self.player_id, pod_id
+            );
+            self.send_unsequenced(&msg);
+            self.migrated = true;
+            ctx.stop();
+            return;
+        }
+
+        // 대기열이 끝났으면 MatchCoordinator 의 대기 상태 정리
+        if matches!(
+            msg,
+            ServerMessage::MatchFound { .. }
+                | ServerMessage::DeQueued
+                | ServerMessage::Error { .. }
+        ) {
+            self.deps.match_coordinator_addr.do_send(QueueSettled {
+                player_id: self.player_id,
+            });
+        }
+        self.send(&msg);
+    }
+}
+
+impl Handler<Attach> for PlayerGameActor {
+    type Result = ();
+
+    fn handle(&mut self, msg: Attach, _ctx: &mut Self::Context) -> Self::Result {
+        self.attach(msg.connection_id, msg.connection, msg.resume);
+    }
+}
+
+impl Handler<Detach> for PlayerGameActor {
+    type Result = ();
+
+    fn handle(&mut self, msg: Detach, ctx: &mut Self::Context) -> Self::Result {
+        self.detach(msg.connection_id, ctx);
+    }
+}
+
+impl Handler<ClientText> for PlayerGameActor {
+    type Result = ();
+
+    fn handle(&mut self, msg: ClientText, ctx: &mut Self::Context) -> Self::Result {
+        // 교체된 이전 연결에서 늦게 도착한 프레임은 무시
+        if self.connection.as_ref().map(|(id, _)| *id) != Some(msg.connection_id) {
+            return;
+        }
+
+        match serde_json::from_str::<GameClientMessage>(&msg.text) {
+            Ok(msg) => {
+                // 검사가 끝날 때까지 다음 메시지를 처리하지 않으므로 행동 순서가 유지된다
+                let rate_limiter = self.deps.rate_limiter.clone();
+                let (player_id, kind) = (self.player_id, msg.kind());
+                async move { rate_limiter.check_message(player_id, kind).await }
+                    .into_actor(self)
+                    .map(move |allowed, act, ctx| {
+                        if !allowed {
+                            warn!("Rate limit exceeded for player {} ({})", player_id, kind);
+                            act.send(&GameServerMessage::error(
+                                ErrorCode::RateLimitExceeded,
+                                "Too many requests. Please slow down.",
+                            ));
+                            return;
+                        }
+                        match msg {
+                            GameClientMessage::EnterQueue { game_mode } => {
+                                act.enter_queue(ctx, game_mode)
+                            }
+                            GameClientMessage::LeaveQueue => act.leave_queue(ctx),
+                            msg => match msg.into_behavior() {
+                                Some(behavior) => act.handle_behavior(ctx, behavior),
+                                None => act.send_snapshot(),
+                            },
+                        }
+                    })
+                    .wait(ctx);
+            }
+            Err(e) => {
+                warn!("Failed to parse game client message: {}", e);
+                // 잘못된 프레임도 invalid 버킷을 소모해야 에러 응답으로 증폭되지 않는다
+                let rate_limiter = self.deps.rate_limiter.clone();
+                let player_id = self.player_id;
+                async move {
+                    rate_limiter
+                        .check_invalid_message(&player_id.to_string())
+                        .await
+                }
+                .into_actor(self)
+                .map(move |allowed, act, _ctx| {
+                    if !allowed {
+                        warn!("Dropping invalid frames from player {}", player_id);
+                        return;
+                    }
+                    act.send(&GameServerMessage::error(
+                        ErrorCode::InvalidMessageFormat,
+                        "Invalid message format",
+                    ));
+                })
+                .wait(ctx);
+            }
+        }
+    }
+}
+
+impl Handler<CheckResumeToken> for PlayerGameActor {
+    type Result = bool;
+
+    fn handle(&mut self, msg: CheckResumeToken, _ctx: &mut Self::Context) -> Self::Result {
+        self.resume_token_matches(&msg.resume_token)
+    }
+}
+
+impl Handler<PushBattleTimeline> for PlayerGameActor {
+    type Result = ();
+
+    fn handle(&mut self, msg: PushBattleTimeline, _ctx: &mut Self::Context) -> Self::Result {
+        self.send(&GameServerMessage::BattleTimeline {
+            timeline: Box::new(msg.timeline),
+        });
+    }
+}
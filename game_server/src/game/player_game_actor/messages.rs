@@ -1 +1,63 @@
-
+use actix::{Addr, Message};
+use game_core::game::battle::timeline::Timeline;
+use uuid::Uuid;
+
+use super::connection::GameConnection;
+
+/// 전투 타임라인을 클라이언트에 전달 (전투 액터 → PlayerGameActor)
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct PushBattleTimeline {
+    pub timeline: Timeline,
+}
+
+// ============================================================
+// GameConnection ↔ PlayerGameActor
+// ============================================================
+
+/// 새 WebSocket 연결을 런에 연결 (기존 연결이 있으면 끊고 교체)
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct Attach {
+    pub connection_id: Uuid,
+    pub connection: Addr<GameConnection>,
+    /// 재접속이면 Some (클라이언트가 마지막으로 받은 seq)
+    pub resume: Option<ResumeRequest>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ResumeRequest {
+    pub last_seq: Option<u64>,
+}
+
+/// 연결 종료 → 재접속 대기 시작
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct Detach {
+    pub connection_id: Uuid,
+}
+
+/// 클라이언트가 보낸 텍스트 프레임
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct ClientText {
+    pub connection_id: Uuid,
+    pub text: String,
+}
+
+/// 재접속 토큰 확인 (업그레이드 전에 거부하기 위함)
+#[derive(Message)]
+#[rtype(result = "bool")]
+pub struct CheckResumeToken {
+    pub resume_token: String,
+}
+
+/// 클라이언트로 보낼 JSON (PlayerGameActor → GameConnection)
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct OutboundText(pub String);
+
+/// 연결 종료 요청 (런 종료, 다른 연결로 교체 등)
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct CloseConnection;
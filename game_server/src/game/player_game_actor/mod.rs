@@ -1,9 +1,700 @@
-use actix::{Actor, Context};
-
-pub mod handlers;
-
-pub struct PlayerGameActor {}
-
-impl Actor for PlayerGameActor {
-    type Context = Context<Self>;
-}
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use actix::{
+    Actor, ActorContext, ActorFutureExt, Addr, AsyncContext, Context, ContextFutureSpawner,
+    Running, WrapFuture,
+};
+use chrono::{DateTime, Utc};
+use game_core::ecs::resources::GameState;
+use game_core::game::{
+    behavior::{BehaviorResult, PlayerBehavior},
+    data::registry::DataVersion,
+    enums::{OrdealType, PhaseType},
+    managers::run_manager::RunSummary,
+    world::GameCore,
+};
+use redis::aio::ConnectionManager;
+use serde::Serialize;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::game::battle_actor::simulator::BattleBuild;
+use crate::game::ghost::{bot::bot_ghost, pick_seed, GhostSnapshot};
+use crate::game::load_balance_actor::{
+    messages::{Deregister, Register},
+    LoadBalanceActor,
+};
+use crate::game::match_coordinator::{
+    messages::{DequeuePlayer, EnqueuePlayer, FindGhost, PlayerLeft, QueueReadiness},
+    MatchCoordinator,
+};
+use crate::game::player_game_actor::connection::GameConnection;
+use crate::game::player_game_actor::messages::{CloseConnection, OutboundText, ResumeRequest};
+use crate::game::player_game_actor::resume::{ReplayBuffer, ResumedRun, RunLogEntry, RunStore};
+use crate::game::player_game_actor::state::PlayerStateSnapshot;
+use crate::matchmaking::matchmaker::operations::rating::{fetch_rating, PlayerRating};
+use crate::shared::metrics::{BehaviorObservation, MetricsCtx};
+use crate::shared::protocol::{ErrorCode, GameServerMessage, GAME_PROTOCOL_VERSION};
+use crate::shared::run_report::RunReporter;
+use crate::{AppState, GameMode, RateLimiter};
+
+pub mod connection;
+pub mod handlers;
+pub mod messages;
+pub mod resume;
+pub mod state;
+
+type Ctx = Context<PlayerGameActor>;
+
+/// PlayerGameActor 가 사용하는 주소/설정 묶음
+#[derive(Clone)]
+pub struct PlayerGameDeps {
+    pub load_balance_addr: Addr<LoadBalanceActor>,
+    pub match_coordinator_addr: Addr<MatchCoordinator>,
+    pub run_store: RunStore,
+    pub rate_limiter: Arc<RateLimiter>,
+    pub metrics: Arc<MetricsCtx>,
+    /// 런 종료 시 auth_server 로 런 요약 제출
+    pub run_reporter: Arc<RunReporter>,
+    pub heartbeat_interval: Duration,
+    pub heartbeat_timeout: Duration,
+    /// 이 시간 동안 행동이 없으면 연결 종료
+    pub idle_timeout: Duration,
+    /// 연결이 끊긴 뒤 재접속을 기다리는 시간
+    pub grace_period: Duration,
+    pub replay_buffer_size: usize,
+    /// 레이팅 캐시 조회용 (봇 Ghost 의 mmr)
+    pub redis: ConnectionManager,
+    pub redis_timeout_secs: u64,
+}
+
+impl PlayerGameDeps {
+    pub fn from_state(state: &AppState) -> Self {
+        let settings = &state.settings;
+        Self {
+            load_balance_addr: state.load_balance_addr.clone(),
+            match_coordinator_addr: state.match_coordinator_addr.clone(),
+            run_store: RunStore::new(
+                state.redis.clone(),
+                state.pod_id.clone(),
+                settings.resume.run_ttl_seconds,
+                settings.matchmaking.redis_operation_timeout_seconds,
+            ),
+            rate_limiter: state.rate_limiter.clone(),
+            metrics: state.metrics.clone(),
+            run_reporter: state.run_reporter.clone(),
+            heartbeat_interval: Duration::from_secs(
+                settings.matchmaking.heartbeat_interval_seconds,
+            ),
+            heartbeat_timeout: Duration::from_secs(settings.matchmaking.heartbeat_timeout),
+            idle_timeout: Duration::from_secs(settings.server.player_idle_timeout_secs),
+            grace_period: Duration::from_secs(settings.resume.grace_period_seconds),
+            replay_buffer_size: settings.resume.replay_buffer_size,
+            redis: state.redis.clone(),
+            redis_timeout_secs: settings.matchmaking.redis_operation_timeout_seconds,
+        }
+    }
+}
+
+/// 플레이어 1명의 게임 런을 호스팅하는 액터
+///
+/// GameCore 를 소유하고, 연결된 GameConnection 이 전달한 PlayerBehavior 를
+/// GameCore::execute 로 적용한 뒤 결과와 상태 변경분(diff)을 돌려보낸다.
+/// 시작 시 LoadBalanceActor 에 등록되어 다른 액터가 ServerMessage 를 라우팅할 수 있다.
+///
+/// 연결이 끊겨도 grace_period 동안은 종료하지 않는다. 그 사이 재접속한 연결이 다시
+/// Attach 하면 놓친 메시지를 재전송 버퍼에서 보낸다.
+pub struct PlayerGameActor {
+    player_id: Uuid,
+    /// 접속 시 협상된 프로토콜 버전
+    protocol_version: u32,
+    core: GameCore,
+    data_version: Arc<DataVersion>,
+    run_seed: u64,
+    /// 런 기록을 만든 시각 (런 시간 측정 기준, pod 이전 후에도 유지)
+    run_started_at: DateTime<Utc>,
+    resume_token: String,
+    /// 최근 보낸 메시지 (seq 발급, 재접속 시 재전송)
+    replay: ReplayBuffer,
+    /// Redis 에 기록된 행동 로그 수 (다음 index)
+    log_len: u64,
+    /// 마지막으로 클라이언트에 보낸 상태 (diff 계산용)
+    last_snapshot: PlayerStateSnapshot,
+    /// 상대 Ghost 를 기다리는 시련 Phase (응답 전에 전투를 시작하면 봇 Ghost 사용)
+    awaiting_ghost: Option<(OrdealType, PhaseType)>,
+    /// 플레이어 레이팅 (시작 시 캐시에서 읽음, 봇 Ghost 생성용)
+    rating: PlayerRating,
+    deps: PlayerGameDeps,
+    /// 현재 연결 (None 이면 재접속 대기 중)
+    connection: Option<(Uuid, Addr<GameConnection>)>,
+    /// 연결이 없어진 시각
+    disconnected_at: Option<Instant>,
+    /// 새 런이면 첫 연결에 Welcome 을 보냄
+    welcome_pending: bool,
+    /// 다른 pod 에서 이전해 온 런: 재전송 버퍼가 비어 있으므로 재접속 시 전체 상태 전송
+    full_sync_pending: bool,
+    last_activity: Instant,
+    /// 다른 pod 로 런이 이전됨 (종료 시 등록 해제/기록 삭제를 하지 않음)
+    migrated: bool,
+}
+
+impl PlayerGameActor {
+    /// # Arguments
+    /// * `protocol_version` - negotiate_protocol_version 으로 협상된 버전
+    /// * `data_version` - DataRegistry::current() (런이 끝날 때까지 고정)
+    /// * `run_seed` - GameCore 결정론 시드
+    pub fn new(
+        player_id: Uuid,
+        protocol_version: u32,
+        data_version: Arc<DataVersion>,
+        run_seed: u64,
+        deps: PlayerGameDeps,
+    ) -> Self {
+        let core = GameCore::with_version(data_version.clone(), run_seed);
+        let last_snapshot = PlayerStateSnapshot::capture(&core);
+
+        Self {
+            player_id,
+            protocol_version,
+            core,
+            data_version,
+            run_seed,
+            run_started_at: Utc::now(),
+            resume_token: Uuid::new_v4().simple().to_string(),
+            replay: ReplayBuffer::new(deps.replay_buffer_size, 1),
+            log_len: 0,
+            last_snapshot,
+            awaiting_ghost: None,
+            rating: PlayerRating::default(),
+            deps,
+            connection: None,
+            disconnected_at: Some(Instant::now()),
+            welcome_pending: true,
+            full_sync_pending: false,
+            last_activity: Instant::now(),
+            migrated: false,
+        }
+    }
+
+    /// 다른 pod 에서 이전해 온 런 (RunStore::restore)
+    pub fn resume(
+        player_id: Uuid,
+        protocol_version: u32,
+        run: ResumedRun,
+        deps: PlayerGameDeps,
+    ) -> Self {
+        let last_snapshot = PlayerStateSnapshot::capture(&run.core);
+
+        Self {
+            player_id,
+            protocol_version,
+            core: run.core,
+            data_version: run.data_version,
+            run_seed: run.run_seed,
+            run_started_at: run.started_at,
+            resume_token: run.resume_token,
+            replay: run.replay,
+            log_len: run.log_len,
+            last_snapshot,
+            awaiting_ghost: None,
+            rating: PlayerRating::default(),
+            deps,
+            connection: None,
+            disconnected_at: Some(Instant::now()),
+            welcome_pending: false,
+            full_sync_pending: true,
+            last_activity: Instant::now(),
+            migrated: false,
+        }
+    }
+
+    pub fn resume_token_matches(&self, resume_token: &str) -> bool {
+        self.resume_token == resume_token
+    }
+
+    /// 행동 적용 후 결과/diff 및 push 메시지 전송
+    fn handle_behavior(&mut self, ctx: &mut Ctx, behavior: PlayerBehavior) {
+        self.last_activity = Instant::now();
+
+        if let PlayerBehavior::SelectEvent { event_id } = behavior {
+            if self.core.is_ordeal_event(event_id) {
+                self.ensure_ordeal_opponent();
+            }
+        }
+
+        let battles_before = self.core.get_battle_records().len();
+        match self.core.execute(self.player_id, behavior.clone()) {
+            Ok(mut result) => {
+                if let Some(summary) = result.as_run_ended_mut() {
+                    self.finish_run(summary);
+                }
+                let snapshot = PlayerStateSnapshot::capture(&self.core);
+                let timeline = self.core.take_battle_timeline();
+                let enkephalin = self.core.take_enkephalin_changes();
+                self.deps.metrics.observe_behavior(&BehaviorObservation {
+                    behavior: &behavior,
+                    result: &result,
+                    before: &self.last_snapshot,
+                    after: &snapshot,
+                    battles: self
+                        .core
+                        .get_battle_records()
+                        .get(battles_before..)
+                        .unwrap_or_default(),
+                    timeline: timeline.as_ref(),
+                    enkephalin: &enkephalin,
+                });
+                self.append_run_log(RunLogEntry::Behavior { behavior });
+
+                let diff = snapshot.diff(&self.last_snapshot);
+                self.last_snapshot = snapshot;
+
+                let pushes = GameServerMessage::pushes_for(&result);
+                let ordeal_phase_data = Self::is_ordeal_phase_data(&result);
+                self.send(&GameServerMessage::BehaviorResult {
+                    result: Box::new(result),
+                    diff,
+                });
+                for push in &pushes {
+                    self.send(push);
+                }
+                if let Some(timeline) = timeline {
+                    self.send(&GameServerMessage::BattleTimeline {
+                        timeline: Box::new(timeline),
+                    });
+                }
+                if ordeal_phase_data {
+                    self.request_ghost(ctx);
+                }
+            }
+            Err(error) => {
+                warn!(
+                    "Behavior rejected for player {}: {:?}",
+                    self.player_id, error
+                );
+                self.deps.metrics.inc_game_error(&error);
+                self.send(&GameServerMessage::from_game_error(&error));
+            }
+        }
+    }
+
+    /// 런 시간을 채우고 auth_server 에 런 요약 제출 (실패해도 클라이언트 응답에는 영향 없음)
+    fn finish_run(&self, summary: &mut RunSummary) {
+        let ended_at = Utc::now();
+        summary.duration_seconds = (ended_at - self.run_started_at).num_seconds().max(0) as u64;
+        info!(
+            "Run ended for player {} ({:?}, {}s)",
+            self.player_id, summary.end_reason, summary.duration_seconds
+        );
+
+        let reporter = self.deps.run_reporter.clone();
+        let (player_id, started_at, summary) =
+            (self.player_id, self.run_started_at, summary.clone());
+        actix::spawn(async move {
+            if let Err(e) = reporter
+                .submit(player_id, started_at, ended_at, &summary)
+                .await
+            {
+                warn!("Failed to submit run for player {}: {}", player_id, e);
+            }
+        });
+    }
+
+    /// 시작했지만 아직 끝나지 않은 런
+    fn is_run_in_progress(&self) -> bool {
+        !matches!(
+            self.core.get_state(),
+            GameState::NotStarted | GameState::GameOver
+        )
+    }
+
+    fn is_ordeal_phase_data(result: &BehaviorResult) -> bool {
+        result
+            .as_request_phase_data()
+            .is_some_and(|event| event.is_ordeal())
+    }
+
+    /// 시련 Phase 진입 시 내 빌드를 Ghost 풀에 등록하고 상대 Ghost 를 미리 받아둔다
+    fn request_ghost(&mut self, ctx: &mut Ctx) {
+        if self.core.has_ordeal_opponent() || self.awaiting_ghost.is_some() {
+            return;
+        }
+        let Ok((ordeal, phase)) = self.core.get_progression() else {
+            return;
+        };
+        let build = match self.core.battle_deck() {
+            Ok(deck) => BattleBuild::from_deck_info(&deck),
+            Err(e) => {
+                warn!(
+                    "Failed to build deck for player {}: {:?}",
+                    self.player_id, e
+                );
+                BattleBuild::default()
+            }
+        };
+
+        self.awaiting_ghost = Some((ordeal, phase));
+        self.deps
+            .match_coordinator_addr
+            .send(FindGhost {
+                player_id: self.player_id,
+                run_seed: self.run_seed,
+                ordeal,
+                phase,
+                build,
+                data_version: self.data_version.clone(),
+            })
+            .into_actor(self)
+            .map(move |res, act, _ctx| {
+                // 이미 봇 Ghost 로 전투를 시작했거나 Phase 가 바뀌었으면 무시
+                if act.awaiting_ghost != Some((ordeal, phase)) {
+                    return;
+                }
+                match res {
+                    Ok(ghost) => act.set_ghost_opponent(&ghost),
+                    Err(e) => {
+                        warn!("FindGhost failed for player {}: {}", act.player_id, e);
+                        act.ensure_ordeal_opponent();
+                    }
+                }
+            })
+            .spawn(ctx);
+    }
+
+    /// 시련 전투 직전까지 상대가 없으면 봇 Ghost 로 대체 (전투는 바로 시작)
+    ///
+    /// Ghost 응답을 기다리는 중이거나, 재접속 등으로 요청하지 못한 경우 모두 해당한다.
+    fn ensure_ordeal_opponent(&mut self) {
+        if self.core.has_ordeal_opponent() {
+            self.awaiting_ghost = None;
+            return;
+        }
+        let Ok((ordeal, phase)) = self.core.get_progression() else {
+            return;
+        };
+
+        let ghost = bot_ghost(
+            ordeal,
+            phase,
+            pick_seed(self.run_seed, ordeal, phase),
+            self.rating.mmr,
+            &self.data_version.data,
+            self.data_version.id,
+        );
+        self.set_ghost_opponent(&ghost);
+    }
+
+    fn set_ghost_opponent(&mut self, ghost: &GhostSnapshot) {
+        let opponent_id = ghost.player_id.to_string();
+        self.core.set_ordeal_opponent(
+            ghost
+                .build
+                .to_deck_info(&opponent_id, &self.data_version.data),
+        );
+        self.awaiting_ghost = None;
+        self.append_run_log(RunLogEntry::OrdealOpponent {
+            build: ghost.build.clone(),
+        });
+        info!(
+            "Ordeal opponent ready for player {}: {} (bot={})",
+            self.player_id, ghost.snapshot_id, ghost.is_bot
+        );
+    }
+
+    /// PvP 대기열 진입 (준비 상태 확인과 매칭 방식 선택은 MatchCoordinator 가 담당)
+    ///
+    /// 응답이 올 때까지 다음 메시지를 처리하지 않는다.
+    fn enter_queue(&mut self, ctx: &mut Ctx, game_mode: GameMode) {
+        self.last_activity = Instant::now();
+        let build = match self.core.battle_deck() {
+            Ok(deck) => BattleBuild::from_deck_info(&deck),
+            Err(_) => BattleBuild::default(),
+        };
+        let readiness = QueueReadiness {
+            state: self.core.get_state(),
+            progression: self.core.get_progression().ok(),
+            build,
+        };
+
+        self.deps
+            .match_coordinator_addr
+            .send(EnqueuePlayer {
+                player_id: self.player_id,
+                game_mode,
+                run_seed: self.run_seed,
+                readiness,
+                data_version: self.data_version.clone(),
+            })
+            .into_actor(self)
+            .map(move |res, act, _ctx| match res {
+                Ok(Ok(mode)) => info!(
+                    "Player {} entered {:?} queue ({:?})",
+                    act.player_id, game_mode, mode
+                ),
+                Ok(Err(code)) => {
+                    act.send(&GameServerMessage::error(code, "Cannot enter the queue"));
+                }
+                Err(e) => {
+                    warn!("EnqueuePlayer failed for player {}: {}", act.player_id, e);
+                    act.send(&GameServerMessage::error(
+                        ErrorCode::InternalError,
+                        "Matchmaking unavailable",
+                    ));
+                }
+            })
+            .wait(ctx);
+    }
+
+    /// PvP 대기열 이탈
+    fn leave_queue(&mut self, ctx: &mut Ctx) {
+        self.last_activity = Instant::now();
+        self.deps
+            .match_coordinator_addr
+            .send(DequeuePlayer {
+                player_id: self.player_id,
+            })
+            .into_actor(self)
+            .map(|res, act, _ctx| match res {
+                Ok(Ok(())) => {}
+                Ok(Err(code)) => {
+                    act.send(&GameServerMessage::error(code, "Not in the queue"));
+                }
+                Err(e) => {
+                    warn!("DequeuePlayer failed for player {}: {}", act.player_id, e);
+                    act.send(&GameServerMessage::error(
+                        ErrorCode::InternalError,
+                        "Matchmaking unavailable",
+                    ));
+                }
+            })
+            .wait(ctx);
+    }
+
+    /// 런 복구용 행동 로그 기록 (실패해도 진행 중인 런에는 영향 없음)
+    fn append_run_log(&mut self, entry: RunLogEntry) {
+        let index = self.log_len;
+        self.log_len += 1;
+
+        let mut run_store = self.deps.run_store.clone();
+        let player_id = self.player_id;
+        actix::spawn(async move {
+            if let Err(e) = run_store.append(player_id, index, &entry).await {
+                warn!("Failed to append run log for player {}: {}", player_id, e);
+            }
+        });
+    }
+
+    /// 접속 직후 협상 결과 + 전체 상태 전송
+    fn send_welcome(&mut self) {
+        self.last_snapshot = PlayerStateSnapshot::capture(&self.core);
+        self.send_unsequenced(&GameServerMessage::Welcome {
+            protocol_version: self.protocol_version,
+            server_protocol_version: GAME_PROTOCOL_VERSION,
+            state: self.last_snapshot.clone(),
+            resume_token: self.resume_token.clone(),
+        });
+    }
+
+    /// 재접속 직후 놓친 메시지 재전송 (버퍼로 부족하면 전체 상태)
+    fn send_resumed(&mut self, last_seq: Option<u64>) {
+        let replay = match last_seq {
+            Some(last_seq) if !self.full_sync_pending => self.replay.since(last_seq),
+            _ => None,
+        };
+        self.full_sync_pending = false;
+        let state = match replay {
+            Some(_) => None,
+            None => {
+                self.last_snapshot = PlayerStateSnapshot::capture(&self.core);
+                Some(self.last_snapshot.clone())
+            }
+        };
+
+        info!(
+            "Player {} resumed (last_seq={:?}, replayed={}, full_sync={})",
+            self.player_id,
+            last_seq,
+            replay.as_ref().map_or(0, Vec::len),
+            state.is_some()
+        );
+        self.send_unsequenced(&GameServerMessage::Resumed {
+            protocol_version: self.protocol_version,
+            server_protocol_version: GAME_PROTOCOL_VERSION,
+            resume_token: self.resume_token.clone(),
+            last_seq: self.replay.last_seq(),
+            state,
+        });
+        for json in replay.unwrap_or_default() {
+            self.send_text(json);
+        }
+    }
+
+    /// 전체 상태 스냅샷 전송
+    fn send_snapshot(&mut self) {
+        self.last_snapshot = PlayerStateSnapshot::capture(&self.core);
+        let state = self.last_snapshot.clone();
+        self.send(&GameServerMessage::Snapshot { state });
+    }
+
+    /// seq 를 붙여 재전송 버퍼에 저장 후 전송 (연결이 없으면 버퍼에만 저장)
+    fn send<T: Serialize>(&mut self, msg: &T) {
+        match self.replay.push(msg) {
+            Ok(json) => self.send_text(json),
+            Err(e) => warn!("Failed to serialize game message: {}", e),
+        }
+    }
+
+    /// 연결 단위 메시지 (Welcome/Resumed 등, 재전송 대상 아님)
+    fn send_unsequenced<T: Serialize>(&self, msg: &T) {
+        match serde_json::to_string(msg) {
+            Ok(json) => self.send_text(json),
+            Err(e) => warn!("Failed to serialize game message: {}", e),
+        }
+    }
+
+    fn send_text(&self, json: String) {
+        if let Some((_, connection)) = &self.connection {
+            connection.do_send(OutboundText(json));
+        }
+    }
+
+    /// 새 연결 연결 (기존 연결은 끊음)
+    ///
+    /// 새 런의 첫 연결이면 Welcome, 재접속이면 Resumed + 놓친 메시지를 보낸다.
+    fn attach(
+        &mut self,
+        connection_id: Uuid,
+        connection: Addr<GameConnection>,
+        resume: Option<ResumeRequest>,
+    ) {
+        if let Some((_, previous)) = self.connection.replace((connection_id, connection)) {
+            info!("Replacing game connection for player {}", self.player_id);
+            previous.do_send(CloseConnection);
+        }
+        self.disconnected_at = None;
+        self.last_activity = Instant::now();
+
+        let welcome = std::mem::take(&mut self.welcome_pending);
+        match resume {
+            Some(resume) => self.send_resumed(resume.last_seq),
+            None if welcome => self.send_welcome(),
+            // 토큰 없이 다시 붙은 연결은 전체 상태부터 받음
+            None => self.send_resumed(None),
+        }
+    }
+
+    /// 연결 끊김 → 재접속 대기 (grace_period 가 지나면 종료)
+    fn detach(&mut self, connection_id: Uuid, ctx: &mut Ctx) {
+        // 이미 새 연결로 교체된 경우 무시
+        if self.connection.as_ref().map(|(id, _)| *id) != Some(connection_id) {
+            return;
+        }
+        self.connection = None;
+        self.disconnected_at = Some(Instant::now());
+        info!(
+            "Player {} disconnected, keeping run for {:?}",
+            self.player_id, self.deps.grace_period
+        );
+        if self.deps.grace_period.is_zero() {
+            ctx.stop();
+        }
+    }
+
+    /// idle / 재접속 유예 타임아웃 감시 (heartbeat 는 GameConnection 이 담당)
+    fn watch_timeouts(&self, ctx: &mut Ctx) {
+        ctx.run_interval(self.deps.heartbeat_interval, |act, ctx| {
+            if let Some(disconnected_at) = act.disconnected_at {
+                if disconnected_at.elapsed() > act.deps.grace_period {
+                    info!("Player {} did not reconnect, stopping run", act.player_id);
+                    ctx.stop();
+                }
+                return;
+            }
+            if act.last_activity.elapsed() > act.deps.idle_timeout {
+                info!("Player {} idle timeout, disconnecting!", act.player_id);
+                ctx.stop();
+            }
+        });
+    }
+}
+
+impl Actor for PlayerGameActor {
+    type Context = Ctx;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        info!(
+            "PlayerGameActor started for player {} (protocol=v{}, data_version={})",
+            self.player_id,
+            self.protocol_version,
+            self.core.data_version()
+        );
+
+        self.deps.load_balance_addr.do_send(Register {
+            player_id: self.player_id,
+            addr: ctx.address(),
+        });
+        self.watch_timeouts(ctx);
+
+        let mut redis = self.deps.redis.clone();
+        let (player_id, timeout_secs) = (self.player_id, self.deps.redis_timeout_secs);
+        async move { fetch_rating(&mut redis, player_id, timeout_secs).await }
+            .into_actor(self)
+            .map(|rating, act, _ctx| act.rating = rating)
+            .spawn(ctx);
+
+        // 새 런이면 재접속용 기록 생성 (이전해 온 런은 RunStore::restore 가 소유권을 가져옴)
+        if self.welcome_pending {
+            let mut run_store = self.deps.run_store.clone();
+            let (player_id, token, run_seed, data_version, started_at) = (
+                self.player_id,
+                self.resume_token.clone(),
+                self.run_seed,
+                self.data_version.clone(),
+                self.run_started_at,
+            );
+            actix::spawn(async move {
+                if let Err(e) = run_store
+                    .create(player_id, &token, run_seed, &data_version, started_at)
+                    .await
+                {
+                    warn!("Failed to record run for player {}: {}", player_id, e);
+                }
+            });
+        }
+    }
+
+    fn stopping(&mut self, _ctx: &mut Self::Context) -> Running {
+        info!("PlayerGameActor stopping for player {}", self.player_id);
+
+        if let Some((_, connection)) = self.connection.take() {
+            connection.do_send(CloseConnection);
+        }
+        self.deps.load_balance_addr.do_send(Deregister {
+            player_id: self.player_id,
+        });
+        self.deps.match_coordinator_addr.do_send(PlayerLeft {
+            player_id: self.player_id,
+        });
+
+        // 다른 pod 로 이전된 런은 새 소유자가 기록을 관리
+        if !self.migrated {
+            if self.is_run_in_progress() {
+                if let Ok((ordeal, _)) = self.core.get_progression() {
+                    self.deps.metrics.inc_run_abandoned(ordeal);
+                }
+            }
+
+            let mut run_store = self.deps.run_store.clone();
+            let (player_id, token) = (self.player_id, self.resume_token.clone());
+            actix::spawn(async move {
+                if let Err(e) = run_store.release(player_id, &token).await {
+                    warn!("Failed to release run for player {}: {}", player_id, e);
+                }
+            });
+        }
+        Running::Stop
+    }
+}
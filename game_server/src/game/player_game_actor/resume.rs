@@ -0,0 +1,588 @@
+//! 게임 연결 재접속 / 런 복구
+//!
+//! - 같은 pod: 유예 시간 동안 살아 있는 PlayerGameActor 에 새 연결을 붙이고 놓친 메시지를 재전송한다.
+//! - 다른 pod: Redis 행동 로그를 같은 run_seed 로 다시 실행해 GameCore 를 복원한다.
+//!
+//! Redis 키:
+//! - `game_run:{player_id}` (Hash): token, pod_id, run_seed, data_version, data_revision, started_at
+//! - `game_run_log:{player_id}` (Hash): index → RunLogEntry JSON
+//!
+//! 두 키 모두 행동이 기록될 때마다 TTL 이 갱신된다.
+//!
+//! 행동 로그는 런을 시작한 데이터로 다시 실행해야 같은 상태가 된다. 데이터 버전 ID 는 pod 마다
+//! 따로 증가하므로 파일 내용 해시(`DataVersion::revision`)를 함께 기록하고, 이 pod 의 revision 이
+//! 다르면 소유권을 가져오지 않고 복원을 거부한다.
+
+use std::{collections::VecDeque, sync::Arc};
+
+use chrono::{DateTime, Utc};
+use game_core::game::{behavior::PlayerBehavior, data::registry::DataVersion, world::GameCore};
+use redis::{aio::ConnectionManager, Script};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::{
+    game::battle_actor::simulator::BattleBuild,
+    matchmaking::matchmaker::{operations::with_redis_timeout, scripts},
+    shared::protocol::{ErrorCode, ServerMessage},
+};
+
+pub fn run_key(player_id: Uuid) -> String {
+    format!("game_run:{}", player_id)
+}
+
+pub fn run_log_key(player_id: Uuid) -> String {
+    format!("game_run_log:{}", player_id)
+}
+
+// ============================================================
+// 재전송 버퍼
+// ============================================================
+
+/// 클라이언트로 보낸 최근 메시지 (seq 포함 JSON)
+///
+/// 모든 메시지에 1 부터 증가하는 `seq` 필드를 붙인다. 재접속한 클라이언트가
+/// 마지막으로 받은 seq 를 알려주면 그 이후 메시지를 다시 보낸다.
+pub struct ReplayBuffer {
+    capacity: usize,
+    next_seq: u64,
+    messages: VecDeque<(u64, String)>,
+}
+
+impl ReplayBuffer {
+    pub fn new(capacity: usize, next_seq: u64) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            next_seq: next_seq.max(1),
+            messages: VecDeque::new(),
+        }
+    }
+
+    /// 마지막으로 발급한 seq (아직 없으면 next_seq - 1)
+    pub fn last_seq(&self) -> u64 {
+        self.next_seq - 1
+    }
+
+    /// seq 를 붙여 저장하고 전송할 JSON 반환 (오래된 메시지부터 밀려남)
+    pub fn push<T: Serialize>(&mut self, message: &T) -> Result<String, serde_json::Error> {
+        let mut value = serde_json::to_value(message)?;
+        let seq = self.next_seq;
+        if let Some(obj) = value.as_object_mut() {
+            obj.insert("seq".to_string(), serde_json::json!(seq));
+        }
+        let json = serde_json::to_string(&value)?;
+
+        self.next_seq += 1;
+        if self.messages.len() == self.capacity {
+            self.messages.pop_front();
+        }
+        self.messages.push_back((seq, json.clone()));
+        Ok(json)
+    }
+
+    /// last_seq 이후 메시지. 필요한 메시지가 이미 밀려났으면 None
+    pub fn since(&self, last_seq: u64) -> Option<Vec<String>> {
+        if last_seq >= self.last_seq() {
+            return Some(Vec::new());
+        }
+        let oldest = self.messages.front()?.0;
+        if oldest > last_seq + 1 {
+            return None;
+        }
+        Some(
+            self.messages
+                .iter()
+                .filter(|(seq, _)| *seq > last_seq)
+                .map(|(_, json)| json.clone())
+                .collect(),
+        )
+    }
+}
+
+// ============================================================
+// 런 복구
+// ============================================================
+
+/// 런 복구에 필요한 행동 로그 항목 (GameCore 상태를 바꾼 입력만 기록)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RunLogEntry {
+    /// 성공한 플레이어 행동
+    Behavior { behavior: PlayerBehavior },
+    /// 시련 전투 상대 (Ghost 는 Redis 상태에 따라 달라지므로 결과를 기록)
+    OrdealOpponent { build: BattleBuild },
+}
+
+/// 다른 pod 에서 이전해 온 런 (재전송 버퍼가 없으므로 재접속 시 전체 상태를 보냄)
+pub struct ResumedRun {
+    pub core: GameCore,
+    pub data_version: Arc<DataVersion>,
+    pub run_seed: u64,
+    /// 런 기록을 만든 시각 (런 시간 측정 기준)
+    pub started_at: DateTime<Utc>,
+    pub resume_token: String,
+    /// 클라이언트가 마지막으로 받은 seq 다음부터 발급
+    pub replay: ReplayBuffer,
+    /// 지금까지 기록된 로그 항목 수 (다음 index)
+    pub log_len: u64,
+}
+
+#[derive(Debug)]
+pub enum ResumeError {
+    /// 런 기록이 없음 (만료되었거나 종료됨)
+    NotFound,
+    InvalidToken,
+    /// 런을 시작한 데이터 revision 을 이 pod 가 로드하고 있지 않음
+    DataMismatch {
+        recorded: String,
+        current: String,
+    },
+    /// 행동 로그를 다시 실행하지 못함
+    Replay(String),
+    Redis(String),
+}
+
+impl From<&ResumeError> for ErrorCode {
+    fn from(error: &ResumeError) -> Self {
+        match error {
+            ResumeError::NotFound => ErrorCode::RunNotFound,
+            ResumeError::InvalidToken => ErrorCode::InvalidResumeToken,
+            ResumeError::DataMismatch { .. } => ErrorCode::RunDataUnavailable,
+            ResumeError::Replay(_) | ResumeError::Redis(_) => ErrorCode::InternalError,
+        }
+    }
+}
+
+/// 행동 로그를 처음부터 다시 실행해 GameCore 복원
+pub fn rebuild_core(
+    player_id: Uuid,
+    data_version: Arc<DataVersion>,
+    run_seed: u64,
+    entries: &[RunLogEntry],
+) -> Result<GameCore, ResumeError> {
+    let mut core = GameCore::with_version(data_version.clone(), run_seed);
+    let opponent_id = format!("ghost-of-{}", player_id);
+
+    for (index, entry) in entries.iter().enumerate() {
+        match entry {
+            RunLogEntry::Behavior { behavior } => {
+                core.execute(player_id, behavior.clone()).map_err(|e| {
+                    ResumeError::Replay(format!("entry {} ({:?}): {:?}", index, behavior, e))
+                })?;
+            }
+            RunLogEntry::OrdealOpponent { build } => {
+                core.set_ordeal_opponent(build.to_deck_info(&opponent_id, &data_version.data));
+            }
+        }
+    }
+
+    // 복원 중 실행된 전투 타임라인은 이미 클라이언트가 받았거나 받을 수 없음
+    core.take_battle_timeline();
+    // 엔케팔린 증감은 이전 pod 가 이미 메트릭에 반영함
+    core.take_enkephalin_changes();
+    Ok(core)
+}
+
+/// CLAIM_GAME_RUN 으로 가져온 런 기록
+#[derive(Debug)]
+struct ClaimedRun {
+    previous_pod: String,
+    run_seed: u64,
+    /// 이전 pod 에서 기록된 데이터 버전 ID
+    data_version: u64,
+    started_at: DateTime<Utc>,
+}
+
+/// CLAIM_GAME_RUN 결과 파싱 (started_at 이 없는 이전 기록은 지금 시작한 것으로 봄)
+fn parse_claim_result(
+    claimed: &[String],
+    data_version: &DataVersion,
+) -> Result<ClaimedRun, ResumeError> {
+    match claimed.first().map(String::as_str) {
+        Some("ok") if claimed.len() >= 4 => Ok(ClaimedRun {
+            previous_pod: claimed[1].clone(),
+            run_seed: claimed[2].parse::<u64>().unwrap_or_default(),
+            data_version: claimed[3].parse::<u64>().unwrap_or_default(),
+            started_at: claimed
+                .get(4)
+                .and_then(|s| s.parse::<i64>().ok())
+                .and_then(|secs| DateTime::from_timestamp(secs, 0))
+                .unwrap_or_else(Utc::now),
+        }),
+        Some("invalid_token") => Err(ResumeError::InvalidToken),
+        Some("not_found") => Err(ResumeError::NotFound),
+        Some("data_mismatch") => Err(ResumeError::DataMismatch {
+            recorded: claimed.get(1).cloned().unwrap_or_default(),
+            current: data_version.revision.clone(),
+        }),
+        other => Err(ResumeError::Redis(format!(
+            "Unexpected claim result: {:?}",
+            other
+        ))),
+    }
+}
+
+/// 런 기록 저장소 (Redis)
+#[derive(Clone)]
+pub struct RunStore {
+    redis: ConnectionManager,
+    pod_id: String,
+    ttl_secs: u64,
+    timeout_secs: u64,
+}
+
+impl RunStore {
+    pub fn new(redis: ConnectionManager, pod_id: String, ttl_secs: u64, timeout_secs: u64) -> Self {
+        Self {
+            redis,
+            pod_id,
+            ttl_secs: ttl_secs.max(1),
+            timeout_secs,
+        }
+    }
+
+    /// 새 런 기록 (같은 플레이어의 이전 런 기록은 덮어씀)
+    pub async fn create(
+        &mut self,
+        player_id: Uuid,
+        resume_token: &str,
+        run_seed: u64,
+        data_version: &DataVersion,
+        started_at: DateTime<Utc>,
+    ) -> Result<(), String> {
+        let run_key = run_key(player_id);
+        let log_key = run_log_key(player_id);
+        let ttl = self.ttl_secs;
+        let pod_id = self.pod_id.clone();
+
+        let redis = &mut self.redis;
+        with_redis_timeout("game_run_create", self.timeout_secs, async {
+            redis::pipe()
+                .atomic()
+                .cmd("DEL")
+                .arg(&run_key)
+                .arg(&log_key)
+                .ignore()
+                .cmd("HSET")
+                .arg(&run_key)
+                .arg("token")
+                .arg(resume_token)
+                .arg("pod_id")
+                .arg(&pod_id)
+                .arg("run_seed")
+                .arg(run_seed.to_string())
+                .arg("data_version")
+                .arg(data_version.id)
+                .arg("data_revision")
+                .arg(&data_version.revision)
+                .arg("started_at")
+                .arg(started_at.timestamp())
+                .ignore()
+                .cmd("EXPIRE")
+                .arg(&run_key)
+                .arg(ttl)
+                .ignore()
+                .query_async::<_, ()>(redis)
+                .await
+        })
+        .await
+    }
+
+    /// 행동 로그 기록 (index 로 저장하므로 도착 순서와 무관)
+    pub async fn append(
+        &mut self,
+        player_id: Uuid,
+        index: u64,
+        entry: &RunLogEntry,
+    ) -> Result<(), String> {
+        let json = serde_json::to_string(entry)
+            .map_err(|e| format!("Failed to serialize run log entry: {}", e))?;
+        let run_key = run_key(player_id);
+        let log_key = run_log_key(player_id);
+        let ttl = self.ttl_secs;
+
+        let redis = &mut self.redis;
+        with_redis_timeout("game_run_append", self.timeout_secs, async {
+            redis::pipe()
+                .cmd("HSET")
+                .arg(&log_key)
+                .arg(index)
+                .arg(&json)
+                .ignore()
+                .cmd("EXPIRE")
+                .arg(&log_key)
+                .arg(ttl)
+                .ignore()
+                .cmd("EXPIRE")
+                .arg(&run_key)
+                .arg(ttl)
+                .ignore()
+                .query_async::<_, ()>(redis)
+                .await
+        })
+        .await
+    }
+
+    /// 런 기록 삭제 (이 pod 가 소유하고 토큰이 같을 때만)
+    pub async fn release(&mut self, player_id: Uuid, resume_token: &str) -> Result<bool, String> {
+        let script = Script::new(scripts::release_game_run_script());
+        let pod_id = self.pod_id.clone();
+        let redis = &mut self.redis;
+        let released: i64 = with_redis_timeout("game_run_release", self.timeout_secs, async {
+            script
+                .key(run_key(player_id))
+                .key(run_log_key(player_id))
+                .arg(resume_token)
+                .arg(&pod_id)
+                .invoke_async(redis)
+                .await
+        })
+        .await?;
+        Ok(released == 1)
+    }
+
+    /// 다른 pod 에 남은 런을 이 pod 로 이전해 복원
+    ///
+    /// 소유권을 먼저 가져온 뒤 행동 로그를 다시 실행하고, 이전 pod 의 액터에는
+    /// RunMigrated 를 보내 종료시킨다.
+    pub async fn restore(
+        &mut self,
+        player_id: Uuid,
+        resume_token: &str,
+        data_version: Arc<DataVersion>,
+        last_seq: Option<u64>,
+        replay_capacity: usize,
+    ) -> Result<ResumedRun, ResumeError> {
+        // 1. 소유권 이전
+        let script = Script::new(scripts::claim_game_run_script());
+        let pod_id = self.pod_id.clone();
+        let ttl = self.ttl_secs;
+        let redis = &mut self.redis;
+        let claimed: Vec<String> = with_redis_timeout("game_run_claim", self.timeout_secs, async {
+            script
+                .key(run_key(player_id))
+                .key(run_log_key(player_id))
+                .arg(resume_token)
+                .arg(&pod_id)
+                .arg(ttl)
+                .arg(&data_version.revision)
+                .invoke_async(redis)
+                .await
+        })
+        .await
+        .map_err(ResumeError::Redis)?;
+
+        let claimed = parse_claim_result(&claimed, &data_version)?;
+        let (previous_pod, run_seed) = (claimed.previous_pod, claimed.run_seed);
+        if claimed.data_version != data_version.id {
+            info!(
+                "Restoring run for player {} with data version {} (recorded {} on the previous pod, same revision)",
+                player_id, data_version.id, claimed.data_version
+            );
+        }
+
+        // 2. 행동 로그 재실행
+        let entries = self.load_log(player_id).await?;
+        let core = rebuild_core(player_id, data_version.clone(), run_seed, &entries)?;
+
+        // 3. 이전 pod 의 액터 종료
+        if !previous_pod.is_empty() && previous_pod != self.pod_id {
+            self.notify_migrated(&previous_pod, player_id).await;
+        }
+
+        info!(
+            "Run restored for player {} from pod {} ({} log entries)",
+            player_id,
+            previous_pod,
+            entries.len()
+        );
+        Ok(ResumedRun {
+            core,
+            data_version,
+            run_seed,
+            started_at: claimed.started_at,
+            resume_token: resume_token.to_string(),
+            replay: ReplayBuffer::new(replay_capacity, last_seq.unwrap_or(0) + 1),
+            log_len: entries.len() as u64,
+        })
+    }
+
+    /// index 순 행동 로그 (중간이 비어 있으면 복원 불가)
+    async fn load_log(&mut self, player_id: Uuid) -> Result<Vec<RunLogEntry>, ResumeError> {
+        let redis = &mut self.redis;
+        let raw: Vec<(u64, String)> =
+            with_redis_timeout("game_run_log", self.timeout_secs, async {
+                redis::cmd("HGETALL")
+                    .arg(run_log_key(player_id))
+                    .query_async(redis)
+                    .await
+            })
+            .await
+            .map_err(ResumeError::Redis)?;
+
+        let mut raw = raw;
+        raw.sort_by_key(|(index, _)| *index);
+        raw.into_iter()
+            .enumerate()
+            .map(|(expected, (index, json))| {
+                if index != expected as u64 {
+                    return Err(ResumeError::Replay(format!(
+                        "run log gap at index {}",
+                        expected
+                    )));
+                }
+                serde_json::from_str(&json)
+                    .map_err(|e| ResumeError::Replay(format!("entry {}: {}", index, e)))
+            })
+            .collect()
+    }
+
+    /// 이전 pod 의 game_message 채널로 RunMigrated 전송
+    async fn notify_migrated(&mut self, previous_pod: &str, player_id: Uuid) {
+        let channel = format!("pod:{}:game_message", previous_pod);
+        let payload = serde_json::json!({
+            "player_id": player_id,
+            "message": ServerMessage::RunMigrated {
+                pod_id: self.pod_id.clone(),
+            },
+            "timestamp": chrono::Utc::now().to_rfc3339(),
+        });
+
+        let redis = &mut self.redis;
+        let result: Result<i64, String> =
+            with_redis_timeout("game_run_migrated", self.timeout_secs, async {
+                redis::cmd("PUBLISH")
+                    .arg(&channel)
+                    .arg(payload.to_string())
+                    .query_async(redis)
+                    .await
+            })
+            .await;
+        if let Err(e) = result {
+            warn!(
+                "Failed to notify pod {} about migrated run of {}: {}",
+                previous_pod, player_id, e
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use game_core::game::data::registry::DataRegistry;
+
+    use super::*;
+
+    #[test]
+    fn test_replay_buffer_adds_seq_and_replays_missed() {
+        let mut buffer = ReplayBuffer::new(3, 1);
+        for n in 0..4 {
+            let json = buffer
+                .push(&serde_json::json!({ "type": "test", "n": n }))
+                .unwrap();
+            let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+            assert_eq!(value["seq"], n + 1);
+        }
+        assert_eq!(buffer.last_seq(), 4);
+
+        // 최신 상태면 보낼 것이 없음
+        assert_eq!(buffer.since(4), Some(Vec::new()));
+        // seq 2 까지 받았으면 3, 4 재전송
+        let missed = buffer.since(2).unwrap();
+        assert_eq!(missed.len(), 2);
+        assert!(missed[0].contains("\"seq\":3"));
+        // seq 1 까지 받은 클라이언트: seq 2 부터 남아 있으므로 가능
+        assert_eq!(buffer.since(1).unwrap().len(), 3);
+        // seq 1 은 이미 밀려났음
+        assert!(buffer.since(0).is_none());
+    }
+
+    #[test]
+    fn test_replay_buffer_starts_after_client_seq() {
+        // pod 이전 후에는 클라이언트가 받은 seq 다음부터 발급
+        let mut buffer = ReplayBuffer::new(8, 11);
+        assert_eq!(buffer.last_seq(), 10);
+        let json = buffer.push(&serde_json::json!({ "type": "test" })).unwrap();
+        assert!(json.contains("\"seq\":11"));
+    }
+
+    #[test]
+    fn test_run_log_entry_roundtrip() {
+        let entry = RunLogEntry::Behavior {
+            behavior: PlayerBehavior::RequestPhaseData,
+        };
+        let json = serde_json::to_string(&entry).unwrap();
+        assert!(json.contains("\"type\":\"behavior\""));
+        let parsed: RunLogEntry = serde_json::from_str(&json).unwrap();
+        assert!(matches!(
+            parsed,
+            RunLogEntry::Behavior {
+                behavior: PlayerBehavior::RequestPhaseData
+            }
+        ));
+    }
+
+    #[test]
+    fn test_restore_after_reload_requires_same_revision() {
+        let manifest = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let balance_path =
+            std::env::temp_dir().join(format!("game_balance_{}.toml", Uuid::new_v4()));
+        std::fs::copy(
+            manifest.join("../core/config/game_balance.toml"),
+            &balance_path,
+        )
+        .unwrap();
+        let registry = DataRegistry::load(
+            manifest.join("../game_resources/data"),
+            Some(balance_path.clone()),
+        )
+        .unwrap();
+
+        // Given: 버전 1 로 시작한 런 (create 가 기록하는 revision)
+        let player_id = Uuid::new_v4();
+        let recorded = registry.current();
+        let entries = vec![
+            RunLogEntry::Behavior {
+                behavior: PlayerBehavior::StartNewGame,
+            },
+            RunLogEntry::Behavior {
+                behavior: PlayerBehavior::RequestPhaseData,
+            },
+        ];
+
+        // When: 파일 변경 없이 리로드 → ID 는 달라도 revision 이 같으므로 복원 가능
+        let reloaded = registry.reload().unwrap();
+        assert_ne!(reloaded.id, recorded.id);
+        assert_eq!(reloaded.revision, recorded.revision);
+        let claimed = vec![
+            "ok".to_string(),
+            "pod-a".to_string(),
+            "42".to_string(),
+            recorded.id.to_string(),
+            "1700000000".to_string(),
+        ];
+        let claimed = parse_claim_result(&claimed, &reloaded).unwrap();
+        assert_eq!(claimed.started_at.timestamp(), 1700000000);
+        assert!(rebuild_core(player_id, reloaded, claimed.run_seed, &entries).is_ok());
+
+        // When: 내용이 바뀐 뒤 리로드 → CLAIM_GAME_RUN 이 data_mismatch 로 거부
+        let mut contents = std::fs::read_to_string(&balance_path).unwrap();
+        contents.push_str("\n# edited\n");
+        std::fs::write(&balance_path, contents).unwrap();
+        let edited = registry.reload().unwrap();
+        std::fs::remove_file(&balance_path).ok();
+        assert_ne!(edited.revision, recorded.revision);
+
+        let claimed = vec!["data_mismatch".to_string(), recorded.revision.clone()];
+        let error = parse_claim_result(&claimed, &edited).unwrap_err();
+        assert!(matches!(
+            &error,
+            ResumeError::DataMismatch { recorded: r, current: c }
+                if *r == recorded.revision && *c == edited.revision
+        ));
+        assert_eq!(ErrorCode::from(&error), ErrorCode::RunDataUnavailable);
+    }
+}
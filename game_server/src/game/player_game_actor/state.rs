@@ -1 +1,146 @@
-
+use game_core::{
+    ecs::resources::{GameState, QliphothLevel},
+    game::{
+        behavior::PlayerBehavior,
+        enums::{OrdealType, PhaseType},
+        world::GameCore,
+    },
+};
+use serde::Serialize;
+
+/// 클라이언트에 노출하는 GameCore 상태 요약
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PlayerStateSnapshot {
+    pub state: GameState,
+    pub enkephalin: u32,
+    pub ordeal: Option<OrdealType>,
+    pub phase: Option<PhaseType>,
+    pub qliphoth: Option<u32>,
+    pub qliphoth_level: Option<QliphothLevel>,
+    pub level: u32,
+    pub win_count: u32,
+    pub allowed_actions: Vec<String>,
+    pub data_version: u64,
+}
+
+impl PlayerStateSnapshot {
+    pub fn capture(core: &GameCore) -> Self {
+        let progression = core.get_progression().ok();
+        let qliphoth = core.get_qliphoth().ok();
+
+        Self {
+            state: core.get_state(),
+            enkephalin: core.get_enkephalin(),
+            ordeal: progression.map(|(ordeal, _)| ordeal),
+            phase: progression.map(|(_, phase)| phase),
+            qliphoth: qliphoth.as_ref().map(|q| q.amount()),
+            qliphoth_level: qliphoth.as_ref().map(|q| q.level()),
+            level: core.get_level(),
+            win_count: core.get_win_count(),
+            allowed_actions: core.get_allowed_actions().iter().map(action_name).collect(),
+            data_version: core.data_version(),
+        }
+    }
+
+    /// 이전 스냅샷 대비 변경된 필드만 담은 diff
+    pub fn diff(&self, previous: &Self) -> StateDiff {
+        fn changed<T: Clone + PartialEq>(current: &T, previous: &T) -> Option<T> {
+            (current != previous).then(|| current.clone())
+        }
+
+        StateDiff {
+            state: changed(&self.state, &previous.state),
+            enkephalin: changed(&self.enkephalin, &previous.enkephalin),
+            ordeal: changed(&self.ordeal, &previous.ordeal).flatten(),
+            phase: changed(&self.phase, &previous.phase).flatten(),
+            qliphoth: changed(&self.qliphoth, &previous.qliphoth).flatten(),
+            qliphoth_level: changed(&self.qliphoth_level, &previous.qliphoth_level).flatten(),
+            level: changed(&self.level, &previous.level),
+            win_count: changed(&self.win_count, &previous.win_count),
+            allowed_actions: changed(&self.allowed_actions, &previous.allowed_actions),
+        }
+    }
+}
+
+/// 행동 처리 전후 상태 변경분 (변경되지 않은 필드는 직렬화하지 않음)
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct StateDiff {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<GameState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enkephalin: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ordeal: Option<OrdealType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phase: Option<PhaseType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qliphoth: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qliphoth_level: Option<QliphothLevel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub win_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_actions: Option<Vec<String>>,
+}
+
+impl StateDiff {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// 허용 행동 목록은 payload 없이 variant 이름만 전달
+fn action_name(action: &PlayerBehavior) -> String {
+    let debug = format!("{:?}", action);
+    debug
+        .split([' ', '{', '('])
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_action_name_strips_payload() {
+        assert_eq!(action_name(&PlayerBehavior::StartNewGame), "StartNewGame");
+        assert_eq!(
+            action_name(&PlayerBehavior::PurchaseItem {
+                item_uuid: uuid::Uuid::nil()
+            }),
+            "PurchaseItem"
+        );
+    }
+
+    #[test]
+    fn test_diff_contains_only_changed_fields() {
+        let previous = PlayerStateSnapshot {
+            state: GameState::NotStarted,
+            enkephalin: 0,
+            ordeal: None,
+            phase: None,
+            qliphoth: Some(10),
+            qliphoth_level: Some(QliphothLevel::Stable),
+            level: 1,
+            win_count: 0,
+            allowed_actions: vec!["StartNewGame".to_string()],
+            data_version: 1,
+        };
+        let mut current = previous.clone();
+        current.state = GameState::WaitingPhaseRequest;
+        current.enkephalin = 300;
+
+        let diff = current.diff(&previous);
+        assert_eq!(diff.state, Some(GameState::WaitingPhaseRequest));
+        assert_eq!(diff.enkephalin, Some(300));
+        assert!(diff.qliphoth.is_none());
+
+        let json = serde_json::to_value(&diff).unwrap();
+        assert_eq!(json.as_object().unwrap().len(), 2);
+        assert!(previous.diff(&previous).is_empty());
+    }
+}
@@ -2,12 +2,13 @@ use ::redis::aio::ConnectionManager;
 use actix::{Addr, Message};
 use actix_web::HttpRequest;
 use backoff::ExponentialBackoff;
+use game_core::game::data::registry::DataRegistry;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::io;
 use std::net::IpAddr;
-use std::sync::{Arc, RwLock};
-use std::time::{Duration, Instant};
+use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock as TokioRwLock;
 use tracing::{debug, error, warn};
 use tracing_appender::rolling::{RollingFileAppender, Rotation};
@@ -17,6 +18,8 @@ use uuid::Uuid;
 use crate::env::RetrySettings;
 use crate::game::{load_balance_actor::LoadBalanceActor, match_coordinator::MatchCoordinator};
 use crate::matchmaking::subscript::SubScriptionManager;
+use crate::shared::auth::TokenVerifier;
+use crate::shared::run_report::RunReporter;
 use crate::{env::Settings, matchmaking::matchmaker::MatchmakerAddr, shared::metrics::MetricsCtx};
 
 lazy_static::lazy_static! {
@@ -30,6 +33,8 @@ pub mod game; // Game Server new modules
 pub mod matchmaking; // Match Server legacy modules
 pub mod shared; // Shared infrastructure modules
 
+pub use shared::rate_limit::RateLimiter;
+
 pub struct LoggerManager {
     _guard: tracing_appender::non_blocking::WorkerGuard,
 }
@@ -134,9 +139,15 @@ pub struct AppState {
     pub redis: ConnectionManager,
     pub logger_manager: Arc<LoggerManager>,
     pub current_run_id: Uuid,
+    /// 이 서버의 pod ID (크로스 Pod 메시지 채널, 런 소유권에 사용)
+    pub pod_id: String,
     pub metrics: Arc<MetricsCtx>,
     pub metrics_registry: prometheus::Registry,
     pub rate_limiter: Arc<RateLimiter>,
+    pub token_verifier: Arc<TokenVerifier>,
+    pub data_registry: Arc<DataRegistry>,
+    /// 런 종료 시 auth_server 로 런 요약 제출
+    pub run_reporter: Arc<RunReporter>,
 }
 
 pub fn extract_client_ip(req: &HttpRequest) -> Option<IpAddr> {
@@ -192,65 +203,9 @@ pub enum GameMode {
     Normal,
     #[serde(rename = "Ranked")]
     Ranked,
-}
-
-/// Simple rate limiter using token bucket algorithm
-pub struct RateLimiter {
-    buckets: Arc<RwLock<HashMap<IpAddr, TokenBucket>>>,
-    max_requests_per_second: u32,
-    #[allow(dead_code)]
-    cleanup_interval: Duration,
-}
-
-struct TokenBucket {
-    tokens: f64,
-    last_refill: Instant,
-    max_tokens: f64,
-    refill_rate: f64, // tokens per second
-}
-
-impl RateLimiter {
-    pub fn new(max_requests_per_second: u32) -> Self {
-        Self {
-            buckets: Arc::new(RwLock::new(HashMap::new())),
-            max_requests_per_second,
-            cleanup_interval: Duration::from_secs(300), // cleanup every 5 minutes
-        }
-    }
-
-    pub fn check(&self, _ip: &IpAddr) -> bool {
-        true
-        // let mut buckets = self.buckets.write().unwrap();
-        // let bucket = buckets.entry(*ip).or_insert_with(|| TokenBucket {
-        //     tokens: self.max_requests_per_second as f64,
-        //     last_refill: Instant::now(),
-        //     max_tokens: self.max_requests_per_second as f64,
-        //     refill_rate: self.max_requests_per_second as f64,
-        // });
-
-        // // Refill tokens based on elapsed time
-        // let now = Instant::now();
-        // let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
-        // bucket.tokens = (bucket.tokens + elapsed * bucket.refill_rate).min(bucket.max_tokens);
-        // bucket.last_refill = now;
-
-        // // Check if we have tokens
-        // if bucket.tokens >= 1.0 {
-        //     bucket.tokens -= 1.0;
-        //     true
-        // } else {
-        //     false
-        // }
-    }
-
-    /// Cleanup old entries (call periodically)
-    pub fn cleanup(&self) {
-        let mut buckets = self.buckets.write().unwrap();
-        let now = Instant::now();
-        buckets.retain(|_, bucket| {
-            now.duration_since(bucket.last_refill) < Duration::from_secs(600) // 10 minutes
-        });
-    }
+    /// 파티 단위 매칭 (리더가 파티 전체를 enqueue)
+    #[serde(rename = "Party")]
+    Party,
 }
 
 pub async fn flush_redis_default() -> Result<(), Box<dyn std::error::Error>> {
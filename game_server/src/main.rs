@@ -1,22 +1,38 @@
-use actix::Actor;
+use actix::{Actor, StreamHandler};
 use actix_web::{get, web, App, Error, HttpRequest, HttpResponse, HttpServer};
 use actix_web_actors::ws;
+use game_core::game::data::registry::DataRegistry;
 use game_server::{
     env::Settings,
     extract_client_ip, flush_redis_default,
     game::{
-        load_balance_actor::LoadBalanceActor, match_coordinator::MatchCoordinator,
+        load_balance_actor::{messages::FindPlayer, LoadBalanceActor},
+        match_coordinator::MatchCoordinator,
+        player_game_actor::{
+            connection::GameConnection,
+            messages::{CheckResumeToken, ResumeRequest},
+            resume::ResumeError,
+            PlayerGameActor, PlayerGameDeps,
+        },
         pubsub::spawn_redis_subscribers,
     },
     init_retry_config,
     matchmaking::matchmaker::{spawn_matchmakers, MatchmakerDeps},
     matchmaking::session::Session,
     matchmaking::subscript::SubScriptionManager,
+    shared::auth::{TokenVerifier, WS_AUTH_PROTOCOL},
+    shared::data_reload::{data_version_route, reload_data_route, spawn_data_watcher},
     shared::event_stream::{EventStreamSession, StreamSessionId},
     shared::metrics::MetricsCtx,
+    shared::protocol::{
+        negotiate_protocol_version, ErrorCode, GameServerMessage, ServerMessage,
+        GAME_PROTOCOL_VERSION, MIN_GAME_PROTOCOL_VERSION,
+    },
+    shared::run_report::RunReporter,
     AppState, GameMode, LoggerManager,
 };
 use prometheus::{Encoder, TextEncoder};
+use serde::Deserialize;
 use std::{sync::Arc, time::Duration};
 use tokio_util::sync::CancellationToken;
 use tracing::{error, info, warn};
@@ -33,23 +49,253 @@ async fn matchmaking_ws_route(
         actix_web::error::ErrorBadRequest("Unable to determine client IP")
     })?;
 
+    if !state.rate_limiter.check_connection(&client_ip).await {
+        warn!("Connection rate limit exceeded for IP: {}", client_ip);
+        return Ok(HttpResponse::TooManyRequests().json(ServerMessage::Error {
+            code: ErrorCode::RateLimitExceeded,
+            message: "Too many connection attempts. Please slow down.".to_string(),
+        }));
+    }
+
+    let (auth, via_protocol) = match state.token_verifier.authenticate(&req).await {
+        Ok(result) => result,
+        Err(e) => {
+            warn!(
+                "Rejected matchmaking connection from {}: {:?}",
+                client_ip, e
+            );
+            return Ok(HttpResponse::Unauthorized().json(ServerMessage::Error {
+                code: ErrorCode::from(&e),
+                message: "Invalid or missing token".to_string(),
+            }));
+        }
+    };
+
     let session = Session::new(
         state.sub_manager_addr.clone(),
         Duration::from_secs(state.settings.matchmaking.heartbeat_interval_seconds),
         Duration::from_secs(state.settings.matchmaking.heartbeat_timeout),
         state.clone(),
         client_ip,
+        auth,
     );
 
-    ws::start(session, &req, stream)
+    start_ws(session, &req, stream, via_protocol)
+}
+
+/// WebSocket 업그레이드 (토큰을 서브프로토콜로 받았으면 응답에 같은 프로토콜 포함)
+fn start_ws<A>(
+    actor: A,
+    req: &HttpRequest,
+    stream: web::Payload,
+    via_protocol: bool,
+) -> Result<HttpResponse, Error>
+where
+    A: Actor<Context = ws::WebsocketContext<A>>
+        + StreamHandler<Result<ws::Message, ws::ProtocolError>>,
+{
+    if via_protocol {
+        ws::WsResponseBuilder::new(actor, req, stream)
+            .protocols(&[WS_AUTH_PROTOCOL])
+            .start()
+    } else {
+        ws::start(actor, req, stream)
+    }
 }
 
+/// 게임 런 연결 쿼리 파라미터
+#[derive(Deserialize)]
+struct GameConnectQuery {
+    player_id: Uuid,
+    /// 클라이언트가 사용하는 게임 프로토콜 버전
+    protocol_version: Option<u32>,
+    /// 재접속 시 Welcome 에서 받은 토큰
+    resume_token: Option<String>,
+    /// 재접속 시 마지막으로 받은 메시지 seq
+    last_seq: Option<u64>,
+}
+
+/// 재접속 실패 응답
+fn resume_rejected(error: &ResumeError) -> HttpResponse {
+    let code = ErrorCode::from(error);
+    let mut response = match error {
+        ResumeError::NotFound => HttpResponse::Gone(),
+        ResumeError::InvalidToken => HttpResponse::Forbidden(),
+        ResumeError::DataMismatch { .. } => HttpResponse::Conflict(),
+        ResumeError::Replay(_) | ResumeError::Redis(_) => HttpResponse::InternalServerError(),
+    };
+    response.json(GameServerMessage::error(code, format!("{:?}", error)))
+}
+
+#[get("/game")]
+async fn player_game_ws_route(
+    req: HttpRequest,
+    stream: web::Payload,
+    query: web::Query<GameConnectQuery>,
+    state: web::Data<AppState>,
+) -> Result<HttpResponse, Error> {
+    let player_id = query.player_id;
+
+    let client_ip = extract_client_ip(&req).ok_or_else(|| {
+        error!("Failed to extract client IP - rejecting game connection");
+        actix_web::error::ErrorBadRequest("Unable to determine client IP")
+    })?;
+    if !state.rate_limiter.check_connection(&client_ip).await {
+        warn!("Connection rate limit exceeded for IP: {}", client_ip);
+        return Ok(
+            HttpResponse::TooManyRequests().json(GameServerMessage::error(
+                ErrorCode::RateLimitExceeded,
+                "Too many connection attempts. Please slow down.",
+            )),
+        );
+    }
+
+    let (auth, via_protocol) = match state.token_verifier.authenticate(&req).await {
+        Ok(result) => result,
+        Err(e) => {
+            warn!("Rejected game connection for player {}: {:?}", player_id, e);
+            return Ok(HttpResponse::Unauthorized().json(GameServerMessage::error(
+                ErrorCode::from(&e),
+                "Invalid or missing token",
+            )));
+        }
+    };
+    if auth
+        .as_ref()
+        .is_some_and(|auth| auth.player_id != player_id)
+    {
+        warn!(
+            "Rejected game connection: token does not belong to player {}",
+            player_id
+        );
+        return Ok(HttpResponse::Forbidden().json(GameServerMessage::error(
+            ErrorCode::PlayerIdMismatch,
+            "Token does not belong to this player",
+        )));
+    }
+
+    // 프로토콜 버전 협상 (지원 범위 밖이면 업그레이드 전에 거부)
+    let protocol_version = match negotiate_protocol_version(query.protocol_version) {
+        Ok(version) => version,
+        Err(code) => {
+            warn!(
+                "Rejected game connection for player {}: protocol_version={:?}",
+                player_id, query.protocol_version
+            );
+            return Ok(HttpResponse::BadRequest().json(GameServerMessage::error(
+                code,
+                format!(
+                    "Supported protocol versions: {}..={}",
+                    MIN_GAME_PROTOCOL_VERSION, GAME_PROTOCOL_VERSION
+                ),
+            )));
+        }
+    };
+
+    let deps = PlayerGameDeps::from_state(&state);
+    let existing = state
+        .load_balance_addr
+        .send(FindPlayer { player_id })
+        .await
+        .map_err(actix_web::error::ErrorInternalServerError)?;
+    let resume = query.resume_token.as_ref().map(|_| ResumeRequest {
+        last_seq: query.last_seq,
+    });
+
+    let game_addr = match (existing, query.resume_token.clone()) {
+        // 같은 pod 에 런이 남아 있음 → 토큰 확인 후 연결만 교체
+        (Some(addr), Some(resume_token)) => {
+            let valid = addr
+                .send(CheckResumeToken { resume_token })
+                .await
+                .map_err(actix_web::error::ErrorInternalServerError)?;
+            if !valid {
+                warn!(
+                    "Rejected resume with invalid token for player {}",
+                    player_id
+                );
+                return Ok(resume_rejected(&ResumeError::InvalidToken));
+            }
+            info!("Player {} reconnecting to live run", player_id);
+            addr
+        }
+        (Some(_), None) => {
+            warn!(
+                "Rejected duplicate game connection for player {}",
+                player_id
+            );
+            return Ok(HttpResponse::Conflict().body("Player already has an active game session"));
+        }
+        // 다른 pod (또는 재시작 전) 의 런 → Redis 행동 로그로 복원
+        (None, Some(resume_token)) => {
+            let restored = deps
+                .run_store
+                .clone()
+                .restore(
+                    player_id,
+                    &resume_token,
+                    state.data_registry.current(),
+                    query.last_seq,
+                    deps.replay_buffer_size,
+                )
+                .await;
+            match restored {
+                Ok(run) => {
+                    info!("Player {} run restored on this pod", player_id);
+                    PlayerGameActor::resume(player_id, protocol_version, run, deps.clone()).start()
+                }
+                Err(e) => {
+                    warn!("Failed to resume run for player {}: {:?}", player_id, e);
+                    return Ok(resume_rejected(&e));
+                }
+            }
+        }
+        // 새 런은 현재 최신 데이터 버전으로 시작 (런 도중 리로드되어도 유지)
+        (None, None) => {
+            let run_seed = Uuid::new_v4().as_u64_pair().0;
+            info!(
+                "Game connection for player {} (run_seed={})",
+                player_id, run_seed
+            );
+            PlayerGameActor::new(
+                player_id,
+                protocol_version,
+                state.data_registry.current(),
+                run_seed,
+                deps.clone(),
+            )
+            .start()
+        }
+    };
+
+    let connection = GameConnection::new(
+        player_id,
+        game_addr,
+        resume,
+        deps.heartbeat_interval,
+        deps.heartbeat_timeout,
+        auth.map(|auth| auth.remaining()),
+    );
+    start_ws(connection, &req, stream, via_protocol)
+}
+
 #[get("/events/stream")]
 async fn events_stream_route(
     req: HttpRequest,
     stream: web::Payload,
-    _state: web::Data<AppState>,
+    state: web::Data<AppState>,
 ) -> Result<HttpResponse, Error> {
+    let (auth, via_protocol) = match state.token_verifier.authenticate(&req).await {
+        Ok(result) => result,
+        Err(e) => {
+            warn!("Rejected event stream connection: {:?}", e);
+            return Ok(HttpResponse::Unauthorized().json(ServerMessage::Error {
+                code: ErrorCode::from(&e),
+                message: "Invalid or missing token".to_string(),
+            }));
+        }
+    };
+
     // 쿼리 파라미터에서 session_id 추출
     let query = req.query_string();
     let session_id = StreamSessionId::from_query(query).map(|s| s.session_id);
@@ -61,8 +307,8 @@ async fn events_stream_route(
     }
 
     // EventStreamSession 시작
-    let session = EventStreamSession::new(session_id);
-    ws::start(session, &req, stream)
+    let session = EventStreamSession::new(session_id, auth.map(|auth| auth.remaining()));
+    start_ws(session, &req, stream, via_protocol)
 }
 
 #[actix_web::main]
@@ -107,6 +353,41 @@ async fn main() -> std::io::Result<()> {
     metrics::register_custom_metrics(&metrics_registry).expect("Failed to register custom metrics");
     info!("Metrics initialized and registered");
 
+    // 게임 데이터(RON) + 밸런스 설정 로드 및 참조 검증 - 실패 시 모든 문제를 출력하고 기동 중단
+    let data_registry = match DataRegistry::load(
+        &settings.server.game_data_dir,
+        settings.server.balance_config_path.as_ref().map(Into::into),
+    ) {
+        Ok(registry) => Arc::new(registry),
+        Err(e) => {
+            for issue in &e.issues {
+                error!("Game data error: {}", issue);
+            }
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!(
+                    "Failed to load game data from {}: {} issue(s)",
+                    settings.server.game_data_dir,
+                    e.issues.len()
+                ),
+            ));
+        }
+    };
+    info!(
+        "Game data loaded from {} (version={})",
+        settings.server.game_data_dir,
+        data_registry.current_id()
+    );
+
+    // 데이터 파일 변경 감시 (핫 리로드)
+    if let Some(secs) = settings.server.data_watch_interval_secs {
+        spawn_data_watcher(
+            data_registry.clone(),
+            Duration::from_secs(secs.max(1)),
+            shutdown_token.clone(),
+        );
+    }
+
     // 9. Circuit Breaker 생성 (Matchmaker와 Redis Pub/Sub에서 공유)
     let redis_circuit = Arc::new(game_server::shared::circuit_breaker::CircuitBreaker::new(
         5, 60,
@@ -125,19 +406,24 @@ async fn main() -> std::io::Result<()> {
         metrics: metrics.clone(),
         shutdown_token: shutdown_token.clone(),
         redis_circuit: redis_circuit.clone(),
+        data_registry: data_registry.clone(),
     };
 
-    // 12. Matchmaker들 시작 (Normal, Ranked)
-    let game_modes = vec![GameMode::Normal, GameMode::Ranked];
+    // 12. Matchmaker들 시작 (Normal, Ranked, Party)
+    let game_modes = vec![GameMode::Normal, GameMode::Ranked, GameMode::Party];
     let matchmakers =
         spawn_matchmakers(&matchmaker_deps, game_modes).expect("Failed to spawn matchmakers");
-    info!("Matchmakers started: Normal, Ranked");
+    info!("Matchmakers started: Normal, Ranked, Party");
 
     // 13. MatchCoordinator 시작
     let match_coordinator_addr = MatchCoordinator::new(
         matchmakers.clone(),
         load_balance_addr.clone(),
         redis_conn_manager.clone(),
+        settings.ghost.clone(),
+        settings.match_coordinator.clone(),
+        redis_circuit.clone(),
+        settings.matchmaking.redis_operation_timeout_seconds,
     )
     .start();
     info!("MatchCoordinator started");
@@ -150,29 +436,6 @@ async fn main() -> std::io::Result<()> {
         default_pod_id
     });
 
-    // TODO: GameData 로드 추가 필요
-    // ============================================================
-    // 1. RON 파일에서 게임 데이터 로드
-    //    use game_core::game::data::{GameData, shop_data::ShopDatabase, event_pools::EventPoolConfig};
-    //
-    //    let shops_ron = include_str!("../../game_resources/data/events/shops.ron");
-    //    let shops: ShopDatabase = ron::de::from_str(shops_ron)
-    //        .expect("Failed to load shops.ron");
-    //
-    //    let event_pools_ron = include_str!("../../game_resources/data/events/event_pools.ron");
-    //    let event_pools: EventPoolConfig = ron::de::from_str(event_pools_ron)
-    //        .expect("Failed to load event_pools.ron");
-    //
-    //    let game_data = Arc::new(GameData { shops, event_pools });
-    //    info!("Game data loaded successfully");
-    //
-    // 2. AppState에 game_data: Arc<GameData> 필드 추가
-    //
-    // 3. PlayerGameActor 생성 시 Arc 클론해서 전달
-    //    let core = GameCore::new(app_state.game_data.clone());
-    // ============================================================
-
-
     spawn_redis_subscribers(
         redis_client.clone(),
         pod_id.clone(),
@@ -183,11 +446,26 @@ async fn main() -> std::io::Result<()> {
     .await;
     info!("Redis Pub/Sub subscribers started for pod: {}", pod_id);
 
-    // 14. Rate Limiter 초기화 (10 requests/second per IP)
-    let rate_limiter = Arc::new(game_server::RateLimiter::new(10));
-    info!("Rate limiter initialized: 10 req/sec per IP");
+    // 14. Rate Limiter 초기화 (IP 별 연결, 플레이어별 메시지)
+    let rate_limiter = Arc::new(game_server::RateLimiter::new(
+        settings.rate_limit.clone(),
+        Some(redis_conn_manager.clone()),
+        settings.matchmaking.redis_operation_timeout_seconds,
+    ));
+    rate_limiter.spawn_cleanup();
+    info!(
+        "Rate limiter initialized: enabled={}, use_redis={}",
+        settings.rate_limit.enabled, settings.rate_limit.use_redis
+    );
+
+    // 15. 런 요약 제출기 (auth_server_url 이 비어 있으면 비활성화)
+    let run_reporter = Arc::new(RunReporter::new(&settings.run_report));
+    info!(
+        "Run reporter initialized: enabled={}",
+        run_reporter.is_enabled()
+    );
 
-    // 15. AppState 구성
+    // 16. AppState 구성
     let app_state = AppState {
         settings: settings.clone(),
         matchmakers,
@@ -197,12 +475,20 @@ async fn main() -> std::io::Result<()> {
         redis: redis_conn_manager.clone(),
         logger_manager,
         current_run_id,
+        pod_id,
         metrics,
         metrics_registry: metrics_registry.clone(),
         rate_limiter,
+        token_verifier: Arc::new(TokenVerifier::new(&settings.jwt).with_revocation(
+            &settings.jwt,
+            redis_conn_manager.clone(),
+            settings.matchmaking.redis_operation_timeout_seconds,
+        )),
+        data_registry,
+        run_reporter,
     };
 
-    // 16. HTTP 서버 시작
+    // 17. HTTP 서버 시작
     let bind_address = format!("{}:{}", settings.server.bind_address, settings.server.port);
     info!("Starting HTTP server on {}", bind_address);
 
@@ -243,8 +529,11 @@ async fn main() -> std::io::Result<()> {
         App::new()
             .app_data(web::Data::new(app_state.clone()))
             .service(matchmaking_ws_route)
+            .service(player_game_ws_route)
             .service(events_stream_route)
             .route("/metrics", web::get().to(metrics_route))
+            .route("/admin/data/version", web::get().to(data_version_route))
+            .route("/admin/data/reload", web::post().to(reload_data_route))
             .route("/health", web::get().to(health_route))
             .route("/ready", web::get().to(ready_route))
     })
@@ -253,7 +542,7 @@ async fn main() -> std::io::Result<()> {
 
     info!("Match Server is running on {}", bind_address);
 
-    // 17. 종료 신호 대기
+    // 18. 종료 신호 대기
     let server_handle = server.handle();
     tokio::select! {
         res = server => {
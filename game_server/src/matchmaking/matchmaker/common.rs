@@ -1,13 +1,14 @@
 use std::sync::{atomic::AtomicBool, Arc};
 
 use actix::Addr;
+use game_core::game::data::registry::DataRegistry;
 use redis::aio::ConnectionManager;
 use tokio_util::sync::CancellationToken;
 
 use crate::{
     env::{MatchModeSettings, MatchmakingSettings},
     game::load_balance_actor::LoadBalanceActor,
-    matchmaking::subscript::SubScriptionManager,
+    matchmaking::{matchmaker::MatchmakerDeps, subscript::SubScriptionManager},
     shared::{circuit_breaker::CircuitBreaker, metrics::MetricsCtx},
     GameMode,
 };
@@ -22,29 +23,22 @@ pub struct MatchmakerInner {
     pub shutdown_token: CancellationToken,
     pub is_matching: Arc<AtomicBool>,
     pub redis_circuit: Arc<CircuitBreaker>,
+    pub data_registry: Arc<DataRegistry>,
 }
 
 impl MatchmakerInner {
-    pub fn new(
-        redis: ConnectionManager,
-        settings: MatchmakingSettings,
-        mode_settings: MatchModeSettings,
-        sub_manager_addr: Addr<SubScriptionManager>,
-        load_balance_addr: Addr<LoadBalanceActor>,
-        metrics: Arc<MetricsCtx>,
-        shutdown_token: CancellationToken,
-        redis_circuit: Arc<CircuitBreaker>,
-    ) -> Self {
+    pub fn new(deps: MatchmakerDeps, mode_settings: MatchModeSettings) -> Self {
         Self {
-            redis,
-            settings,
+            redis: deps.redis,
+            settings: deps.settings,
             mode_settings,
-            sub_manager_addr,
-            load_balance_addr,
-            metrics,
-            shutdown_token,
+            sub_manager_addr: deps.subscription_addr,
+            load_balance_addr: deps.load_balance_addr,
+            metrics: deps.metrics,
+            shutdown_token: deps.shutdown_token,
             is_matching: Arc::new(AtomicBool::new(false)),
-            redis_circuit,
+            redis_circuit: deps.redis_circuit,
+            data_registry: deps.data_registry,
         }
     }
 
@@ -52,6 +46,7 @@ impl MatchmakerInner {
         match mode {
             GameMode::Ranked => "ranked",
             GameMode::Normal => "normal",
+            GameMode::Party => "party",
             GameMode::None => "none",
         }
     }
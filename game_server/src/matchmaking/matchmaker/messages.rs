@@ -23,3 +23,32 @@ pub struct Dequeue {
 pub struct TryMatch {
     pub match_mode_settings: MatchModeSettings,
 }
+
+// ===== Party =====
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct PartySync {
+    pub player_id: Uuid,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct PartyInvite {
+    pub player_id: Uuid,
+    pub target_player_id: Uuid,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct PartyAccept {
+    pub player_id: Uuid,
+    pub party_id: Uuid,
+    pub metadata: String,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct PartyLeave {
+    pub player_id: Uuid,
+}
@@ -136,6 +136,7 @@ impl Handler<TryMatch> for NormalMatchmaker {
             let candidates = match collect_candidates_with_retry(
                 queue_suffix,
                 required_players as usize * 2,
+                &settings,
                 &deps,
                 &shutdown_token,
             )
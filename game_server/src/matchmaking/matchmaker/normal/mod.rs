@@ -3,19 +3,14 @@ use std::{
     time::Duration,
 };
 
-use actix::{Actor, Addr, AsyncContext};
-use redis::aio::ConnectionManager;
-use tokio_util::sync::CancellationToken;
+use actix::{Actor, AsyncContext};
 use tracing::info;
 
 pub mod handlers;
 
 use crate::{
-    env::{MatchModeSettings, MatchmakingSettings},
-    game::load_balance_actor::LoadBalanceActor,
-    matchmaking::matchmaker::{common::MatchmakerInner, messages::TryMatch},
-    matchmaking::subscript::SubScriptionManager,
-    shared::{circuit_breaker::CircuitBreaker, metrics::MetricsCtx},
+    env::MatchModeSettings,
+    matchmaking::matchmaker::{common::MatchmakerInner, messages::TryMatch, MatchmakerDeps},
 };
 
 pub struct NormalMatchmaker {
@@ -37,27 +32,9 @@ impl DerefMut for NormalMatchmaker {
 }
 
 impl NormalMatchmaker {
-    pub fn new(
-        redis: ConnectionManager,
-        settings: MatchmakingSettings,
-        mode_settings: MatchModeSettings,
-        sub_manager_addr: Addr<SubScriptionManager>,
-        load_balance_addr: Addr<LoadBalanceActor>,
-        metrics: std::sync::Arc<MetricsCtx>,
-        shutdown_token: CancellationToken,
-        redis_circuit: std::sync::Arc<CircuitBreaker>,
-    ) -> Self {
+    pub fn new(deps: MatchmakerDeps, mode_settings: MatchModeSettings) -> Self {
         Self {
-            inner: MatchmakerInner::new(
-                redis,
-                settings,
-                mode_settings,
-                sub_manager_addr,
-                load_balance_addr,
-                metrics,
-                shutdown_token,
-                redis_circuit,
-            ),
+            inner: MatchmakerInner::new(deps, mode_settings),
         }
     }
 }
@@ -8,7 +8,12 @@ use uuid::Uuid;
 
 use crate::{
     matchmaking::matchmaker::{
-        operations::{notify::{self, MessageRoutingDeps}, try_match::PlayerCandidate, with_redis_timeout},
+        operations::{
+            notify::{self, MessageRoutingDeps},
+            rating,
+            try_match::PlayerCandidate,
+            with_redis_timeout,
+        },
         MatchmakerDeps,
     },
     shared::protocol::{ErrorCode, ServerMessage},
@@ -19,12 +24,17 @@ use crate::{
 async fn invoke_dequeue_script(
     redis: &mut ConnectionManager,
     queue_key: String,
+    joined_key: Option<String>,
     player_id: Uuid,
     timeout_secs: u64,
 ) -> Result<(i64, i64, String), String> {
     with_redis_timeout("dequeue_player_script", timeout_secs, async {
-        Script::new(scripts::dequeue_player_script())
-            .key(queue_key)
+        let script = Script::new(scripts::dequeue_player_script());
+        let mut invocation = script.key(queue_key);
+        if let Some(joined_key) = joined_key {
+            invocation.key(joined_key);
+        }
+        invocation
             .arg(player_id.to_string())
             .invoke_async(redis)
             .await
@@ -67,6 +77,8 @@ pub async fn dequeue(
     let suffix = queue_suffix;
     let hash_tag = format!("{{{}}}", suffix);
     let queue_key = format!("queue:{}", hash_tag);
+    let joined_key = rating::mmr_settings_for(&settings, game_mode)
+        .map(|_| rating::joined_key(suffix));
     let timeout_secs = settings.redis_operation_timeout_seconds;
 
     let backoff = RETRY_CONFIG
@@ -80,8 +92,14 @@ pub async fn dequeue(
     let dequeue_result = loop {
         let mut redis_clone = redis.clone();
 
-        match invoke_dequeue_script(&mut redis_clone, queue_key.clone(), player_id, timeout_secs)
-            .await
+        match invoke_dequeue_script(
+            &mut redis_clone,
+            queue_key.clone(),
+            joined_key.clone(),
+            player_id,
+            timeout_secs,
+        )
+        .await
         {
             Ok(res) => break Ok(res),
             Err(err) => {
@@ -9,7 +9,12 @@ use uuid::Uuid;
 
 use crate::{
     matchmaking::matchmaker::{
-        operations::{notify::{self, MessageRoutingDeps}, try_match::PlayerCandidate, with_redis_timeout},
+        operations::{
+            notify::{self, MessageRoutingDeps},
+            rating::{self, PlayerRating},
+            try_match::PlayerCandidate,
+            with_redis_timeout,
+        },
         scripts, MatchmakerDeps,
     },
     shared::protocol::{ErrorCode, ServerMessage},
@@ -19,7 +24,7 @@ use crate::{
 
 /// metadata JSON에 pod_id를 자동으로 추가합니다.
 /// Client는 pod_id를 알 수 없으므로 Match Server가 자동으로 주입합니다.
-fn add_pod_id_to_metadata(metadata: &str, pod_id: &str) -> String {
+pub(crate) fn add_pod_id_to_metadata(metadata: &str, pod_id: &str) -> String {
     match serde_json::from_str::<serde_json::Value>(metadata) {
         Ok(mut value) => {
             if let Some(obj) = value.as_object_mut() {
@@ -38,24 +43,64 @@ fn add_pod_id_to_metadata(metadata: &str, pod_id: &str) -> String {
     }
 }
 
+/// 큐 score 기준
+#[derive(Clone)]
+enum QueueScore {
+    /// 일반 큐: score = enqueue 시각
+    Timestamp,
+    /// MMR 큐: score = 레이팅, 대기 시작 시각은 joined_key 에 따로 기록
+    Rating { joined_key: String, mmr: i64 },
+}
+
+impl QueueScore {
+    fn for_mode(mmr_matching: bool, queue_suffix: &str, rating: Option<PlayerRating>) -> Self {
+        if !mmr_matching {
+            return QueueScore::Timestamp;
+        }
+        QueueScore::Rating {
+            joined_key: rating::joined_key(queue_suffix),
+            mmr: rating.unwrap_or_default().queue_score(),
+        }
+    }
+}
+
 async fn invoke_enqueue_script(
     redis: &mut ConnectionManager,
     queue_key: String,
+    score: &QueueScore,
     player_id: Uuid,
     timestamp: String,
     metadata: String,
     timeout_secs: u64,
 ) -> Result<Vec<i64>, String> {
-    with_redis_timeout("enqueue_player_script", timeout_secs, async {
-        Script::new(scripts::enqueue_player_script())
-            .key(queue_key)
-            .arg(player_id.to_string())
-            .arg(timestamp)
-            .arg(metadata)
-            .invoke_async(redis)
+    match score {
+        QueueScore::Timestamp => {
+            with_redis_timeout("enqueue_player_script", timeout_secs, async {
+                Script::new(scripts::enqueue_player_script())
+                    .key(queue_key)
+                    .arg(player_id.to_string())
+                    .arg(timestamp)
+                    .arg(metadata)
+                    .invoke_async(redis)
+                    .await
+            })
             .await
-    })
-    .await
+        }
+        QueueScore::Rating { joined_key, mmr } => {
+            with_redis_timeout("enqueue_ranked_player_script", timeout_secs, async {
+                Script::new(scripts::enqueue_ranked_player_script())
+                    .key(queue_key)
+                    .key(joined_key)
+                    .arg(player_id.to_string())
+                    .arg(*mmr)
+                    .arg(timestamp)
+                    .arg(metadata)
+                    .invoke_async(redis)
+                    .await
+            })
+            .await
+        }
+    }
 }
 
 pub async fn enqueue(
@@ -93,15 +138,27 @@ pub async fn enqueue(
 
     // pod_id를 metadata에 자동 추가
     let pod_id = PlayerCandidate::current_pod_id();
-    let metadata_with_pod = add_pod_id_to_metadata(&metadata, pod_id);
+    let mut metadata_with_pod = add_pod_id_to_metadata(&metadata, pod_id);
 
     let suffix = queue_suffix;
     let hash_tag = format!("{{{}}}", suffix);
     let queue_key = format!("queue:{}", hash_tag);
 
-    let timestamp = Utc::now().timestamp().to_string();
+    let now = Utc::now().timestamp();
+    let timestamp = now.to_string();
     let timeout_secs = settings.redis_operation_timeout_seconds;
 
+    // MMR 매칭 모드: 레이팅은 서버에서 조회해 metadata 에 넣는다 (클라이언트 값은 덮어씀)
+    let mmr_matching = rating::mmr_settings_for(&settings, game_mode).is_some();
+    let player_rating = if mmr_matching {
+        let player_rating = rating::fetch_rating(&mut redis, player_id, timeout_secs).await;
+        metadata_with_pod = rating::add_rating_to_metadata(&metadata_with_pod, player_rating, now);
+        Some(player_rating)
+    } else {
+        None
+    };
+    let queue_score = QueueScore::for_mode(mmr_matching, suffix, player_rating);
+
     let backoff = RETRY_CONFIG
         .read()
         .await
@@ -116,6 +173,7 @@ pub async fn enqueue(
         match invoke_enqueue_script(
             &mut redis_clone,
             queue_key.clone(),
+            &queue_score,
             player_id,
             timestamp.clone(),
             metadata_with_pod.clone(),
@@ -233,6 +291,7 @@ pub async fn re_enqueue_candidates(
     let hash_tag = format!("{{{}}}", suffix);
     let queue_key = format!("queue:{}", hash_tag);
     let pod_id = PlayerCandidate::current_pod_id();
+    let mmr_matching = rating::mmr_settings_for(&deps.settings, game_mode).is_some();
 
     for candidate in candidates {
         let player_id = match Uuid::parse_str(&candidate.player_id) {
@@ -251,7 +310,19 @@ pub async fn re_enqueue_candidates(
             }
         };
 
-        let timestamp = Utc::now().timestamp().to_string();
+        // MMR 큐는 최초 enqueue 시각을 유지해야 윈도우가 다시 좁아지지 않는다
+        let timestamp = candidate
+            .metadata
+            .get("enqueued_at")
+            .and_then(|v| v.as_i64())
+            .filter(|_| mmr_matching)
+            .unwrap_or_else(|| Utc::now().timestamp())
+            .to_string();
+        let queue_score = QueueScore::for_mode(
+            mmr_matching,
+            suffix,
+            PlayerRating::from_metadata(&candidate.metadata),
+        );
         let mut redis = deps.redis.clone();
         let timeout_secs = deps.settings.redis_operation_timeout_seconds;
 
@@ -259,6 +330,7 @@ pub async fn re_enqueue_candidates(
         match invoke_enqueue_script(
             &mut redis,
             queue_key.clone(),
+            &queue_score,
             player_id,
             timestamp.clone(),
             metadata_json.clone(),
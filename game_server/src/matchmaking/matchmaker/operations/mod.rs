@@ -1,6 +1,8 @@
 pub mod dequeue;
 pub mod enqueue;
 pub mod notify;
+pub mod party;
+pub mod rating;
 pub mod try_match;
 pub mod try_match_collect;
 pub mod try_match_process;
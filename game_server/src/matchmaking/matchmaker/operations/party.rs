@@ -0,0 +1,665 @@
+//! 파티 (Party 모드)
+//!
+//! Redis 구조:
+//! - `party:{party_id}` (Hash): leader, members (가입 순서 JSON 배열), state (idle | queued)
+//! - `party_metadata:{party_id}` (Hash): player_id → 매칭 metadata (pod_id 포함, 라우팅에도 사용)
+//! - `player_party:{player_id}` (String): 소속 party_id
+//! - `party_invite:{party_id}:{player_id}` (String, TTL): 초대한 리더 ID
+//! - `queue:{party}` (Sorted Set): member = party_id, score = enqueue 시각
+//!
+//! 상태 변경은 모두 Lua 스크립트에서 원자적으로 처리한다.
+
+use std::collections::HashMap;
+
+use chrono::Utc;
+use redis::Script;
+use serde_json::json;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::{
+    env::PartySettings,
+    matchmaking::matchmaker::{
+        operations::{
+            enqueue::add_pod_id_to_metadata,
+            notify::{self, MessageRoutingDeps},
+            try_match::PlayerCandidate,
+            with_redis_timeout,
+        },
+        scripts, MatchmakerDeps,
+    },
+    shared::{
+        protocol::{ErrorCode, PartyInfo, ServerMessage},
+        redis_events,
+    },
+    GameMode,
+};
+
+/// 파티 스크립트 성공 응답 ({"ok", party_id, leader, members_json, state, ...})
+struct PartyReply {
+    party_id: Uuid,
+    /// 마지막 멤버가 나가 파티가 삭제되면 None
+    party: Option<PartyInfo>,
+    /// 스크립트별 추가 반환값
+    extra: Vec<String>,
+}
+
+/// 파티 스크립트 실패 응답
+struct PartyRejected {
+    code: ErrorCode,
+    message: &'static str,
+}
+
+fn rejected(status: &str) -> PartyRejected {
+    let (code, message) = match status {
+        "not_in_party" => (ErrorCode::NotInParty, "Not in a party"),
+        "already_in_party" => (ErrorCode::AlreadyInParty, "Player is already in a party"),
+        "not_leader" => (
+            ErrorCode::NotPartyLeader,
+            "Only the party leader can do this",
+        ),
+        "party_full" => (ErrorCode::PartyFull, "Party is full"),
+        "party_in_queue" => (ErrorCode::PartyInQueue, "Party is in queue"),
+        "invite_not_found" => (
+            ErrorCode::PartyInviteNotFound,
+            "Party invite not found or expired",
+        ),
+        "invalid_target" => (ErrorCode::InvalidPartyTarget, "Invalid invite target"),
+        "already_in_queue" => (ErrorCode::AlreadyInQueue, "Already in queue"),
+        "not_in_queue" => (ErrorCode::NotInQueue, "Not found in queue"),
+        "invalid_metadata" => (
+            ErrorCode::InvalidMetadata,
+            "Party member metadata is missing",
+        ),
+        _ => (ErrorCode::InternalError, "Party operation failed"),
+    };
+    PartyRejected { code, message }
+}
+
+fn parse_reply(raw: Vec<String>) -> Result<PartyReply, PartyRejected> {
+    let status = raw.first().map(String::as_str).unwrap_or_default();
+    if status != "ok" {
+        return Err(rejected(status));
+    }
+    if raw.len() < 5 {
+        error!("Unexpected party script reply: {:?}", raw);
+        return Err(rejected("internal"));
+    }
+
+    let Ok(party_id) = Uuid::parse_str(&raw[1]) else {
+        error!("Invalid party_id in party script reply: {}", raw[1]);
+        return Err(rejected("internal"));
+    };
+    let members: Vec<Uuid> = serde_json::from_str(&raw[3]).unwrap_or_default();
+    let party = Uuid::parse_str(&raw[2])
+        .ok()
+        .filter(|_| !members.is_empty())
+        .map(|leader_id| PartyInfo {
+            party_id,
+            leader_id,
+            members,
+            in_queue: raw[4] == "queued",
+        });
+
+    Ok(PartyReply {
+        party_id,
+        party,
+        extra: raw[5..].to_vec(),
+    })
+}
+
+/// Party 모드 설정 (없으면 기본값)
+fn party_settings(deps: &MatchmakerDeps) -> PartySettings {
+    deps.settings
+        .game_modes
+        .iter()
+        .find(|mode| mode.game_mode == GameMode::Party)
+        .map(|mode| mode.party.clone())
+        .unwrap_or_default()
+}
+
+fn party_queue_key(queue_suffix: &str) -> String {
+    format!("queue:{{{}}}", queue_suffix)
+}
+
+/// 파티 멤버별 metadata 조회 (player_id → metadata JSON)
+async fn fetch_party_metadata(party_id: Uuid, deps: &MatchmakerDeps) -> HashMap<String, String> {
+    let timeout_secs = deps.settings.redis_operation_timeout_seconds;
+    let mut redis = deps.redis.clone();
+
+    with_redis_timeout("party_metadata", timeout_secs, async {
+        redis::cmd("HGETALL")
+            .arg(format!("party_metadata:{}", party_id))
+            .query_async(&mut redis)
+            .await
+    })
+    .await
+    .unwrap_or_else(|e| {
+        warn!("Failed to read party {} metadata: {}", party_id, e);
+        HashMap::new()
+    })
+}
+
+/// 파티 멤버에게 메시지 전달
+///
+/// party_metadata 에 pod_id 가 있으면 해당 Pod 로 라우팅하고, 없으면 현재 Pod 로 보낸다.
+async fn notify_members(
+    party_id: Uuid,
+    members: &[Uuid],
+    message: &ServerMessage,
+    deps: &MatchmakerDeps,
+) {
+    let routing_deps = MessageRoutingDeps::from(deps);
+    let metadata = fetch_party_metadata(party_id, deps).await;
+
+    for member_id in members {
+        let candidate = metadata
+            .get(&member_id.to_string())
+            .and_then(|raw| serde_json::from_str::<serde_json::Value>(raw).ok())
+            .and_then(|metadata| {
+                let pod_id = metadata.get("pod_id")?.as_str()?.to_string();
+                Some(PlayerCandidate {
+                    player_id: member_id.to_string(),
+                    score: 0,
+                    pod_id,
+                    metadata,
+                })
+            });
+
+        match candidate {
+            Some(candidate) => {
+                notify::send_message_to_player(&candidate, message.clone(), &routing_deps).await
+            }
+            None => {
+                notify::send_message_to_player_by_id(*member_id, message.clone(), &routing_deps)
+                    .await
+            }
+        }
+    }
+}
+
+/// 멤버별 테스트 이벤트 발행 (player.enqueued / player.dequeued + global.queue_size_changed)
+async fn publish_queue_events<'a>(
+    member_metadata: impl IntoIterator<Item = (Uuid, &'a str)>,
+    reason: &str,
+    queue_size: &str,
+    deps: &MatchmakerDeps,
+) {
+    let mut redis = deps.redis.clone();
+    let pod_id = PlayerCandidate::current_pod_id();
+    let game_mode = format!("{:?}", GameMode::Party);
+
+    for (member_id, metadata) in member_metadata {
+        redis_events::try_publish_test_event(
+            &mut redis,
+            metadata,
+            &format!("player.{}", reason),
+            pod_id,
+            vec![
+                ("player_id", member_id.to_string()),
+                ("queue_size", queue_size.to_string()),
+                ("game_mode", game_mode.clone()),
+            ],
+        )
+        .await;
+
+        redis_events::try_publish_test_event(
+            &mut redis,
+            metadata,
+            "global.queue_size_changed",
+            pod_id,
+            vec![
+                ("size", queue_size.to_string()),
+                ("game_mode", game_mode.clone()),
+                ("reason", reason.to_string()),
+            ],
+        )
+        .await;
+    }
+}
+
+/// party_metadata 에 저장된 멤버만 골라 (member_id, metadata) 쌍으로 반환
+fn members_with_metadata<'a>(
+    members: &'a [Uuid],
+    metadata: &'a HashMap<String, String>,
+) -> impl Iterator<Item = (Uuid, &'a str)> {
+    members.iter().filter_map(move |member_id| {
+        metadata
+            .get(&member_id.to_string())
+            .map(|raw| (*member_id, raw.as_str()))
+    })
+}
+
+async fn notify_error(player_id: Uuid, rejected: PartyRejected, deps: &MatchmakerDeps) {
+    warn!(
+        "Party request from player {} rejected: {:?}",
+        player_id, rejected.code
+    );
+    notify::send_message_to_player_by_id(
+        player_id,
+        ServerMessage::Error {
+            code: rejected.code,
+            message: rejected.message.to_string(),
+        },
+        &MessageRoutingDeps::from(deps),
+    )
+    .await;
+}
+
+async fn invoke_party_script(
+    operation: &str,
+    script: &'static str,
+    keys: &[String],
+    args: &[String],
+    deps: &MatchmakerDeps,
+) -> Result<PartyReply, PartyRejected> {
+    let mut redis = deps.redis.clone();
+    let timeout_secs = deps.settings.redis_operation_timeout_seconds;
+
+    let raw: Result<Vec<String>, String> = with_redis_timeout(operation, timeout_secs, async {
+        let script = Script::new(script);
+        let mut invocation = script.prepare_invoke();
+        for key in keys {
+            invocation.key(key);
+        }
+        for arg in args {
+            invocation.arg(arg);
+        }
+        invocation.invoke_async(&mut redis).await
+    })
+    .await;
+
+    match raw {
+        Ok(raw) => parse_reply(raw),
+        Err(e) => {
+            error!("{} failed: {}", operation, e);
+            Err(rejected("internal"))
+        }
+    }
+}
+
+// ============================================================
+// 파티 구성
+// ============================================================
+
+/// 현재 파티 상태를 요청자에게 전달
+pub async fn sync(player_id: Uuid, deps: &MatchmakerDeps) {
+    let mut redis = deps.redis.clone();
+    let timeout_secs = deps.settings.redis_operation_timeout_seconds;
+
+    let party_id: Result<Option<String>, String> =
+        with_redis_timeout("party_sync", timeout_secs, async {
+            redis::cmd("GET")
+                .arg(format!("player_party:{}", player_id))
+                .query_async(&mut redis)
+                .await
+        })
+        .await;
+
+    let party = match party_id {
+        Ok(Some(party_id)) => {
+            let fields: Result<HashMap<String, String>, String> =
+                with_redis_timeout("party_sync", timeout_secs, async {
+                    redis::cmd("HGETALL")
+                        .arg(format!("party:{}", party_id))
+                        .query_async(&mut redis)
+                        .await
+                })
+                .await;
+
+            match fields {
+                Ok(fields) => Uuid::parse_str(&party_id).ok().and_then(|party_id| {
+                    Some(PartyInfo {
+                        party_id,
+                        leader_id: Uuid::parse_str(fields.get("leader")?).ok()?,
+                        members: serde_json::from_str(fields.get("members")?).ok()?,
+                        in_queue: fields.get("state").map(String::as_str) == Some("queued"),
+                    })
+                }),
+                Err(e) => {
+                    error!("Failed to read party {}: {}", party_id, e);
+                    notify_error(player_id, rejected("internal"), deps).await;
+                    return;
+                }
+            }
+        }
+        Ok(None) => None,
+        Err(e) => {
+            error!("Failed to read party of player {}: {}", player_id, e);
+            notify_error(player_id, rejected("internal"), deps).await;
+            return;
+        }
+    };
+
+    notify::send_message_to_player_by_id(
+        player_id,
+        ServerMessage::PartyState { party },
+        &MessageRoutingDeps::from(deps),
+    )
+    .await;
+}
+
+/// 파티 초대 (파티가 없으면 요청자를 리더로 새 파티 생성)
+///
+/// 초대 알림은 대상이 같은 Pod 에 연결되어 있을 때만 즉시 전달된다.
+/// 다른 경로로 party_id 를 전달받은 경우에도 TTL 안에는 수락할 수 있다.
+pub async fn invite(player_id: Uuid, target_player_id: Uuid, deps: &MatchmakerDeps) {
+    let settings = party_settings(deps);
+    let routing_metadata = json!({ "pod_id": PlayerCandidate::current_pod_id() }).to_string();
+
+    let reply = invoke_party_script(
+        "party_invite_script",
+        scripts::party_invite_script(),
+        &[],
+        &[
+            player_id.to_string(),
+            target_player_id.to_string(),
+            Uuid::new_v4().to_string(),
+            settings.max_size.to_string(),
+            settings.invite_ttl_seconds.to_string(),
+            routing_metadata,
+        ],
+        deps,
+    )
+    .await;
+
+    let reply = match reply {
+        Ok(reply) => reply,
+        Err(rejected) => {
+            notify_error(player_id, rejected, deps).await;
+            return;
+        }
+    };
+
+    info!(
+        "Player {} invited {} to party {}",
+        player_id, target_player_id, reply.party_id
+    );
+
+    notify::send_message_to_player_by_id(
+        target_player_id,
+        ServerMessage::PartyInvited {
+            party_id: reply.party_id,
+            leader_id: player_id,
+        },
+        &MessageRoutingDeps::from(deps),
+    )
+    .await;
+
+    if let Some(party) = reply.party {
+        let members = party.members.clone();
+        let message = ServerMessage::PartyState { party: Some(party) };
+        notify_members(reply.party_id, &members, &message, deps).await;
+    }
+}
+
+/// 초대 수락
+pub async fn accept(player_id: Uuid, party_id: Uuid, metadata: String, deps: &MatchmakerDeps) {
+    let settings = party_settings(deps);
+    let metadata = add_pod_id_to_metadata(&metadata, PlayerCandidate::current_pod_id());
+
+    let reply = invoke_party_script(
+        "party_accept_script",
+        scripts::party_accept_script(),
+        &[],
+        &[
+            player_id.to_string(),
+            party_id.to_string(),
+            metadata,
+            settings.max_size.to_string(),
+        ],
+        deps,
+    )
+    .await;
+
+    match reply {
+        Ok(PartyReply {
+            party: Some(party), ..
+        }) => {
+            info!("Player {} joined party {}", player_id, party_id);
+            let members = party.members.clone();
+            let message = ServerMessage::PartyState { party: Some(party) };
+            notify_members(party_id, &members, &message, deps).await;
+        }
+        Ok(_) => notify_error(player_id, rejected("internal"), deps).await,
+        Err(rejected) => notify_error(player_id, rejected, deps).await,
+    }
+}
+
+/// 파티 탈퇴 (연결 종료 시에도 호출)
+///
+/// 파티가 대기열에 있었다면 파티 전체가 dequeue 되고 모든 멤버에게 DeQueued 가 전달된다.
+pub async fn leave(queue_suffix: &str, player_id: Uuid, deps: &MatchmakerDeps) {
+    let reply = invoke_party_script(
+        "party_leave_script",
+        scripts::party_leave_script(),
+        &[party_queue_key(queue_suffix)],
+        &[player_id.to_string()],
+        deps,
+    )
+    .await;
+
+    let reply = match reply {
+        Ok(reply) => reply,
+        Err(rejected) => {
+            notify_error(player_id, rejected, deps).await;
+            return;
+        }
+    };
+
+    let remaining = reply
+        .party
+        .as_ref()
+        .map(|party| party.members.clone())
+        .unwrap_or_default();
+    let was_queued = reply.extra.first().map(String::as_str) == Some("1");
+    let queue_size = reply.extra.get(1).cloned().unwrap_or_default();
+    let leaver_metadata = reply.extra.get(2).cloned().unwrap_or_default();
+
+    info!(
+        "Player {} left party {} ({} member(s) remaining, was_queued: {})",
+        player_id,
+        reply.party_id,
+        remaining.len(),
+        was_queued
+    );
+
+    let routing_deps = MessageRoutingDeps::from(deps);
+    if was_queued {
+        let metadata = fetch_party_metadata(reply.party_id, deps).await;
+        let leaver = (!leaver_metadata.is_empty()).then_some((player_id, leaver_metadata.as_str()));
+        publish_queue_events(
+            leaver
+                .into_iter()
+                .chain(members_with_metadata(&remaining, &metadata)),
+            "dequeued",
+            &queue_size,
+            deps,
+        )
+        .await;
+
+        notify::send_message_to_player_by_id(player_id, ServerMessage::DeQueued, &routing_deps)
+            .await;
+        notify_members(reply.party_id, &remaining, &ServerMessage::DeQueued, deps).await;
+    }
+
+    notify::send_message_to_player_by_id(
+        player_id,
+        ServerMessage::PartyState { party: None },
+        &routing_deps,
+    )
+    .await;
+    if let Some(party) = reply.party {
+        let message = ServerMessage::PartyState { party: Some(party) };
+        notify_members(reply.party_id, &remaining, &message, deps).await;
+    }
+}
+
+// ============================================================
+// 파티 대기열
+// ============================================================
+
+/// 리더가 파티 전체를 대기열에 등록
+pub async fn enqueue(queue_suffix: &str, player_id: Uuid, metadata: String, deps: &MatchmakerDeps) {
+    let metadata = add_pod_id_to_metadata(&metadata, PlayerCandidate::current_pod_id());
+
+    let reply = invoke_party_script(
+        "enqueue_party_script",
+        scripts::enqueue_party_script(),
+        &[party_queue_key(queue_suffix)],
+        &[
+            player_id.to_string(),
+            Utc::now().timestamp().to_string(),
+            metadata,
+        ],
+        deps,
+    )
+    .await;
+
+    let (party, queue_size) = match reply {
+        Ok(PartyReply {
+            party: Some(party),
+            extra,
+            ..
+        }) => (party, extra.first().cloned().unwrap_or_default()),
+        Ok(_) => {
+            notify_error(player_id, rejected("internal"), deps).await;
+            return;
+        }
+        Err(rejected) => {
+            notify_error(player_id, rejected, deps).await;
+            return;
+        }
+    };
+
+    info!(
+        "Party {} ({} member(s)) enqueued by leader {}",
+        party.party_id,
+        party.members.len(),
+        player_id
+    );
+
+    metrics::PLAYERS_ENQUEUED_NEW_TOTAL.inc_by(party.members.len() as u64);
+    metrics::ENQUEUED_TOTAL_BY_MODE
+        .with_label_values(&[&format!("{:?}", GameMode::Party)])
+        .inc_by(party.members.len() as u64);
+
+    let metadata = fetch_party_metadata(party.party_id, deps).await;
+    publish_queue_events(
+        members_with_metadata(&party.members, &metadata),
+        "enqueued",
+        &queue_size,
+        deps,
+    )
+    .await;
+
+    let message = ServerMessage::EnQueued {
+        pod_id: PlayerCandidate::current_pod_id().to_string(),
+    };
+    notify_members(party.party_id, &party.members, &message, deps).await;
+}
+
+/// 파티 전체를 대기열에서 제거 (멤버 누구나 요청 가능)
+pub async fn dequeue(queue_suffix: &str, player_id: Uuid, deps: &MatchmakerDeps) {
+    let reply = invoke_party_script(
+        "dequeue_party_script",
+        scripts::dequeue_party_script(),
+        &[party_queue_key(queue_suffix)],
+        &[player_id.to_string()],
+        deps,
+    )
+    .await;
+
+    let (party, queue_size) = match reply {
+        Ok(PartyReply {
+            party: Some(party),
+            extra,
+            ..
+        }) => (party, extra.first().cloned().unwrap_or_default()),
+        Ok(_) => {
+            notify_error(player_id, rejected("internal"), deps).await;
+            return;
+        }
+        Err(rejected) => {
+            notify_error(player_id, rejected, deps).await;
+            return;
+        }
+    };
+
+    info!("Party {} dequeued by member {}", party.party_id, player_id);
+
+    let metadata = fetch_party_metadata(party.party_id, deps).await;
+    publish_queue_events(
+        members_with_metadata(&party.members, &metadata),
+        "dequeued",
+        &queue_size,
+        deps,
+    )
+    .await;
+
+    notify_members(
+        party.party_id,
+        &party.members,
+        &ServerMessage::DeQueued,
+        deps,
+    )
+    .await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reply(parts: &[&str]) -> Vec<String> {
+        parts.iter().map(|part| part.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_reply_ok() {
+        let party_id = Uuid::from_u128(1);
+        let leader_id = Uuid::from_u128(2);
+        let member_id = Uuid::from_u128(3);
+        let members = serde_json::to_string(&[leader_id, member_id]).unwrap();
+
+        let parsed = parse_reply(reply(&[
+            "ok",
+            &party_id.to_string(),
+            &leader_id.to_string(),
+            &members,
+            "queued",
+            "7",
+        ]))
+        .ok()
+        .unwrap();
+
+        assert_eq!(parsed.party_id, party_id);
+        assert_eq!(
+            parsed.party,
+            Some(PartyInfo {
+                party_id,
+                leader_id,
+                members: vec![leader_id, member_id],
+                in_queue: true,
+            })
+        );
+        assert_eq!(parsed.extra, vec!["7".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_reply_disbanded_and_rejected() {
+        // 마지막 멤버가 나가면 leader 가 비고 파티는 None
+        let party_id = Uuid::from_u128(1);
+        let parsed = parse_reply(reply(&["ok", &party_id.to_string(), "", "[]", "idle", "1"]))
+            .ok()
+            .unwrap();
+        assert!(parsed.party.is_none());
+
+        let rejected = parse_reply(reply(&["party_full", &party_id.to_string()]))
+            .err()
+            .unwrap();
+        assert_eq!(rejected.code, ErrorCode::PartyFull);
+
+        let unknown = parse_reply(reply(&["something_else"])).err().unwrap();
+        assert_eq!(unknown.code, ErrorCode::InternalError);
+    }
+}
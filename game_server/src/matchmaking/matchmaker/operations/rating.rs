@@ -0,0 +1,236 @@
+use std::collections::HashMap;
+
+use redis::aio::ConnectionManager;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::{
+    env::{MatchmakingSettings, MmrMatchingSettings},
+    matchmaking::matchmaker::operations::{try_match::PlayerCandidate, with_redis_timeout},
+    GameMode,
+};
+
+/// Glicko 레이팅 (auth_server player_profiles 의 mmr/rd/volatility 와 같은 의미)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PlayerRating {
+    pub mmr: f64,
+    pub rd: f64,
+    pub volatility: f64,
+}
+
+impl Default for PlayerRating {
+    /// 신규 플레이어 기본값 (Glicko-2 권장값)
+    fn default() -> Self {
+        Self {
+            mmr: 1500.0,
+            rd: 350.0,
+            volatility: 0.06,
+        }
+    }
+}
+
+impl PlayerRating {
+    /// 큐 score 로 쓰는 정수 레이팅
+    pub fn queue_score(&self) -> i64 {
+        self.mmr.round() as i64
+    }
+
+    /// 매칭 metadata 의 `rating` 필드에서 읽기
+    pub fn from_metadata(metadata: &serde_json::Value) -> Option<Self> {
+        serde_json::from_value(metadata.get("rating")?.clone()).ok()
+    }
+
+    /// 레이팅 캐시 Hash 필드에서 읽기 (빠진 필드는 기본값)
+    pub fn from_cache_fields(fields: &HashMap<String, f64>) -> Self {
+        let default = Self::default();
+        Self {
+            mmr: fields.get("mmr").copied().unwrap_or(default.mmr),
+            rd: fields.get("rd").copied().unwrap_or(default.rd),
+            volatility: fields
+                .get("volatility")
+                .copied()
+                .unwrap_or(default.volatility),
+        }
+    }
+}
+
+/// 플레이어 레이팅 캐시 키 (Hash: mmr, rd, volatility)
+///
+/// auth_server 가 랭크 매치 결과 반영과 시즌 소프트 리셋 때 `player_profiles` 값으로 갱신한다.
+pub fn rating_key(player_id: Uuid) -> String {
+    format!("rating:{}", player_id)
+}
+
+/// MMR 큐의 대기 시작 시각 Sorted Set 키 (queue 키와 같은 hash tag 사용)
+pub fn joined_key(queue_suffix: &str) -> String {
+    format!("queue_joined:{{{}}}", queue_suffix)
+}
+
+/// MMR 매칭을 사용하는 모드면 해당 설정 반환
+pub fn mmr_settings_for(
+    settings: &MatchmakingSettings,
+    game_mode: GameMode,
+) -> Option<&MmrMatchingSettings> {
+    settings
+        .game_modes
+        .iter()
+        .find(|mode| mode.game_mode == game_mode && mode.use_mmr_matching)
+        .map(|mode| &mode.mmr)
+}
+
+/// metadata JSON 에 레이팅과 최초 enqueue 시각 추가 (TRY_MATCH_RANKED.lua 가 rating.rd 를 읽음)
+pub fn add_rating_to_metadata(metadata: &str, rating: PlayerRating, enqueued_at: i64) -> String {
+    let Ok(mut value) = serde_json::from_str::<serde_json::Value>(metadata) else {
+        return metadata.to_string();
+    };
+    let Some(obj) = value.as_object_mut() else {
+        return metadata.to_string();
+    };
+
+    obj.insert("rating".to_string(), serde_json::json!(rating));
+    obj.insert("enqueued_at".to_string(), serde_json::json!(enqueued_at));
+    serde_json::to_string(&value).unwrap_or_else(|_| metadata.to_string())
+}
+
+/// Redis 레이팅 캐시 조회 (없거나 읽기 실패 시 기본 레이팅)
+pub async fn fetch_rating(
+    redis: &mut ConnectionManager,
+    player_id: Uuid,
+    timeout_secs: u64,
+) -> PlayerRating {
+    let fields: Result<HashMap<String, f64>, String> =
+        with_redis_timeout("fetch_rating", timeout_secs, async {
+            redis::cmd("HGETALL")
+                .arg(rating_key(player_id))
+                .query_async(redis)
+                .await
+        })
+        .await;
+
+    match fields {
+        Ok(fields) if !fields.is_empty() => PlayerRating::from_cache_fields(&fields),
+        Ok(_) => PlayerRating::default(),
+        Err(e) => {
+            warn!(
+                "Failed to fetch rating for player {}: {} (using default)",
+                player_id, e
+            );
+            PlayerRating::default()
+        }
+    }
+}
+
+/// 허용 레이팅 차이 (TRY_MATCH_RANKED.lua 와 같은 계산식)
+pub fn match_window(settings: &MmrMatchingSettings, rd: f64, wait_secs: f64) -> f64 {
+    let widened = settings.base_window + settings.window_growth_per_second * wait_secs.max(0.0);
+    widened.min(settings.max_window) + rd * settings.rd_weight
+}
+
+/// 메트릭용 레이팅 구간 라벨 (예: bracket_size 200 → "1400-1599")
+pub fn rating_bracket(mmr: f64, bracket_size: f64) -> String {
+    let size = bracket_size.max(1.0);
+    let lower = (mmr / size).floor() * size;
+    format!("{}-{}", lower as i64, (lower + size) as i64 - 1)
+}
+
+/// 랭크 매칭 품질 메트릭 기록 (레이팅 차이, 구간별 대기시간)
+pub fn record_match_quality(
+    player1: &PlayerCandidate,
+    player2: &PlayerCandidate,
+    settings: &MmrMatchingSettings,
+    now: i64,
+) {
+    let (Some(rating1), Some(rating2)) = (
+        PlayerRating::from_metadata(&player1.metadata),
+        PlayerRating::from_metadata(&player2.metadata),
+    ) else {
+        return;
+    };
+
+    metrics::RANKED_MATCH_RATING_GAP.observe((rating1.mmr - rating2.mmr).abs());
+
+    for (candidate, rating) in [(player1, rating1), (player2, rating2)] {
+        let Some(enqueued_at) = candidate
+            .metadata
+            .get("enqueued_at")
+            .and_then(|v| v.as_i64())
+        else {
+            continue;
+        };
+        metrics::RANKED_WAIT_SECONDS_BY_BRACKET
+            .with_label_values(&[&rating_bracket(rating.mmr, settings.bracket_size)])
+            .observe((now - enqueued_at).max(0) as f64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_match_window_widens_with_wait_and_rd() {
+        let settings = MmrMatchingSettings::default();
+
+        assert_eq!(match_window(&settings, 0.0, 0.0), 100.0);
+        assert_eq!(match_window(&settings, 0.0, 10.0), 200.0);
+        // 대기시간에 의한 확장은 max_window 에서 멈추고, RD 보정은 그 위에 더해진다
+        assert_eq!(match_window(&settings, 0.0, 1000.0), 400.0);
+        assert_eq!(match_window(&settings, 350.0, 1000.0), 575.0);
+    }
+
+    /// TRY_MATCH_RANKED.lua 의 판정: 레이팅 차이가 양쪽 윈도우 안이어야 한다
+    fn pairs(settings: &MmrMatchingSettings, a: PlayerRating, b: PlayerRating, wait: f64) -> bool {
+        let gap = (a.queue_score() - b.queue_score()).abs() as f64;
+        gap <= match_window(settings, a.rd, wait) && gap <= match_window(settings, b.rd, wait)
+    }
+
+    #[test]
+    fn test_distant_cached_ratings_are_not_paired_at_wait_zero() {
+        let settings = MmrMatchingSettings::default();
+        // auth_server 가 HSET 으로 쓴 값
+        let cached = |mmr: f64| {
+            PlayerRating::from_cache_fields(&HashMap::from([
+                ("mmr".to_string(), mmr),
+                ("rd".to_string(), 60.0),
+                ("volatility".to_string(), 0.06),
+            ]))
+        };
+        let low = cached(1400.0);
+        let high = cached(1900.0);
+
+        assert!(!pairs(&settings, low, high, 0.0));
+        // 대기시간이 길어도 max_window + rd 보정을 넘는 차이는 매칭하지 않는다
+        assert!(!pairs(&settings, low, high, 1000.0));
+        assert!(pairs(&settings, cached(1500.0), cached(1700.0), 1000.0));
+
+        // 캐시가 없으면 둘 다 기본 레이팅이라 바로 매칭된다
+        let missing = PlayerRating::from_cache_fields(&HashMap::new());
+        assert_eq!(missing, PlayerRating::default());
+        assert!(pairs(&settings, missing, missing, 0.0));
+    }
+
+    #[test]
+    fn test_add_rating_to_metadata() {
+        let metadata = add_rating_to_metadata(
+            r#"{"pod_id":"pod-a"}"#,
+            PlayerRating::default(),
+            1_700_000_000,
+        );
+        let value: serde_json::Value = serde_json::from_str(&metadata).unwrap();
+
+        assert_eq!(value["pod_id"], "pod-a");
+        assert_eq!(value["enqueued_at"], 1_700_000_000);
+        assert_eq!(
+            PlayerRating::from_metadata(&value),
+            Some(PlayerRating::default())
+        );
+    }
+
+    #[test]
+    fn test_rating_bracket_labels() {
+        assert_eq!(rating_bracket(1500.0, 200.0), "1400-1599");
+        assert_eq!(rating_bracket(1400.0, 200.0), "1400-1599");
+        assert_eq!(rating_bracket(1399.9, 200.0), "1200-1399");
+    }
+}
@@ -1,9 +1,16 @@
+use chrono::Utc;
 use redis::{aio::ConnectionManager, Script};
 use std::sync::OnceLock;
 use tracing::{error, info, warn};
 use uuid::Uuid;
 
-use crate::matchmaking::matchmaker::{operations::with_redis_timeout, scripts, MatchmakerDeps};
+use crate::{
+    env::{MmrMatchingSettings, PartySettings},
+    matchmaking::matchmaker::{
+        operations::{rating, with_redis_timeout},
+        scripts, MatchmakerDeps,
+    },
+};
 
 async fn invoke_try_match_script(
     redis: &mut ConnectionManager,
@@ -80,49 +87,10 @@ pub async fn pop_candidates(
     let mut poisoned_player_ids = Vec::new();
 
     for chunk in raw.chunks_exact(3) {
-        let player_id = chunk[0].clone();
-
-        // score 파싱 실패 시 스킵
-        let score = match chunk[1].parse::<i64>() {
-            Ok(s) => s,
-            Err(e) => {
-                error!("Poisoned candidate {}: invalid score - {}", player_id, e);
-                poisoned_player_ids.push(player_id);
-                continue;
-            }
-        };
-
-        let metadata_json = chunk[2].clone();
-
-        // metadata JSON 파싱 실패 시 스킵
-        let metadata = match serde_json::from_str::<serde_json::Value>(&metadata_json) {
-            Ok(m) => m,
-            Err(e) => {
-                error!("Poisoned candidate {}: invalid JSON - {}", player_id, e);
-                poisoned_player_ids.push(player_id);
-                continue;
-            }
-        };
-
-        // pod_id 없으면 오염된 플레이어에 추가
-        let pod_id = match metadata.get("pod_id").and_then(|p| p.as_str()) {
-            Some(p) => p.to_string(),
-            None => {
-                error!(
-                    "Poisoned candidate {}: pod_id not found in metadata",
-                    player_id
-                );
-                poisoned_player_ids.push(player_id);
-                continue;
-            }
-        };
-
-        candidates.push(PlayerCandidate {
-            player_id,
-            score,
-            pod_id,
-            metadata,
-        });
+        match parse_candidate(chunk) {
+            Ok(candidate) => candidates.push(candidate),
+            Err(player_id) => poisoned_player_ids.push(player_id),
+        }
     }
 
     if !poisoned_player_ids.is_empty() {
@@ -143,6 +111,190 @@ pub async fn pop_candidates(
     Ok((candidates, poisoned_player_ids))
 }
 
+/// Lua 결과 triplet [player_id, score, metadata_json] 파싱 (실패 시 오염된 player_id 반환)
+fn parse_candidate(chunk: &[String]) -> Result<PlayerCandidate, String> {
+    let player_id = chunk[0].clone();
+
+    // score 파싱 실패 시 스킵
+    let score = match chunk[1].parse::<i64>() {
+        Ok(s) => s,
+        Err(e) => {
+            error!("Poisoned candidate {}: invalid score - {}", player_id, e);
+            return Err(player_id);
+        }
+    };
+
+    // metadata JSON 파싱 실패 시 스킵
+    let metadata = match serde_json::from_str::<serde_json::Value>(&chunk[2]) {
+        Ok(m) => m,
+        Err(e) => {
+            error!("Poisoned candidate {}: invalid JSON - {}", player_id, e);
+            return Err(player_id);
+        }
+    };
+
+    // pod_id 없으면 오염된 플레이어에 추가
+    let pod_id = match metadata.get("pod_id").and_then(|p| p.as_str()) {
+        Some(p) => p.to_string(),
+        None => {
+            error!(
+                "Poisoned candidate {}: pod_id not found in metadata",
+                player_id
+            );
+            return Err(player_id);
+        }
+    };
+
+    Ok(PlayerCandidate {
+        player_id,
+        score,
+        pod_id,
+        metadata,
+    })
+}
+
+/// 짝 단위로 뽑은 결과 (MMR 큐, 파티 큐)
+#[derive(Default)]
+pub struct PairedPop {
+    /// 짝지어진 후보 (항상 2명씩 연속)
+    pub candidates: Vec<PlayerCandidate>,
+    pub poisoned_player_ids: Vec<String>,
+    /// 상대가 오염되어 짝을 잃은 후보 (호출자가 다시 큐에 넣거나 dequeue 처리)
+    pub orphaned: Vec<PlayerCandidate>,
+}
+
+/// MMR 큐에서 레이팅 윈도우 안의 쌍을 원자적으로 pop
+pub async fn pop_ranked_candidates(
+    queue_suffix: &str,
+    batch_size: usize,
+    mmr: &MmrMatchingSettings,
+    deps: &MatchmakerDeps,
+) -> Result<PairedPop, String> {
+    let max_pairs = batch_size / 2;
+    if max_pairs == 0 {
+        return Ok(PairedPop::default());
+    }
+
+    let mut redis = deps.redis.clone();
+    let queue_key = format!("queue:{{{}}}", queue_suffix);
+    let joined_key = rating::joined_key(queue_suffix);
+    let timeout_secs = deps.settings.redis_operation_timeout_seconds;
+
+    let raw: Vec<String> =
+        with_redis_timeout("pop_ranked_candidates_script", timeout_secs, async {
+            Script::new(scripts::try_match_ranked_script())
+                .key(queue_key)
+                .key(joined_key)
+                .arg(max_pairs)
+                .arg(Utc::now().timestamp())
+                .arg(mmr.base_window)
+                .arg(mmr.window_growth_per_second)
+                .arg(mmr.max_window)
+                .arg(mmr.rd_weight)
+                .arg(mmr.scan_limit)
+                .invoke_async(&mut redis)
+                .await
+        })
+        .await?;
+
+    let pop = parse_pairs(&raw)?;
+    if !pop.poisoned_player_ids.is_empty() {
+        warn!(
+            "pop_ranked_candidates: Skipped {} poisoned candidate(s) from queue {}, {} orphaned",
+            pop.poisoned_player_ids.len(),
+            queue_suffix,
+            pop.orphaned.len()
+        );
+    }
+
+    info!(
+        "pop_ranked_candidates: Matched {} pair(s) from queue {}",
+        pop.candidates.len() / 2,
+        queue_suffix
+    );
+    Ok(pop)
+}
+
+/// 파티 큐에서 같은 인원의 파티 두 개씩 원자적으로 pop
+///
+/// 두 파티의 i 번째 멤버끼리 한 쌍으로 반환된다.
+pub async fn pop_party_candidates(
+    queue_suffix: &str,
+    max_matches: usize,
+    party: &PartySettings,
+    deps: &MatchmakerDeps,
+) -> Result<PairedPop, String> {
+    if max_matches == 0 {
+        return Ok(PairedPop::default());
+    }
+
+    let mut redis = deps.redis.clone();
+    let queue_key = format!("queue:{{{}}}", queue_suffix);
+    let timeout_secs = deps.settings.redis_operation_timeout_seconds;
+
+    let raw: Vec<String> = with_redis_timeout("pop_party_candidates_script", timeout_secs, async {
+        Script::new(scripts::try_match_party_script())
+            .key(queue_key)
+            .arg(max_matches)
+            .arg(party.scan_limit)
+            .invoke_async(&mut redis)
+            .await
+    })
+    .await?;
+
+    let pop = parse_pairs(&raw)?;
+    if !pop.poisoned_player_ids.is_empty() {
+        warn!(
+            "pop_party_candidates: Skipped {} poisoned candidate(s) from queue {}, {} orphaned",
+            pop.poisoned_player_ids.len(),
+            queue_suffix,
+            pop.orphaned.len()
+        );
+    }
+
+    info!(
+        "pop_party_candidates: Matched {} pair(s) from queue {}",
+        pop.candidates.len() / 2,
+        queue_suffix
+    );
+    Ok(pop)
+}
+
+/// [p1 triplet, p2 triplet, ...] 형식의 쌍 단위 결과 파싱
+///
+/// 한쪽이 오염되면 다른 한쪽은 orphaned 로 분리해 반환 후보가 항상 짝을 이루도록 한다.
+fn parse_pairs(raw: &[String]) -> Result<PairedPop, String> {
+    if !raw.len().is_multiple_of(6) {
+        return Err(format!(
+            "unexpected response length in paired pop script - expected pairs of triplets, got {} items",
+            raw.len()
+        ));
+    }
+
+    let mut pop = PairedPop::default();
+    for pair in raw.chunks_exact(6) {
+        match (parse_candidate(&pair[..3]), parse_candidate(&pair[3..])) {
+            (Ok(player1), Ok(player2)) => {
+                pop.candidates.push(player1);
+                pop.candidates.push(player2);
+            }
+            (Ok(valid), Err(poisoned)) | (Err(poisoned), Ok(valid)) => {
+                pop.poisoned_player_ids.push(poisoned);
+                pop.orphaned.push(valid);
+            }
+            (Err(poisoned1), Err(poisoned2)) => {
+                pop.poisoned_player_ids.push(poisoned1);
+                pop.poisoned_player_ids.push(poisoned2);
+            }
+        }
+    }
+
+    if !pop.poisoned_player_ids.is_empty() {
+        metrics::POISONED_CANDIDATES_TOTAL.inc_by(pop.poisoned_player_ids.len() as u64);
+    }
+    Ok(pop)
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct PlayerCandidate {
     pub player_id: String,
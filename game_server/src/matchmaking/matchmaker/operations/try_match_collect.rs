@@ -5,23 +5,28 @@ use tracing::{error, warn};
 use uuid::Uuid;
 
 use crate::{
+    env::MatchModeSettings,
     matchmaking::matchmaker::{
         operations::{
+            enqueue::re_enqueue_candidates,
             notify::{self, MessageRoutingDeps},
-            try_match::pop_candidates,
+            try_match::{pop_candidates, pop_party_candidates, pop_ranked_candidates},
         },
         MatchmakerDeps,
     },
     shared::protocol::{ErrorCode, ServerMessage},
-    RETRY_CONFIG,
+    GameMode, RETRY_CONFIG,
 };
 
 use super::try_match::PlayerCandidate;
 
 /// Candidates 수집 (백오프 및 poisoned 처리 포함)
+///
+/// MMR 매칭 모드는 레이팅 윈도우로 짝지어진 후보만 반환한다 (2명씩 연속).
 pub async fn collect_candidates_with_retry(
     queue_suffix: &str,
     required_count: usize,
+    mode_settings: &MatchModeSettings,
     deps: &MatchmakerDeps,
     shutdown_token: &CancellationToken,
 ) -> Result<Vec<PlayerCandidate>, String> {
@@ -38,8 +43,22 @@ pub async fn collect_candidates_with_retry(
             return Err("Shutdown requested".to_string());
         }
 
-        match pop_candidates(queue_suffix, required_count, deps).await {
-            Ok((candidates, poisoned_ids)) => {
+        let popped = if mode_settings.game_mode == GameMode::Party {
+            pop_party_candidates(queue_suffix, required_count / 2, &mode_settings.party, deps)
+                .await
+                .map(|pop| (pop.candidates, pop.poisoned_player_ids, pop.orphaned))
+        } else if mode_settings.use_mmr_matching {
+            pop_ranked_candidates(queue_suffix, required_count, &mode_settings.mmr, deps)
+                .await
+                .map(|pop| (pop.candidates, pop.poisoned_player_ids, pop.orphaned))
+        } else {
+            pop_candidates(queue_suffix, required_count, deps)
+                .await
+                .map(|(candidates, poisoned)| (candidates, poisoned, Vec::new()))
+        };
+
+        match popped {
+            Ok((candidates, poisoned_ids, orphaned)) => {
                 // Circuit breaker 성공 기록
                 deps.redis_circuit.record_success();
 
@@ -48,6 +67,22 @@ pub async fn collect_candidates_with_retry(
                     notify_poisoned_candidates(poisoned_ids, deps).await;
                 }
 
+                // 상대가 오염되어 짝을 잃은 후보는 다시 큐로
+                // (파티는 이미 큐에서 빠졌으므로 개인 단위로 되돌릴 수 없어 dequeue 처리)
+                if !orphaned.is_empty() {
+                    if mode_settings.game_mode == GameMode::Party {
+                        notify_dequeued_candidates(&orphaned, deps).await;
+                    } else {
+                        re_enqueue_candidates(
+                            queue_suffix,
+                            mode_settings.game_mode,
+                            &orphaned,
+                            deps,
+                        )
+                        .await;
+                    }
+                }
+
                 return Ok(candidates);
             }
             Err(err) => {
@@ -79,6 +114,19 @@ pub async fn collect_candidates_with_retry(
     }
 }
 
+/// 매칭되지 못하고 큐에서 빠진 후보에게 DeQueued 알림
+pub async fn notify_dequeued_candidates(candidates: &[PlayerCandidate], deps: &MatchmakerDeps) {
+    let routing_deps = MessageRoutingDeps::from(deps);
+
+    for candidate in candidates {
+        warn!(
+            "Notifying candidate {} that they were dequeued without a match",
+            candidate.player_id
+        );
+        notify::send_message_to_player(candidate, ServerMessage::DeQueued, &routing_deps).await;
+    }
+}
+
 /// Poisoned candidates에게 알림
 async fn notify_poisoned_candidates(poisoned_ids: Vec<String>, deps: &MatchmakerDeps) {
     let routing_deps = MessageRoutingDeps::from(deps);
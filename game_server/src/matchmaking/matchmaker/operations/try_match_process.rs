@@ -1,6 +1,7 @@
 use actix::Addr;
 use redis::aio::ConnectionManager;
 use tracing::info;
+use uuid::Uuid;
 
 use crate::{
     game::{battle_actor, load_balance_actor::LoadBalanceActor},
@@ -40,7 +41,14 @@ pub async fn process_match_pair(
     let my_pod_id = PlayerCandidate::current_pod_id();
 
     // Battle 실행 (동기적으로 결과 대기)
-    let battle_result = battle_actor::execute_battle(player1, player2, game_mode).await;
+    let battle_result = battle_actor::execute_battle(
+        player1,
+        player2,
+        game_mode,
+        Uuid::new_v4(),
+        deps.data_registry.current(),
+    )
+    .await;
 
     // 플레이어들에게 MatchFound 알림 (battle_result 포함)
     notify_match_found_with_result(
@@ -0,0 +1,263 @@
+use actix::{dev::ContextFutureSpawner, ActorContext, Handler, WrapFuture};
+use tracing::{error, info, warn};
+
+use crate::{
+    matchmaking::matchmaker::{
+        messages::{Dequeue, Enqueue, PartyAccept, PartyInvite, PartyLeave, PartySync, TryMatch},
+        operations::{
+            notify::{self, MessageRoutingDeps},
+            party,
+            try_match_collect::{collect_candidates_with_retry, notify_dequeued_candidates},
+            try_match_process::process_match_pair,
+        },
+        patry::PartyMatchmaker,
+        MatchmakerDeps,
+    },
+    shared::protocol::{ErrorCode, ServerMessage},
+    GameMode, Stop,
+};
+
+impl Handler<Enqueue> for PartyMatchmaker {
+    type Result = ();
+
+    fn handle(&mut self, msg: Enqueue, ctx: &mut Self::Context) -> Self::Result {
+        info!(
+            "PartyMatchmaker: Enqueue handler called for player {}",
+            msg.player_id
+        );
+        let deps: MatchmakerDeps = (&self.inner).into();
+        let game_mode = msg.game_mode;
+        let queue_prefix = self.queue_suffix(game_mode);
+        let player_id = msg.player_id;
+        let routing_deps = MessageRoutingDeps::from(&deps);
+
+        async move {
+            if game_mode != GameMode::Party {
+                warn!(
+                    "Player {} tried to enqueue using mismatched matchmaker for mode {:?}",
+                    player_id, game_mode
+                );
+                notify::send_message_to_player_by_id(
+                    player_id,
+                    ServerMessage::Error {
+                        code: ErrorCode::InvalidGameMode,
+                        message: "Invalid game mode".to_string(),
+                    },
+                    &routing_deps,
+                )
+                .await;
+                return;
+            }
+            // 리더만 파티 전체를 enqueue 할 수 있음
+            party::enqueue(queue_prefix, player_id, msg.metadata, &deps).await;
+        }
+        .into_actor(self)
+        .spawn(ctx);
+    }
+}
+
+impl Handler<Dequeue> for PartyMatchmaker {
+    type Result = ();
+
+    fn handle(&mut self, msg: Dequeue, ctx: &mut Self::Context) -> Self::Result {
+        let deps: MatchmakerDeps = (&self.inner).into();
+        let game_mode = msg.game_mode;
+        let queue_prefix = self.queue_suffix(game_mode);
+        let player_id = msg.player_id;
+        let routing_deps = MessageRoutingDeps::from(&deps);
+
+        async move {
+            if game_mode != GameMode::Party {
+                warn!(
+                    "Player {} tried to dequeue using mismatched matchmaker for mode {:?}",
+                    player_id, game_mode
+                );
+                notify::send_message_to_player_by_id(
+                    player_id,
+                    ServerMessage::Error {
+                        code: ErrorCode::InvalidGameMode,
+                        message: "Invalid game mode".to_string(),
+                    },
+                    &routing_deps,
+                )
+                .await;
+                return;
+            }
+            // 멤버 누구든 파티 전체를 dequeue
+            party::dequeue(queue_prefix, player_id, &deps).await;
+        }
+        .into_actor(self)
+        .spawn(ctx);
+    }
+}
+
+impl Handler<PartySync> for PartyMatchmaker {
+    type Result = ();
+
+    fn handle(&mut self, msg: PartySync, ctx: &mut Self::Context) -> Self::Result {
+        let deps: MatchmakerDeps = (&self.inner).into();
+
+        async move {
+            party::sync(msg.player_id, &deps).await;
+        }
+        .into_actor(self)
+        .spawn(ctx);
+    }
+}
+
+impl Handler<PartyInvite> for PartyMatchmaker {
+    type Result = ();
+
+    fn handle(&mut self, msg: PartyInvite, ctx: &mut Self::Context) -> Self::Result {
+        let deps: MatchmakerDeps = (&self.inner).into();
+
+        async move {
+            party::invite(msg.player_id, msg.target_player_id, &deps).await;
+        }
+        .into_actor(self)
+        .spawn(ctx);
+    }
+}
+
+impl Handler<PartyAccept> for PartyMatchmaker {
+    type Result = ();
+
+    fn handle(&mut self, msg: PartyAccept, ctx: &mut Self::Context) -> Self::Result {
+        let deps: MatchmakerDeps = (&self.inner).into();
+
+        async move {
+            party::accept(msg.player_id, msg.party_id, msg.metadata, &deps).await;
+        }
+        .into_actor(self)
+        .spawn(ctx);
+    }
+}
+
+impl Handler<PartyLeave> for PartyMatchmaker {
+    type Result = ();
+
+    fn handle(&mut self, msg: PartyLeave, ctx: &mut Self::Context) -> Self::Result {
+        let deps: MatchmakerDeps = (&self.inner).into();
+        let queue_prefix = self.queue_suffix(GameMode::Party);
+
+        async move {
+            party::leave(queue_prefix, msg.player_id, &deps).await;
+        }
+        .into_actor(self)
+        .spawn(ctx);
+    }
+}
+
+impl Handler<TryMatch> for PartyMatchmaker {
+    type Result = ();
+
+    fn handle(&mut self, msg: TryMatch, ctx: &mut Self::Context) -> Self::Result {
+        // Skip if already matching
+        if self.is_matching.load(std::sync::atomic::Ordering::Relaxed) {
+            info!("TryMatch already in progress, skipping this tick");
+            metrics::TRY_MATCH_SKIPPED_TOTAL.inc();
+            return;
+        }
+
+        let deps: MatchmakerDeps = (&self.inner).into();
+        let settings = msg.match_mode_settings;
+        let queue_suffix = self.queue_suffix(settings.game_mode);
+        let required_players = settings.required_players;
+        let mut redis = deps.redis.clone();
+        let shutdown_token = self.shutdown_token.clone();
+        let subscription_addr = self.sub_manager_addr.clone();
+        let load_balance_addr = self.inner.load_balance_addr.clone();
+        let is_matching = self.is_matching.clone();
+
+        // Set matching flag
+        is_matching.store(true, std::sync::atomic::Ordering::Relaxed);
+
+        // Spawn async future
+        async move {
+            // ===== 1. Early exit checks =====
+            if shutdown_token.is_cancelled() {
+                is_matching.store(false, std::sync::atomic::Ordering::Relaxed);
+                return;
+            }
+
+            if let Err(e) = deps.redis_circuit.check() {
+                warn!("Redis circuit open, skipping TryMatch: {}", e);
+                is_matching.store(false, std::sync::atomic::Ordering::Relaxed);
+                return;
+            }
+
+            // ===== 2. Collect candidates (파티 멤버끼리 짝지어진 순서) =====
+            let candidates = match collect_candidates_with_retry(
+                queue_suffix,
+                required_players as usize * 2,
+                &settings,
+                &deps,
+                &shutdown_token,
+            )
+            .await
+            {
+                Ok(c) => c,
+                Err(e) => {
+                    error!("Failed to collect candidates: {}", e);
+                    is_matching.store(false, std::sync::atomic::Ordering::Relaxed);
+                    return;
+                }
+            };
+
+            if candidates.is_empty() {
+                is_matching.store(false, std::sync::atomic::Ordering::Relaxed);
+                return;
+            }
+
+            info!("Found {} party candidates", candidates.len());
+
+            // ===== 3. Process matches =====
+            for chunk in candidates.chunks(2) {
+                // 파티는 이미 큐에서 빠졌으므로 개인 단위로 되돌리지 않고 dequeue 처리
+                if shutdown_token.is_cancelled() {
+                    warn!("Shutdown requested, dequeueing remaining party candidates");
+                    notify_dequeued_candidates(chunk, &deps).await;
+                    continue;
+                }
+
+                match chunk {
+                    [player1, player2] => {
+                        process_match_pair(
+                            player1,
+                            player2,
+                            settings.game_mode,
+                            queue_suffix,
+                            &deps,
+                            &mut redis,
+                            subscription_addr.clone(),
+                            &load_balance_addr,
+                        )
+                        .await;
+                    }
+                    [single] => {
+                        error!("Unpaired party candidate {}, dequeueing", single.player_id);
+                        notify_dequeued_candidates(chunk, &deps).await;
+                    }
+                    _ => unreachable!("chunks(2) only returns 1 or 2 elements"),
+                }
+            }
+
+            // Release matching flag
+            is_matching.store(false, std::sync::atomic::Ordering::Relaxed);
+        }
+        .into_actor(self)
+        .spawn(ctx);
+    }
+}
+
+impl Handler<Stop> for PartyMatchmaker {
+    type Result = ();
+
+    fn handle(&mut self, msg: Stop, ctx: &mut Self::Context) -> Self::Result {
+        info!(
+            "PartyMatchmaker for mode {:?} stopping: {:?}",
+            self.mode_settings.game_mode, msg.reason
+        );
+        ctx.stop();
+    }
+}
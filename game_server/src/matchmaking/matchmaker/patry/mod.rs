@@ -1,4 +1,90 @@
-// TOOD: 일단 Normal, Ranked 모드만 구현해놨고,
-// Game Server 구조만 어느정도 잡아둔 뒤 party 모드 구현 할 계획.
-// Game Server 구현할 때, Redis 연결 부분 조금 신경 써야함.
-// 또한 MatchMaker/Handlers - 184 line 부근에서 적혀있듯이, Orchestra 시스템 추가해야함. ( 오류 전파 및 k8s 처리 )
+use std::{
+    ops::{Deref, DerefMut},
+    time::Duration,
+};
+
+use actix::{Actor, AsyncContext};
+use tracing::info;
+
+pub mod handlers;
+
+use crate::{
+    env::MatchModeSettings,
+    matchmaking::matchmaker::{common::MatchmakerInner, messages::TryMatch, MatchmakerDeps},
+};
+
+/// Party 모드 매칭
+///
+/// 파티 구성(초대/수락/탈퇴)과 파티 단위 대기열을 담당한다. 같은 인원의 파티끼리 매칭하고,
+/// 두 파티의 멤버끼리 1:1 전투를 진행한다. 파티 상태는 Redis 에 있으므로 어느 Pod 에서든 처리할 수 있다.
+pub struct PartyMatchmaker {
+    inner: MatchmakerInner,
+}
+
+impl Deref for PartyMatchmaker {
+    type Target = MatchmakerInner;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl DerefMut for PartyMatchmaker {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl PartyMatchmaker {
+    pub fn new(deps: MatchmakerDeps, mode_settings: MatchModeSettings) -> Self {
+        Self {
+            inner: MatchmakerInner::new(deps, mode_settings),
+        }
+    }
+}
+
+impl Actor for PartyMatchmaker {
+    type Context = actix::Context<Self>;
+
+    fn started(&mut self, ctx: &mut Self::Context) {
+        info!(
+            "PartyMatchmaker actor started for mode {:?}",
+            self.mode_settings.game_mode
+        );
+        let interval = self.settings.try_match_tick_interval_seconds;
+        let mode_settings = self.mode_settings.clone();
+        let addr = ctx.address();
+        let shutdown_token = self.shutdown_token.clone();
+
+        // Use tokio::spawn instead of ctx.run_interval for more reliable timing
+        tokio::spawn(async move {
+            let mut interval_timer = tokio::time::interval(Duration::from_secs(interval));
+            // First tick completes immediately, so we skip it to start after `interval` seconds
+            interval_timer.tick().await;
+
+            loop {
+                interval_timer.tick().await;
+
+                if shutdown_token.is_cancelled() {
+                    break;
+                }
+
+                addr.do_send(TryMatch {
+                    match_mode_settings: mode_settings.clone(),
+                });
+            }
+        });
+    }
+
+    fn stopping(&mut self, _ctx: &mut Self::Context) -> actix::Running {
+        info!(
+            "PartyMatchmaker for mode {:?} stopping, cancelling futures",
+            self.mode_settings.game_mode
+        );
+
+        // 모든 실행 중인 future에게 종료 신호
+        self.shutdown_token.cancel();
+
+        actix::Running::Stop
+    }
+}
@@ -1,4 +1,5 @@
 use actix::{dev::ContextFutureSpawner, ActorContext, Handler, WrapFuture};
+use chrono::Utc;
 use tracing::{error, info, warn};
 
 use crate::{
@@ -8,6 +9,7 @@ use crate::{
             dequeue::dequeue,
             enqueue::{enqueue, re_enqueue_candidates},
             notify::{self, MessageRoutingDeps},
+            rating,
             try_match_collect::collect_candidates_with_retry,
             try_match_process::process_match_pair,
         },
@@ -136,6 +138,7 @@ impl Handler<TryMatch> for RankedMatchmaker {
             let candidates = match collect_candidates_with_retry(
                 queue_suffix,
                 required_players as usize * 2,
+                &settings,
                 &deps,
                 &shutdown_token,
             )
@@ -168,6 +171,16 @@ impl Handler<TryMatch> for RankedMatchmaker {
 
                 match chunk {
                     [player1, player2] => {
+                        // 매칭 품질 메트릭 (레이팅 차이, 구간별 대기시간)
+                        if settings.use_mmr_matching {
+                            rating::record_match_quality(
+                                player1,
+                                player2,
+                                &settings.mmr,
+                                Utc::now().timestamp(),
+                            );
+                        }
+
                         // Process match pair
                         process_match_pair(
                             player1,
@@ -10,6 +10,50 @@ const TRY_MATCH_POP_SCRIPT: &str = include_str!(concat!(
     env!("CARGO_MANIFEST_DIR"),
     "/scripts/TRY_MATCH_POP.lua"
 ));
+const ENQUEUE_RANKED_PLAYER_SCRIPT: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/scripts/ENQUEUE_RANKED_PLAYER.lua"
+));
+const TRY_MATCH_RANKED_SCRIPT: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/scripts/TRY_MATCH_RANKED.lua"
+));
+const PARTY_INVITE_SCRIPT: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/scripts/PARTY_INVITE.lua"
+));
+const PARTY_ACCEPT_SCRIPT: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/scripts/PARTY_ACCEPT.lua"
+));
+const PARTY_LEAVE_SCRIPT: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/scripts/PARTY_LEAVE.lua"
+));
+const ENQUEUE_PARTY_SCRIPT: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/scripts/ENQUEUE_PARTY.lua"
+));
+const DEQUEUE_PARTY_SCRIPT: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/scripts/DEQUEUE_PARTY.lua"
+));
+const TRY_MATCH_PARTY_SCRIPT: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/scripts/TRY_MATCH_PARTY.lua"
+));
+const CLAIM_GAME_RUN_SCRIPT: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/scripts/CLAIM_GAME_RUN.lua"
+));
+const RELEASE_GAME_RUN_SCRIPT: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/scripts/RELEASE_GAME_RUN.lua"
+));
+const RATE_LIMIT_SCRIPT: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/scripts/RATE_LIMIT.lua"
+));
 
 pub fn enqueue_player_script() -> &'static str {
     ENQUEUE_PLAYER_SCRIPT
@@ -22,3 +66,47 @@ pub fn dequeue_player_script() -> &'static str {
 pub fn try_match_pop_script() -> &'static str {
     TRY_MATCH_POP_SCRIPT
 }
+
+pub fn enqueue_ranked_player_script() -> &'static str {
+    ENQUEUE_RANKED_PLAYER_SCRIPT
+}
+
+pub fn try_match_ranked_script() -> &'static str {
+    TRY_MATCH_RANKED_SCRIPT
+}
+
+pub fn party_invite_script() -> &'static str {
+    PARTY_INVITE_SCRIPT
+}
+
+pub fn party_accept_script() -> &'static str {
+    PARTY_ACCEPT_SCRIPT
+}
+
+pub fn party_leave_script() -> &'static str {
+    PARTY_LEAVE_SCRIPT
+}
+
+pub fn enqueue_party_script() -> &'static str {
+    ENQUEUE_PARTY_SCRIPT
+}
+
+pub fn dequeue_party_script() -> &'static str {
+    DEQUEUE_PARTY_SCRIPT
+}
+
+pub fn try_match_party_script() -> &'static str {
+    TRY_MATCH_PARTY_SCRIPT
+}
+
+pub fn claim_game_run_script() -> &'static str {
+    CLAIM_GAME_RUN_SCRIPT
+}
+
+pub fn release_game_run_script() -> &'static str {
+    RELEASE_GAME_RUN_SCRIPT
+}
+
+pub fn rate_limit_script() -> &'static str {
+    RATE_LIMIT_SCRIPT
+}
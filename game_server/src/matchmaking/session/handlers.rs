@@ -1,9 +1,12 @@
 use crate::{
+    matchmaking::matchmaker::messages::{PartyAccept, PartyInvite, PartyLeave, PartySync},
     matchmaking::session::{helper::SessionState, Session},
     shared::protocol::{ClientMessage, ErrorCode, ServerMessage},
     Stop,
 };
-use actix::{ActorContext, Handler, StreamHandler};
+use actix::{
+    ActorContext, ActorFutureExt, ContextFutureSpawner, Handler, StreamHandler, WrapFuture,
+};
 use actix_web_actors::ws::{self, Message, ProtocolError};
 use tracing::{info, warn};
 
@@ -58,6 +61,21 @@ impl Handler<ServerMessage> for Session {
                 ctx.close(Some(ws::CloseCode::Normal.into()));
                 ctx.stop();
             }
+            ServerMessage::PartyInvited { .. } | ServerMessage::PartyState { .. } => {
+                // 파티 알림은 세션 상태를 바꾸지 않음 (대기열 상태는 EnQueued/DeQueued 로 전달)
+                if let ServerMessage::PartyState { party } = &msg {
+                    self.set_party(party.as_ref().map(|party| party.party_id));
+                }
+                if let Ok(json) = serde_json::to_string(&msg) {
+                    ctx.text(json);
+                } else {
+                    warn!("Failed to serialize party ServerMessage");
+                }
+            }
+            ServerMessage::RunMigrated { .. } => {
+                // 게임 런 전용 메시지 (매칭 세션에는 전달되지 않아야 함)
+                warn!("Ignoring RunMigrated routed to matchmaking session");
+            }
             ServerMessage::Error {
                 code: _,
                 message: _,
@@ -86,27 +104,10 @@ impl StreamHandler<Result<Message, ProtocolError>> for Session {
                 self.last_heartbeat = std::time::Instant::now();
             }
             Ok(ws::Message::Text(text)) => match serde_json::from_str::<ClientMessage>(&text) {
-                Ok(ClientMessage::Enqueue {
-                    player_id,
-                    game_mode,
-                    metadata,
-                }) => {
-                    self.handle_enqueue(ctx, player_id, game_mode, metadata);
-                }
-                Ok(ClientMessage::Dequeue {
-                    player_id,
-                    game_mode,
-                }) => {
-                    self.handle_dequeue(ctx, player_id, game_mode);
-                }
+                Ok(msg) => self.handle_client_message(ctx, msg),
                 Err(e) => {
                     warn!("Failed to parse client message: {}", e);
-                    self.send_error(
-                        ctx,
-                        ErrorCode::InvalidMessageFormat,
-                        "Invalid message format",
-                    );
-                    ctx.stop();
+                    self.handle_invalid_frame(ctx);
                 }
             },
             Ok(ws::Message::Close(reason)) => {
@@ -117,3 +118,126 @@ impl StreamHandler<Result<Message, ProtocolError>> for Session {
         }
     }
 }
+
+impl Session {
+    /// 파싱할 수 없는 프레임: invalid 버킷이 남아 있을 때만 에러를 보내고 연결 종료
+    ///
+    /// 인증된 세션은 플레이어별, 인증 전이면 IP 별 버킷을 쓴다.
+    fn handle_invalid_frame(&mut self, ctx: &mut ws::WebsocketContext<Self>) {
+        let rate_limiter = self.app_state.rate_limiter.clone();
+        let subject = match &self.auth {
+            Some(auth) => auth.player_id.to_string(),
+            None => self.client_ip.to_string(),
+        };
+
+        async move { rate_limiter.check_invalid_message(&subject).await }
+            .into_actor(self)
+            .map(|allowed, act, ctx| {
+                if allowed {
+                    act.send_error(
+                        ctx,
+                        ErrorCode::InvalidMessageFormat,
+                        "Invalid message format",
+                    );
+                } else {
+                    warn!("Dropping invalid frames from {}", act.client_ip);
+                }
+                ctx.stop();
+            })
+            .wait(ctx);
+    }
+
+    /// 플레이어/메시지 종류별 속도 제한 후 처리
+    ///
+    /// 검사가 끝날 때까지 다음 메시지를 처리하지 않으므로 요청 순서가 유지된다.
+    fn handle_client_message(&mut self, ctx: &mut ws::WebsocketContext<Self>, msg: ClientMessage) {
+        let player_id = msg.player_id();
+        if !self.is_authorized_player(player_id) {
+            warn!(
+                "Rejected {} for player {}: session belongs to another player",
+                msg.kind(),
+                player_id
+            );
+            self.send_error(ctx, ErrorCode::PlayerIdMismatch, "Player ID mismatch");
+            return;
+        }
+
+        let rate_limiter = self.app_state.rate_limiter.clone();
+        let kind = msg.kind();
+
+        async move { rate_limiter.check_message(player_id, kind).await }
+            .into_actor(self)
+            .map(move |allowed, act, ctx| {
+                if !allowed {
+                    warn!(
+                        "Rate limit exceeded for player {} ({}) from {}",
+                        player_id, kind, act.client_ip
+                    );
+                    act.send_error(
+                        ctx,
+                        ErrorCode::RateLimitExceeded,
+                        "Too many requests. Please slow down.",
+                    );
+                    return;
+                }
+                act.dispatch_client_message(ctx, msg);
+            })
+            .wait(ctx);
+    }
+
+    fn dispatch_client_message(
+        &mut self,
+        ctx: &mut ws::WebsocketContext<Self>,
+        msg: ClientMessage,
+    ) {
+        match msg {
+            ClientMessage::Enqueue {
+                player_id,
+                game_mode,
+                metadata,
+            } => {
+                self.handle_enqueue(ctx, player_id, game_mode, metadata);
+            }
+            ClientMessage::Dequeue {
+                player_id,
+                game_mode,
+            } => {
+                self.handle_dequeue(ctx, player_id, game_mode);
+            }
+            ClientMessage::PartySync { player_id } => {
+                self.handle_party_request(ctx, player_id, move |addr| {
+                    addr.do_send(PartySync { player_id })
+                });
+            }
+            ClientMessage::PartyInvite {
+                player_id,
+                target_player_id,
+            } => {
+                self.handle_party_request(ctx, player_id, move |addr| {
+                    addr.do_send(PartyInvite {
+                        player_id,
+                        target_player_id,
+                    })
+                });
+            }
+            ClientMessage::PartyAccept {
+                player_id,
+                party_id,
+                metadata,
+            } => {
+                self.handle_party_request(ctx, player_id, move |addr| {
+                    addr.do_send(PartyAccept {
+                        player_id,
+                        party_id,
+                        metadata,
+                    })
+                });
+            }
+            ClientMessage::PartyLeave { player_id } => {
+                self.handle_party_request(ctx, player_id, move |addr| {
+                    addr.do_send(PartyLeave { player_id })
+                });
+            }
+        }
+    }
+}
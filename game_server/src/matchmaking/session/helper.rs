@@ -69,6 +69,7 @@ impl SessionState {
             // From Idle
             (Idle, Enqueuing) => true,
             (Idle, Error) => true,
+            (Idle, InQueue) => true, // 파티원: 리더가 파티 단위로 enqueue
 
             // From Enqueuing
             (Enqueuing, InQueue) => true,
@@ -80,6 +81,7 @@ impl SessionState {
             (InQueue, Completed) => true,
             (InQueue, Error) => true,
             (InQueue, Disconnecting) => true,
+            (InQueue, Dequeued) => true, // 파티 단위 dequeue (다른 멤버의 요청/연결 종료)
 
             // From Dequeuing
             (Dequeuing, Dequeued) => true,
@@ -88,6 +90,7 @@ impl SessionState {
 
             // From Dequeued
             (Dequeued, Enqueuing) => true,
+            (Dequeued, InQueue) => true, // 파티원: 리더가 다시 enqueue
             (Dequeued, Error) => true,
             (Dequeued, Disconnecting) => true,
 
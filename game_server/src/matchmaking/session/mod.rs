@@ -1,6 +1,8 @@
-use crate::matchmaking::matchmaker::messages::{Dequeue, Enqueue};
+use crate::matchmaking::matchmaker::messages::{Dequeue, Enqueue, PartyLeave};
+use crate::matchmaking::matchmaker::patry::PartyMatchmaker;
 use crate::matchmaking::session::helper::{classify_violation, SessionState, TransitionViolation};
 use crate::matchmaking::subscript::messages::{Deregister, Register};
+use crate::shared::auth::AuthenticatedPlayer;
 use crate::shared::protocol::ErrorCode;
 use crate::{
     matchmaking::matchmaker::MatchmakerAddr, matchmaking::subscript::SubScriptionManager, AppState,
@@ -37,6 +39,9 @@ pub struct Session {
     cleanup_started: bool,
     client_ip: IpAddr,
     metadata: Option<String>, // Store metadata for test event publishing
+    party_id: Option<Uuid>,   // 소속 파티 (PartyState 메시지로 갱신)
+    /// JWT 로 인증된 플레이어 (있으면 다른 player_id 의 메시지를 거부)
+    auth: Option<AuthenticatedPlayer>,
 }
 
 impl Session {
@@ -46,13 +51,16 @@ impl Session {
         heartbeat_timeout: Duration,
         app_state: web::Data<AppState>,
         client_ip: IpAddr,
+        auth: Option<AuthenticatedPlayer>,
     ) -> Self {
         Self {
             state: SessionState::Idle,
             matchmaker_addr: OnceCell::new(),
             subscript_addr,
             app_state,
-            player_id: Uuid::new_v4(),
+            player_id: auth
+                .as_ref()
+                .map_or_else(Uuid::new_v4, |auth| auth.player_id),
             game_mode: GameMode::None,
             heartbeat_interval,
             heartbeat_timeout,
@@ -60,6 +68,8 @@ impl Session {
             cleanup_started: false,
             client_ip,
             metadata: None,
+            party_id: None,
+            auth,
         }
     }
 
@@ -135,6 +145,19 @@ impl Actor for Session {
         // No need to transition_to(Idle) here
 
         self.hb(ctx);
+
+        // 토큰이 만료되면 세션 종료 (재인증 후 다시 연결)
+        if let Some(auth) = &self.auth {
+            ctx.run_later(auth.remaining(), |act, ctx| {
+                info!(
+                    "Token expired for player {}, closing session",
+                    act.player_id
+                );
+                act.send_error(ctx, ErrorCode::TokenExpired, "Token expired");
+                ctx.close(Some(ws::CloseCode::Policy.into()));
+                ctx.stop();
+            });
+        }
     }
 
     fn stopping(&mut self, ctx: &mut Self::Context) -> Running {
@@ -159,11 +182,15 @@ impl Actor for Session {
         let player_id = self.player_id.clone();
         let game_mode = self.game_mode.clone();
         let current_state = self.state;
+        // 파티 멤버가 끊기면 파티에서 탈퇴 (대기 중이면 파티 전체 dequeue)
+        let party_addr = self.party_id.and_then(|_| self.party_matchmaker());
 
         async move {
             // Only dequeue if player is still in queue
             // Skip if already dequeued or never enqueued
-            let res_match = if let Some(addr) = matchmaker_addr {
+            let res_match = if let Some(addr) = party_addr {
+                addr.send(PartyLeave { player_id }).await
+            } else if let Some(addr) = matchmaker_addr {
                 if current_state == SessionState::InQueue
                     || current_state == SessionState::Dequeuing
                 {
@@ -216,17 +243,6 @@ impl Session {
         game_mode: GameMode,
         metadata: String,
     ) {
-        // Rate limiting check
-        if !self.app_state.rate_limiter.check(&self.client_ip) {
-            warn!("Rate limit exceeded for IP: {}", self.client_ip);
-            self.send_error(
-                ctx,
-                ErrorCode::RateLimitExceeded,
-                "Too many requests. Please slow down.",
-            );
-            return;
-        }
-
         // Session 객체 상태가 Idle 에서만 Enqueue 허용
         if self.state != SessionState::Idle && self.state != SessionState::Error {
             warn!(
@@ -236,6 +252,10 @@ impl Session {
             return;
         }
 
+        if !self.allow_session_queue(ctx, game_mode) {
+            return;
+        }
+
         let matchmaker = match self.resolve_matchmaker(game_mode) {
             Ok(handle) => handle,
             Err(code) => {
@@ -295,6 +315,10 @@ impl Session {
             return;
         }
 
+        if !self.allow_session_queue(ctx, game_mode) {
+            return;
+        }
+
         let matchmaker = match self.resolve_matchmaker(game_mode) {
             Ok(handle) => handle,
             Err(code) => {
@@ -315,6 +339,76 @@ impl Session {
 }
 
 impl Session {
+    /// 파티 요청 처리
+    ///
+    /// 세션을 player_id 에 연결(Register)한 뒤 PartyMatchmaker 로 전달한다.
+    /// 파티 알림(초대, 파티 상태, 파티 단위 enqueue/dequeue)을 받으려면 먼저 파티 요청을 보내야 한다.
+    pub(crate) fn handle_party_request<F>(&mut self, ctx: &mut Ctx, player_id: Uuid, send: F)
+    where
+        F: FnOnce(&Addr<PartyMatchmaker>) + 'static,
+    {
+        // 이미 파티에 속한 세션은 다른 player_id 로 요청 불가
+        if self.party_id.is_some() && self.player_id != player_id {
+            self.send_error(ctx, ErrorCode::WrongSessionId, "Player ID mismatch");
+            return;
+        }
+
+        let Some(party_matchmaker) = self.party_matchmaker() else {
+            self.send_error(ctx, ErrorCode::InvalidGameMode, "Unsupported game mode");
+            return;
+        };
+
+        self.player_id = player_id;
+        self.game_mode = GameMode::Party;
+        let subscript_addr = self.subscript_addr.clone();
+        let ctx_addr = ctx.address();
+
+        async move {
+            if let Err(err) = subscript_addr
+                .send(Register {
+                    player_id,
+                    addr: ctx_addr,
+                })
+                .await
+            {
+                warn!("Failed to register player {}: {:?}", player_id, err);
+                return;
+            }
+
+            send(&party_matchmaker);
+        }
+        .into_actor(self)
+        .spawn(ctx);
+    }
+
+    /// 파티 상태 갱신 (PartyState 메시지 수신 시)
+    pub(crate) fn set_party(&mut self, party_id: Option<Uuid>) {
+        self.party_id = party_id;
+    }
+
+    /// 솔로 PvP(Normal/Ranked) 대기열은 `/game` 의 MatchCoordinator 로만 진입 가능
+    ///
+    /// 두 경로로 같은 플레이어가 중복 대기하지 않도록 `/ws/` 에서는 파티 대기열만 허용한다.
+    /// 허용되면 true, 거부하고 에러를 보냈으면 false
+    fn allow_session_queue(&self, ctx: &mut Ctx, game_mode: GameMode) -> bool {
+        if matches!(game_mode, GameMode::Normal | GameMode::Ranked) {
+            self.send_error(
+                ctx,
+                ErrorCode::InvalidGameMode,
+                "Solo PvP queue is only available on /game",
+            );
+            return false;
+        }
+        true
+    }
+
+    fn party_matchmaker(&self) -> Option<Addr<PartyMatchmaker>> {
+        self.app_state
+            .matchmakers
+            .get(&GameMode::Party)
+            .and_then(|handle| handle.party().cloned())
+    }
+
     fn resolve_matchmaker(&mut self, game_mode: GameMode) -> Result<MatchmakerAddr, ErrorCode> {
         if let Some(existing) = self.matchmaker_addr.get() {
             return Ok(existing.clone());
@@ -332,6 +426,13 @@ impl Session {
         Ok(handle)
     }
 
+    /// 인증된 세션이면 메시지의 player_id 가 토큰의 플레이어인지 확인
+    pub(crate) fn is_authorized_player(&self, player_id: Uuid) -> bool {
+        self.auth
+            .as_ref()
+            .is_none_or(|auth| auth.player_id == player_id)
+    }
+
     /// Send error to client via WebSocket and publish to Redis event stream for tests
     fn send_error(&self, ctx: &mut Ctx, code: ErrorCode, message: &str) {
         // Publish to Redis event stream first (before moving code)
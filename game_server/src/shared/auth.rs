@@ -0,0 +1,333 @@
+//! auth_server 가 발급한 JWT 검증
+//!
+//! 토큰은 WebSocket 업그레이드 요청에서 다음 순서로 찾는다.
+//! - `Authorization: Bearer <token>`
+//! - `Sec-WebSocket-Protocol: bearer, <token>` (헤더를 지정할 수 없는 브라우저 클라이언트용)
+//!
+//! 토큰의 `sub` 는 SteamID64 이며, 게임 서버의 player_id 는
+//! `Uuid::from_u64_pair(0, steam_id)` 로 변환해 사용한다 (`sub` 가 UUID 면 그대로 사용).
+//!
+//! 서명/만료 검증 후 auth_server 가 Redis 에 기록한 폐기 목록을 MGET 한 번으로 확인한다.
+//! - `auth:revoked:jti:{jti}`: 로그아웃한 토큰
+//! - `auth:revoked:sub:{sub}`: 값(unix 초) 이전에 발급된 플레이어의 모든 토큰 (재사용 감지, 밴)
+//!
+//! Redis 오류 시에는 서명 검증 결과만으로 통과시킨다 (액세스 토큰 수명이 짧음).
+
+use std::time::Duration;
+
+use actix_web::HttpRequest;
+use jsonwebtoken::{decode, errors::ErrorKind, Algorithm, DecodingKey, Validation};
+use redis::aio::ConnectionManager;
+use serde::Deserialize;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::env::JwtSettings;
+use crate::matchmaking::matchmaker::operations::with_redis_timeout;
+use crate::shared::protocol::ErrorCode;
+
+/// 토큰 전달용 WebSocket 서브프로토콜 이름 (서버가 응답에 그대로 돌려준다)
+pub const WS_AUTH_PROTOCOL: &str = "bearer";
+
+/// auth_server 의 Claims 와 같은 형식
+#[derive(Debug, Deserialize)]
+struct Claims {
+    sub: String,
+    exp: u64,
+    #[serde(default)]
+    iat: u64,
+    #[serde(default)]
+    jti: Option<String>,
+}
+
+pub fn revoked_jti_key(jti: &str) -> String {
+    format!("auth:revoked:jti:{}", jti)
+}
+
+pub fn revoked_subject_key(subject: &str) -> String {
+    format!("auth:revoked:sub:{}", subject)
+}
+
+/// 검증된 연결의 플레이어
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthenticatedPlayer {
+    pub player_id: Uuid,
+    /// 토큰의 sub (SteamID64)
+    pub subject: String,
+    /// 만료 시각 (unix 초)
+    pub expires_at: u64,
+    /// 발급 시각 (unix 초)
+    pub issued_at: u64,
+    pub jti: Option<String>,
+}
+
+impl AuthenticatedPlayer {
+    /// 만료까지 남은 시간
+    pub fn remaining(&self) -> Duration {
+        let now = chrono::Utc::now().timestamp().max(0) as u64;
+        Duration::from_secs(self.expires_at.saturating_sub(now))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum AuthError {
+    /// 토큰 없음 (jwt.required 일 때만 에러)
+    Missing,
+    Expired,
+    Invalid(String),
+    /// 로그아웃 / 밴 등으로 폐기된 토큰
+    Revoked,
+    /// sub 를 player_id 로 변환할 수 없음
+    InvalidSubject(String),
+}
+
+impl From<&AuthError> for ErrorCode {
+    fn from(error: &AuthError) -> Self {
+        match error {
+            AuthError::Expired => ErrorCode::TokenExpired,
+            AuthError::Missing
+            | AuthError::Invalid(_)
+            | AuthError::Revoked
+            | AuthError::InvalidSubject(_) => ErrorCode::Unauthorized,
+        }
+    }
+}
+
+/// SteamID64 (또는 UUID) → player_id
+pub fn player_id_from_subject(subject: &str) -> Option<Uuid> {
+    if let Ok(steam_id) = subject.parse::<u64>() {
+        return Some(Uuid::from_u64_pair(0, steam_id));
+    }
+    Uuid::parse_str(subject).ok()
+}
+
+/// 업그레이드 요청에서 토큰 추출 (서브프로토콜로 받았으면 true)
+pub fn extract_token(req: &HttpRequest) -> Option<(String, bool)> {
+    if let Some(token) = req
+        .headers()
+        .get("Authorization")
+        .and_then(|h| h.to_str().ok())
+        .and_then(|s| s.strip_prefix("Bearer "))
+    {
+        return Some((token.trim().to_string(), false));
+    }
+
+    let protocols: Vec<&str> = req
+        .headers()
+        .get_all("Sec-WebSocket-Protocol")
+        .filter_map(|h| h.to_str().ok())
+        .flat_map(|s| s.split(','))
+        .map(str::trim)
+        .collect();
+    if !protocols.contains(&WS_AUTH_PROTOCOL) {
+        return None;
+    }
+    protocols
+        .into_iter()
+        .find(|p| *p != WS_AUTH_PROTOCOL && !p.is_empty())
+        .map(|token| (token.to_string(), true))
+}
+
+/// JWT 검증기 (AppState 에서 공유)
+pub struct TokenVerifier {
+    key: DecodingKey,
+    validation: Validation,
+    required: bool,
+    /// 폐기 목록 확인용 Redis 와 타임아웃 (초)
+    revocation: Option<(ConnectionManager, u64)>,
+}
+
+impl TokenVerifier {
+    pub fn new(settings: &JwtSettings) -> Self {
+        let mut validation = Validation::new(Algorithm::HS256);
+        validation.leeway = settings.leeway_seconds;
+        validation.set_required_spec_claims(&["sub", "exp"]);
+
+        Self {
+            key: DecodingKey::from_secret(settings.secret.as_bytes()),
+            validation,
+            required: settings.required,
+            revocation: None,
+        }
+    }
+
+    /// 폐기 목록 확인 활성화 (jwt.check_revocation 이 꺼져 있으면 그대로)
+    pub fn with_revocation(
+        mut self,
+        settings: &JwtSettings,
+        redis: ConnectionManager,
+        timeout_secs: u64,
+    ) -> Self {
+        if settings.check_revocation {
+            self.revocation = Some((redis, timeout_secs));
+        }
+        self
+    }
+
+    pub fn verify(&self, token: &str) -> Result<AuthenticatedPlayer, AuthError> {
+        let data =
+            decode::<Claims>(token, &self.key, &self.validation).map_err(|e| match e.kind() {
+                ErrorKind::ExpiredSignature => AuthError::Expired,
+                _ => AuthError::Invalid(e.to_string()),
+            })?;
+        let claims = data.claims;
+        let player_id = player_id_from_subject(&claims.sub)
+            .ok_or_else(|| AuthError::InvalidSubject(claims.sub.clone()))?;
+
+        Ok(AuthenticatedPlayer {
+            player_id,
+            subject: claims.sub,
+            expires_at: claims.exp,
+            issued_at: claims.iat,
+            jti: claims.jti,
+        })
+    }
+
+    /// 폐기 목록 확인 (비활성화 또는 Redis 오류 시 false)
+    pub async fn is_revoked(&self, player: &AuthenticatedPlayer) -> bool {
+        let Some((redis, timeout_secs)) = &self.revocation else {
+            return false;
+        };
+        let mut redis = redis.clone();
+        let jti_key = revoked_jti_key(player.jti.as_deref().unwrap_or_default());
+
+        let result: Result<(Option<i64>, Option<i64>), String> =
+            with_redis_timeout("token_revocation", *timeout_secs, async {
+                redis::cmd("MGET")
+                    .arg(&jti_key)
+                    .arg(revoked_subject_key(&player.subject))
+                    .query_async(&mut redis)
+                    .await
+            })
+            .await;
+
+        match result {
+            Ok((jti, revoked_before)) => {
+                (player.jti.is_some() && jti.is_some())
+                    || revoked_before.is_some_and(|at| player.issued_at as i64 <= at)
+            }
+            Err(e) => {
+                warn!("Token revocation check skipped: {}", e);
+                false
+            }
+        }
+    }
+
+    /// 업그레이드 요청 인증
+    ///
+    /// 토큰이 없으면 jwt.required 가 꺼져 있을 때만 Ok(None) (인증 없는 개발/테스트 연결).
+    /// 두 번째 값은 응답에 서브프로토콜을 돌려줘야 하는지 여부다.
+    pub async fn authenticate(
+        &self,
+        req: &HttpRequest,
+    ) -> Result<(Option<AuthenticatedPlayer>, bool), AuthError> {
+        match extract_token(req) {
+            Some((token, via_protocol)) => {
+                let player = self.verify(&token)?;
+                if self.is_revoked(&player).await {
+                    return Err(AuthError::Revoked);
+                }
+                Ok((Some(player), via_protocol))
+            }
+            None if self.required => Err(AuthError::Missing),
+            None => Ok((None, false)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::test::TestRequest;
+    use jsonwebtoken::{encode, EncodingKey, Header};
+    use serde::Serialize;
+
+    const SECRET: &str = "test-secret";
+
+    #[derive(Serialize)]
+    struct TestClaims<'a> {
+        sub: &'a str,
+        iat: u64,
+        exp: u64,
+    }
+
+    fn verifier(required: bool) -> TokenVerifier {
+        TokenVerifier::new(&JwtSettings {
+            secret: SECRET.to_string(),
+            required,
+            leeway_seconds: 0,
+            check_revocation: false,
+        })
+    }
+
+    fn token(sub: &str, exp_offset: i64) -> String {
+        let now = chrono::Utc::now().timestamp();
+        let claims = TestClaims {
+            sub,
+            iat: now as u64,
+            exp: (now + exp_offset) as u64,
+        };
+        encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(SECRET.as_bytes()),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_verify_maps_steam_id_to_player_id() {
+        let player = verifier(true)
+            .verify(&token("76561198000000001", 3600))
+            .unwrap();
+        assert_eq!(player.player_id, Uuid::from_u64_pair(0, 76561198000000001));
+        assert!(player.remaining() > Duration::from_secs(3500));
+    }
+
+    #[test]
+    fn test_verify_rejects_expired_and_tampered_tokens() {
+        let verifier = verifier(true);
+        assert_eq!(verifier.verify(&token("1", -60)), Err(AuthError::Expired));
+
+        let mut tampered = token("1", 3600);
+        tampered.push('x');
+        assert!(matches!(
+            verifier.verify(&tampered),
+            Err(AuthError::Invalid(_))
+        ));
+        assert!(matches!(
+            verifier.verify(&token("not-a-player", 3600)),
+            Err(AuthError::InvalidSubject(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_reads_header_or_subprotocol() {
+        let verifier = verifier(true);
+        let jwt = token("42", 3600);
+
+        let req = TestRequest::default()
+            .insert_header(("Authorization", format!("Bearer {}", jwt)))
+            .to_http_request();
+        let (player, via_protocol) = verifier.authenticate(&req).await.unwrap();
+        assert_eq!(player.unwrap().subject, "42");
+        assert!(!via_protocol);
+
+        let req = TestRequest::default()
+            .insert_header(("Sec-WebSocket-Protocol", format!("bearer, {}", jwt)))
+            .to_http_request();
+        let (player, via_protocol) = verifier.authenticate(&req).await.unwrap();
+        assert_eq!(player.unwrap().subject, "42");
+        assert!(via_protocol);
+
+        let anonymous = TestRequest::default().to_http_request();
+        assert_eq!(
+            verifier.authenticate(&anonymous).await,
+            Err(AuthError::Missing)
+        );
+        assert_eq!(
+            self::verifier(false).authenticate(&anonymous).await,
+            Ok((None, false))
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use game_core::game::data::registry::DataRegistry;
+use serde_json::json;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info, warn};
+
+use crate::AppState;
+
+/// 게임 데이터 핫 리로드
+///
+/// 레지스트리를 다시 로드해 새 버전을 게시한다. 실패하면 기존 버전을 유지하고
+/// 발견된 문제를 모두 로그로 남긴다. 진행 중인 런은 자신이 고정한 버전을 계속 사용한다.
+pub fn reload_game_data(registry: &DataRegistry, trigger: &str) -> Result<u64, Vec<String>> {
+    match registry.reload() {
+        Ok(version) => {
+            info!("Game data reloaded ({}): version={}", trigger, version.id);
+            Ok(version.id)
+        }
+        Err(e) => {
+            let issues: Vec<String> = e.issues.iter().map(|issue| issue.to_string()).collect();
+            for issue in &issues {
+                error!("Game data reload rejected ({}): {}", trigger, issue);
+            }
+            Err(issues)
+        }
+    }
+}
+
+/// 데이터 파일 변경 감시 (polling)
+///
+/// `interval` 마다 데이터 디렉토리/밸런스 파일의 수정 시각을 확인해서
+/// 변경되었으면 리로드한다.
+pub fn spawn_data_watcher(
+    registry: Arc<DataRegistry>,
+    interval: Duration,
+    shutdown_token: CancellationToken,
+) {
+    tokio::spawn(async move {
+        let mut last_modified = registry.latest_modified();
+        let mut ticker = tokio::time::interval(interval);
+        ticker.tick().await;
+
+        loop {
+            tokio::select! {
+                _ = shutdown_token.cancelled() => {
+                    info!("Game data watcher stopped");
+                    break;
+                }
+                _ = ticker.tick() => {
+                    let modified = registry.latest_modified();
+                    if modified > last_modified {
+                        last_modified = modified;
+                        let _ = reload_game_data(&registry, "file watcher");
+                    }
+                }
+            }
+        }
+    });
+    info!("Game data watcher started (interval: {:?})", interval);
+}
+
+/// 관리자 API 인증 실패 사유
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AdminAuthError {
+    /// admin_token 미설정 (관리자 API 비활성화)
+    Disabled,
+    /// 토큰 누락 또는 불일치
+    InvalidToken,
+}
+
+impl AdminAuthError {
+    fn into_response(self) -> HttpResponse {
+        match self {
+            Self::Disabled => HttpResponse::Forbidden().body("Admin API disabled"),
+            Self::InvalidToken => {
+                HttpResponse::Unauthorized().body("Unauthorized: Invalid or missing token")
+            }
+        }
+    }
+}
+
+/// 관리자 토큰 검증 (admin_token 미설정 시 관리자 API 비활성화)
+fn authorize_admin(req: &HttpRequest, state: &AppState) -> Result<(), AdminAuthError> {
+    let Some(expected_token) = &state.settings.server.admin_token else {
+        return Err(AdminAuthError::Disabled);
+    };
+
+    let provided_token = req
+        .headers()
+        .get("Authorization")
+        .and_then(|h| h.to_str().ok())
+        .and_then(|s| s.strip_prefix("Bearer "));
+
+    match provided_token {
+        Some(token) if constant_time_eq(token.as_bytes(), expected_token.as_bytes()) => Ok(()),
+        _ => {
+            warn!("Rejected admin request: invalid or missing token");
+            Err(AdminAuthError::InvalidToken)
+        }
+    }
+}
+
+/// 길이가 같으면 내용과 무관하게 같은 시간이 걸리는 비교 (토큰 추측 방지)
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// GET /admin/data/version
+pub async fn data_version_route(req: HttpRequest, state: web::Data<AppState>) -> HttpResponse {
+    if let Err(e) = authorize_admin(&req, &state) {
+        return e.into_response();
+    }
+
+    let version = state.data_registry.current();
+    let loaded_at = version
+        .loaded_at
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+
+    HttpResponse::Ok().json(json!({
+        "version": version.id,
+        "loaded_at": loaded_at,
+        "data_dir": state.data_registry.data_dir().display().to_string(),
+    }))
+}
+
+/// POST /admin/data/reload
+pub async fn reload_data_route(req: HttpRequest, state: web::Data<AppState>) -> HttpResponse {
+    if let Err(e) = authorize_admin(&req, &state) {
+        return e.into_response();
+    }
+
+    match reload_game_data(&state.data_registry, "admin") {
+        Ok(version) => HttpResponse::Ok().json(json!({
+            "version": version,
+            "reloaded_at": SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default(),
+        })),
+        Err(issues) => HttpResponse::UnprocessableEntity().json(json!({
+            "version": state.data_registry.current_id(),
+            "issues": issues,
+        })),
+    }
+}
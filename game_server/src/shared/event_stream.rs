@@ -3,6 +3,7 @@ use actix_web_actors::ws;
 use chrono::Utc;
 use futures_util::StreamExt;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 use tokio::sync::mpsc;
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
@@ -45,10 +46,12 @@ pub struct EventStreamSession {
     session_id: Option<String>,
     /// 내부 메시지 수신용 채널
     event_rx: Option<mpsc::UnboundedReceiver<EventStreamMessage>>,
+    /// JWT 만료까지 남은 시간 (지나면 연결 종료)
+    token_ttl: Option<Duration>,
 }
 
 impl EventStreamSession {
-    pub fn new(session_id: Option<String>) -> Self {
+    pub fn new(session_id: Option<String>, token_ttl: Option<Duration>) -> Self {
         let (tx, rx) = mpsc::unbounded_channel();
 
         // Pub/Sub 구독을 별도 tokio 태스크에서 실행
@@ -80,6 +83,7 @@ impl EventStreamSession {
         Self {
             session_id,
             event_rx: Some(rx),
+            token_ttl,
         }
     }
 
@@ -163,6 +167,14 @@ impl Actor for EventStreamSession {
     fn started(&mut self, ctx: &mut Self::Context) {
         info!("Event stream WebSocket session started");
 
+        if let Some(ttl) = self.token_ttl {
+            ctx.run_later(ttl, |_act, ctx| {
+                info!("Event stream token expired, closing");
+                ctx.close(Some(ws::CloseCode::Policy.into()));
+                ctx.stop();
+            });
+        }
+
         // 채널에서 이벤트 수신하여 WebSocket으로 전송
         if let Some(mut rx) = self.event_rx.take() {
             ctx.add_stream(async_stream::stream! {
@@ -1,6 +1,16 @@
+use game_core::{
+    ecs::resources::{BattleRecord, EnkephalinChange, QliphothLevel},
+    game::{
+        battle::timeline::Timeline,
+        behavior::{BehaviorResult, GameError, PlayerBehavior},
+        enums::{EnkephalinFlow, OrdealType, RewardEventKind, RewardOutcome, RunEndReason},
+    },
+};
 use metrics::{BATTLE_RESULTS_LOCAL_TOTAL, BATTLE_RESULTS_REMOTE_TOTAL};
 use prometheus::IntCounter;
 
+use crate::game::player_game_actor::state::PlayerStateSnapshot;
+
 pub struct MetricsCtx {
     pub battle_results_local_total: IntCounter,
     pub battle_results_remote_total: IntCounter,
@@ -17,4 +27,262 @@ impl MetricsCtx {
     pub fn inc_redis_connection_failure(&self) {
         metrics::REDIS_CONNECTION_FAILURES_TOTAL.inc();
     }
+
+    // ============================================================
+    // 게임플레이 메트릭 (라벨은 고정된 enum 이름만 사용)
+    // ============================================================
+
+    /// 행동 1회 처리 결과를 게임플레이 메트릭에 반영
+    pub fn observe_behavior(&self, observed: &BehaviorObservation) {
+        let BehaviorObservation {
+            behavior,
+            result,
+            before,
+            after,
+            battles,
+            timeline,
+            enkephalin,
+        } = observed;
+
+        let started = result.is_start_new_game();
+        if started {
+            metrics::RUNS_STARTED_TOTAL.inc();
+        }
+        if let Some(ordeal) = after.ordeal {
+            if started || before.ordeal != after.ordeal {
+                metrics::ORDEALS_REACHED_TOTAL
+                    .with_label_values(&[ordeal_label(ordeal)])
+                    .inc();
+            }
+        }
+        if let Some(summary) = result.as_run_ended() {
+            metrics::RUNS_ENDED_TOTAL
+                .with_label_values(&[
+                    ordeal_label(summary.reached_ordeal),
+                    end_reason_label(summary.end_reason),
+                ])
+                .inc();
+        }
+
+        for battle in battles.iter() {
+            metrics::GAME_BATTLES_TOTAL
+                .with_label_values(&[
+                    battle_kind_label(battle.kind),
+                    outcome_label(battle.outcome),
+                ])
+                .inc();
+        }
+        // 타임라인은 이번 행동의 마지막 전투 것만 남는다
+        if let (Some(battle), Some(timeline)) = (battles.last(), timeline) {
+            metrics::GAME_BATTLE_DURATION_SECONDS
+                .with_label_values(&[battle_kind_label(battle.kind)])
+                .observe(timeline.duration_ms() as f64 / 1000.0);
+        }
+
+        // 행동 1회에 획득과 소비가 함께 일어날 수 있으므로 증감마다 따로 반영
+        for change in enkephalin.iter() {
+            match *change {
+                EnkephalinChange::Earned(flow, amount) => metrics::ENKEPHALIN_EARNED_TOTAL
+                    .with_label_values(&[enkephalin_flow_label(flow)])
+                    .inc_by(u64::from(amount)),
+                EnkephalinChange::Spent(flow, amount) => metrics::ENKEPHALIN_SPENT_TOTAL
+                    .with_label_values(&[enkephalin_flow_label(flow)])
+                    .inc_by(u64::from(amount)),
+            }
+        }
+
+        match behavior {
+            PlayerBehavior::PurchaseItem { .. } => metrics::SHOP_PURCHASES_TOTAL
+                .with_label_values(&[snapshot_ordeal_label(after)])
+                .inc(),
+            PlayerBehavior::RerollShop => metrics::SHOP_REROLLS_TOTAL
+                .with_label_values(&[snapshot_ordeal_label(after)])
+                .inc(),
+            _ => {}
+        }
+
+        if let (Some(from), Some(to)) = (before.qliphoth_level, after.qliphoth_level) {
+            if from != to {
+                metrics::QLIPHOTH_LEVEL_TRANSITIONS_TOTAL
+                    .with_label_values(&[qliphoth_level_label(from), qliphoth_level_label(to)])
+                    .inc();
+            }
+        }
+    }
+
+    /// GameCore 가 거부한 행동
+    pub fn inc_game_error(&self, error: &GameError) {
+        metrics::GAME_ERRORS_TOTAL
+            .with_label_values(&[game_error_label(error)])
+            .inc();
+    }
+
+    /// 끝나지 않은 채 정리된 런 (재접속 유예 만료 / idle 타임아웃)
+    pub fn inc_run_abandoned(&self, ordeal: OrdealType) {
+        metrics::RUNS_ENDED_TOTAL
+            .with_label_values(&[ordeal_label(ordeal), "abandoned"])
+            .inc();
+    }
+}
+
+/// 행동 1회 처리 전후 관측값
+pub struct BehaviorObservation<'a> {
+    pub behavior: &'a PlayerBehavior,
+    pub result: &'a BehaviorResult,
+    pub before: &'a PlayerStateSnapshot,
+    pub after: &'a PlayerStateSnapshot,
+    /// 이번 행동으로 새로 기록된 전투
+    pub battles: &'a [BattleRecord],
+    pub timeline: Option<&'a Timeline>,
+    /// 이번 행동으로 기록된 엔케팔린 증감 (GameCore::take_enkephalin_changes)
+    pub enkephalin: &'a [EnkephalinChange],
+}
+
+fn ordeal_label(ordeal: OrdealType) -> &'static str {
+    match ordeal {
+        OrdealType::Dawn => "dawn",
+        OrdealType::Noon => "noon",
+        OrdealType::Dusk => "dusk",
+        OrdealType::Midnight => "midnight",
+        OrdealType::White => "white",
+    }
+}
+
+fn snapshot_ordeal_label(snapshot: &PlayerStateSnapshot) -> &'static str {
+    snapshot.ordeal.map_or("none", ordeal_label)
+}
+
+fn end_reason_label(reason: RunEndReason) -> &'static str {
+    match reason {
+        RunEndReason::Cleared => "cleared",
+        RunEndReason::OrdealLossLimit => "ordeal_loss_limit",
+        RunEndReason::Meltdown => "meltdown",
+    }
+}
+
+fn battle_kind_label(kind: RewardEventKind) -> &'static str {
+    match kind {
+        RewardEventKind::Suppression => "suppression",
+        RewardEventKind::Ordeal => "ordeal",
+        RewardEventKind::WhiteNights => "white_nights",
+    }
+}
+
+fn outcome_label(outcome: RewardOutcome) -> &'static str {
+    match outcome {
+        RewardOutcome::Win => "win",
+        RewardOutcome::Loss => "loss",
+    }
+}
+
+fn qliphoth_level_label(level: QliphothLevel) -> &'static str {
+    match level {
+        QliphothLevel::Stable => "stable",
+        QliphothLevel::Caution => "caution",
+        QliphothLevel::Critical => "critical",
+        QliphothLevel::Meltdown => "meltdown",
+    }
+}
+
+fn enkephalin_flow_label(flow: EnkephalinFlow) -> &'static str {
+    match flow {
+        EnkephalinFlow::Battle => "battle",
+        EnkephalinFlow::ShopPurchase => "shop_purchase",
+        EnkephalinFlow::ShopSell => "shop_sell",
+        EnkephalinFlow::Bonus => "bonus",
+        EnkephalinFlow::RandomEvent => "random_event",
+    }
+}
+
+/// GameError variant 이름 (메시지 내용은 라벨에 넣지 않음)
+fn game_error_label(error: &GameError) -> &'static str {
+    match error {
+        GameError::EventNotFound => "event_not_found",
+        GameError::EventTypeMismatch => "event_type_mismatch",
+        GameError::InvalidAction => "invalid_action",
+        GameError::NotInShopState => "not_in_shop_state",
+        GameError::NotInBonusState => "not_in_bonus_state",
+        GameError::NotInRandomEventState => "not_in_random_event_state",
+        GameError::EventChoiceNotFound => "event_choice_not_found",
+        GameError::ShopRerollNotAllowed => "shop_reroll_not_allowed",
+        GameError::ShopItemNotFound => "shop_item_not_found",
+        GameError::InventoryFull => "inventory_full",
+        GameError::InventoryItemNotFound => "inventory_item_not_found",
+        GameError::InsufficientResources => "insufficient_resources",
+        GameError::PhaseNotReady => "phase_not_ready",
+        GameError::MissingResource(_) => "missing_resource",
+        GameError::InvalidUnitStats(_) => "invalid_unit_stats",
+        GameError::OutOfBounds => "out_of_bounds",
+        GameError::PositionOccupied => "position_occupied",
+        GameError::UnitAlreadyPlaced => "unit_already_placed",
+        GameError::UnitNotFound => "unit_not_found",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use game_core::ecs::resources::GameState;
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn shop_snapshot(enkephalin: u32, qliphoth_level: QliphothLevel) -> PlayerStateSnapshot {
+        PlayerStateSnapshot {
+            state: GameState::InShop {
+                shop_uuid: Uuid::nil(),
+            },
+            enkephalin,
+            ordeal: Some(OrdealType::Noon),
+            phase: None,
+            qliphoth: Some(7),
+            qliphoth_level: Some(qliphoth_level),
+            level: 1,
+            win_count: 0,
+            allowed_actions: Vec::new(),
+            data_version: 1,
+        }
+    }
+
+    #[test]
+    fn test_observe_behavior_uses_bounded_labels() {
+        let spent = metrics::ENKEPHALIN_SPENT_TOTAL.with_label_values(&["shop_purchase"]);
+        let earned = metrics::ENKEPHALIN_EARNED_TOTAL.with_label_values(&["random_event"]);
+        let purchases = metrics::SHOP_PURCHASES_TOTAL.with_label_values(&["noon"]);
+        let transitions =
+            metrics::QLIPHOTH_LEVEL_TRANSITIONS_TOTAL.with_label_values(&["stable", "caution"]);
+        let (spent_before, earned_before, purchases_before, transitions_before) = (
+            spent.get(),
+            earned.get(),
+            purchases.get(),
+            transitions.get(),
+        );
+
+        let ctx = MetricsCtx::new();
+        ctx.observe_behavior(&BehaviorObservation {
+            behavior: &PlayerBehavior::PurchaseItem {
+                item_uuid: Uuid::nil(),
+            },
+            result: &BehaviorResult::Ok,
+            before: &shop_snapshot(300, QliphothLevel::Stable),
+            after: &shop_snapshot(200, QliphothLevel::Caution),
+            battles: &[],
+            timeline: None,
+            // 잔액 변화(-100)가 아니라 출처별 증감을 그대로 반영
+            enkephalin: &[
+                EnkephalinChange::Spent(EnkephalinFlow::ShopPurchase, 150),
+                EnkephalinChange::Earned(EnkephalinFlow::RandomEvent, 50),
+            ],
+        });
+
+        assert_eq!(spent.get() - spent_before, 150);
+        assert_eq!(earned.get() - earned_before, 50);
+        assert_eq!(purchases.get() - purchases_before, 1);
+        assert_eq!(transitions.get() - transitions_before, 1);
+
+        // 메시지가 붙는 variant 도 variant 이름만 라벨로 사용
+        assert_eq!(
+            game_error_label(&GameError::MissingResource("Inventory")),
+            "missing_resource"
+        );
+    }
 }
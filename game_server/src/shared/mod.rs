@@ -1,6 +1,10 @@
 // Shared infrastructure modules
+pub mod auth;
 pub mod circuit_breaker;
+pub mod data_reload;
 pub mod event_stream;
 pub mod metrics;
 pub mod protocol;
+pub mod rate_limit;
 pub mod redis_events;
+pub mod run_report;
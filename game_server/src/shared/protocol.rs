@@ -1,7 +1,17 @@
 use actix::prelude::*;
+use game_core::{
+    ecs::resources::{InventoryDiffDto, Position},
+    game::{
+        battle::timeline::Timeline,
+        behavior::{BehaviorResult, GameError, PlayerBehavior},
+        enums::{PhaseEvent, ZoneType},
+        managers::run_manager::RunSummary,
+    },
+};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::game::player_game_actor::state::{PlayerStateSnapshot, StateDiff};
 use crate::GameMode;
 
 // --- Client to Server Messages ---
@@ -24,6 +34,54 @@ pub enum ClientMessage {
         player_id: Uuid,
         game_mode: GameMode,
     },
+
+    /// 현재 파티 상태를 요청합니다. (세션을 player_id 에 연결해 파티 알림을 받을 수 있게 함)
+    #[serde(rename = "party_sync")]
+    PartySync { player_id: Uuid },
+
+    /// 다른 플레이어를 파티에 초대합니다. 파티가 없으면 요청자를 리더로 새 파티를 만듭니다.
+    #[serde(rename = "party_invite")]
+    PartyInvite {
+        player_id: Uuid,
+        target_player_id: Uuid,
+    },
+
+    /// 받은 초대를 수락합니다. metadata 는 파티 enqueue 시 이 플레이어의 매칭 metadata 로 사용됩니다.
+    #[serde(rename = "party_accept")]
+    PartyAccept {
+        player_id: Uuid,
+        party_id: Uuid,
+        metadata: String,
+    },
+
+    /// 파티에서 나갑니다. 파티가 대기열에 있으면 파티 전체가 dequeue 됩니다.
+    #[serde(rename = "party_leave")]
+    PartyLeave { player_id: Uuid },
+}
+
+impl ClientMessage {
+    /// 메시지 type (속도 제한 규칙 키)
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ClientMessage::Enqueue { .. } => "enqueue",
+            ClientMessage::Dequeue { .. } => "dequeue",
+            ClientMessage::PartySync { .. } => "party_sync",
+            ClientMessage::PartyInvite { .. } => "party_invite",
+            ClientMessage::PartyAccept { .. } => "party_accept",
+            ClientMessage::PartyLeave { .. } => "party_leave",
+        }
+    }
+
+    pub fn player_id(&self) -> Uuid {
+        match self {
+            ClientMessage::Enqueue { player_id, .. }
+            | ClientMessage::Dequeue { player_id, .. }
+            | ClientMessage::PartySync { player_id }
+            | ClientMessage::PartyInvite { player_id, .. }
+            | ClientMessage::PartyAccept { player_id, .. }
+            | ClientMessage::PartyLeave { player_id } => *player_id,
+        }
+    }
 }
 
 // --- Server to Client Messages ---
@@ -50,23 +108,48 @@ pub enum ServerMessage {
         battle_data: Option<serde_json::Value>,
     },
 
+    /// 파티 초대를 받았음을 알립니다.
+    #[serde(rename = "party_invited")]
+    PartyInvited { party_id: Uuid, leader_id: Uuid },
+
+    /// 파티 상태가 바뀌었음을 알립니다. (파티가 없으면 None)
+    #[serde(rename = "party_state")]
+    PartyState { party: Option<PartyInfo> },
+
+    /// 게임 런이 다른 연결(다른 pod 포함)로 옮겨져 이 연결은 종료됨을 알립니다.
+    #[serde(rename = "run_migrated")]
+    RunMigrated { pod_id: String },
+
     /// 에러가 발생했음을 알립니다.
     #[serde(rename = "error")]
     Error { code: ErrorCode, message: String },
 }
 
+/// 파티 정보
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct PartyInfo {
+    pub party_id: Uuid,
+    pub leader_id: Uuid,
+    /// 가입 순서
+    pub members: Vec<Uuid>,
+    pub in_queue: bool,
+}
+
 impl ServerMessage {
     pub fn to_string(&self) -> String {
         match &self {
             ServerMessage::EnQueued { .. } => "player.enqueued".to_string(),
             ServerMessage::DeQueued => "player.dequeued".to_string(),
             ServerMessage::MatchFound { .. } => "player.match_found".to_string(),
+            ServerMessage::PartyInvited { .. } => "player.party_invited".to_string(),
+            ServerMessage::PartyState { .. } => "player.party_state".to_string(),
+            ServerMessage::RunMigrated { .. } => "player.run_migrated".to_string(),
             ServerMessage::Error { .. } => "player.error".to_string(),
         }
     }
 }
 
-#[derive(Serialize, Deserialize, Message, Clone, Debug)]
+#[derive(Serialize, Deserialize, Message, Clone, Debug, PartialEq, Eq)]
 #[rtype(result = "()")]
 #[serde(rename_all = "snake_case")]
 pub enum ErrorCode {
@@ -84,4 +167,439 @@ pub enum ErrorCode {
     PlayerTemporarilyBlocked,
     RateLimitExceeded,
     InvalidMetadata,
+
+    // --- 인증 ---
+    /// 토큰이 없거나 유효하지 않음
+    Unauthorized,
+    TokenExpired,
+    /// 메시지의 player_id 가 토큰의 플레이어와 다름
+    PlayerIdMismatch,
+
+    // --- 파티 ---
+    NotInParty,
+    AlreadyInParty,
+    NotPartyLeader,
+    PartyFull,
+    PartyInQueue,
+    PartyInviteNotFound,
+    InvalidPartyTarget,
+
+    // --- PvP 대기열 (/game) ---
+    /// 출전할 기물이 없음
+    EmptyBoard,
+    /// 이벤트 진행 중이거나 런이 끝나 대기열에 들어갈 수 없음
+    NotReadyForQueue,
+
+    // --- 게임 프로토콜 ---
+    UnsupportedProtocolVersion,
+    /// 재접속할 런이 없음 (만료되었거나 종료됨)
+    RunNotFound,
+    InvalidResumeToken,
+    /// 런을 시작한 데이터 revision 을 이 pod 가 로드하고 있지 않음 (다른 pod 로 재시도)
+    RunDataUnavailable,
+
+    // --- GameError 매핑 ---
+    EventNotFound,
+    EventTypeMismatch,
+    InvalidAction,
+    NotInShopState,
+    NotInBonusState,
+    NotInRandomEventState,
+    EventChoiceNotFound,
+    ShopRerollNotAllowed,
+    ShopItemNotFound,
+    InventoryFull,
+    InventoryItemNotFound,
+    InsufficientResources,
+    PhaseNotReady,
+    OutOfBounds,
+    PositionOccupied,
+    UnitAlreadyPlaced,
+    UnitNotFound,
+}
+
+impl From<&GameError> for ErrorCode {
+    fn from(error: &GameError) -> Self {
+        match error {
+            GameError::EventNotFound => ErrorCode::EventNotFound,
+            GameError::EventTypeMismatch => ErrorCode::EventTypeMismatch,
+            GameError::InvalidAction => ErrorCode::InvalidAction,
+            GameError::NotInShopState => ErrorCode::NotInShopState,
+            GameError::NotInBonusState => ErrorCode::NotInBonusState,
+            GameError::NotInRandomEventState => ErrorCode::NotInRandomEventState,
+            GameError::EventChoiceNotFound => ErrorCode::EventChoiceNotFound,
+            GameError::ShopRerollNotAllowed => ErrorCode::ShopRerollNotAllowed,
+            GameError::ShopItemNotFound => ErrorCode::ShopItemNotFound,
+            GameError::InventoryFull => ErrorCode::InventoryFull,
+            GameError::InventoryItemNotFound => ErrorCode::InventoryItemNotFound,
+            GameError::InsufficientResources => ErrorCode::InsufficientResources,
+            GameError::PhaseNotReady => ErrorCode::PhaseNotReady,
+            GameError::OutOfBounds => ErrorCode::OutOfBounds,
+            GameError::PositionOccupied => ErrorCode::PositionOccupied,
+            GameError::UnitAlreadyPlaced => ErrorCode::UnitAlreadyPlaced,
+            GameError::UnitNotFound => ErrorCode::UnitNotFound,
+            // 서버 내부 상태 문제는 클라이언트에 상세를 노출하지 않음
+            GameError::MissingResource(_) | GameError::InvalidUnitStats(_) => {
+                ErrorCode::InternalError
+            }
+        }
+    }
+}
+
+impl ErrorCode {
+    /// GameError 매핑 코드의 클라이언트용 고정 메시지 (내부 상세는 로그로만 남김)
+    pub fn game_error_message(&self) -> &'static str {
+        match self {
+            ErrorCode::EventNotFound => "Event not found",
+            ErrorCode::EventTypeMismatch => "Event type mismatch",
+            ErrorCode::InvalidAction => "Invalid action",
+            ErrorCode::NotInShopState => "Not in shop",
+            ErrorCode::NotInBonusState => "Not in bonus",
+            ErrorCode::NotInRandomEventState => "Not in random event",
+            ErrorCode::EventChoiceNotFound => "Event choice not found",
+            ErrorCode::ShopRerollNotAllowed => "Shop reroll not allowed",
+            ErrorCode::ShopItemNotFound => "Shop item not found",
+            ErrorCode::InventoryFull => "Inventory full",
+            ErrorCode::InventoryItemNotFound => "Inventory item not found",
+            ErrorCode::InsufficientResources => "Insufficient resources",
+            ErrorCode::PhaseNotReady => "Phase not ready",
+            ErrorCode::OutOfBounds => "Position out of bounds",
+            ErrorCode::PositionOccupied => "Position occupied",
+            ErrorCode::UnitAlreadyPlaced => "Unit already placed",
+            ErrorCode::UnitNotFound => "Unit not found",
+            _ => "Internal error",
+        }
+    }
+}
+
+// ============================================================
+// 게임 플레이 프로토콜 (/game)
+// ============================================================
+
+/// 현재 게임 프로토콜 버전 (호환되지 않는 변경 시 증가)
+pub const GAME_PROTOCOL_VERSION: u32 = 1;
+/// 서버가 아직 지원하는 가장 낮은 프로토콜 버전
+pub const MIN_GAME_PROTOCOL_VERSION: u32 = 1;
+
+/// 접속 시 클라이언트가 요청한 프로토콜 버전 협상
+///
+/// 지원 범위 안이면 요청한 버전을 그대로 사용한다.
+pub fn negotiate_protocol_version(requested: Option<u32>) -> Result<u32, ErrorCode> {
+    match requested {
+        Some(version) if (MIN_GAME_PROTOCOL_VERSION..=GAME_PROTOCOL_VERSION).contains(&version) => {
+            Ok(version)
+        }
+        _ => Err(ErrorCode::UnsupportedProtocolVersion),
+    }
+}
+
+// --- Client to Server Messages ---
+
+/// 게임 플레이 요청. Sync 와 대기열 메시지를 제외한 모든 메시지는 PlayerBehavior 로 변환된다.
+#[derive(Deserialize, Message, Debug)]
+#[rtype(result = "()")]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GameClientMessage {
+    StartGame,
+    RequestPhaseData,
+    SelectEvent {
+        event_id: Uuid,
+    },
+
+    PurchaseItem {
+        item_uuid: Uuid,
+    },
+    SellItem {
+        item_uuid: Uuid,
+    },
+    RerollShop,
+    ExitShop,
+
+    ClaimBonus,
+    ExitBonus,
+
+    SelectEventChoice {
+        choice_id: String,
+    },
+    ExitRandomEvent,
+
+    StartSuppression {
+        abnormality_id: String,
+    },
+
+    EquipItem {
+        item_uuid: Uuid,
+        target_unit: Uuid,
+    },
+    UnequipItem {
+        item_uuid: Uuid,
+        target_unit: Uuid,
+    },
+    MoveUnit {
+        target_unit_uuid: Uuid,
+        dest_pos: Position,
+    },
+    TransferUnit {
+        target_unit_uuid: Uuid,
+        dest_zone: ZoneType,
+    },
+
+    /// 현재 상태 전체 스냅샷 요청 (GameCore 상태 변경 없음)
+    Sync,
+
+    /// 현재 빌드로 PvP 대기열 진입 (MatchCoordinator 가 실시간/Ghost 매칭 선택)
+    EnterQueue {
+        game_mode: GameMode,
+    },
+    LeaveQueue,
+}
+
+impl GameClientMessage {
+    /// 메시지 type (속도 제한 규칙 키)
+    pub fn kind(&self) -> &'static str {
+        match self {
+            GameClientMessage::StartGame => "start_game",
+            GameClientMessage::RequestPhaseData => "request_phase_data",
+            GameClientMessage::SelectEvent { .. } => "select_event",
+            GameClientMessage::PurchaseItem { .. } => "purchase_item",
+            GameClientMessage::SellItem { .. } => "sell_item",
+            GameClientMessage::RerollShop => "reroll_shop",
+            GameClientMessage::ExitShop => "exit_shop",
+            GameClientMessage::ClaimBonus => "claim_bonus",
+            GameClientMessage::ExitBonus => "exit_bonus",
+            GameClientMessage::SelectEventChoice { .. } => "select_event_choice",
+            GameClientMessage::ExitRandomEvent => "exit_random_event",
+            GameClientMessage::StartSuppression { .. } => "start_suppression",
+            GameClientMessage::EquipItem { .. } => "equip_item",
+            GameClientMessage::UnequipItem { .. } => "unequip_item",
+            GameClientMessage::MoveUnit { .. } => "move_unit",
+            GameClientMessage::TransferUnit { .. } => "transfer_unit",
+            GameClientMessage::Sync => "sync",
+            GameClientMessage::EnterQueue { .. } => "enter_queue",
+            GameClientMessage::LeaveQueue => "leave_queue",
+        }
+    }
+
+    /// GameCore 에 전달할 행동으로 변환 (Sync / 대기열 메시지는 None)
+    pub fn into_behavior(self) -> Option<PlayerBehavior> {
+        let behavior = match self {
+            GameClientMessage::StartGame => PlayerBehavior::StartNewGame,
+            GameClientMessage::RequestPhaseData => PlayerBehavior::RequestPhaseData,
+            GameClientMessage::SelectEvent { event_id } => PlayerBehavior::SelectEvent { event_id },
+            GameClientMessage::PurchaseItem { item_uuid } => {
+                PlayerBehavior::PurchaseItem { item_uuid }
+            }
+            GameClientMessage::SellItem { item_uuid } => PlayerBehavior::SellItem { item_uuid },
+            GameClientMessage::RerollShop => PlayerBehavior::RerollShop,
+            GameClientMessage::ExitShop => PlayerBehavior::ExitShop,
+            GameClientMessage::ClaimBonus => PlayerBehavior::ClaimBonus,
+            GameClientMessage::ExitBonus => PlayerBehavior::ExitBonus,
+            GameClientMessage::SelectEventChoice { choice_id } => {
+                PlayerBehavior::SelectEventChoice { choice_id }
+            }
+            GameClientMessage::ExitRandomEvent => PlayerBehavior::ExitRandomEvent,
+            GameClientMessage::StartSuppression { abnormality_id } => {
+                PlayerBehavior::StartSuppression { abnormality_id }
+            }
+            GameClientMessage::EquipItem {
+                item_uuid,
+                target_unit,
+            } => PlayerBehavior::EquipItem {
+                item_uuid,
+                target_unit,
+            },
+            GameClientMessage::UnequipItem {
+                item_uuid,
+                target_unit,
+            } => PlayerBehavior::UnEquipItem {
+                item_uuid,
+                target_unit,
+            },
+            GameClientMessage::MoveUnit {
+                target_unit_uuid,
+                dest_pos,
+            } => PlayerBehavior::MoveUnit {
+                target_unit_uuid,
+                dest_pos,
+            },
+            GameClientMessage::TransferUnit {
+                target_unit_uuid,
+                dest_zone,
+            } => PlayerBehavior::TransferUnit {
+                target_unit_uuid,
+                dest_zone,
+            },
+            GameClientMessage::Sync
+            | GameClientMessage::EnterQueue { .. }
+            | GameClientMessage::LeaveQueue => return None,
+        };
+        Some(behavior)
+    }
+}
+
+// --- Server to Client Messages ---
+
+/// 게임 서버 → 클라이언트 메시지
+///
+/// Welcome/Resumed 를 제외한 모든 메시지에는 1 부터 증가하는 `seq` 필드가 붙는다.
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GameServerMessage {
+    /// 연결 직후 1회. 협상된 프로토콜 버전과 전체 상태
+    ///
+    /// resume_token 은 연결이 끊겼을 때 `/game?resume_token=..&last_seq=..` 로 재접속하는 데 쓴다.
+    Welcome {
+        protocol_version: u32,
+        server_protocol_version: u32,
+        state: PlayerStateSnapshot,
+        resume_token: String,
+    },
+
+    /// 재접속 직후 1회. state 가 있으면 전체 상태로 교체하고,
+    /// 없으면 뒤따라 오는 놓친 메시지(원래 seq 유지)를 순서대로 적용한다.
+    Resumed {
+        protocol_version: u32,
+        server_protocol_version: u32,
+        resume_token: String,
+        /// 서버가 마지막으로 보낸 seq
+        last_seq: u64,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        state: Option<PlayerStateSnapshot>,
+    },
+
+    /// Sync 요청에 대한 전체 상태
+    Snapshot { state: PlayerStateSnapshot },
+
+    /// 행동 처리 결과 + 상태 변경분
+    BehaviorResult {
+        result: Box<BehaviorResult>,
+        diff: StateDiff,
+    },
+
+    /// 현재 Phase 이벤트 (RequestPhaseData 처리 시 push)
+    PhaseEvents { event: Box<PhaseEvent> },
+
+    /// 인벤토리 변경분 (구매/판매/보너스 등)
+    InventoryDiff {
+        enkephalin: u32,
+        diff: InventoryDiffDto,
+    },
+
+    /// 전투 타임라인 (클라이언트 재생용)
+    BattleTimeline { timeline: Box<Timeline> },
+
+    /// 런 종료 (이후 행동은 거부됨)
+    RunEnded { summary: Box<RunSummary> },
+
+    /// 요청 처리 실패 (GameCore 상태는 변경되지 않음)
+    Error { code: ErrorCode, message: String },
+}
+
+impl GameServerMessage {
+    pub fn error(code: ErrorCode, message: impl Into<String>) -> Self {
+        GameServerMessage::Error {
+            code,
+            message: message.into(),
+        }
+    }
+
+    /// GameError → 클라이언트 에러 메시지
+    pub fn from_game_error(error: &GameError) -> Self {
+        let code = ErrorCode::from(error);
+        let message = code.game_error_message();
+        Self::error(code, message)
+    }
+
+    /// 행동 결과에 딸린 push 메시지 (phase_events / inventory_diff / run_ended)
+    pub fn pushes_for(result: &BehaviorResult) -> Vec<GameServerMessage> {
+        let mut pushes = Vec::new();
+
+        if let Some(event) = result.as_request_phase_data() {
+            pushes.push(GameServerMessage::PhaseEvents {
+                event: Box::new(event.clone()),
+            });
+        }
+
+        let inventory = result
+            .as_purchase_item()
+            .or_else(|| result.as_sell_item())
+            .or_else(|| result.as_bonus_reward());
+        if let Some((enkephalin, diff)) = inventory {
+            pushes.push(GameServerMessage::InventoryDiff {
+                enkephalin,
+                diff: diff.clone(),
+            });
+        }
+
+        if let Some(summary) = result.as_run_ended() {
+            pushes.push(GameServerMessage::RunEnded {
+                summary: Box::new(summary.clone()),
+            });
+        }
+
+        pushes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_negotiate_protocol_version() {
+        assert_eq!(
+            negotiate_protocol_version(Some(GAME_PROTOCOL_VERSION)),
+            Ok(GAME_PROTOCOL_VERSION)
+        );
+        assert_eq!(
+            negotiate_protocol_version(Some(GAME_PROTOCOL_VERSION + 1)),
+            Err(ErrorCode::UnsupportedProtocolVersion)
+        );
+        assert_eq!(
+            negotiate_protocol_version(None),
+            Err(ErrorCode::UnsupportedProtocolVersion)
+        );
+    }
+
+    #[test]
+    fn test_client_message_maps_to_behavior() {
+        let msg: GameClientMessage = serde_json::from_str(
+            r#"{"type":"unequip_item","item_uuid":"00000000-0000-0000-0000-000000000001","target_unit":"00000000-0000-0000-0000-000000000002"}"#,
+        )
+        .unwrap();
+        // 속도 제한 키는 메시지 type 과 같다
+        assert_eq!(msg.kind(), "unequip_item");
+        assert!(matches!(
+            msg.into_behavior(),
+            Some(PlayerBehavior::UnEquipItem { .. })
+        ));
+
+        let sync: GameClientMessage = serde_json::from_str(r#"{"type":"sync"}"#).unwrap();
+        assert_eq!(sync.kind(), "sync");
+        assert!(sync.into_behavior().is_none());
+
+        let enter: GameClientMessage =
+            serde_json::from_str(r#"{"type":"enter_queue","game_mode":"Ranked"}"#).unwrap();
+        assert_eq!(enter.kind(), "enter_queue");
+        assert!(matches!(
+            enter,
+            GameClientMessage::EnterQueue {
+                game_mode: GameMode::Ranked
+            }
+        ));
+    }
+
+    #[test]
+    fn test_game_error_maps_to_error_code() {
+        let msg = GameServerMessage::from_game_error(&GameError::InsufficientResources);
+        let json = serde_json::to_value(&msg).unwrap();
+        assert_eq!(json["type"], "error");
+        assert_eq!(json["code"], "insufficient_resources");
+        assert_eq!(json["message"], "Insufficient resources");
+
+        let msg = GameServerMessage::from_game_error(&GameError::MissingResource("Inventory"));
+        let json = serde_json::to_value(&msg).unwrap();
+        assert_eq!(json["code"], "internal_error");
+        assert_eq!(json["message"], "Internal error");
+    }
 }
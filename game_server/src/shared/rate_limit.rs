@@ -0,0 +1,266 @@
+//! 토큰 버킷 속도 제한
+//!
+//! - 연결 시도: IP 별 (`/ws/`, `/game` 업그레이드 전)
+//! - 메시지: 플레이어별, 메시지 `type` 마다 별도 버킷
+//! - 파싱할 수 없는 프레임: `type` 을 신뢰할 수 없으므로 고정된 `invalid` 버킷 하나
+//!
+//! `use_redis` 가 켜져 있으면 버킷을 Redis (`rate_limit:{scope}:{subject}`) 에 두어
+//! 모든 pod 가 같은 한도를 공유한다. Redis 오류 시에는 pod 로컬 버킷으로 판단한다.
+
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+use redis::{aio::ConnectionManager, Script};
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::env::{RateLimitRule, RateLimitSettings};
+use crate::matchmaking::matchmaker::{operations::with_redis_timeout, scripts};
+
+/// 파싱할 수 없는 프레임에 쓰는 메시지 종류 (`[rate_limit.messages] invalid` 로 한도 지정)
+pub const INVALID_MESSAGE_KIND: &str = "invalid";
+
+pub fn bucket_key(scope: &str, subject: &str) -> String {
+    format!("rate_limit:{}:{}", scope, subject)
+}
+
+struct TokenBucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(rule: RateLimitRule, now: Instant) -> Self {
+        Self {
+            tokens: rule.burst as f64,
+            last_refill: now,
+        }
+    }
+
+    /// 경과 시간만큼 회복한 뒤 토큰 1개 사용
+    fn try_take(&mut self, rule: RateLimitRule, now: Instant) -> bool {
+        let elapsed = now
+            .saturating_duration_since(self.last_refill)
+            .as_secs_f64();
+        self.tokens = (self.tokens + elapsed * rule.per_second).min(rule.burst as f64);
+        self.last_refill = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// 연결/메시지 속도 제한기 (AppState 에서 공유)
+pub struct RateLimiter {
+    settings: RateLimitSettings,
+    buckets: RwLock<HashMap<String, TokenBucket>>,
+    redis: Option<ConnectionManager>,
+    timeout_secs: u64,
+}
+
+impl RateLimiter {
+    /// # Arguments
+    /// * `redis` - settings.use_redis 일 때만 사용 (None 이면 pod 로컬 버킷)
+    pub fn new(
+        settings: RateLimitSettings,
+        redis: Option<ConnectionManager>,
+        timeout_secs: u64,
+    ) -> Self {
+        let redis = redis.filter(|_| settings.use_redis);
+        Self {
+            settings,
+            buckets: RwLock::new(HashMap::new()),
+            redis,
+            timeout_secs,
+        }
+    }
+
+    /// WebSocket 연결 시도 허용 여부 (IP 별)
+    pub async fn check_connection(&self, ip: &IpAddr) -> bool {
+        self.check(
+            "connection",
+            "connection",
+            &ip.to_string(),
+            self.settings.connection,
+        )
+        .await
+    }
+
+    /// 메시지 허용 여부 (플레이어 + 메시지 종류별)
+    pub async fn check_message(&self, player_id: Uuid, kind: &str) -> bool {
+        let subject = format!("{}:{}", player_id, kind);
+        self.check("message", kind, &subject, self.settings.message_rule(kind))
+            .await
+    }
+
+    /// 파싱할 수 없는 프레임 허용 여부 (subject 별 `invalid` 버킷)
+    ///
+    /// 에러 응답을 보내기 전에 호출한다. 거부되면 응답 없이 버린다.
+    /// subject 는 플레이어 ID, 인증 전이면 클라이언트 IP.
+    pub async fn check_invalid_message(&self, subject: &str) -> bool {
+        let subject = format!("{}:{}", subject, INVALID_MESSAGE_KIND);
+        self.check(
+            "message",
+            INVALID_MESSAGE_KIND,
+            &subject,
+            self.settings.message_rule(INVALID_MESSAGE_KIND),
+        )
+        .await
+    }
+
+    async fn check(&self, scope: &str, kind: &str, subject: &str, rule: RateLimitRule) -> bool {
+        if !self.settings.enabled {
+            return true;
+        }
+
+        let key = bucket_key(scope, subject);
+        let allowed = match self.take_shared(&key, rule).await {
+            Some(allowed) => allowed,
+            None => self.take_local(&key, rule, Instant::now()),
+        };
+
+        if !allowed {
+            metrics::RATE_LIMITED_TOTAL
+                .with_label_values(&[scope, kind])
+                .inc();
+        }
+        allowed
+    }
+
+    /// Redis 공유 버킷 (Redis 미사용 또는 오류 시 None)
+    async fn take_shared(&self, key: &str, rule: RateLimitRule) -> Option<bool> {
+        let mut redis = self.redis.clone()?;
+        let now_ms = chrono::Utc::now().timestamp_millis();
+        let script = Script::new(scripts::rate_limit_script());
+
+        let result: Result<i32, String> =
+            with_redis_timeout("rate_limit", self.timeout_secs, async {
+                script
+                    .key(key)
+                    .arg(rule.burst)
+                    .arg(rule.per_second)
+                    .arg(now_ms)
+                    .invoke_async(&mut redis)
+                    .await
+            })
+            .await;
+
+        match result {
+            Ok(allowed) => Some(allowed == 1),
+            Err(e) => {
+                warn!("Rate limit check fell back to local bucket: {}", e);
+                metrics::RATE_LIMIT_REDIS_FALLBACK_TOTAL.inc();
+                None
+            }
+        }
+    }
+
+    fn take_local(&self, key: &str, rule: RateLimitRule, now: Instant) -> bool {
+        let mut buckets = self.buckets.write().unwrap();
+        buckets
+            .entry(key.to_string())
+            .or_insert_with(|| TokenBucket::new(rule, now))
+            .try_take(rule, now)
+    }
+
+    /// 오래 쓰이지 않은 로컬 버킷 정리 (주기적으로 호출)
+    pub fn cleanup(&self) {
+        let idle = Duration::from_secs(self.settings.idle_bucket_seconds);
+        let now = Instant::now();
+        let mut buckets = self.buckets.write().unwrap();
+        buckets.retain(|_, bucket| now.saturating_duration_since(bucket.last_refill) < idle);
+    }
+
+    /// cleanup 을 idle_bucket_seconds 주기로 실행
+    pub fn spawn_cleanup(self: &Arc<Self>) {
+        let limiter = Arc::clone(self);
+        let interval = Duration::from_secs(self.settings.idle_bucket_seconds.max(1));
+        actix::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                limiter.cleanup();
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter(burst: u32, per_second: f64) -> RateLimiter {
+        let rule = RateLimitRule { burst, per_second };
+        let settings = RateLimitSettings {
+            connection: rule,
+            message: rule,
+            ..RateLimitSettings::default()
+        };
+        RateLimiter::new(settings, None, 1)
+    }
+
+    #[test]
+    fn test_local_bucket_allows_burst_then_refills() {
+        let limiter = limiter(3, 2.0);
+        let rule = limiter.settings.connection;
+        let start = Instant::now();
+
+        for _ in 0..3 {
+            assert!(limiter.take_local("k", rule, start));
+        }
+        assert!(!limiter.take_local("k", rule, start));
+
+        // 0.5 초 → 토큰 1개 회복
+        let later = start + Duration::from_millis(500);
+        assert!(limiter.take_local("k", rule, later));
+        assert!(!limiter.take_local("k", rule, later));
+
+        // 다른 키는 별도 버킷
+        assert!(limiter.take_local("other", rule, later));
+    }
+
+    #[test]
+    fn test_message_rule_overrides_default() {
+        let mut settings = RateLimitSettings::default();
+        let enqueue = RateLimitRule {
+            burst: 1,
+            per_second: 0.1,
+        };
+        settings.messages.insert("enqueue".to_string(), enqueue);
+
+        assert_eq!(settings.message_rule("enqueue"), enqueue);
+        assert_eq!(settings.message_rule("dequeue"), settings.message);
+    }
+
+    #[tokio::test]
+    async fn test_check_message_is_per_player_and_kind() {
+        let limiter = limiter(1, 0.001);
+        let player = Uuid::from_u128(1);
+
+        assert!(limiter.check_message(player, "enqueue").await);
+        assert!(!limiter.check_message(player, "enqueue").await);
+        assert!(limiter.check_message(player, "dequeue").await);
+        assert!(limiter.check_message(Uuid::from_u128(2), "enqueue").await);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_frames_share_one_bucket() {
+        let limiter = limiter(2, 0.001);
+        let player = Uuid::from_u128(1);
+
+        // 내용이 무엇이든 같은 invalid 버킷을 소모
+        assert!(limiter.check_invalid_message(&player.to_string()).await);
+        assert!(limiter.check_invalid_message(&player.to_string()).await);
+        assert!(!limiter.check_invalid_message(&player.to_string()).await);
+
+        // 정상 메시지 버킷과는 별개
+        assert!(limiter.check_message(player, "enqueue").await);
+        assert!(limiter.check_invalid_message("127.0.0.1").await);
+    }
+}
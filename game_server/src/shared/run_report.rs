@@ -0,0 +1,159 @@
+//! 런 종료 시 auth_server 로 런 요약 제출 (`POST /internal/runs`)
+//!
+//! 런 시간(`RunSummary::duration_seconds`)은 이 서버가 런 기록을 만든 시각부터 런이 끝난
+//! 시각까지 잰 값이다. auth_server 의 최단 클리어 리더보드는 이 값으로 순위를 매긴다.
+//!
+//! 연결 실패나 5xx 는 지수 백오프로 재시도하고, 4xx 는 바로 포기한다.
+//! auth_server 는 (player_id, run_seed) 로 중복 제출을 무시하므로 재시도해도 안전하다.
+
+use std::time::Duration;
+
+use backoff::ExponentialBackoff;
+use chrono::{DateTime, Utc};
+use game_core::game::managers::run_manager::RunSummary;
+use serde::Serialize;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::env::RunReportSettings;
+
+/// player_id → SteamID64 (auth_server 의 players.id). UUID 플레이어(개발용)는 None
+pub fn steam_id(player_id: Uuid) -> Option<u64> {
+    match player_id.as_u64_pair() {
+        (0, steam_id) => Some(steam_id),
+        _ => None,
+    }
+}
+
+#[derive(Serialize)]
+struct SubmitRunRequest<'a> {
+    player_id: u64,
+    started_at: DateTime<Utc>,
+    ended_at: DateTime<Utc>,
+    summary: &'a RunSummary,
+}
+
+/// auth_server 런 요약 제출기 (AppState 에서 공유)
+pub struct RunReporter {
+    /// (HTTP 클라이언트, 제출 URL). None 이면 제출하지 않음
+    endpoint: Option<(reqwest::Client, String)>,
+    service_token: String,
+    max_elapsed: Duration,
+}
+
+impl RunReporter {
+    pub fn new(settings: &RunReportSettings) -> Self {
+        let base_url = settings.auth_server_url.trim_end_matches('/');
+        if base_url.is_empty() {
+            return Self::disabled();
+        }
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(settings.request_timeout_seconds))
+            .build()
+            .unwrap_or_default();
+
+        Self {
+            endpoint: Some((client, format!("{}/internal/runs", base_url))),
+            service_token: settings.service_token.clone(),
+            max_elapsed: Duration::from_secs(settings.max_elapsed_seconds),
+        }
+    }
+
+    /// 제출하지 않는 제출기 (auth_server 미설정, 테스트)
+    pub fn disabled() -> Self {
+        Self {
+            endpoint: None,
+            service_token: String::new(),
+            max_elapsed: Duration::ZERO,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.endpoint.is_some()
+    }
+
+    /// 런 요약 제출 (재시도 포함). 제출하지 않았으면 Ok(false)
+    pub async fn submit(
+        &self,
+        player_id: Uuid,
+        started_at: DateTime<Utc>,
+        ended_at: DateTime<Utc>,
+        summary: &RunSummary,
+    ) -> Result<bool, String> {
+        let Some((client, url)) = &self.endpoint else {
+            return Ok(false);
+        };
+        let Some(steam_id) = steam_id(player_id) else {
+            return Ok(false);
+        };
+        let body = SubmitRunRequest {
+            player_id: steam_id,
+            started_at,
+            ended_at,
+            summary,
+        };
+        let backoff = ExponentialBackoff {
+            max_elapsed_time: Some(self.max_elapsed),
+            ..Default::default()
+        };
+
+        backoff::future::retry(backoff, || async {
+            let response = client
+                .post(url)
+                .header("X-Service-Token", &self.service_token)
+                .json(&body)
+                .send()
+                .await
+                .map_err(|e| {
+                    warn!("Run submission for player {} failed: {}", player_id, e);
+                    backoff::Error::transient(e.to_string())
+                })?;
+
+            let status = response.status();
+            if status.is_success() {
+                Ok(())
+            } else if status.is_client_error() {
+                Err(backoff::Error::permanent(format!(
+                    "auth_server rejected the run: {}",
+                    status
+                )))
+            } else {
+                warn!("Run submission for player {} failed: {}", player_id, status);
+                Err(backoff::Error::transient(format!(
+                    "auth_server returned {}",
+                    status
+                )))
+            }
+        })
+        .await?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_steam_id_only_for_steam_players() {
+        let steam_player = Uuid::from_u64_pair(0, 76561198000000001);
+        assert_eq!(steam_id(steam_player), Some(76561198000000001));
+        assert_eq!(steam_id(Uuid::new_v4()), None);
+    }
+
+    #[test]
+    fn test_reporter_disabled_without_url() {
+        let settings = RunReportSettings::default();
+        assert!(!RunReporter::new(&settings).is_enabled());
+
+        let settings = RunReportSettings {
+            auth_server_url: "http://127.0.0.1:3000/".to_string(),
+            ..Default::default()
+        };
+        let reporter = RunReporter::new(&settings);
+        assert_eq!(
+            reporter.endpoint.as_ref().map(|(_, url)| url.as_str()),
+            Some("http://127.0.0.1:3000/internal/runs")
+        );
+    }
+}
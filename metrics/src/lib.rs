@@ -224,6 +224,142 @@ lazy_static! {
             "Total messages routed to cross-pod players via Redis Pub/Sub"
         ))
         .unwrap();
+
+    // Ranked (MMR) matching quality
+    pub static ref RANKED_MATCH_RATING_GAP: Histogram =
+        Histogram::with_opts(HistogramOpts::new(
+            "ranked_match_rating_gap",
+            "Absolute MMR difference between matched ranked players"
+        ).buckets(vec![10.0, 25.0, 50.0, 100.0, 150.0, 200.0, 300.0, 400.0, 600.0])).unwrap();
+
+    pub static ref RANKED_WAIT_SECONDS_BY_BRACKET: HistogramVec = HistogramVec::new(
+        HistogramOpts::new(
+            "ranked_wait_seconds_by_bracket",
+            "Ranked queue wait time from enqueue to match by rating bracket (seconds)",
+        )
+        .buckets(vec![
+            1.0, 2.0, 5.0, 10.0, 20.0, 30.0, 45.0, 60.0, 90.0, 120.0, 180.0, 300.0,
+        ]),
+        &["bracket"],
+    )
+    .unwrap();
+
+    // Rate limiting
+    pub static ref RATE_LIMITED_TOTAL: IntCounterVec = IntCounterVec::new(
+        Opts::new(
+            "rate_limited_total",
+            "Requests rejected by rate limiting (scope = connection | message, kind = message type)",
+        ),
+        &["scope", "kind"],
+    )
+    .unwrap();
+    pub static ref RATE_LIMIT_REDIS_FALLBACK_TOTAL: IntCounter =
+        IntCounter::with_opts(opts!(
+            "rate_limit_redis_fallback_total",
+            "Rate limit checks that fell back to the pod-local bucket due to Redis errors"
+        ))
+        .unwrap();
+
+    // Gameplay (labels are fixed enum names only: never player/item/run ids)
+
+    /// Total number of runs started
+    pub static ref RUNS_STARTED_TOTAL: IntCounter =
+        IntCounter::with_opts(opts!("runs_started_total", "Total number of game runs started")).unwrap();
+
+    /// Total number of runs that reached each ordeal (run funnel)
+    pub static ref ORDEALS_REACHED_TOTAL: IntCounterVec = IntCounterVec::new(
+        Opts::new(
+            "ordeals_reached_total",
+            "Total runs that reached each ordeal (ordeal = dawn | noon | dusk | midnight | white)",
+        ),
+        &["ordeal"],
+    )
+    .unwrap();
+
+    /// Total number of runs ended by furthest ordeal reached and end reason
+    pub static ref RUNS_ENDED_TOTAL: IntCounterVec = IntCounterVec::new(
+        Opts::new(
+            "runs_ended_total",
+            "Total runs ended by ordeal reached (reason = cleared | ordeal_loss_limit | meltdown | abandoned)",
+        ),
+        &["ordeal", "reason"],
+    )
+    .unwrap();
+
+    /// Total number of in-run battles by event type and outcome
+    pub static ref GAME_BATTLES_TOTAL: IntCounterVec = IntCounterVec::new(
+        Opts::new(
+            "game_battles_total",
+            "Total in-run battles (kind = suppression | ordeal | white_nights, outcome = win | loss)",
+        ),
+        &["kind", "outcome"],
+    )
+    .unwrap();
+
+    /// Simulated in-run battle length by event type (seconds, capped at 60s by the simulator)
+    pub static ref GAME_BATTLE_DURATION_SECONDS: HistogramVec = HistogramVec::new(
+        HistogramOpts::new(
+            "game_battle_duration_seconds",
+            "Simulated in-run battle length by event type (seconds)",
+        )
+        .buckets(vec![5.0, 10.0, 15.0, 20.0, 30.0, 40.0, 50.0, 60.0]),
+        &["kind"],
+    )
+    .unwrap();
+
+    /// Total Enkephalin earned by source
+    pub static ref ENKEPHALIN_EARNED_TOTAL: IntCounterVec = IntCounterVec::new(
+        Opts::new(
+            "enkephalin_earned_total",
+            "Total Enkephalin earned (source = battle | shop_sell | bonus | random_event)",
+        ),
+        &["source"],
+    )
+    .unwrap();
+
+    /// Total Enkephalin spent or lost by sink
+    pub static ref ENKEPHALIN_SPENT_TOTAL: IntCounterVec = IntCounterVec::new(
+        Opts::new(
+            "enkephalin_spent_total",
+            "Total Enkephalin spent or lost (sink = battle | shop_purchase | random_event)",
+        ),
+        &["sink"],
+    )
+    .unwrap();
+
+    /// Total shop purchases by ordeal
+    pub static ref SHOP_PURCHASES_TOTAL: IntCounterVec = IntCounterVec::new(
+        Opts::new("shop_purchases_total", "Total shop purchases by ordeal"),
+        &["ordeal"],
+    )
+    .unwrap();
+
+    /// Total shop rerolls by ordeal
+    pub static ref SHOP_REROLLS_TOTAL: IntCounterVec = IntCounterVec::new(
+        Opts::new("shop_rerolls_total", "Total shop rerolls by ordeal"),
+        &["ordeal"],
+    )
+    .unwrap();
+
+    /// Total Qliphoth level transitions
+    pub static ref QLIPHOTH_LEVEL_TRANSITIONS_TOTAL: IntCounterVec = IntCounterVec::new(
+        Opts::new(
+            "qliphoth_level_transitions_total",
+            "Total Qliphoth level transitions (stable | caution | critical | meltdown)",
+        ),
+        &["from", "to"],
+    )
+    .unwrap();
+
+    /// Total player behaviors rejected by the game core by GameError variant
+    pub static ref GAME_ERRORS_TOTAL: IntCounterVec = IntCounterVec::new(
+        Opts::new(
+            "game_errors_total",
+            "Total player behaviors rejected by the game core by GameError variant",
+        ),
+        &["error"],
+    )
+    .unwrap();
 }
 
 // All test and per-mode metrics removed
@@ -283,5 +419,26 @@ pub fn register_custom_metrics(registry: &Registry) -> Result<(), prometheus::Er
     registry.register(Box::new(MESSAGES_ROUTED_SAME_POD_TOTAL.clone()))?;
     registry.register(Box::new(MESSAGES_ROUTED_CROSS_POD_TOTAL.clone()))?;
 
+    // Ranked matching quality metrics
+    registry.register(Box::new(RANKED_MATCH_RATING_GAP.clone()))?;
+    registry.register(Box::new(RANKED_WAIT_SECONDS_BY_BRACKET.clone()))?;
+
+    // Rate limiting metrics
+    registry.register(Box::new(RATE_LIMITED_TOTAL.clone()))?;
+    registry.register(Box::new(RATE_LIMIT_REDIS_FALLBACK_TOTAL.clone()))?;
+
+    // Gameplay metrics
+    registry.register(Box::new(RUNS_STARTED_TOTAL.clone()))?;
+    registry.register(Box::new(ORDEALS_REACHED_TOTAL.clone()))?;
+    registry.register(Box::new(RUNS_ENDED_TOTAL.clone()))?;
+    registry.register(Box::new(GAME_BATTLES_TOTAL.clone()))?;
+    registry.register(Box::new(GAME_BATTLE_DURATION_SECONDS.clone()))?;
+    registry.register(Box::new(ENKEPHALIN_EARNED_TOTAL.clone()))?;
+    registry.register(Box::new(ENKEPHALIN_SPENT_TOTAL.clone()))?;
+    registry.register(Box::new(SHOP_PURCHASES_TOTAL.clone()))?;
+    registry.register(Box::new(SHOP_REROLLS_TOTAL.clone()))?;
+    registry.register(Box::new(QLIPHOTH_LEVEL_TRANSITIONS_TOTAL.clone()))?;
+    registry.register(Box::new(GAME_ERRORS_TOTAL.clone()))?;
+
     Ok(())
 }
@@ -1,8 +1,14 @@
-use crate::{player_actor::PlayerContext, protocols::ErrorCode, BehaviorOutcome};
+use crate::{
+    player_actor::PlayerContext,
+    protocols::{ErrorCode, PartyInfo},
+    BehaviorOutcome,
+};
 use async_trait::async_trait;
+use uuid::Uuid;
 
 pub mod invalid;
 pub mod normal;
+pub mod party;
 pub mod quit;
 
 #[async_trait]
@@ -41,6 +47,25 @@ pub trait PlayerBehavior: Send + Sync {
         BehaviorOutcome::Complete
     }
 
+    // 파티 초대 수신
+    async fn on_party_invited(
+        &self,
+        _player: &PlayerContext,
+        _party_id: Uuid,
+        _leader_id: Uuid,
+    ) -> BehaviorOutcome {
+        BehaviorOutcome::Continue
+    }
+
+    // 파티 상태 변경 (None 이면 파티 없음)
+    async fn on_party_state(
+        &self,
+        _player: &PlayerContext,
+        _party: Option<PartyInfo>,
+    ) -> BehaviorOutcome {
+        BehaviorOutcome::Continue
+    }
+
     fn clone_trait(&self) -> Box<dyn PlayerBehavior>;
 }
 
@@ -61,6 +86,10 @@ pub enum BehaviorType {
     InvalidDequeueMissingField,
     InvalidDequeueDuplicate,
     InvalidDequeueWrongPlayerId,
+
+    // Party behaviors (member_id 는 Scenario 가 플레이어 ID 생성 후 채움)
+    PartyLeader { member_id: Option<Uuid> },
+    PartyMemberQuitInQueue,
     // Deprecated (Loading phase removed)
     // SlowLoader, SpikyLoader, TimeoutLoader
 }
@@ -103,6 +132,16 @@ impl PlayerBehavior for BehaviorType {
                     .on_connected(p)
                     .await
             }
+            BehaviorType::PartyLeader { member_id } => {
+                self::party::PartyLeader {
+                    member_id: *member_id,
+                }
+                .on_connected(p)
+                .await
+            }
+            BehaviorType::PartyMemberQuitInQueue => {
+                self::party::PartyMemberQuitInQueue.on_connected(p).await
+            }
         }
     }
 
@@ -148,6 +187,18 @@ impl PlayerBehavior for BehaviorType {
                     .on_error(p, code, m)
                     .await
             }
+            BehaviorType::PartyLeader { member_id } => {
+                self::party::PartyLeader {
+                    member_id: *member_id,
+                }
+                .on_error(p, code, m)
+                .await
+            }
+            BehaviorType::PartyMemberQuitInQueue => {
+                self::party::PartyMemberQuitInQueue
+                    .on_error(p, code, m)
+                    .await
+            }
         }
     }
 
@@ -187,6 +238,16 @@ impl PlayerBehavior for BehaviorType {
                     .on_enqueued(p)
                     .await
             }
+            BehaviorType::PartyLeader { member_id } => {
+                self::party::PartyLeader {
+                    member_id: *member_id,
+                }
+                .on_enqueued(p)
+                .await
+            }
+            BehaviorType::PartyMemberQuitInQueue => {
+                self::party::PartyMemberQuitInQueue.on_enqueued(p).await
+            }
         }
     }
 
@@ -226,6 +287,16 @@ impl PlayerBehavior for BehaviorType {
                     .on_dequeued(p)
                     .await
             }
+            BehaviorType::PartyLeader { member_id } => {
+                self::party::PartyLeader {
+                    member_id: *member_id,
+                }
+                .on_dequeued(p)
+                .await
+            }
+            BehaviorType::PartyMemberQuitInQueue => {
+                self::party::PartyMemberQuitInQueue.on_dequeued(p).await
+            }
         }
     }
 
@@ -269,6 +340,57 @@ impl PlayerBehavior for BehaviorType {
                     .on_match_found(p)
                     .await
             }
+            BehaviorType::PartyLeader { member_id } => {
+                self::party::PartyLeader {
+                    member_id: *member_id,
+                }
+                .on_match_found(p)
+                .await
+            }
+            BehaviorType::PartyMemberQuitInQueue => {
+                self::party::PartyMemberQuitInQueue.on_match_found(p).await
+            }
+        }
+    }
+
+    async fn on_party_invited(
+        &self,
+        p: &PlayerContext,
+        party_id: Uuid,
+        leader_id: Uuid,
+    ) -> BehaviorOutcome {
+        match self {
+            BehaviorType::PartyLeader { member_id } => {
+                self::party::PartyLeader {
+                    member_id: *member_id,
+                }
+                .on_party_invited(p, party_id, leader_id)
+                .await
+            }
+            BehaviorType::PartyMemberQuitInQueue => {
+                self::party::PartyMemberQuitInQueue
+                    .on_party_invited(p, party_id, leader_id)
+                    .await
+            }
+            _ => BehaviorOutcome::Continue,
+        }
+    }
+
+    async fn on_party_state(&self, p: &PlayerContext, party: Option<PartyInfo>) -> BehaviorOutcome {
+        match self {
+            BehaviorType::PartyLeader { member_id } => {
+                self::party::PartyLeader {
+                    member_id: *member_id,
+                }
+                .on_party_state(p, party)
+                .await
+            }
+            BehaviorType::PartyMemberQuitInQueue => {
+                self::party::PartyMemberQuitInQueue
+                    .on_party_state(p, party)
+                    .await
+            }
+            _ => BehaviorOutcome::Continue,
         }
     }
 
@@ -276,3 +398,15 @@ impl PlayerBehavior for BehaviorType {
         Box::new(self.clone())
     }
 }
+
+impl BehaviorType {
+    /// 파티 리더에게 초대할 상대 플레이어 ID 를 채워 넣음 (다른 behavior 는 그대로)
+    pub fn with_party_peer(self, peer_id: Uuid) -> Self {
+        match self {
+            BehaviorType::PartyLeader { member_id: None } => BehaviorType::PartyLeader {
+                member_id: Some(peer_id),
+            },
+            other => other,
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use super::{ErrorCode, PlayerBehavior};
+use crate::{
+    player_actor::{
+        message::{InternalClose, InternalSendText},
+        PlayerContext,
+    },
+    protocols::{ClientMessage, PartyInfo},
+    BehaviorOutcome,
+};
+use async_trait::async_trait;
+use tracing::info;
+use uuid::Uuid;
+
+const PARTY_GAME_MODE: &str = "Party";
+/// 초대 대상이 party_sync 로 세션을 등록할 때까지 대기
+const INVITE_DELAY_MS: u64 = 300;
+
+fn test_metadata(player_context: &PlayerContext) -> String {
+    serde_json::json!({
+        "test_session_id": player_context.test_session_id
+    })
+    .to_string()
+}
+
+/// 상대 플레이어를 초대하고, 파티가 모이면 파티 단위로 Enqueue 하는 리더
+#[derive(Debug, Clone)]
+pub struct PartyLeader {
+    pub member_id: Option<Uuid>,
+}
+
+#[async_trait]
+impl PlayerBehavior for PartyLeader {
+    async fn on_connected(&self, player_context: &PlayerContext) -> BehaviorOutcome {
+        let Some(member_id) = self.member_id else {
+            return BehaviorOutcome::Error("party member not assigned".to_string());
+        };
+
+        info!(
+            "[{}] PartyLeader connected, inviting {}",
+            player_context.player_id, member_id
+        );
+
+        let sync = ClientMessage::PartySync {
+            player_id: player_context.player_id,
+        };
+        player_context
+            .addr
+            .do_send(InternalSendText(sync.to_string()));
+
+        tokio::time::sleep(tokio::time::Duration::from_millis(INVITE_DELAY_MS)).await;
+
+        let invite = ClientMessage::PartyInvite {
+            player_id: player_context.player_id,
+            target_player_id: member_id,
+        };
+        player_context
+            .addr
+            .do_send(InternalSendText(invite.to_string()));
+        BehaviorOutcome::Continue
+    }
+
+    /// 멤버가 수락해 파티가 모이면 Enqueue (대기 중이거나 멤버가 나간 뒤에는 무시)
+    async fn on_party_state(
+        &self,
+        player_context: &PlayerContext,
+        party: Option<PartyInfo>,
+    ) -> BehaviorOutcome {
+        let Some(party) = party else {
+            return BehaviorOutcome::Continue;
+        };
+        if party.in_queue || party.members.len() < 2 {
+            return BehaviorOutcome::Continue;
+        }
+
+        info!(
+            "[{}] Party {} ready, sending Enqueue",
+            player_context.player_id, party.party_id
+        );
+
+        let msg = ClientMessage::Enqueue {
+            player_id: player_context.player_id,
+            game_mode: PARTY_GAME_MODE.to_string(),
+            metadata: test_metadata(player_context),
+        };
+        player_context
+            .addr
+            .do_send(InternalSendText(msg.to_string()));
+        BehaviorOutcome::Continue
+    }
+
+    /// 멤버가 나가 파티 전체가 대기열에서 빠짐
+    async fn on_dequeued(&self, player_context: &PlayerContext) -> BehaviorOutcome {
+        info!(
+            "[{}] Party dequeued together with its member",
+            player_context.player_id
+        );
+        BehaviorOutcome::Complete
+    }
+
+    fn clone_trait(&self) -> Box<dyn PlayerBehavior> {
+        Box::new(self.clone())
+    }
+}
+
+/// 초대를 수락하고, 파티가 대기열에 들어가면 연결을 끊는 멤버
+#[derive(Debug, Clone)]
+pub struct PartyMemberQuitInQueue;
+
+#[async_trait]
+impl PlayerBehavior for PartyMemberQuitInQueue {
+    /// party_sync 로 세션을 등록해야 초대를 받을 수 있음
+    async fn on_connected(&self, player_context: &PlayerContext) -> BehaviorOutcome {
+        let msg = ClientMessage::PartySync {
+            player_id: player_context.player_id,
+        };
+        player_context
+            .addr
+            .do_send(InternalSendText(msg.to_string()));
+        BehaviorOutcome::Continue
+    }
+
+    async fn on_party_invited(
+        &self,
+        player_context: &PlayerContext,
+        party_id: Uuid,
+        leader_id: Uuid,
+    ) -> BehaviorOutcome {
+        info!(
+            "[{}] Accepting party invite from {}",
+            player_context.player_id, leader_id
+        );
+
+        let msg = ClientMessage::PartyAccept {
+            player_id: player_context.player_id,
+            party_id,
+            metadata: test_metadata(player_context),
+        };
+        player_context
+            .addr
+            .do_send(InternalSendText(msg.to_string()));
+        BehaviorOutcome::Continue
+    }
+
+    /// 리더가 파티를 Enqueue 한 직후 연결 종료 → 파티 전체 dequeue
+    async fn on_enqueued(&self, player_context: &PlayerContext) -> BehaviorOutcome {
+        tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+        player_context.addr.do_send(InternalClose);
+        BehaviorOutcome::IntendError
+    }
+
+    async fn on_error(
+        &self,
+        _player_context: &PlayerContext,
+        _error_code: ErrorCode,
+        _error_msg: &str,
+    ) -> BehaviorOutcome {
+        BehaviorOutcome::Continue
+    }
+
+    fn clone_trait(&self) -> Box<dyn PlayerBehavior> {
+        Box::new(self.clone())
+    }
+}
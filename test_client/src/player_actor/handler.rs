@@ -131,6 +131,17 @@ impl StreamHandler<Result<Message, tokio_tungstenite::tungstenite::Error>> for P
                 }
                 ServerMessage::DeQueued => behavior.on_dequeued(&player_ctx).await,
                 ServerMessage::MatchFound { .. } => behavior.on_match_found(&player_ctx).await,
+                ServerMessage::PartyInvited {
+                    party_id,
+                    leader_id,
+                } => {
+                    behavior
+                        .on_party_invited(&player_ctx, party_id, leader_id)
+                        .await
+                }
+                ServerMessage::PartyState { party } => {
+                    behavior.on_party_state(&player_ctx, party).await
+                }
                 ServerMessage::Error { code, message } => {
                     behavior.on_error(&player_ctx, code, message.as_str()).await
                 }
@@ -230,6 +241,18 @@ impl PlayerActor {
                 );
                 self.state = PlayerState::Matched;
             }
+            ServerMessage::PartyInvited {
+                party_id,
+                leader_id,
+            } => {
+                info!(
+                    "[{}] Party invite from {} (party {})",
+                    self.player_id, leader_id, party_id
+                );
+            }
+            ServerMessage::PartyState { party } => {
+                info!("[{}] Party state: {:?}", self.player_id, party);
+            }
             ServerMessage::Error { code, message } => {
                 error!(
                     "[{}] Server error: {:?} - {}",
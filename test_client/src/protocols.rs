@@ -12,6 +12,21 @@ pub enum ClientMessage {
     },
     #[serde(rename = "dequeue")]
     Dequeue { player_id: Uuid, game_mode: String },
+    #[serde(rename = "party_sync")]
+    PartySync { player_id: Uuid },
+    #[serde(rename = "party_invite")]
+    PartyInvite {
+        player_id: Uuid,
+        target_player_id: Uuid,
+    },
+    #[serde(rename = "party_accept")]
+    PartyAccept {
+        player_id: Uuid,
+        party_id: Uuid,
+        metadata: String,
+    },
+    #[serde(rename = "party_leave")]
+    PartyLeave { player_id: Uuid },
 }
 
 impl ClientMessage {
@@ -34,10 +49,22 @@ pub enum ServerMessage {
         #[serde(skip_serializing_if = "Option::is_none")]
         battle_data: Option<serde_json::Value>,
     },
+    #[serde(rename = "party_invited")]
+    PartyInvited { party_id: Uuid, leader_id: Uuid },
+    #[serde(rename = "party_state")]
+    PartyState { party: Option<PartyInfo> },
     #[serde(rename = "error")]
     Error { code: ErrorCode, message: String },
 }
 
+#[derive(Deserialize, Debug, PartialEq, Clone)]
+pub struct PartyInfo {
+    pub party_id: Uuid,
+    pub leader_id: Uuid,
+    pub members: Vec<Uuid>,
+    pub in_queue: bool,
+}
+
 #[derive(Deserialize, Debug, PartialEq, Clone, Copy)]
 #[serde(rename_all = "snake_case")]
 pub enum ErrorCode {
@@ -54,4 +81,11 @@ pub enum ErrorCode {
     MatchmakingTimeout,
     PlayerTemporarilyBlocked,
     RateLimitExceeded,
+    NotInParty,
+    AlreadyInParty,
+    NotPartyLeader,
+    PartyFull,
+    PartyInQueue,
+    PartyInviteNotFound,
+    InvalidPartyTarget,
 }
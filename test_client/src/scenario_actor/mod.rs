@@ -62,8 +62,17 @@ impl Scenario {
 
         let observer_addr = observer.start();
 
-        let normal_behavior = Box::new(self.normal_behavior.clone());
-        let abnormal_behavior = Box::new(self.abnormal_behavior.clone());
+        // 파티 behavior 는 상대 플레이어를 초대해야 하므로 ID 를 채워 넣음
+        let normal_behavior = Box::new(
+            self.normal_behavior
+                .clone()
+                .with_party_peer(abnormal_player_id),
+        );
+        let abnormal_behavior = Box::new(
+            self.abnormal_behavior
+                .clone()
+                .with_party_peer(normal_player_id),
+        );
 
         let normal_actor = PlayerActor::new(
             observer_addr.clone(),
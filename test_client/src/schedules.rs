@@ -106,6 +106,42 @@ fn build_schedule_for_behavior(behavior: &BehaviorType) -> HashMap<Phase, PhaseC
 
             schedule
         }
+        // 파티 멤버가 대기 중 연결 종료 → 리더와 멤버 모두 파티 단위로 dequeue
+        BehaviorType::PartyLeader { .. } | BehaviorType::PartyMemberQuitInQueue => {
+            let mut schedule = HashMap::new();
+
+            // Enqueuing Phase: 리더의 파티 Enqueue 로 PlayerEnqueued 받으면 InQueue로 전환
+            schedule.insert(
+                Phase::Enqueuing,
+                PhaseCondition {
+                    required_events: vec![],
+                    transition_event: EventRequirement::new(EventType::PlayerEnqueued),
+                    next_phase: Phase::InQueue,
+                },
+            );
+
+            // InQueue Phase: GlobalQueueSizeChanged 필수, PlayerDequeued 받으면 Dequeued로 전환
+            schedule.insert(
+                Phase::InQueue,
+                PhaseCondition {
+                    required_events: vec![EventRequirement::new(EventType::GlobalQueueSizeChanged)],
+                    transition_event: EventRequirement::new(EventType::PlayerDequeued),
+                    next_phase: Phase::Dequeued,
+                },
+            );
+
+            // Dequeued Phase: GlobalQueueSizeChanged 받으면 Finished로 전환
+            schedule.insert(
+                Phase::Dequeued,
+                PhaseCondition {
+                    required_events: vec![],
+                    transition_event: EventRequirement::new(EventType::GlobalQueueSizeChanged),
+                    next_phase: Phase::Finished,
+                },
+            );
+
+            schedule
+        }
         // Invalid Enqueue behaviors - Enqueued 후 잘못된 메시지로 인한 에러
         BehaviorType::InvalidEnqueueUnknownType
         | BehaviorType::InvalidEnqueueMissingField
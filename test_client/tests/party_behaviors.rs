@@ -0,0 +1,36 @@
+use test_client::{
+    behaviors::BehaviorType, scenario_actor::Scenario, setup_logger,
+    test_utils::flush_redis_default,
+};
+use uuid::Uuid;
+
+#[actix::test]
+async fn test_party_member_quit_in_queue() {
+    // Setup logger
+    setup_logger();
+    flush_redis_default().await.unwrap();
+
+    // Create completion channel
+    let (tx, rx) = tokio::sync::oneshot::channel::<bool>();
+
+    // Create scenario
+    let scenario = Scenario {
+        id: Uuid::new_v4(),
+        name: "PartyLeader vs PartyMemberQuitInQueue".to_string(),
+        description: "Leader invites member and enqueues the party, member disconnects in queue and the whole party is dequeued"
+            .to_string(),
+        normal_behavior: BehaviorType::PartyLeader { member_id: None },
+        abnormal_behavior: BehaviorType::PartyMemberQuitInQueue,
+    };
+
+    // Run scenario
+    let _observer_addr = scenario.run(Some(tx));
+
+    // Wait for test completion with timeout
+    let result = tokio::time::timeout(tokio::time::Duration::from_secs(30), rx)
+        .await
+        .expect("Test timed out after 30 seconds")
+        .expect("Failed to receive completion signal");
+
+    assert!(result, "Test should succeed");
+}